@@ -0,0 +1,119 @@
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+use resym_core::pdb_types::PrimitiveReconstructionFlavor;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    resym_app::LeftPanelTab,
+    settings::{PrimitiveReconstructionFlavorDef, ResymAppSettings},
+    ui_components::PersistedDocumentTab,
+};
+
+/// Extension of session files
+pub const SESSION_FILE_EXTENSION: &str = "resym";
+/// Maximum number of entries kept in the lists of recent files and sessions
+const MAX_RECENT_ENTRY_COUNT: usize = 10;
+
+/// Location a PDB file has been loaded from
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub enum PdbLocation {
+    Path(PathBuf),
+    Url(String),
+}
+
+impl fmt::Display for PdbLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PdbLocation::Path(path) => write!(f, "{}", path.display()),
+            PdbLocation::Url(url) => write!(f, "{url}"),
+        }
+    }
+}
+
+/// Settings that affect the content of the views, which override the
+/// application's settings when a session is loaded
+#[derive(Serialize, Deserialize)]
+pub struct SessionSettings {
+    #[serde(with = "PrimitiveReconstructionFlavorDef")]
+    pub primitive_types_flavor: PrimitiveReconstructionFlavor,
+    pub print_header: bool,
+    pub reconstruct_dependencies: bool,
+    pub print_access_specifiers: bool,
+    pub integers_as_hexadecimal: bool,
+    pub ignore_std_types: bool,
+    pub search_case_insensitive: bool,
+    pub search_use_regex: bool,
+}
+
+impl SessionSettings {
+    pub fn new(app_settings: &ResymAppSettings) -> Self {
+        Self {
+            primitive_types_flavor: app_settings.primitive_types_flavor,
+            print_header: app_settings.print_header,
+            reconstruct_dependencies: app_settings.reconstruct_dependencies,
+            print_access_specifiers: app_settings.print_access_specifiers,
+            integers_as_hexadecimal: app_settings.integers_as_hexadecimal,
+            ignore_std_types: app_settings.ignore_std_types,
+            search_case_insensitive: app_settings.search_case_insensitive,
+            search_use_regex: app_settings.search_use_regex,
+        }
+    }
+
+    /// Override the given settings with the session's settings
+    pub fn apply(&self, app_settings: &mut ResymAppSettings) {
+        app_settings.primitive_types_flavor = self.primitive_types_flavor;
+        app_settings.print_header = self.print_header;
+        app_settings.reconstruct_dependencies = self.reconstruct_dependencies;
+        app_settings.print_access_specifiers = self.print_access_specifiers;
+        app_settings.integers_as_hexadecimal = self.integers_as_hexadecimal;
+        app_settings.ignore_std_types = self.ignore_std_types;
+        app_settings.search_case_insensitive = self.search_case_insensitive;
+        app_settings.search_use_regex = self.search_use_regex;
+    }
+}
+
+/// Set of PDB files and views, which can be saved to a file to be restored
+/// later.
+/// Note: the mode of the application (i.e., browsing or comparing) is implied
+/// by the PDB files.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppSession {
+    /// PDB file to browse (or to compare from)
+    pub main_pdb: Option<PdbLocation>,
+    /// PDB file to compare to, if any
+    pub diff_pdb: Option<PdbLocation>,
+    pub tabs: Vec<PersistedDocumentTab>,
+    pub active_tab_index: usize,
+    pub left_panel_tab: LeftPanelTab,
+    pub type_filter: String,
+    pub symbol_filter: String,
+    pub module_filter: String,
+    /// Settings to apply when loading the session (the application's
+    /// settings are left untouched if `None`)
+    pub settings: Option<SessionSettings>,
+}
+
+impl AppSession {
+    pub fn load_from_file(file_path: &Path) -> Result<Self> {
+        Ok(ron::from_str(&fs::read_to_string(file_path)?)?)
+    }
+
+    pub fn save_to_file(&self, file_path: &Path) -> Result<()> {
+        let serialized_session = ron::ser::to_string_pretty(self, Default::default())?;
+        fs::write(file_path, serialized_session)?;
+
+        Ok(())
+    }
+}
+
+/// Move (or add) the given entry to the top of a list of recent entries
+pub fn push_recent_entry<T: PartialEq>(recent_entries: &mut Vec<T>, entry: T) {
+    recent_entries.retain(|recent_entry| *recent_entry != entry);
+    recent_entries.insert(0, entry);
+    recent_entries.truncate(MAX_RECENT_ENTRY_COUNT);
+}
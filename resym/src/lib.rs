@@ -7,6 +7,8 @@ mod module_tree;
 #[cfg(target_arch = "wasm32")]
 mod module_tree_view;
 #[cfg(target_arch = "wasm32")]
+mod navigation_history;
+#[cfg(target_arch = "wasm32")]
 mod resym_app;
 #[cfg(target_arch = "wasm32")]
 mod settings;
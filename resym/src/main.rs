@@ -1,15 +1,17 @@
 #![windows_subsystem = "windows"]
 
+mod app_session;
 mod frontend;
 mod mode;
 mod module_tree;
 mod module_tree_view;
+mod navigation_history;
 mod resym_app;
 mod settings;
 mod syntax_highlighting;
 mod ui_components;
 
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 
 use anyhow::{anyhow, Result};
 use memory_logger::blocking::MemoryLogger;
@@ -20,6 +22,8 @@ const PKG_NAME: &str = env!("CARGO_PKG_NAME");
 
 fn main() -> Result<()> {
     let logger = MemoryLogger::setup(log::Level::Info)?;
+    // Usage: resym [SESSION_FILE]
+    let session_file_path = std::env::args_os().nth(1).map(PathBuf::from);
     let viewport = if let Some(icon) = load_icon() {
         eframe::egui::ViewportBuilder::default().with_icon(Arc::new(icon))
     } else {
@@ -35,9 +39,12 @@ fn main() -> Result<()> {
         PKG_NAME,
         native_options,
         Box::new(|cc| {
-            Ok(Box::new(
-                ResymApp::new(cc, logger).expect("application creation"),
-            ))
+            let mut app = ResymApp::new(cc, logger).expect("application creation");
+            if let Some(session_file_path) = session_file_path {
+                app.load_session_file(&session_file_path);
+            }
+
+            Ok(Box::new(app))
         }),
     )
     .map_err(|err| anyhow!("eframe::run_native failed: {err}"))
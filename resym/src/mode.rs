@@ -1,11 +1,24 @@
-use resym_core::diffing::DiffChange;
+use crate::syntax_highlighting::LineDescriptions;
 
-#[derive(PartialEq)]
+#[derive(PartialEq, Hash)]
 pub enum ResymAppMode {
     /// Mode in which the application starts
     Idle,
     /// This mode means we're browsing a single PDB file
     Browsing(String, usize, String),
     /// This mode means we're comparing two PDB files for differences
-    Comparing(String, String, usize, Vec<DiffChange>, String),
+    Comparing(usize, LineDescriptions, String),
+}
+
+impl ResymAppMode {
+    /// Return the same mode, without any reconstructed content
+    pub fn without_content(&self) -> Self {
+        match self {
+            ResymAppMode::Idle => ResymAppMode::Idle,
+            ResymAppMode::Browsing(..) => {
+                ResymAppMode::Browsing(String::default(), 0, String::default())
+            }
+            ResymAppMode::Comparing(..) => ResymAppMode::Comparing(0, vec![], String::default()),
+        }
+    }
 }
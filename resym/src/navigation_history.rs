@@ -0,0 +1,171 @@
+use eframe::egui;
+use resym_core::{
+    annotations::Bookmark,
+    pdb_file::{ModuleIndex, SymbolIndex, TypeIndex},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::resym_app::LeftPanelTab;
+
+/// Item displayed in the code view
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub enum NavigationTarget {
+    Type {
+        type_name: String,
+        type_index: TypeIndex,
+    },
+    Symbol {
+        symbol_name: String,
+        symbol_index: Option<SymbolIndex>,
+    },
+    Module {
+        module_path: String,
+        module_index: ModuleIndex,
+    },
+}
+
+impl From<&NavigationTarget> for Bookmark {
+    fn from(target: &NavigationTarget) -> Self {
+        match target.clone() {
+            NavigationTarget::Type {
+                type_name,
+                type_index,
+            } => Bookmark::Type {
+                type_name,
+                type_index,
+            },
+            NavigationTarget::Symbol {
+                symbol_name,
+                symbol_index,
+            } => Bookmark::Symbol {
+                symbol_name,
+                symbol_index,
+            },
+            NavigationTarget::Module {
+                module_path,
+                module_index,
+            } => Bookmark::Module {
+                module_path,
+                module_index,
+            },
+        }
+    }
+}
+
+impl From<Bookmark> for NavigationTarget {
+    fn from(bookmark: Bookmark) -> Self {
+        match bookmark {
+            Bookmark::Type {
+                type_name,
+                type_index,
+            } => NavigationTarget::Type {
+                type_name,
+                type_index,
+            },
+            Bookmark::Symbol {
+                symbol_name,
+                symbol_index,
+            } => NavigationTarget::Symbol {
+                symbol_name,
+                symbol_index,
+            },
+            Bookmark::Module {
+                module_path,
+                module_index,
+            } => NavigationTarget::Module {
+                module_path,
+                module_index,
+            },
+        }
+    }
+}
+
+/// Entry of the navigation history, which contains everything needed to
+/// restore the state of the application's views
+#[derive(Clone)]
+pub struct NavigationEntry {
+    pub target: NavigationTarget,
+    /// Left panel tab the item was opened from
+    pub left_panel_tab: LeftPanelTab,
+    /// Last known scroll position of the code view
+    pub scroll_offset: egui::Vec2,
+}
+
+/// Back/forward navigation history, akin to the ones found in web browsers
+#[derive(Default)]
+pub struct NavigationHistory {
+    back_entries: Vec<NavigationEntry>,
+    current_entry: Option<NavigationEntry>,
+    forward_entries: Vec<NavigationEntry>,
+}
+
+impl NavigationHistory {
+    /// Maximum number of entries kept in each direction
+    const MAX_ENTRY_COUNT: usize = 256;
+
+    /// Record a navigation to the given target, which discards the "forward"
+    /// part of the history
+    pub fn push(&mut self, target: NavigationTarget, left_panel_tab: LeftPanelTab) {
+        if let Some(current_entry) = &mut self.current_entry {
+            if current_entry.target == target {
+                // Note: still update the tab, in case the item has been
+                // opened from somewhere else
+                current_entry.left_panel_tab = left_panel_tab;
+                return;
+            }
+        }
+
+        if let Some(current_entry) = self.current_entry.take() {
+            self.back_entries.push(current_entry);
+            if self.back_entries.len() > Self::MAX_ENTRY_COUNT {
+                self.back_entries.remove(0);
+            }
+        }
+        self.forward_entries.clear();
+        self.current_entry = Some(NavigationEntry {
+            target,
+            left_panel_tab,
+            scroll_offset: egui::Vec2::ZERO,
+        });
+    }
+
+    /// Return the entry currently displayed, if any
+    pub fn current_entry(&self) -> Option<&NavigationEntry> {
+        self.current_entry.as_ref()
+    }
+
+    /// Update the scroll position of the current entry
+    pub fn set_scroll_offset(&mut self, scroll_offset: egui::Vec2) {
+        if let Some(current_entry) = &mut self.current_entry {
+            current_entry.scroll_offset = scroll_offset;
+        }
+    }
+
+    pub fn can_go_back(&self) -> bool {
+        !self.back_entries.is_empty()
+    }
+
+    pub fn can_go_forward(&self) -> bool {
+        !self.forward_entries.is_empty()
+    }
+
+    /// Move one entry back in the history and return the new current entry
+    pub fn go_back(&mut self) -> Option<&NavigationEntry> {
+        let previous_entry = self.back_entries.pop()?;
+        if let Some(current_entry) = self.current_entry.replace(previous_entry) {
+            self.forward_entries.push(current_entry);
+        }
+
+        self.current_entry.as_ref()
+    }
+
+    /// Move one entry forward in the history and return the new current entry
+    pub fn go_forward(&mut self) -> Option<&NavigationEntry> {
+        let next_entry = self.forward_entries.pop()?;
+        if let Some(current_entry) = self.current_entry.replace(next_entry) {
+            self.back_entries.push(current_entry);
+        }
+
+        self.current_entry.as_ref()
+    }
+}
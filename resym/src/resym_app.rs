@@ -2,30 +2,57 @@ use anyhow::Result;
 use eframe::egui;
 use memory_logger::blocking::MemoryLogger;
 use resym_core::{
+    annotations::{Annotations, Bookmark},
     backend::{Backend, BackendCommand, PDBSlot},
+    field_search::FieldSearchQuery,
     frontend::FrontendCommand,
-    pdb_file::{SymbolIndex, TypeIndex},
+    inline_sites::{InlineSite, InlineSiteList},
+    layout_search::LayoutQuery,
+    options::FilterOptions,
+    pdb_file::{SymbolIndex, TypeIndex, TypeList},
+    type_graph::TypeGraphQuery,
 };
+#[cfg(not(target_arch = "wasm32"))]
+use resym_core::{index_cache::IndexCache, symbol_store::SymbolPath};
+use serde::{Deserialize, Serialize};
 
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::{Path, PathBuf};
 #[cfg(target_arch = "wasm32")]
 use std::{cell::RefCell, rc::Rc};
 use std::{fmt::Write, sync::Arc, vec};
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::app_session::{
+    push_recent_entry, AppSession, PdbLocation, SessionSettings, SESSION_FILE_EXTENSION,
+};
 #[cfg(feature = "http")]
 use crate::ui_components::OpenURLComponent;
 use crate::{
     frontend::EguiFrontendController,
     mode::ResymAppMode,
     module_tree::{ModuleInfo, ModulePath},
+    navigation_history::{NavigationEntry, NavigationTarget},
     settings::ResymAppSettings,
     ui_components::{
-        CodeViewComponent, ConsoleComponent, IndexListComponent, IndexListOrdering,
-        ModuleTreeComponent, SettingsComponent, TextSearchComponent,
+        AnnotationsChange, BookmarksComponent, ClassHierarchyComponent, CodeViewComponent,
+        ConsoleComponent, DocumentTabsComponent, FieldSearchComponent, IndexListComponent,
+        IndexListOrdering, LayoutSearchComponent, ModuleTreeComponent, ProgressComponent,
+        SettingsComponent, TextSearchComponent, TypeDiffListComponent, TypeGraphComponent,
+        TypeLayoutComponent,
     },
 };
 
 const PKG_NAME: &str = env!("CARGO_PKG_NAME");
 const PKG_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Key used to persist the open tabs
+const DOCUMENT_TABS_KEY: &str = "document_tabs";
+/// Key used to persist the list of recently opened PDB files
+#[cfg(not(target_arch = "wasm32"))]
+const RECENT_FILES_KEY: &str = "recent_files";
+/// Key used to persist the list of recently opened session files
+#[cfg(not(target_arch = "wasm32"))]
+const RECENT_SESSIONS_KEY: &str = "recent_sessions";
 
 #[derive(Clone, Copy)]
 pub enum ResymPDBSlots {
@@ -42,11 +69,14 @@ impl From<ResymPDBSlots> for PDBSlot {
 }
 
 /// Tabs available for the left-side panel
-#[derive(PartialEq)]
-enum LeftPanelTab {
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) enum LeftPanelTab {
+    #[default]
     TypeSearch,
     SymbolSearch,
     ModuleBrowsing,
+    FieldSearch,
+    Bookmarks,
 }
 
 /// Tabs available for the bottom panel
@@ -55,45 +85,93 @@ enum BottomPanelTab {
     Console,
     XRefsTo,
     XRefsFrom,
+    InlineSites,
+    TypeGraph,
+    ClassHierarchy,
+    MemoryLayout,
 }
 
 /// Struct that represents our GUI application.
 /// It contains the whole application's context at all time.
 pub struct ResymApp {
+    /// Mode of the application, which also holds the active tab's content
     current_mode: ResymAppMode,
+    document_tabs: DocumentTabsComponent,
+    /// Scroll position to restore once the content opened from the navigation
+    /// history has been received
+    pending_scroll_offset: Option<egui::Vec2>,
     // Components used in the left-side panel
     left_panel_selected_tab: LeftPanelTab,
     type_search: TextSearchComponent,
     type_list: IndexListComponent<TypeIndex>,
+    /// List of types displayed instead of `type_list` when comparing PDBs
+    type_diff_list: TypeDiffListComponent,
     selected_type_index: Option<TypeIndex>,
+    selected_type_name: Option<String>,
     symbol_search: TextSearchComponent,
     symbol_list: IndexListComponent<SymbolIndex>,
     selected_symbol_index: Option<SymbolIndex>,
+    selected_symbol_name: Option<String>,
     module_search: TextSearchComponent,
     module_tree: ModuleTreeComponent,
+    field_search: FieldSearchComponent,
+    /// Bookmarks and notes attached to the main PDB file
+    bookmarks: BookmarksComponent,
     code_view: CodeViewComponent,
     // Components used in the bottom panel
     bottom_panel_selected_tab: BottomPanelTab,
     console: ConsoleComponent,
     xref_to_list: IndexListComponent<TypeIndex>,
     xref_from_list: IndexListComponent<TypeIndex>,
+    inline_site_list: IndexListComponent<usize>,
+    inline_sites: InlineSiteList,
+    type_graph: TypeGraphComponent,
+    class_hierarchy: ClassHierarchyComponent,
+    type_layout: TypeLayoutComponent,
     // Other components
+    layout_search: LayoutSearchComponent,
+    progress: ProgressComponent,
     settings: SettingsComponent,
     #[cfg(feature = "http")]
     open_url: OpenURLComponent,
     frontend_controller: Arc<EguiFrontendController>,
     backend: Backend,
+    /// Locations of the PDB files loaded in each slot, saved in session files
+    #[cfg(not(target_arch = "wasm32"))]
+    pdb_locations: [Option<PdbLocation>; 2],
+    /// PDB file used for diffing to load once the main PDB file has been
+    /// loaded (e.g., when restoring a session)
+    #[cfg(not(target_arch = "wasm32"))]
+    pending_diff_pdb: Option<PdbLocation>,
+    #[cfg(not(target_arch = "wasm32"))]
+    recent_files: Vec<PdbLocation>,
+    #[cfg(not(target_arch = "wasm32"))]
+    recent_sessions: Vec<PathBuf>,
     /// Field used by wasm32 targets to store PDB file information
     /// temporarily when selecting a PDB file to open.
     #[cfg(target_arch = "wasm32")]
     open_pdb_data: Rc<RefCell<Option<(PDBSlot, String, Vec<u8>)>>>,
+    /// Field used by wasm32 targets to store PE file information
+    /// temporarily when selecting a PE file to open.
+    #[cfg(target_arch = "wasm32")]
+    open_pe_data: Rc<RefCell<Option<(PDBSlot, String, Vec<u8>)>>>,
 }
 
 // GUI-related trait
 impl eframe::App for ResymApp {
     fn save(&mut self, storage: &mut dyn eframe::Storage) {
-        // Save settings on shutdown
+        // Save settings and open tabs on shutdown
         eframe::set_value(storage, eframe::APP_KEY, &self.settings.app_settings);
+        eframe::set_value(
+            storage,
+            DOCUMENT_TABS_KEY,
+            &self.document_tabs.persisted_tabs(),
+        );
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            eframe::set_value(storage, RECENT_FILES_KEY, &self.recent_files);
+            eframe::set_value(storage, RECENT_SESSIONS_KEY, &self.recent_sessions);
+        }
     }
 
     /// Called each time the UI needs repainting, which may be many times per second.
@@ -103,6 +181,12 @@ impl eframe::App for ResymApp {
         // check for PDB file opening results manually in an non-blocking way.
         #[cfg(target_arch = "wasm32")]
         self.process_open_pdb_file_result();
+        #[cfg(target_arch = "wasm32")]
+        self.process_open_pe_file_result();
+
+        // Switch to the tab requested during the last frame, if any, so that
+        // incoming results are displayed in the right tab
+        self.activate_requested_document_tab();
 
         // Process incoming commands, if any
         self.process_ui_commands();
@@ -113,9 +197,14 @@ impl eframe::App for ResymApp {
         // Update the "Settings" window if open
         self.settings.update(ctx);
 
+        // Update the "Match layout" window if open
+        self.update_layout_search_window(ctx);
+
         // Update "Open URL" window if open
         #[cfg(feature = "http")]
-        self.open_url.update(ctx, &self.backend);
+        if let Some((pdb_slot, url)) = self.open_url.update(ctx) {
+            self.load_pdb_from_url(pdb_slot.into(), url);
+        }
 
         // Update the top panel (i.e, menu bar)
         self.update_top_panel(ctx);
@@ -152,37 +241,74 @@ impl ResymApp {
         ));
         let backend = Backend::new(frontend_controller.clone())?;
 
-        // Load settings on launch
-        let app_settings = if let Some(storage) = cc.storage {
-            eframe::get_value(storage, eframe::APP_KEY).unwrap_or_default()
+        // Load settings and open tabs on launch
+        let (app_settings, persisted_tabs) = if let Some(storage) = cc.storage {
+            (
+                eframe::get_value(storage, eframe::APP_KEY).unwrap_or_default(),
+                eframe::get_value(storage, DOCUMENT_TABS_KEY).unwrap_or_default(),
+            )
+        } else {
+            (ResymAppSettings::default(), vec![])
+        };
+        // Load the lists of recent files and sessions
+        #[cfg(not(target_arch = "wasm32"))]
+        let (recent_files, recent_sessions) = if let Some(storage) = cc.storage {
+            (
+                eframe::get_value(storage, RECENT_FILES_KEY).unwrap_or_default(),
+                eframe::get_value(storage, RECENT_SESSIONS_KEY).unwrap_or_default(),
+            )
         } else {
-            ResymAppSettings::default()
+            (vec![], vec![])
         };
 
         log::info!("{} {}", PKG_NAME, PKG_VERSION);
         Ok(Self {
             current_mode: ResymAppMode::Idle,
+            document_tabs: DocumentTabsComponent::new(persisted_tabs),
+            pending_scroll_offset: None,
             left_panel_selected_tab: LeftPanelTab::TypeSearch,
             type_search: TextSearchComponent::new(),
             type_list: IndexListComponent::new(IndexListOrdering::Alphabetical),
+            type_diff_list: TypeDiffListComponent::new(),
             selected_type_index: None,
+            selected_type_name: None,
             symbol_search: TextSearchComponent::new(),
             symbol_list: IndexListComponent::new(IndexListOrdering::Alphabetical),
             selected_symbol_index: None,
+            selected_symbol_name: None,
             module_search: TextSearchComponent::new(),
             module_tree: ModuleTreeComponent::new(),
+            field_search: FieldSearchComponent::new(),
+            bookmarks: BookmarksComponent::new(),
             code_view: CodeViewComponent::new(),
             bottom_panel_selected_tab: BottomPanelTab::Console,
             console: ConsoleComponent::new(logger),
             xref_to_list: IndexListComponent::new(IndexListOrdering::Alphabetical),
             xref_from_list: IndexListComponent::new(IndexListOrdering::Alphabetical),
+            inline_site_list: IndexListComponent::new(IndexListOrdering::None),
+            inline_sites: vec![],
+            type_graph: TypeGraphComponent::new(),
+            class_hierarchy: ClassHierarchyComponent::new(),
+            type_layout: TypeLayoutComponent::new(),
+            layout_search: LayoutSearchComponent::new(),
+            progress: ProgressComponent::default(),
             settings: SettingsComponent::new(app_settings),
             #[cfg(feature = "http")]
             open_url: OpenURLComponent::new(),
             frontend_controller,
             backend,
+            #[cfg(not(target_arch = "wasm32"))]
+            pdb_locations: Default::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            pending_diff_pdb: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            recent_files,
+            #[cfg(not(target_arch = "wasm32"))]
+            recent_sessions,
             #[cfg(target_arch = "wasm32")]
             open_pdb_data: Rc::new(RefCell::new(None)),
+            #[cfg(target_arch = "wasm32")]
+            open_pe_data: Rc::new(RefCell::new(None)),
         })
     }
 
@@ -195,6 +321,52 @@ impl ResymApp {
         ctx.set_visuals(theme);
     }
 
+    fn update_layout_search_window(&mut self, ctx: &egui::Context) {
+        let mut selected_type = None;
+        if let Some((query, max_results)) =
+            self.layout_search
+                .update(ctx, &mut |type_name: &str, type_index: TypeIndex| {
+                    selected_type = Some((type_name.to_string(), type_index));
+                })
+        {
+            self.match_layout(query, max_results);
+        }
+
+        // Reconstruct the type selected in the result list, if any
+        if let Some((type_name, type_index)) = selected_type {
+            let backend_command = match self.current_mode {
+                ResymAppMode::Browsing(..) => BackendCommand::ReconstructTypeByIndex(
+                    ResymPDBSlots::Main as usize,
+                    type_index,
+                    self.settings.app_settings.reconstruction_options(),
+                ),
+                ResymAppMode::Comparing(..) => BackendCommand::DiffTypeByName(
+                    ResymPDBSlots::Main as usize,
+                    ResymPDBSlots::Diff as usize,
+                    type_name.clone(),
+                    self.settings.app_settings.reconstruction_options(),
+                ),
+                _ => {
+                    log::error!("Invalid application state");
+                    return;
+                }
+            };
+            self.document_tabs.open(
+                NavigationTarget::Type {
+                    type_name: type_name.clone(),
+                    type_index,
+                },
+                self.left_panel_selected_tab,
+                false,
+            );
+            self.selected_type_index = Some(type_index);
+            self.selected_type_name = Some(type_name);
+            if let Err(err) = self.backend.send_command(backend_command) {
+                log::error!("Failed to reconstruct type: {}", err);
+            }
+        }
+    }
+
     fn update_top_panel(&mut self, ctx: &egui::Context) {
         egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
             // Process keyboard shortcuts, if any
@@ -202,6 +374,9 @@ impl ResymApp {
 
             // The top panel is often a good place for a menu bar
             self.update_menu_bar(ui);
+
+            // Display the progress of long-running requests, if any
+            self.progress.update(ui, &self.backend);
         });
     }
 
@@ -227,6 +402,16 @@ impl ResymApp {
                         LeftPanelTab::ModuleBrowsing,
                         "Browse modules",
                     );
+                    ui.selectable_value(
+                        &mut self.left_panel_selected_tab,
+                        LeftPanelTab::FieldSearch,
+                        "Search fields",
+                    );
+                    ui.selectable_value(
+                        &mut self.left_panel_selected_tab,
+                        LeftPanelTab::Bookmarks,
+                        "Bookmarks",
+                    );
                 });
                 ui.separator();
 
@@ -236,23 +421,18 @@ impl ResymApp {
                         let on_query_update = |search_query: &str| {
                             // Update filtered list if filter has changed
                             let result = if let ResymAppMode::Comparing(..) = self.current_mode {
-                                self.backend.send_command(BackendCommand::ListTypesMerged(
-                                    vec![
-                                        ResymPDBSlots::Main as usize,
-                                        ResymPDBSlots::Diff as usize,
-                                    ],
+                                self.backend.send_command(BackendCommand::DiffTypeList(
+                                    ResymPDBSlots::Main as usize,
+                                    ResymPDBSlots::Diff as usize,
                                     search_query.to_string(),
-                                    self.settings.app_settings.search_case_insensitive,
-                                    self.settings.app_settings.search_use_regex,
-                                    self.settings.app_settings.ignore_std_types,
+                                    self.settings.app_settings.filter_options(),
+                                    self.settings.app_settings.reconstruction_options(),
                                 ))
                             } else {
                                 self.backend.send_command(BackendCommand::ListTypes(
                                     ResymPDBSlots::Main as usize,
                                     search_query.to_string(),
-                                    self.settings.app_settings.search_case_insensitive,
-                                    self.settings.app_settings.search_use_regex,
-                                    self.settings.app_settings.ignore_std_types,
+                                    self.settings.app_settings.filter_options(),
                                 ))
                             };
                             if let Err(err) = result {
@@ -270,6 +450,15 @@ impl ResymApp {
                         let mut on_type_selected = |type_name: &str, type_index: TypeIndex| {
                             // Update currently selected type index
                             self.selected_type_index = Some(type_index);
+                            self.selected_type_name = Some(type_name.to_string());
+                            self.document_tabs.open(
+                                NavigationTarget::Type {
+                                    type_name: type_name.to_string(),
+                                    type_index,
+                                },
+                                LeftPanelTab::TypeSearch,
+                                false,
+                            );
 
                             match self.current_mode {
                                 ResymAppMode::Browsing(..) => {
@@ -277,12 +466,7 @@ impl ResymApp {
                                         BackendCommand::ReconstructTypeByIndex(
                                             ResymPDBSlots::Main as usize,
                                             type_index,
-                                            self.settings.app_settings.primitive_types_flavor,
-                                            self.settings.app_settings.print_header,
-                                            self.settings.app_settings.reconstruct_dependencies,
-                                            self.settings.app_settings.print_access_specifiers,
-                                            self.settings.app_settings.integers_as_hexadecimal,
-                                            self.settings.app_settings.ignore_std_types,
+                                            self.settings.app_settings.reconstruction_options(),
                                         ),
                                     ) {
                                         log::error!("Failed to reconstruct type: {}", err);
@@ -294,12 +478,7 @@ impl ResymApp {
                                             ResymPDBSlots::Main as usize,
                                             ResymPDBSlots::Diff as usize,
                                             type_name.to_string(),
-                                            self.settings.app_settings.primitive_types_flavor,
-                                            self.settings.app_settings.print_header,
-                                            self.settings.app_settings.reconstruct_dependencies,
-                                            self.settings.app_settings.print_access_specifiers,
-                                            self.settings.app_settings.integers_as_hexadecimal,
-                                            self.settings.app_settings.ignore_std_types,
+                                            self.settings.app_settings.reconstruction_options(),
                                         ))
                                     {
                                         log::error!("Failed to reconstruct type diff: {}", err);
@@ -309,7 +488,15 @@ impl ResymApp {
                             }
                         };
                         // Update the type list
-                        self.type_list.update(ui, &mut on_type_selected);
+                        if let ResymAppMode::Comparing(..) = self.current_mode {
+                            // Note: type indices differ between PDBs, types are
+                            // diffed by name
+                            self.type_diff_list.update(ui, &mut |type_name: &str| {
+                                on_type_selected(type_name, TypeIndex::default())
+                            });
+                        } else {
+                            self.type_list.update(ui, &mut on_type_selected);
+                        }
                     }
 
                     LeftPanelTab::SymbolSearch => {
@@ -323,17 +510,13 @@ impl ResymApp {
                                         ResymPDBSlots::Diff as usize,
                                     ],
                                     search_query.to_string(),
-                                    self.settings.app_settings.search_case_insensitive,
-                                    self.settings.app_settings.search_use_regex,
-                                    self.settings.app_settings.ignore_std_types,
+                                    self.settings.app_settings.filter_options(),
                                 ))
                             } else {
                                 self.backend.send_command(BackendCommand::ListSymbols(
                                     ResymPDBSlots::Main as usize,
                                     search_query.to_string(),
-                                    self.settings.app_settings.search_case_insensitive,
-                                    self.settings.app_settings.search_use_regex,
-                                    self.settings.app_settings.ignore_std_types,
+                                    self.settings.app_settings.filter_options(),
                                 ))
                             };
                             if let Err(err) = result {
@@ -352,6 +535,15 @@ impl ResymApp {
                             |symbol_name: &str, symbol_index: SymbolIndex| {
                                 // Update currently selected type index
                                 self.selected_symbol_index = Some(symbol_index);
+                                self.selected_symbol_name = Some(symbol_name.to_string());
+                                self.document_tabs.open(
+                                    NavigationTarget::Symbol {
+                                        symbol_name: symbol_name.to_string(),
+                                        symbol_index: Some(symbol_index),
+                                    },
+                                    LeftPanelTab::SymbolSearch,
+                                    false,
+                                );
 
                                 match self.current_mode {
                                     ResymAppMode::Browsing(..) => {
@@ -359,9 +551,7 @@ impl ResymApp {
                                             BackendCommand::ReconstructSymbolByIndex(
                                                 ResymPDBSlots::Main as usize,
                                                 symbol_index,
-                                                self.settings.app_settings.primitive_types_flavor,
-                                                self.settings.app_settings.print_header,
-                                                self.settings.app_settings.print_access_specifiers,
+                                                self.settings.app_settings.reconstruction_options(),
                                             ),
                                         ) {
                                             log::error!("Failed to reconstruct type: {}", err);
@@ -373,9 +563,7 @@ impl ResymApp {
                                                 ResymPDBSlots::Main as usize,
                                                 ResymPDBSlots::Diff as usize,
                                                 symbol_name.to_string(),
-                                                self.settings.app_settings.primitive_types_flavor,
-                                                self.settings.app_settings.print_header,
-                                                self.settings.app_settings.print_access_specifiers,
+                                                self.settings.app_settings.reconstruction_options(),
                                             ),
                                         ) {
                                             log::error!("Failed to reconstruct type diff: {}", err);
@@ -398,8 +586,7 @@ impl ResymApp {
                                     self.backend.send_command(BackendCommand::ListModules(
                                         ResymPDBSlots::Main as usize,
                                         search_query.to_string(),
-                                        self.settings.app_settings.search_case_insensitive,
-                                        self.settings.app_settings.search_use_regex,
+                                        self.settings.app_settings.filter_options(),
                                     ))
                                 {
                                     log::error!("Failed to update module list: {}", err);
@@ -414,44 +601,122 @@ impl ResymApp {
                         ui.add_space(4.0);
 
                         // Callback run when a module is selected in the tree
-                        let on_module_selected =
-                            |module_path: &ModulePath, module_info: &ModuleInfo| match self
-                                .current_mode
-                            {
+                        let mut on_module_selected =
+                            |module_path: &ModulePath, module_info: &ModuleInfo| {
+                                self.document_tabs.open(
+                                    NavigationTarget::Module {
+                                        module_path: module_path.to_string(),
+                                        module_index: module_info.pdb_index,
+                                    },
+                                    LeftPanelTab::ModuleBrowsing,
+                                    false,
+                                );
+
+                                match self.current_mode {
+                                    ResymAppMode::Browsing(..) => {
+                                        if let Err(err) = self.backend.send_command(
+                                            BackendCommand::ReconstructModuleByIndex(
+                                                ResymPDBSlots::Main as usize,
+                                                module_info.pdb_index,
+                                                self.settings.app_settings.reconstruction_options(),
+                                            ),
+                                        ) {
+                                            log::error!("Failed to reconstruct module: {}", err);
+                                        }
+                                    }
+
+                                    ResymAppMode::Comparing(..) => {
+                                        if let Err(err) = self.backend.send_command(
+                                            BackendCommand::DiffModuleByPath(
+                                                ResymPDBSlots::Main as usize,
+                                                ResymPDBSlots::Diff as usize,
+                                                module_path.to_string(),
+                                                self.settings.app_settings.reconstruction_options(),
+                                            ),
+                                        ) {
+                                            log::error!("Failed to reconstruct type diff: {}", err);
+                                        }
+                                    }
+
+                                    _ => log::error!("Invalid application state"),
+                                }
+                            };
+
+                        // Update the module list
+                        self.module_tree.update(ctx, ui, &mut on_module_selected);
+                    }
+
+                    LeftPanelTab::FieldSearch => {
+                        // Callback run when a field is selected in the list
+                        let mut on_type_selected = |type_name: &str, type_index: TypeIndex| {
+                            // Update currently selected type index
+                            self.selected_type_index = Some(type_index);
+                            self.selected_type_name = Some(type_name.to_string());
+                            self.document_tabs.open(
+                                NavigationTarget::Type {
+                                    type_name: type_name.to_string(),
+                                    type_index,
+                                },
+                                LeftPanelTab::FieldSearch,
+                                false,
+                            );
+
+                            match self.current_mode {
                                 ResymAppMode::Browsing(..) => {
                                     if let Err(err) = self.backend.send_command(
-                                        BackendCommand::ReconstructModuleByIndex(
+                                        BackendCommand::ReconstructTypeByIndex(
                                             ResymPDBSlots::Main as usize,
-                                            module_info.pdb_index,
-                                            self.settings.app_settings.primitive_types_flavor,
-                                            self.settings.app_settings.print_header,
-                                            self.settings.app_settings.print_access_specifiers,
+                                            type_index,
+                                            self.settings.app_settings.reconstruction_options(),
                                         ),
                                     ) {
-                                        log::error!("Failed to reconstruct module: {}", err);
+                                        log::error!("Failed to reconstruct type: {}", err);
                                     }
                                 }
-
                                 ResymAppMode::Comparing(..) => {
                                     if let Err(err) =
-                                        self.backend.send_command(BackendCommand::DiffModuleByPath(
+                                        self.backend.send_command(BackendCommand::DiffTypeByName(
                                             ResymPDBSlots::Main as usize,
                                             ResymPDBSlots::Diff as usize,
-                                            module_path.to_string(),
-                                            self.settings.app_settings.primitive_types_flavor,
-                                            self.settings.app_settings.print_header,
-                                            self.settings.app_settings.print_access_specifiers,
+                                            type_name.to_string(),
+                                            self.settings.app_settings.reconstruction_options(),
                                         ))
                                     {
                                         log::error!("Failed to reconstruct type diff: {}", err);
                                     }
                                 }
-
                                 _ => log::error!("Invalid application state"),
-                            };
+                            }
+                        };
 
-                        // Update the module list
-                        self.module_tree.update(ctx, ui, &on_module_selected);
+                        // Update the field search bars and the result list
+                        if let Some(query) = self.field_search.update(ui, &mut on_type_selected) {
+                            self.find_fields(query);
+                        }
+                    }
+
+                    LeftPanelTab::Bookmarks => {
+                        let current_bookmark =
+                            self.document_tabs.active_tab().target().map(Bookmark::from);
+                        let mut selected_bookmark = None;
+                        let annotations_change = self.bookmarks.update(
+                            ui,
+                            current_bookmark,
+                            self.selected_type_name.as_deref(),
+                            &mut |bookmark: &Bookmark| selected_bookmark = Some(bookmark.clone()),
+                        );
+
+                        if let Some(annotations_change) = annotations_change {
+                            self.store_annotations();
+                            if annotations_change == AnnotationsChange::Notes {
+                                // Reconstruct types again to display the notes
+                                self.document_tabs.invalidate_contents();
+                                self.refresh_active_document_tab();
+                            }
+                        }
+                        if let Some(bookmark) = selected_bookmark {
+                            self.open_bookmark(bookmark);
+                        }
                     }
                 }
             });
@@ -485,27 +750,54 @@ impl ResymApp {
                                 BottomPanelTab::XRefsFrom,
                                 "XRefs from",
                             );
+                            ui.selectable_value(
+                                &mut self.bottom_panel_selected_tab,
+                                BottomPanelTab::InlineSites,
+                                "Inline sites",
+                            );
+                            ui.selectable_value(
+                                &mut self.bottom_panel_selected_tab,
+                                BottomPanelTab::TypeGraph,
+                                "Type graph",
+                            );
+                            ui.selectable_value(
+                                &mut self.bottom_panel_selected_tab,
+                                BottomPanelTab::ClassHierarchy,
+                                "Class hierarchy",
+                            );
+                            ui.selectable_value(
+                                &mut self.bottom_panel_selected_tab,
+                                BottomPanelTab::MemoryLayout,
+                                "Memory layout",
+                            );
                         }
                     });
                     ui.separator();
 
-                    let mut on_type_selected = |_: &str, type_index: TypeIndex| {
+                    // Note: type graph queries apply to the type selected
+                    // before this frame
+                    let selected_type_name = self.selected_type_name.clone();
+                    let mut on_type_selected = |type_name: &str, type_index: TypeIndex| {
                         // Update currently selected type index
                         self.selected_type_index = Some(type_index);
+                        self.selected_type_name = Some(type_name.to_string());
 
                         // Note: only support "Browsing" mode
                         if let ResymAppMode::Browsing(..) = self.current_mode {
+                            self.document_tabs.open(
+                                NavigationTarget::Type {
+                                    type_name: type_name.to_string(),
+                                    type_index,
+                                },
+                                self.left_panel_selected_tab,
+                                false,
+                            );
                             if let Err(err) =
                                 self.backend
                                     .send_command(BackendCommand::ReconstructTypeByIndex(
                                         ResymPDBSlots::Main as usize,
                                         type_index,
-                                        self.settings.app_settings.primitive_types_flavor,
-                                        self.settings.app_settings.print_header,
-                                        self.settings.app_settings.reconstruct_dependencies,
-                                        self.settings.app_settings.print_access_specifiers,
-                                        self.settings.app_settings.integers_as_hexadecimal,
-                                        self.settings.app_settings.ignore_std_types,
+                                        self.settings.app_settings.reconstruction_options(),
                                     ))
                             {
                                 log::error!("Failed to reconstruct type: {}", err);
@@ -513,6 +805,54 @@ impl ResymApp {
                         }
                     };
 
+                    let mut on_inline_site_selected = |_: &str, inline_site_index: usize| {
+                        // Note: only support "Browsing" mode
+                        if let (ResymAppMode::Browsing(..), Some(inline_site)) =
+                            (&self.current_mode, self.inline_sites.get(inline_site_index))
+                        {
+                            // Navigate to the "other side" of the inline site
+                            let is_call_site = self.selected_symbol_name.as_deref()
+                                == Some(inline_site.inlinee_name.as_str());
+                            let (symbol_name, symbol_index) = if is_call_site {
+                                (
+                                    inline_site.caller_name.clone(),
+                                    Some(inline_site.caller_index),
+                                )
+                            } else {
+                                (inline_site.inlinee_name.clone(), None)
+                            };
+                            self.document_tabs.open(
+                                NavigationTarget::Symbol {
+                                    symbol_name,
+                                    symbol_index,
+                                },
+                                self.left_panel_selected_tab,
+                                false,
+                            );
+
+                            let backend_command = if is_call_site {
+                                self.selected_symbol_index = Some(inline_site.caller_index);
+                                self.selected_symbol_name = Some(inline_site.caller_name.clone());
+                                BackendCommand::ReconstructSymbolByIndex(
+                                    ResymPDBSlots::Main as usize,
+                                    inline_site.caller_index,
+                                    self.settings.app_settings.reconstruction_options(),
+                                )
+                            } else {
+                                self.selected_symbol_index = None;
+                                self.selected_symbol_name = Some(inline_site.inlinee_name.clone());
+                                BackendCommand::ReconstructSymbolByName(
+                                    ResymPDBSlots::Main as usize,
+                                    inline_site.inlinee_name.clone(),
+                                    self.settings.app_settings.reconstruction_options(),
+                                )
+                            };
+                            if let Err(err) = self.backend.send_command(backend_command) {
+                                log::error!("Failed to reconstruct symbol: {}", err);
+                            }
+                        }
+                    };
+
                     // Tab body
                     match self.bottom_panel_selected_tab {
                         BottomPanelTab::Console => {
@@ -527,6 +867,29 @@ impl ResymApp {
                             // Update xref list
                             self.xref_from_list.update(ui, &mut on_type_selected);
                         }
+                        BottomPanelTab::InlineSites => {
+                            // Update inline site list
+                            self.inline_site_list
+                                .update(ui, &mut on_inline_site_selected);
+                        }
+                        BottomPanelTab::TypeGraph => {
+                            // Update type graph query results
+                            if let Some(query) = self.type_graph.update(
+                                ui,
+                                selected_type_name.as_deref(),
+                                &mut on_type_selected,
+                            ) {
+                                self.query_type_graph(query);
+                            }
+                        }
+                        BottomPanelTab::ClassHierarchy => {
+                            // Update class hierarchy trees
+                            self.class_hierarchy.update(ui, &mut on_type_selected);
+                        }
+                        BottomPanelTab::MemoryLayout => {
+                            // Update memory layout grid
+                            self.type_layout.update(ui, &mut on_type_selected);
+                        }
                     }
                 });
             });
@@ -535,6 +898,34 @@ impl ResymApp {
     fn update_central_panel(&mut self, ctx: &egui::Context) {
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.horizontal(|ui| {
+                // Navigation buttons
+                if ui
+                    .add_enabled(
+                        self.document_tabs
+                            .active_tab()
+                            .navigation_history
+                            .can_go_back(),
+                        egui::Button::new("⬅"),
+                    )
+                    .on_hover_text("Go back (Alt+Left)")
+                    .clicked()
+                {
+                    self.navigate_back();
+                }
+                if ui
+                    .add_enabled(
+                        self.document_tabs
+                            .active_tab()
+                            .navigation_history
+                            .can_go_forward(),
+                        egui::Button::new("➡"),
+                    )
+                    .on_hover_text("Go forward (Alt+Right)")
+                    .clicked()
+                {
+                    self.navigate_forward();
+                }
+
                 // The central panel the region left after adding TopPanel's and SidePanel's
                 // Put the label on the left
                 ui.label(if let ResymAppMode::Comparing(..) = self.current_mode {
@@ -561,14 +952,79 @@ impl ResymApp {
                                 self.list_xrefs_for_type(selected_type_index);
                             }
                         }
+
+                        // Class hierarchy button
+                        if let Some(selected_type_name) = &self.selected_type_name {
+                            if ui.button("🔍  Class hierarchy (Alt+H)").clicked() {
+                                self.list_class_hierarchy_for_type(selected_type_name);
+                            }
+                        }
+
+                        // Memory layout button
+                        if let Some(selected_type_index) = self.selected_type_index {
+                            if ui.button("🔍  Memory layout (Alt+M)").clicked() {
+                                self.compute_type_layout(selected_type_index);
+                            }
+                        }
+
+                        // Inline sites button
+                        if let Some(selected_symbol_name) = &self.selected_symbol_name {
+                            if ui.button("🔍  Find inline sites (Alt+I)").clicked() {
+                                self.list_inline_sites_for_symbol(selected_symbol_name);
+                            }
+                        }
                     }
                 });
             });
+
+            // Tab bar
+            if self.document_tabs.update(ui, &mut self.current_mode) {
+                self.refresh_active_document_tab();
+            }
             ui.separator();
 
+            let mut on_type_selected =
+                |type_name: &str, type_index: TypeIndex, in_new_tab: bool| {
+                    // Update currently selected type index
+                    self.selected_type_index = Some(type_index);
+                    self.selected_type_name = Some(type_name.to_string());
+                    self.document_tabs.open(
+                        NavigationTarget::Type {
+                            type_name: type_name.to_string(),
+                            type_index,
+                        },
+                        self.left_panel_selected_tab,
+                        in_new_tab,
+                    );
+
+                    if let Err(err) =
+                        self.backend
+                            .send_command(BackendCommand::ReconstructTypeByIndex(
+                                ResymPDBSlots::Main as usize,
+                                type_index,
+                                self.settings.app_settings.reconstruction_options(),
+                            ))
+                    {
+                        log::error!("Failed to reconstruct type: {}", err);
+                    }
+                };
+
             // Update the code view component
-            self.code_view
-                .update(&self.settings.app_settings, &self.current_mode, ui);
+            self.code_view.update(
+                &self.settings.app_settings,
+                &self.current_mode,
+                ui,
+                &mut on_type_selected,
+            );
+
+            // Keep track of the scroll position, unless we're waiting for
+            // content opened from the navigation history
+            if self.pending_scroll_offset.is_none() {
+                self.document_tabs
+                    .active_tab_mut()
+                    .navigation_history
+                    .set_scroll_offset(self.code_view.scroll_offset());
+            }
         });
     }
 
@@ -597,6 +1053,34 @@ impl ResymApp {
             }
         });
 
+        // Keyboard shortcuts and mouse buttons for navigating through the
+        // history
+        const ALT_LEFT_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut {
+            modifiers: egui::Modifiers::ALT,
+            logical_key: egui::Key::ArrowLeft,
+        };
+        const ALT_RIGHT_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut {
+            modifiers: egui::Modifiers::ALT,
+            logical_key: egui::Key::ArrowRight,
+        };
+        let (go_back, go_forward) = ui.input_mut(|input_state| {
+            (
+                input_state.consume_shortcut(&ALT_LEFT_SHORTCUT)
+                    || input_state
+                        .pointer
+                        .button_clicked(egui::PointerButton::Extra1),
+                input_state.consume_shortcut(&ALT_RIGHT_SHORTCUT)
+                    || input_state
+                        .pointer
+                        .button_clicked(egui::PointerButton::Extra2),
+            )
+        });
+        if go_back {
+            self.navigate_back();
+        } else if go_forward {
+            self.navigate_forward();
+        }
+
         // Keyboard shortcut for finding cross-references
         const ALT_X_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut {
             modifiers: egui::Modifiers::ALT,
@@ -610,6 +1094,56 @@ impl ResymApp {
             }
         });
 
+        // Keyboard shortcut for listing base and derived classes
+        const ALT_H_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut {
+            modifiers: egui::Modifiers::ALT,
+            logical_key: egui::Key::H,
+        };
+        ui.input_mut(|input_state| {
+            if input_state.consume_shortcut(&ALT_H_SHORTCUT) {
+                if let Some(selected_type_name) = &self.selected_type_name {
+                    self.list_class_hierarchy_for_type(selected_type_name);
+                }
+            }
+        });
+
+        // Keyboard shortcut for displaying memory layouts
+        const ALT_M_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut {
+            modifiers: egui::Modifiers::ALT,
+            logical_key: egui::Key::M,
+        };
+        ui.input_mut(|input_state| {
+            if input_state.consume_shortcut(&ALT_M_SHORTCUT) {
+                if let Some(selected_type_index) = self.selected_type_index {
+                    self.compute_type_layout(selected_type_index);
+                }
+            }
+        });
+
+        // Keyboard shortcut for finding inline sites
+        const ALT_I_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut {
+            modifiers: egui::Modifiers::ALT,
+            logical_key: egui::Key::I,
+        };
+        ui.input_mut(|input_state| {
+            if input_state.consume_shortcut(&ALT_I_SHORTCUT) {
+                if let Some(selected_symbol_name) = &self.selected_symbol_name {
+                    self.list_inline_sites_for_symbol(selected_symbol_name);
+                }
+            }
+        });
+
+        // Keyboard shortcut for searching in the code view
+        const CTRL_F_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut {
+            modifiers: egui::Modifiers::CTRL,
+            logical_key: egui::Key::F,
+        };
+        ui.input_mut(|input_state| {
+            if input_state.consume_shortcut(&CTRL_F_SHORTCUT) {
+                self.code_view.open_find_bar();
+            }
+        });
+
         // Keyboard shortcut for saving reconstructed content
         #[cfg(not(target_arch = "wasm32"))]
         const CTRL_S_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut {
@@ -632,6 +1166,11 @@ impl ResymApp {
                 FrontendCommand::LoadPDBResult(result) => match result {
                     Err(err) => {
                         log::error!("Failed to load PDB file: {}", err);
+                        // Don't compare PDBs if the main one couldn't be loaded
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            self.pending_diff_pdb = None;
+                        }
                     }
                     Ok(pdb_slot) => {
                         if pdb_slot == ResymPDBSlots::Main as usize {
@@ -650,19 +1189,42 @@ impl ResymApp {
                             // Reset current mode
                             self.current_mode =
                                 ResymAppMode::Browsing(String::default(), 0, String::default());
-                            // Reset selected type
+                            // Reset selected type and symbol
                             self.selected_type_index = None;
-                            // Reset xref lists
+                            self.selected_type_name = None;
+                            self.selected_symbol_index = None;
+                            self.selected_symbol_name = None;
+                            // Reset xref, inline site, type graph, class
+                            // hierarchy, memory layout, field and layout match
+                            // lists
                             self.xref_to_list.update_index_list(vec![]);
                             self.xref_from_list.update_index_list(vec![]);
+                            self.update_inline_site_list(vec![]);
+                            self.type_graph.update_query_result(vec![]);
+                            self.class_hierarchy.set_class_hierarchy(None);
+                            self.type_layout.set_type_layout(None);
+                            self.field_search.update_field_list(vec![]);
+                            self.layout_search.update_layout_match_list(vec![]);
+                            // Reset and load annotations
+                            self.bookmarks.set_annotations(Annotations::default());
+                            #[cfg(not(target_arch = "wasm32"))]
+                            if let Err(err) = self.backend.send_command(
+                                BackendCommand::LoadAnnotations(ResymPDBSlots::Main as usize),
+                            ) {
+                                log::error!("Failed to load annotations: {}", err);
+                            }
+                            // Reconstruct the active tab's content from the
+                            // new PDB(s), other tabs are reconstructed when
+                            // activated
+                            self.document_tabs.invalidate_contents();
+                            self.refresh_active_document_tab();
 
                             // Request a type list update
                             if let Err(err) = self.backend.send_command(BackendCommand::ListTypes(
                                 ResymPDBSlots::Main as usize,
-                                String::default(),
-                                false,
-                                false,
-                                self.settings.app_settings.ignore_std_types,
+                                self.type_search.search_filter().to_string(),
+                                FilterOptions::new()
+                                    .ignore_std_types(self.settings.app_settings.ignore_std_types),
                             )) {
                                 log::error!("Failed to update type filter value: {}", err);
                             }
@@ -670,10 +1232,10 @@ impl ResymApp {
                             if let Err(err) =
                                 self.backend.send_command(BackendCommand::ListSymbols(
                                     ResymPDBSlots::Main as usize,
-                                    String::default(),
-                                    false,
-                                    false,
-                                    self.settings.app_settings.ignore_std_types,
+                                    self.symbol_search.search_filter().to_string(),
+                                    FilterOptions::new().ignore_std_types(
+                                        self.settings.app_settings.ignore_std_types,
+                                    ),
                                 ))
                             {
                                 log::error!("Failed to update type filter value: {}", err);
@@ -682,39 +1244,54 @@ impl ResymApp {
                             if let Err(err) =
                                 self.backend.send_command(BackendCommand::ListModules(
                                     ResymPDBSlots::Main as usize,
-                                    String::default(),
-                                    false,
-                                    false,
+                                    self.module_search.search_filter().to_string(),
+                                    FilterOptions::new(),
                                 ))
                             {
                                 log::error!("Failed to update module list: {}", err);
                             }
+
+                            // Load the PDB used for diffing, if requested
+                            #[cfg(not(target_arch = "wasm32"))]
+                            if let Some(diff_pdb) = self.pending_diff_pdb.take() {
+                                self.load_pdb(ResymPDBSlots::Diff as usize, diff_pdb);
+                            }
                         } else if pdb_slot == ResymPDBSlots::Diff as usize {
                             // Reset current mode
-                            self.current_mode = ResymAppMode::Comparing(
-                                String::default(),
-                                String::default(),
-                                0,
-                                vec![],
-                                String::default(),
-                            );
-                            // Reset selected type
+                            self.current_mode =
+                                ResymAppMode::Comparing(0, vec![], String::default());
+                            // Reset selected type and symbol
                             self.selected_type_index = None;
-                            // Reset xref lists
+                            self.selected_type_name = None;
+                            self.selected_symbol_index = None;
+                            self.selected_symbol_name = None;
+                            // Reset xref, inline site, type graph, class
+                            // hierarchy, memory layout, field and layout match
+                            // lists
                             self.xref_to_list.update_index_list(vec![]);
                             self.xref_from_list.update_index_list(vec![]);
+                            self.update_inline_site_list(vec![]);
+                            self.type_graph.update_query_result(vec![]);
+                            self.class_hierarchy.set_class_hierarchy(None);
+                            self.type_layout.set_type_layout(None);
+                            self.field_search.update_field_list(vec![]);
+                            self.layout_search.update_layout_match_list(vec![]);
+                            // Reconstruct the active tab's content from the
+                            // new PDB(s), other tabs are reconstructed when
+                            // activated
+                            self.document_tabs.invalidate_contents();
+                            self.refresh_active_document_tab();
 
                             // Request a type list update
                             if let Err(err) =
-                                self.backend.send_command(BackendCommand::ListTypesMerged(
-                                    vec![
-                                        ResymPDBSlots::Main as usize,
-                                        ResymPDBSlots::Diff as usize,
-                                    ],
-                                    String::default(),
-                                    false,
-                                    false,
-                                    self.settings.app_settings.ignore_std_types,
+                                self.backend.send_command(BackendCommand::DiffTypeList(
+                                    ResymPDBSlots::Main as usize,
+                                    ResymPDBSlots::Diff as usize,
+                                    self.type_search.search_filter().to_string(),
+                                    FilterOptions::new().ignore_std_types(
+                                        self.settings.app_settings.ignore_std_types,
+                                    ),
+                                    self.settings.app_settings.reconstruction_options(),
                                 ))
                             {
                                 log::error!("Failed to update type filter value: {}", err);
@@ -737,7 +1314,35 @@ impl ResymApp {
                     }
                 },
 
+                FrontendCommand::LoadAnnotationsResult(result) => match result {
+                    Err(err) => {
+                        log::error!("Failed to load annotations: {}", err);
+                    }
+                    Ok((pdb_slot, annotations)) => {
+                        if pdb_slot == ResymPDBSlots::Main as usize {
+                            let has_notes = annotations.notes().next().is_some();
+                            self.bookmarks.set_annotations(annotations);
+                            if has_notes {
+                                // Reconstruct types again to display the notes
+                                self.document_tabs.invalidate_contents();
+                                self.refresh_active_document_tab();
+                            }
+                        }
+                    }
+                },
+
+                FrontendCommand::LoadPEResult(result) => match result {
+                    Err(err) => {
+                        log::error!("Failed to load PE file: {}", err);
+                    }
+                    Ok(_) => {
+                        log::info!("Symbols will now include information from the PE image");
+                    }
+                },
+
                 FrontendCommand::ReconstructTypeResult(type_reconstruction_result) => {
+                    self.restore_scroll_offset();
+
                     match type_reconstruction_result {
                         Err(err) => {
                             let error_msg = format!("Failed to reconstruct type: {}", err);
@@ -748,6 +1353,22 @@ impl ResymApp {
                                 ResymAppMode::Browsing(Default::default(), 0, error_msg);
                         }
                         Ok((reconstructed_type, xrefs_from)) => {
+                            // Render notes as comments
+                            let reconstructed_type =
+                                self.bookmarks.annotations().annotate(&reconstructed_type);
+                            // Make the referenced types (and the type itself)
+                            // clickable in the code view
+                            let mut type_links = xrefs_from.clone();
+                            if let (Some(type_name), Some(type_index)) =
+                                (&self.selected_type_name, self.selected_type_index)
+                            {
+                                type_links.push((type_name.clone(), type_index));
+                            }
+                            self.code_view
+                                .set_type_links(&reconstructed_type, &type_links);
+                            self.document_tabs.active_tab_mut().type_links = type_links.clone();
+                            self.list_type_details(type_links);
+
                             let last_line_number = 1 + reconstructed_type.lines().count();
                             let line_numbers =
                                 (1..last_line_number).fold(String::default(), |mut acc, e| {
@@ -763,8 +1384,21 @@ impl ResymApp {
                             // Update xref lists
                             self.xref_to_list.update_index_list(vec![]);
                             self.xref_from_list.update_index_list(xrefs_from);
-                            // Switch to the "xref from" tab
-                            self.bottom_panel_selected_tab = BottomPanelTab::XRefsFrom;
+                            // Switch to the "xref from" tab, unless the user
+                            // is navigating through the type graph, the class
+                            // hierarchy or memory layouts
+                            match self.bottom_panel_selected_tab {
+                                BottomPanelTab::TypeGraph | BottomPanelTab::ClassHierarchy => {}
+                                BottomPanelTab::MemoryLayout => {
+                                    // Display the layout of the new type
+                                    if let Some(selected_type_index) = self.selected_type_index {
+                                        self.compute_type_layout(selected_type_index);
+                                    }
+                                }
+                                _ => {
+                                    self.bottom_panel_selected_tab = BottomPanelTab::XRefsFrom;
+                                }
+                            }
                         }
                     }
                 }
@@ -781,6 +1415,8 @@ impl ResymApp {
                 }
 
                 FrontendCommand::ReconstructSymbolResult(result) => {
+                    self.restore_scroll_offset();
+
                     match result {
                         Err(err) => {
                             let error_msg = format!("Failed to reconstruct symbol: {}", err);
@@ -791,6 +1427,9 @@ impl ResymApp {
                                 ResymAppMode::Browsing(Default::default(), 0, error_msg);
                         }
                         Ok(reconstructed_symbol) => {
+                            self.code_view.set_type_links(&reconstructed_symbol, &[]);
+                            self.document_tabs.active_tab_mut().type_links.clear();
+
                             let last_line_number = 1 + reconstructed_symbol.lines().count();
                             let line_numbers =
                                 (1..last_line_number).fold(String::default(), |mut acc, e| {
@@ -807,6 +1446,8 @@ impl ResymApp {
                 }
 
                 FrontendCommand::ReconstructModuleResult(module_reconstruction_result) => {
+                    self.restore_scroll_offset();
+
                     match module_reconstruction_result {
                         Err(err) => {
                             let error_msg = format!("Failed to reconstruct module: {}", err);
@@ -817,6 +1458,9 @@ impl ResymApp {
                                 ResymAppMode::Browsing(Default::default(), 0, error_msg);
                         }
                         Ok(reconstructed_module) => {
+                            self.code_view.set_type_links(&reconstructed_module, &[]);
+                            self.document_tabs.active_tab_mut().type_links.clear();
+
                             let last_line_number = 1 + reconstructed_module.lines().count();
                             let line_numbers =
                                 (1..last_line_number).fold(String::default(), |mut acc, e| {
@@ -832,65 +1476,52 @@ impl ResymApp {
                     }
                 }
 
-                FrontendCommand::DiffResult(type_diff_result) => match type_diff_result {
-                    Err(err) => {
-                        let error_msg = format!("Failed to generate diff: {}", err);
-                        log::error!("{}", &error_msg);
-
-                        // Show an empty "reconstruted" view
-                        self.current_mode = ResymAppMode::Comparing(
-                            Default::default(),
-                            Default::default(),
-                            0,
-                            vec![],
-                            error_msg,
-                        );
-                    }
-                    Ok(type_diff) => {
-                        let mut last_line_number = 1;
-                        let (line_numbers_old, line_numbers_new, line_changes) =
-                            type_diff.metadata.iter().fold(
-                                (String::default(), String::default(), vec![]),
-                                |(mut acc_old, mut acc_new, mut acc_changes), metadata| {
-                                    let indices = metadata.0;
-
-                                    if let Some(indice) = indices.0 {
-                                        last_line_number =
-                                            std::cmp::max(last_line_number, 1 + indice);
-                                        let _r = writeln!(&mut acc_old, "{}", 1 + indice);
-                                    } else {
-                                        let _r = writeln!(&mut acc_old);
-                                    }
+                FrontendCommand::DiffResult(type_diff_result) => {
+                    self.restore_scroll_offset();
 
-                                    if let Some(indice) = indices.1 {
-                                        last_line_number =
-                                            std::cmp::max(last_line_number, 1 + indice);
-                                        let _r = writeln!(&mut acc_new, "{}", 1 + indice);
-                                    } else {
-                                        let _r = writeln!(&mut acc_new);
-                                    }
+                    match type_diff_result {
+                        Err(err) => {
+                            let error_msg = format!("Failed to generate diff: {}", err);
+                            log::error!("{}", &error_msg);
 
-                                    acc_changes.push(metadata.1);
+                            // Show an empty "reconstruted" view
+                            self.current_mode = ResymAppMode::Comparing(0, vec![], error_msg);
+                        }
+                        Ok(type_diff) => {
+                            let last_line_number = type_diff
+                                .metadata
+                                .iter()
+                                .flat_map(|((old_index, new_index), _)| [*old_index, *new_index])
+                                .flatten()
+                                .max()
+                                .map_or(1, |max_index| max_index + 1);
 
-                                    (acc_old, acc_new, acc_changes)
-                                },
+                            self.current_mode = ResymAppMode::Comparing(
+                                last_line_number,
+                                type_diff.metadata,
+                                type_diff.data,
                             );
-
-                        self.current_mode = ResymAppMode::Comparing(
-                            line_numbers_old,
-                            line_numbers_new,
-                            last_line_number,
-                            line_changes,
-                            type_diff.data,
-                        );
+                        }
                     }
-                },
+                }
 
                 FrontendCommand::ListTypesResult(filtered_types) => {
                     // Update type list component
                     self.type_list.update_index_list(filtered_types);
                 }
 
+                FrontendCommand::DiffTypeListResult(type_diff_list_result) => {
+                    match type_diff_list_result {
+                        Err(err) => {
+                            log::error!("Failed to compare type lists: {err}");
+                        }
+                        Ok(type_diff_list) => {
+                            // Update type diff list component
+                            self.type_diff_list.update_type_diff_list(type_diff_list);
+                        }
+                    }
+                }
+
                 FrontendCommand::ListSymbolsResult(filtered_symbols) => {
                     // Update symbol list component
                     self.symbol_list.update_index_list(filtered_symbols);
@@ -912,6 +1543,107 @@ impl ResymApp {
                         }
                     }
                 }
+
+                FrontendCommand::ListTypeDetailsResult(type_details_result) => {
+                    match type_details_result {
+                        Err(err) => {
+                            log::error!("Failed to retrieve type details: {err}");
+                        }
+                        Ok(type_details_list) => {
+                            self.code_view.set_type_details(type_details_list);
+                        }
+                    }
+                }
+
+                FrontendCommand::SizeReportResult(_) => {
+                    // Note: size reports aren't displayed in the GUI (yet)
+                }
+
+                FrontendCommand::ListInlineSitesResult(inline_site_list_result) => {
+                    match inline_site_list_result {
+                        Err(err) => {
+                            log::error!("Failed to list inline sites: {err}");
+                        }
+                        Ok(inline_site_list) => {
+                            let inline_site_count = inline_site_list.len();
+                            log::info!("{inline_site_count} inline sites found!");
+
+                            // Update inline site list component
+                            self.update_inline_site_list(inline_site_list);
+                            // Switch to inline sites tab
+                            self.bottom_panel_selected_tab = BottomPanelTab::InlineSites;
+                        }
+                    }
+                }
+
+                FrontendCommand::TypeGraphQueryResult(query_result) => match query_result {
+                    Err(err) => {
+                        log::error!("Failed to query type graph: {err}");
+                    }
+                    Ok(query_result) => {
+                        self.type_graph.update_query_result(query_result);
+                    }
+                },
+
+                FrontendCommand::TypeGraphExportResult(_) => {
+                    // Note: type graph exports aren't available in the GUI (yet)
+                }
+
+                FrontendCommand::ClassHierarchyResult(class_hierarchy_result) => {
+                    match class_hierarchy_result {
+                        Err(err) => {
+                            log::error!("Failed to list base and derived classes: {err}");
+                        }
+                        Ok(class_hierarchy) => {
+                            // Update class hierarchy component
+                            self.class_hierarchy
+                                .set_class_hierarchy(Some(class_hierarchy));
+                            // Switch to class hierarchy tab
+                            self.bottom_panel_selected_tab = BottomPanelTab::ClassHierarchy;
+                        }
+                    }
+                }
+
+                FrontendCommand::TypeLayoutResult(type_layout_result) => {
+                    match type_layout_result {
+                        Err(err) => {
+                            log::error!("Failed to compute memory layout: {err}");
+                            self.type_layout.set_type_layout(None);
+                        }
+                        Ok(type_layout) => {
+                            // Update memory layout component
+                            self.type_layout.set_type_layout(Some(type_layout));
+                            // Switch to memory layout tab
+                            self.bottom_panel_selected_tab = BottomPanelTab::MemoryLayout;
+                        }
+                    }
+                }
+
+                FrontendCommand::FindFieldsResult(field_list_result) => match field_list_result {
+                    Err(err) => {
+                        log::error!("Failed to search fields: {err}");
+                    }
+                    Ok(field_list) => {
+                        self.field_search.update_field_list(field_list);
+                    }
+                },
+
+                FrontendCommand::MatchLayoutResult(layout_match_list_result) => {
+                    match layout_match_list_result {
+                        Err(err) => {
+                            log::error!("Failed to match layout: {err}");
+                        }
+                        Ok(layout_match_list) => {
+                            log::info!("{} matching types found!", layout_match_list.len());
+                            self.layout_search
+                                .update_layout_match_list(layout_match_list);
+                        }
+                    }
+                }
+
+                FrontendCommand::Progress(request_id, progress) => {
+                    self.progress.update_progress(request_id, progress);
+                }
             }
         }
     }
@@ -931,6 +1663,9 @@ impl ResymApp {
                     self.open_url.open(ResymPDBSlots::Main);
                 }
 
+                #[cfg(not(target_arch = "wasm32"))]
+                self.update_session_menu(ui);
+
                 // Separate "Open" from "Compare"
                 ui.separator();
 
@@ -957,7 +1692,35 @@ impl ResymApp {
                     self.open_url.open(ResymPDBSlots::Diff);
                 }
 
-                // Separate "Compare" from "Settings"
+                // Separate "Compare" from "Load PE image"
+                ui.separator();
+
+                if ui
+                    .add_enabled(
+                        matches!(self.current_mode, ResymAppMode::Browsing(..)),
+                        egui::Button::new("Load matching PE image ..."),
+                    )
+                    .clicked()
+                {
+                    ui.close_menu();
+                    self.start_open_pe_file(ResymPDBSlots::Main as usize);
+                }
+
+                // Separate "Load PE image" from "Match layout"
+                ui.separator();
+
+                if ui
+                    .add_enabled(
+                        !matches!(self.current_mode, ResymAppMode::Idle),
+                        egui::Button::new("Match layout ..."),
+                    )
+                    .clicked()
+                {
+                    ui.close_menu();
+                    self.layout_search.open();
+                }
+
+                // Separate "Match layout" from "Settings"
                 ui.separator();
 
                 if ui.button("Settings").clicked() {
@@ -973,21 +1736,102 @@ impl ResymApp {
         });
     }
 
+    /// Send the symbol search path and index cache configured in the settings
+    /// to the backend, before loading files from disk
+    #[cfg(not(target_arch = "wasm32"))]
+    fn update_backend_settings(&self) {
+        let symbol_path = if self.settings.app_settings.symbol_path.trim().is_empty() {
+            Ok(SymbolPath::from_env())
+        } else {
+            self.settings.app_settings.symbol_path.parse()
+        };
+        match symbol_path {
+            Err(err) => log::error!("Invalid symbol search path: {err}"),
+            Ok(symbol_path) => {
+                if let Err(err) = self
+                    .backend
+                    .send_command(BackendCommand::SetSymbolPath(symbol_path))
+                {
+                    log::error!("Failed to update the symbol search path: {err}");
+                }
+            }
+        }
+
+        let index_cache = if self.settings.app_settings.enable_index_cache {
+            Some(IndexCache::from_env_or_default())
+        } else {
+            None
+        };
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::SetIndexCache(index_cache))
+        {
+            log::error!("Failed to update the index cache: {err}");
+        }
+    }
+
     /// Function invoked on `Open PDB File` or when the Ctrl+O shortcut is used
     #[cfg(not(target_arch = "wasm32"))]
     fn start_open_pdb_file(&mut self, pdb_slot: PDBSlot) {
         let file_path_opt = tinyfiledialogs::open_file_dialog(
             "Select a PDB file",
             "",
-            Some((&["*.pdb"], "PDB files (*.pdb)")),
+            Some((
+                &["*.pdb", "*.exe", "*.dll", "*.sys"],
+                "PDB files and PE images (*.pdb, *.exe, *.dll, *.sys)",
+            )),
         );
         if let Some(file_path) = file_path_opt {
-            if let Err(err) = self
-                .backend
-                .send_command(BackendCommand::LoadPDBFromPath(pdb_slot, file_path.into()))
-            {
-                log::error!("Failed to load the PDB file: {err}");
+            self.load_pdb(pdb_slot, PdbLocation::Path(file_path.into()));
+        }
+    }
+
+    /// Load the PDB file found at the given location, and remember it so that
+    /// it can be saved in session files
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_pdb(&mut self, pdb_slot: PDBSlot, pdb_location: PdbLocation) {
+        let load_command = match &pdb_location {
+            PdbLocation::Path(file_path) => {
+                self.update_backend_settings();
+                BackendCommand::LoadPDBFromPath(pdb_slot, file_path.clone())
             }
+            #[cfg(feature = "http")]
+            PdbLocation::Url(url) => BackendCommand::LoadPDBFromURL(pdb_slot, url.clone()),
+            #[cfg(not(feature = "http"))]
+            PdbLocation::Url(url) => {
+                log::error!("Cannot open '{url}', HTTP support is disabled");
+                return;
+            }
+        };
+        if let Err(err) = self.backend.send_command(load_command) {
+            log::error!("Failed to load the PDB file: {err}");
+            return;
+        }
+
+        // Note: the PDB used for diffing is unloaded when the main PDB is
+        // replaced
+        if pdb_slot == ResymPDBSlots::Main as usize {
+            self.pdb_locations[ResymPDBSlots::Diff as usize] = None;
+        }
+        if let Some(slot_location) = self.pdb_locations.get_mut(pdb_slot) {
+            *slot_location = Some(pdb_location.clone());
+        }
+        push_recent_entry(&mut self.recent_files, pdb_location);
+    }
+
+    /// Function invoked when a URL is opened from the `Open URL` window
+    #[cfg(all(feature = "http", not(target_arch = "wasm32")))]
+    fn load_pdb_from_url(&mut self, pdb_slot: PDBSlot, url: String) {
+        self.load_pdb(pdb_slot, PdbLocation::Url(url));
+    }
+
+    #[cfg(all(feature = "http", target_arch = "wasm32"))]
+    fn load_pdb_from_url(&mut self, pdb_slot: PDBSlot, url: String) {
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::LoadPDBFromURL(pdb_slot, url))
+        {
+            log::error!("Failed to load URL: {err}");
         }
     }
 
@@ -1020,6 +1864,242 @@ impl ResymApp {
         }
     }
 
+    /// Function invoked on `Load matching PE image`
+    #[cfg(not(target_arch = "wasm32"))]
+    fn start_open_pe_file(&mut self, pdb_slot: PDBSlot) {
+        let file_path_opt = tinyfiledialogs::open_file_dialog(
+            "Select a PE file",
+            "",
+            Some((
+                &["*.exe", "*.dll", "*.sys"],
+                "PE files (*.exe, *.dll, *.sys)",
+            )),
+        );
+        if let Some(file_path) = file_path_opt {
+            if let Err(err) = self
+                .backend
+                .send_command(BackendCommand::LoadPEFromPath(pdb_slot, file_path.into()))
+            {
+                log::error!("Failed to load the PE file: {err}");
+            }
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn start_open_pe_file(&mut self, pdb_slot: PDBSlot) {
+        let open_pe_data = self.open_pe_data.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            let file_opt = rfd::AsyncFileDialog::new()
+                .add_filter("PE files (*.exe, *.dll, *.sys)", &["exe", "dll", "sys"])
+                .pick_file()
+                .await;
+            if let Some(file) = file_opt {
+                *open_pe_data.borrow_mut() = Some((pdb_slot, file.file_name(), file.read().await));
+            }
+        });
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn process_open_pe_file_result(&self) {
+        if let Some((pdb_slot, pe_name, pe_bytes)) = self.open_pe_data.borrow_mut().take() {
+            if let Err(err) = self
+                .backend
+                .send_command(BackendCommand::LoadPEFromVec(pdb_slot, pe_name, pe_bytes))
+            {
+                log::error!("Failed to load the PE file: {err}");
+            }
+        }
+    }
+
+    fn navigate_back(&mut self) {
+        if let Some(navigation_entry) = self
+            .document_tabs
+            .active_tab_mut()
+            .navigation_history
+            .go_back()
+            .cloned()
+        {
+            self.open_navigation_entry(navigation_entry);
+        }
+    }
+
+    fn navigate_forward(&mut self) {
+        if let Some(navigation_entry) = self
+            .document_tabs
+            .active_tab_mut()
+            .navigation_history
+            .go_forward()
+            .cloned()
+        {
+            self.open_navigation_entry(navigation_entry);
+        }
+    }
+
+    /// Reopen an item from the navigation history.
+    /// Note: items are looked up by name when possible, so that the history
+    /// stays valid when switching between browsing and comparing PDBs.
+    fn open_navigation_entry(&mut self, navigation_entry: NavigationEntry) {
+        let reconstruction_options = self.settings.app_settings.reconstruction_options();
+        let is_comparing = match self.current_mode {
+            ResymAppMode::Browsing(..) => false,
+            ResymAppMode::Comparing(..) => true,
+            ResymAppMode::Idle => return,
+        };
+
+        self.select_navigation_target(&navigation_entry.target);
+        let backend_command = match navigation_entry.target {
+            NavigationTarget::Type { type_name, .. } => {
+                if is_comparing {
+                    BackendCommand::DiffTypeByName(
+                        ResymPDBSlots::Main as usize,
+                        ResymPDBSlots::Diff as usize,
+                        type_name,
+                        reconstruction_options,
+                    )
+                } else {
+                    BackendCommand::ReconstructTypeByName(
+                        ResymPDBSlots::Main as usize,
+                        type_name,
+                        reconstruction_options,
+                    )
+                }
+            }
+            NavigationTarget::Symbol { symbol_name, .. } => {
+                if is_comparing {
+                    BackendCommand::DiffSymbolByName(
+                        ResymPDBSlots::Main as usize,
+                        ResymPDBSlots::Diff as usize,
+                        symbol_name,
+                        reconstruction_options,
+                    )
+                } else {
+                    BackendCommand::ReconstructSymbolByName(
+                        ResymPDBSlots::Main as usize,
+                        symbol_name,
+                        reconstruction_options,
+                    )
+                }
+            }
+            NavigationTarget::Module {
+                module_path,
+                module_index,
+            } => {
+                if is_comparing {
+                    BackendCommand::DiffModuleByPath(
+                        ResymPDBSlots::Main as usize,
+                        ResymPDBSlots::Diff as usize,
+                        module_path,
+                        reconstruction_options,
+                    )
+                } else {
+                    BackendCommand::ReconstructModuleByIndex(
+                        ResymPDBSlots::Main as usize,
+                        module_index,
+                        reconstruction_options,
+                    )
+                }
+            }
+        };
+
+        self.left_panel_selected_tab = navigation_entry.left_panel_tab;
+        self.pending_scroll_offset = Some(navigation_entry.scroll_offset);
+        if let Err(err) = self.backend.send_command(backend_command) {
+            log::error!("Failed to navigate through history: {}", err);
+        }
+    }
+
+    /// Open the given bookmark in the active tab
+    fn open_bookmark(&mut self, bookmark: Bookmark) {
+        let target = NavigationTarget::from(bookmark);
+        self.document_tabs
+            .open(target.clone(), LeftPanelTab::Bookmarks, false);
+        self.open_navigation_entry(NavigationEntry {
+            target,
+            left_panel_tab: LeftPanelTab::Bookmarks,
+            scroll_offset: egui::Vec2::ZERO,
+        });
+    }
+
+    /// Persist the bookmarks and notes attached to the main PDB file
+    #[cfg(not(target_arch = "wasm32"))]
+    fn store_annotations(&self) {
+        if let Err(err) = self.backend.send_command(BackendCommand::StoreAnnotations(
+            ResymPDBSlots::Main as usize,
+            self.bookmarks.annotations().clone(),
+        )) {
+            log::error!("Failed to store annotations: {}", err);
+        }
+    }
+
+    /// Note: annotations aren't persisted on wasm32 targets
+    #[cfg(target_arch = "wasm32")]
+    fn store_annotations(&self) {}
+
+    /// Update the selected type or symbol to match the given item
+    fn select_navigation_target(&mut self, target: &NavigationTarget) {
+        match target {
+            NavigationTarget::Type {
+                type_name,
+                type_index,
+            } => {
+                self.selected_type_index = Some(*type_index);
+                self.selected_type_name = Some(type_name.clone());
+            }
+            NavigationTarget::Symbol {
+                symbol_name,
+                symbol_index,
+            } => {
+                self.selected_symbol_index = *symbol_index;
+                self.selected_symbol_name = Some(symbol_name.clone());
+            }
+            NavigationTarget::Module { .. } => {}
+        }
+    }
+
+    /// Switch to the tab requested since the last frame, if any
+    fn activate_requested_document_tab(&mut self) {
+        if self
+            .document_tabs
+            .activate_requested_tab(&mut self.current_mode)
+        {
+            self.refresh_active_document_tab();
+        }
+    }
+
+    /// Update the views to match the active tab, and reconstruct its content
+    /// again if needed
+    fn refresh_active_document_tab(&mut self) {
+        let active_tab = self.document_tabs.active_tab();
+        let Some(navigation_entry) = active_tab.navigation_history.current_entry().cloned() else {
+            self.code_view.set_type_links("", &[]);
+            return;
+        };
+
+        if active_tab.needs_reload && self.current_mode != ResymAppMode::Idle {
+            self.document_tabs.active_tab_mut().needs_reload = false;
+            self.open_navigation_entry(navigation_entry);
+        } else {
+            let type_links = active_tab.type_links.clone();
+            self.select_navigation_target(&navigation_entry.target);
+            self.left_panel_selected_tab = navigation_entry.left_panel_tab;
+            if let ResymAppMode::Browsing(_, _, ref content) = self.current_mode {
+                self.code_view.set_type_links(content, &type_links);
+            }
+            if !type_links.is_empty() {
+                self.list_type_details(type_links);
+            }
+            self.code_view.scroll_to(navigation_entry.scroll_offset);
+        }
+    }
+
+    /// Restore the scroll position of the content opened from the navigation
+    /// history, if any
+    fn restore_scroll_offset(&mut self) {
+        if let Some(scroll_offset) = self.pending_scroll_offset.take() {
+            self.code_view.scroll_to(scroll_offset);
+        }
+    }
+
     /// Function invoked on 'Find XRefs to'
     fn list_xrefs_for_type(&self, type_index: TypeIndex) {
         log::info!(
@@ -1040,6 +2120,248 @@ impl ResymApp {
         }
     }
 
+    fn list_type_details(&self, type_list: TypeList) {
+        if let Err(err) = self.backend.send_command(BackendCommand::ListTypeDetails(
+            ResymPDBSlots::Main as usize,
+            type_list,
+        )) {
+            log::error!("Failed to retrieve type details: {err}");
+        }
+    }
+
+    fn compute_type_layout(&self, type_index: TypeIndex) {
+        if let Err(err) = self.backend.send_command(BackendCommand::ComputeTypeLayout(
+            ResymPDBSlots::Main as usize,
+            type_index,
+        )) {
+            log::error!("Failed to compute memory layout: {err}");
+        }
+    }
+
+    fn list_class_hierarchy_for_type(&self, type_name: &str) {
+        log::info!("Looking for base and derived classes of '{}'...", type_name);
+        if let Err(err) = self
+            .backend
+            .send_command(BackendCommand::ListClassHierarchy(
+                ResymPDBSlots::Main as usize,
+                type_name.to_string(),
+            ))
+        {
+            log::error!(
+                "Failed to list base and derived classes of '{}': {err}",
+                type_name
+            );
+        }
+    }
+
+    fn list_inline_sites_for_symbol(&self, symbol_name: &str) {
+        log::info!("Looking for inline sites for symbol '{}'...", symbol_name);
+        if let Err(err) = self.backend.send_command(BackendCommand::ListInlineSites(
+            ResymPDBSlots::Main as usize,
+            symbol_name.to_string(),
+        )) {
+            log::error!(
+                "Failed to list inline sites for symbol '{}': {err}",
+                symbol_name
+            );
+        }
+    }
+
+    fn query_type_graph(&self, query: TypeGraphQuery) {
+        if let Err(err) = self.backend.send_command(BackendCommand::QueryTypeGraph(
+            ResymPDBSlots::Main as usize,
+            query,
+        )) {
+            log::error!("Failed to query type graph: {err}");
+        }
+    }
+
+    fn find_fields(&self, query: FieldSearchQuery) {
+        if let Err(err) = self.backend.send_command(BackendCommand::FindFields(
+            ResymPDBSlots::Main as usize,
+            query,
+            self.settings.app_settings.filter_options(),
+        )) {
+            log::error!("Failed to search fields: {err}");
+        }
+    }
+
+    fn match_layout(&self, query: LayoutQuery, max_results: usize) {
+        if let Err(err) = self.backend.send_command(BackendCommand::MatchLayout(
+            ResymPDBSlots::Main as usize,
+            query,
+            Some(max_results),
+        )) {
+            log::error!("Failed to match layout: {err}");
+        }
+    }
+
+    fn update_inline_site_list(&mut self, inline_site_list: InlineSiteList) {
+        let selected_symbol_name = self.selected_symbol_name.as_deref();
+        let index_list = inline_site_list
+            .iter()
+            .enumerate()
+            .map(|(inline_site_index, inline_site)| {
+                (
+                    inline_site_description(inline_site, selected_symbol_name),
+                    inline_site_index,
+                )
+            })
+            .collect();
+        self.inline_site_list.update_index_list(index_list);
+        self.inline_sites = inline_site_list;
+    }
+
+    /// Update/render the session-related entries of the "File" menu
+    #[cfg(not(target_arch = "wasm32"))]
+    fn update_session_menu(&mut self, ui: &mut egui::Ui) {
+        let mut selected_file = None;
+        ui.add_enabled_ui(!self.recent_files.is_empty(), |ui| {
+            ui.menu_button("Open recent", |ui| {
+                for recent_file in &self.recent_files {
+                    if ui.button(recent_file.to_string()).clicked() {
+                        ui.close_menu();
+                        selected_file = Some(recent_file.clone());
+                    }
+                }
+            });
+        });
+        if let Some(selected_file) = selected_file {
+            self.load_pdb(ResymPDBSlots::Main as usize, selected_file);
+        }
+
+        // Separate "Open" from sessions
+        ui.separator();
+
+        if ui.button("Open session ...").clicked() {
+            ui.close_menu();
+            self.start_open_session_file();
+        }
+        if ui
+            .add_enabled(
+                !matches!(self.current_mode, ResymAppMode::Idle),
+                egui::Button::new("Save session ..."),
+            )
+            .clicked()
+        {
+            ui.close_menu();
+            self.start_save_session_file();
+        }
+        let mut selected_session = None;
+        ui.add_enabled_ui(!self.recent_sessions.is_empty(), |ui| {
+            ui.menu_button("Recent sessions", |ui| {
+                for recent_session in &self.recent_sessions {
+                    if ui.button(recent_session.display().to_string()).clicked() {
+                        ui.close_menu();
+                        selected_session = Some(recent_session.clone());
+                    }
+                }
+            });
+        });
+        if let Some(selected_session) = selected_session {
+            self.load_session_file(&selected_session);
+        }
+    }
+
+    /// Function invoked on `Open session`
+    #[cfg(not(target_arch = "wasm32"))]
+    fn start_open_session_file(&mut self) {
+        let file_path_opt = tinyfiledialogs::open_file_dialog(
+            "Select a session file",
+            "",
+            Some((&["*.resym"], "resym session files (*.resym)")),
+        );
+        if let Some(file_path) = file_path_opt {
+            self.load_session_file(Path::new(&file_path));
+        }
+    }
+
+    /// Function invoked on `Save session`
+    #[cfg(not(target_arch = "wasm32"))]
+    fn start_save_session_file(&mut self) {
+        let file_path_opt = tinyfiledialogs::save_file_dialog_with_filter(
+            "Save session to file",
+            "",
+            &["*.resym"],
+            "resym session files (*.resym)",
+        );
+        if let Some(file_path) = file_path_opt {
+            let mut file_path = PathBuf::from(file_path);
+            if file_path.extension().is_none() {
+                file_path.set_extension(SESSION_FILE_EXTENSION);
+            }
+
+            let is_comparing = matches!(self.current_mode, ResymAppMode::Comparing(..));
+            let session = AppSession {
+                main_pdb: self.pdb_locations[ResymPDBSlots::Main as usize].clone(),
+                diff_pdb: if is_comparing {
+                    self.pdb_locations[ResymPDBSlots::Diff as usize].clone()
+                } else {
+                    None
+                },
+                tabs: self.document_tabs.persisted_tabs(),
+                active_tab_index: self.document_tabs.persisted_active_tab_index(),
+                left_panel_tab: self.left_panel_selected_tab,
+                type_filter: self.type_search.search_filter().to_string(),
+                symbol_filter: self.symbol_search.search_filter().to_string(),
+                module_filter: self.module_search.search_filter().to_string(),
+                settings: Some(SessionSettings::new(&self.settings.app_settings)),
+            };
+            match session.save_to_file(&file_path) {
+                Ok(()) => {
+                    log::info!("Session has been saved to '{}'.", file_path.display());
+                    push_recent_entry(&mut self.recent_sessions, file_path);
+                }
+                Err(err) => log::error!("Failed to save session: {err}"),
+            }
+        }
+    }
+
+    /// Restore the PDB files and views saved in the given session file
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_session_file(&mut self, file_path: &Path) {
+        let session = match AppSession::load_from_file(file_path) {
+            Ok(session) => session,
+            Err(err) => {
+                log::error!("Failed to load session '{}': {err}", file_path.display());
+                return;
+            }
+        };
+        log::info!("Loading session '{}' ...", file_path.display());
+        push_recent_entry(&mut self.recent_sessions, file_path.to_path_buf());
+
+        if let Some(session_settings) = &session.settings {
+            session_settings.apply(&mut self.settings.app_settings);
+        }
+        self.type_search.set_search_filter(session.type_filter);
+        self.symbol_search.set_search_filter(session.symbol_filter);
+        self.module_search.set_search_filter(session.module_filter);
+        self.left_panel_selected_tab = session.left_panel_tab;
+        // Note: tabs are reconstructed once the PDB files are loaded
+        self.document_tabs = DocumentTabsComponent::new(session.tabs);
+        self.document_tabs.request_tab(session.active_tab_index);
+
+        // Note: switch to the idle mode before loading the PDB files, so that
+        // the new PDB used for diffing isn't unloaded when the main PDB is
+        // loaded
+        if let ResymAppMode::Comparing(..) = self.current_mode {
+            if let Err(err) = self
+                .backend
+                .send_command(BackendCommand::UnloadPDB(ResymPDBSlots::Diff as usize))
+            {
+                log::error!("Failed to unload the PDB used for comparison: {}", err);
+            }
+        }
+        self.current_mode = ResymAppMode::Idle;
+        // Note: the PDB used for diffing is loaded once the main PDB has been
+        // loaded, as loading the main PDB leaves the comparison mode
+        self.pending_diff_pdb = None;
+        if let Some(main_pdb) = session.main_pdb {
+            self.load_pdb(ResymPDBSlots::Main as usize, main_pdb);
+            self.pending_diff_pdb = session.diff_pdb;
+        }
+    }
+
     /// Function invoked on 'Save' or when the Ctrl+S shortcut is used
     #[cfg(not(target_arch = "wasm32"))]
     fn start_save_reconstruted_content(&self) {
@@ -1063,24 +2385,30 @@ impl ResymApp {
     }
 
     #[cfg(not(target_arch = "wasm32"))]
-    fn handle_drag_and_drop(&self, ctx: &egui::Context) {
-        ctx.input(|i| {
-            // Handle dropped files
-            if !i.raw.dropped_files.is_empty() {
-                // Allow dropping 1 file (to just view it), or 2 files to diff them
-                let slots = [ResymPDBSlots::Main as usize, ResymPDBSlots::Diff as usize];
-                for (slot, file) in slots.iter().zip(i.raw.dropped_files.iter()) {
-                    if let Some(file_path) = &file.path {
-                        if let Err(err) = self
-                            .backend
-                            .send_command(BackendCommand::LoadPDBFromPath(*slot, file_path.into()))
-                        {
-                            log::error!("Failed to load the PDB file: {err}");
-                        }
-                    }
-                }
-            }
+    fn handle_drag_and_drop(&mut self, ctx: &egui::Context) {
+        let dropped_file_paths: Vec<PathBuf> = ctx.input(|i| {
+            i.raw
+                .dropped_files
+                .iter()
+                .filter_map(|file| file.path.clone())
+                .collect()
         });
+        // Handle dropped session files
+        if let [file_path] = dropped_file_paths.as_slice() {
+            if file_path
+                .extension()
+                .is_some_and(|extension| extension == SESSION_FILE_EXTENSION)
+            {
+                self.load_session_file(file_path);
+                return;
+            }
+        }
+
+        // Allow dropping 1 file (to just view it), or 2 files to diff them
+        let slots = [ResymPDBSlots::Main as usize, ResymPDBSlots::Diff as usize];
+        for (slot, file_path) in slots.into_iter().zip(dropped_file_paths) {
+            self.load_pdb(slot, PdbLocation::Path(file_path));
+        }
     }
 
     #[cfg(target_arch = "wasm32")]
@@ -1103,3 +2431,26 @@ impl ResymApp {
         });
     }
 }
+
+/// Generate a one-line description of an inline site, from the point of view
+/// of the given symbol
+fn inline_site_description(inline_site: &InlineSite, symbol_name: Option<&str>) -> String {
+    let (relation, other_function) = if symbol_name == Some(inline_site.inlinee_name.as_str()) {
+        ("Inlined into", &inline_site.caller_name)
+    } else {
+        ("Inlines", &inline_site.inlinee_name)
+    };
+    let mut description = format!("{relation} {other_function}");
+    if let Some(rva) = inline_site.rva() {
+        let _r = write!(&mut description, " at RVA=0x{rva:x}");
+    }
+    if let Some(source_file) = &inline_site.source_file {
+        let _r = write!(&mut description, " ({source_file}");
+        if let Some(source_line) = inline_site.source_line {
+            let _r = write!(&mut description, ":{source_line}");
+        }
+        description.push(')');
+    }
+
+    description
+}
@@ -1,4 +1,7 @@
-use resym_core::pdb_types::PrimitiveReconstructionFlavor;
+use resym_core::{
+    options::{FilterOptions, ReconstructionOptions},
+    pdb_types::PrimitiveReconstructionFlavor,
+};
 use serde::{Deserialize, Serialize};
 
 /// This struct represents the persistent settings of the application.
@@ -18,6 +21,13 @@ pub struct ResymAppSettings {
     // Ignore types in the `std` namespace (e.g., STL-generated types)
     pub ignore_std_types: bool,
     pub print_line_numbers: bool,
+    // `_NT_SYMBOL_PATH`-style search path used to find the PDB matching a PE
+    // image (`_NT_SYMBOL_PATH` is used if empty)
+    #[serde(default)]
+    pub symbol_path: String,
+    // Persist PDB indices on disk to speed up subsequent loads
+    #[serde(default = "enable_index_cache_default")]
+    pub enable_index_cache: bool,
 }
 
 impl Default for ResymAppSettings {
@@ -35,14 +45,41 @@ impl Default for ResymAppSettings {
             print_access_specifiers: true,
             ignore_std_types: true,
             print_line_numbers: false,
+            symbol_path: String::new(),
+            enable_index_cache: enable_index_cache_default(),
         }
     }
 }
 
+impl ResymAppSettings {
+    /// Options used when reconstructing types, symbols and modules
+    pub fn reconstruction_options(&self) -> ReconstructionOptions {
+        ReconstructionOptions::new()
+            .primitives_flavor(self.primitive_types_flavor)
+            .print_header(self.print_header)
+            .reconstruct_dependencies(self.reconstruct_dependencies)
+            .print_access_specifiers(self.print_access_specifiers)
+            .integers_as_hexadecimal(self.integers_as_hexadecimal)
+            .ignore_std_types(self.ignore_std_types)
+    }
+
+    /// Options used when filtering type, symbol and module lists
+    pub fn filter_options(&self) -> FilterOptions {
+        FilterOptions::new()
+            .case_insensitive(self.search_case_insensitive)
+            .use_regex(self.search_use_regex)
+            .ignore_std_types(self.ignore_std_types)
+    }
+}
+
+fn enable_index_cache_default() -> bool {
+    false
+}
+
 // Definition of the remote enum so that serde can its traits
 #[derive(Serialize, Deserialize)]
 #[serde(remote = "PrimitiveReconstructionFlavor")]
-enum PrimitiveReconstructionFlavorDef {
+pub(crate) enum PrimitiveReconstructionFlavorDef {
     Portable,
     Microsoft,
     Raw,
@@ -1,26 +1,41 @@
+use std::ops::Range;
+
 use eframe::{
     egui,
     epaint::text::{LayoutJob, TextWrapping},
 };
-use syntect::{easy::HighlightLines, highlighting::FontStyle, util::LinesWithEndings};
+use syntect::{easy::HighlightLines, highlighting::FontStyle};
 
-use resym_core::{diffing::DiffChange, syntax_highlighting::CodeTheme};
+use resym_core::{
+    diffing::{DiffChange, DiffIndices},
+    syntax_highlighting::CodeTheme,
+};
 
-pub type LineDescriptions = Vec<DiffChange>;
+pub type LineDescriptions = Vec<(DiffIndices, DiffChange)>;
 
-/// Memoized code highlighting
-pub fn highlight_code(
+/// Occurrences of a search query in the code, and the one currently selected
+#[derive(Default)]
+pub struct SearchMatches {
+    /// Sorted, non-overlapping byte ranges
+    pub ranges: Vec<Range<usize>>,
+    pub selected_index: Option<usize>,
+}
+
+/// Memoized highlighting of a single line of code.
+/// Note: lines are highlighted independently from each other, so that only
+/// visible lines have to be highlighted.
+pub fn highlight_line(
     ctx: &egui::Context,
     theme: &CodeTheme,
-    code: &str,
+    line: &str,
     enabled: bool,
-    line_descriptions: Option<&LineDescriptions>,
+    line_change: Option<DiffChange>,
 ) -> LayoutJob {
     type HighlightCache<'a> = egui::util::cache::FrameCache<LayoutJob, CodeHighlighter>;
 
     ctx.memory_mut(|memory| {
         let highlight_cache = memory.caches.cache::<HighlightCache<'_>>();
-        highlight_cache.get((theme, code, enabled, line_descriptions))
+        highlight_cache.get((theme, line, enabled, line_change))
     })
 }
 
@@ -42,40 +57,50 @@ impl CodeHighlighter {
     fn highlight(
         &self,
         theme: &CodeTheme,
-        code: &str,
+        line: &str,
         enabled: bool,
-        line_descriptions: Option<&LineDescriptions>,
+        line_change: Option<DiffChange>,
     ) -> LayoutJob {
-        self.highlight_impl(theme, code, enabled, line_descriptions)
+        const COLOR_RED: egui::Color32 = egui::Color32::from_rgb(0x50, 0x10, 0x10);
+        const COLOR_GREEN: egui::Color32 = egui::Color32::from_rgb(0x10, 0x50, 0x10);
+
+        // Change the background of lines that have been affected in the diff.
+        let bg_color = match line_change {
+            Some(DiffChange::Insert) => COLOR_GREEN,
+            Some(DiffChange::Delete) => COLOR_RED,
+            Some(DiffChange::Equal) | None => egui::Color32::TRANSPARENT,
+        };
+
+        self.highlight_impl(theme, line, enabled, bg_color)
             .unwrap_or_else(|| {
                 // Fallback:
-                LayoutJob::simple(
-                    code.into(),
+                let mut job = LayoutJob::simple_singleline(
+                    line.into(),
                     egui::FontId::monospace(theme.font_size as f32),
                     if theme.dark_mode {
                         egui::Color32::LIGHT_GRAY
                     } else {
                         egui::Color32::DARK_GRAY
                     },
-                    f32::INFINITY,
-                )
+                );
+                for section in &mut job.sections {
+                    section.format.background = bg_color;
+                }
+                job
             })
     }
 
     fn highlight_impl(
         &self,
         theme: &CodeTheme,
-        text: &str,
+        line: &str,
         enabled: bool,
-        line_descriptions: Option<&LineDescriptions>,
+        bg_color: egui::Color32,
     ) -> Option<LayoutJob> {
         if !enabled {
             return None;
         }
 
-        const COLOR_RED: egui::Color32 = egui::Color32::from_rgb(0x50, 0x10, 0x10);
-        const COLOR_GREEN: egui::Color32 = egui::Color32::from_rgb(0x10, 0x50, 0x10);
-
         let syntax = self
             .ps
             .find_syntax_by_name(&theme.language_syntax)
@@ -87,7 +112,7 @@ impl CodeHighlighter {
         use egui::text::{LayoutSection, TextFormat};
 
         let mut job = LayoutJob {
-            text: text.into(),
+            text: line.into(),
             // Disable wrapping forcefully
             wrap: TextWrapping {
                 max_width: f32::INFINITY,
@@ -96,63 +121,127 @@ impl CodeHighlighter {
             ..Default::default()
         };
 
-        for (line_id, line) in LinesWithEndings::from(text).enumerate() {
-            // Change the background of regions that have been affected in the diff.
-            let bg_color = match line_descriptions {
-                None => egui::Color32::TRANSPARENT,
-                Some(line_desc) => match line_desc.get(line_id) {
-                    None => egui::Color32::TRANSPARENT,
-                    Some(line_desc) => match line_desc {
-                        DiffChange::Insert => COLOR_GREEN,
-                        DiffChange::Delete => COLOR_RED,
-                        DiffChange::Equal => egui::Color32::TRANSPARENT,
-                    },
-                },
+        for (style, range) in h.highlight_line(line, &self.ps).ok()? {
+            let fg = style.foreground;
+            let text_color = egui::Color32::from_rgb(fg.r, fg.g, fg.b);
+            let italics = style.font_style.contains(FontStyle::ITALIC);
+            let underline = style.font_style.contains(FontStyle::ITALIC);
+            let underline = if underline {
+                egui::Stroke::new(1.0, text_color)
+            } else {
+                egui::Stroke::NONE
             };
-
-            for (style, range) in h.highlight_line(line, &self.ps).ok()? {
-                let fg = style.foreground;
-                let text_color = egui::Color32::from_rgb(fg.r, fg.g, fg.b);
-                let italics = style.font_style.contains(FontStyle::ITALIC);
-                let underline = style.font_style.contains(FontStyle::ITALIC);
-                let underline = if underline {
-                    egui::Stroke::new(1.0, text_color)
-                } else {
-                    egui::Stroke::NONE
-                };
-                job.sections.push(LayoutSection {
-                    leading_space: 0.0,
-                    byte_range: as_byte_range(text, range),
-                    format: TextFormat {
-                        background: bg_color,
-                        font_id: egui::FontId::monospace(theme.font_size as f32),
-                        color: text_color,
-                        italics,
-                        underline,
-                        ..Default::default()
-                    },
-                });
-            }
+            job.sections.push(LayoutSection {
+                leading_space: 0.0,
+                byte_range: as_byte_range(line, range),
+                format: TextFormat {
+                    background: bg_color,
+                    font_id: egui::FontId::monospace(theme.font_size as f32),
+                    color: text_color,
+                    italics,
+                    underline,
+                    ..Default::default()
+                },
+            });
         }
 
         Some(job)
     }
 }
 
-impl egui::util::cache::ComputerMut<(&CodeTheme, &str, bool, Option<&LineDescriptions>), LayoutJob>
-    for CodeHighlighter
-{
-    fn compute(
-        &mut self,
-        (theme, code, enabled, line_descriptions): (
-            &CodeTheme,
-            &str,
-            bool,
-            Option<&LineDescriptions>,
-        ),
-    ) -> LayoutJob {
-        self.highlight(theme, code, enabled, line_descriptions)
+type HighlightCacheKey<'a> = (&'a CodeTheme, &'a str, bool, Option<DiffChange>);
+
+impl egui::util::cache::ComputerMut<HighlightCacheKey<'_>, LayoutJob> for CodeHighlighter {
+    fn compute(&mut self, (theme, line, enabled, line_change): HighlightCacheKey<'_>) -> LayoutJob {
+        self.highlight(theme, line, enabled, line_change)
+    }
+}
+
+/// Change the background of the sections that match the search query, splitting
+/// sections where needed
+pub fn highlight_search_matches(
+    job: &mut LayoutJob,
+    search_matches: &SearchMatches,
+    dark_mode: bool,
+) {
+    let (match_color, selected_match_color) = if dark_mode {
+        (
+            egui::Color32::from_rgb(0x60, 0x50, 0x10),
+            egui::Color32::from_rgb(0xa0, 0x70, 0x10),
+        )
+    } else {
+        (
+            egui::Color32::from_rgb(0xff, 0xf0, 0x80),
+            egui::Color32::from_rgb(0xff, 0xb0, 0x40),
+        )
+    };
+
+    set_ranges_background(job, &search_matches.ranges, |match_index| {
+        if search_matches.selected_index == Some(match_index) {
+            selected_match_color
+        } else {
+            match_color
+        }
+    });
+}
+
+/// Change the background of the characters that differ between two versions
+/// of a line, splitting sections where needed
+pub fn highlight_changed_ranges(
+    job: &mut LayoutJob,
+    changed_ranges: &[Range<usize>],
+    line_change: DiffChange,
+) {
+    const COLOR_BRIGHT_RED: egui::Color32 = egui::Color32::from_rgb(0x90, 0x20, 0x20);
+    const COLOR_BRIGHT_GREEN: egui::Color32 = egui::Color32::from_rgb(0x20, 0x90, 0x20);
+
+    let background = match line_change {
+        DiffChange::Insert => COLOR_BRIGHT_GREEN,
+        DiffChange::Delete => COLOR_BRIGHT_RED,
+        DiffChange::Equal => return,
+    };
+    set_ranges_background(job, changed_ranges, |_| background);
+}
+
+/// Change the background of the given sorted, non-overlapping byte ranges,
+/// following the color returned by `background` for each range's index
+fn set_ranges_background(
+    job: &mut LayoutJob,
+    ranges: &[Range<usize>],
+    background: impl Fn(usize) -> egui::Color32,
+) {
+    let mut range_index = 0;
+    let mut sections = Vec::with_capacity(job.sections.len());
+    for section in job.sections.drain(..) {
+        let mut start = section.byte_range.start;
+        let end = section.byte_range.end;
+        while start < end {
+            // Skip ranges located before the current position
+            while ranges
+                .get(range_index)
+                .is_some_and(|range| range.end <= start)
+            {
+                range_index += 1;
+            }
+
+            let (piece_end, piece_background) = match ranges.get(range_index) {
+                Some(range) if range.start <= start => {
+                    (range.end.min(end), Some(background(range_index)))
+                }
+                Some(range) => (range.start.min(end), None),
+                None => (end, None),
+            };
+
+            let mut piece = section.clone();
+            piece.byte_range = start..piece_end;
+            if let Some(piece_background) = piece_background {
+                piece.format.background = piece_background;
+            }
+            sections.push(piece);
+            start = piece_end;
+        }
     }
+    job.sections = sections;
 }
 
 fn as_byte_range(whole: &str, range: &str) -> std::ops::Range<usize> {
@@ -0,0 +1,244 @@
+use eframe::egui::{self, ScrollArea};
+
+use resym_core::annotations::{Annotations, Bookmark};
+
+/// Part of the annotations modified by the user
+#[derive(Clone, Copy, PartialEq)]
+pub enum AnnotationsChange {
+    Bookmarks,
+    Notes,
+}
+
+/// UI component in charge of rendering the bookmarks and notes attached to
+/// the loaded PDB file, and of editing them
+pub struct BookmarksComponent {
+    annotations: Annotations,
+    // Note being edited
+    note_type_name: String,
+    /// Name of the annotated field, empty when annotating the type itself
+    note_field_name: String,
+    note_text: String,
+}
+
+impl BookmarksComponent {
+    pub fn new() -> Self {
+        Self {
+            annotations: Annotations::default(),
+            note_type_name: String::default(),
+            note_field_name: String::default(),
+            note_text: String::default(),
+        }
+    }
+
+    pub fn annotations(&self) -> &Annotations {
+        &self.annotations
+    }
+
+    pub fn set_annotations(&mut self, annotations: Annotations) {
+        self.annotations = annotations;
+    }
+
+    /// Update/render the UI component.
+    /// `current_bookmark` designates the item displayed in the active tab, if
+    /// any, and `on_bookmark_selected` is invoked with the bookmark that was
+    /// clicked. Returns which part of the annotations was modified, if any.
+    pub fn update<CB: FnMut(&Bookmark)>(
+        &mut self,
+        ui: &mut egui::Ui,
+        current_bookmark: Option<Bookmark>,
+        current_type_name: Option<&str>,
+        on_bookmark_selected: &mut CB,
+    ) -> Option<AnnotationsChange> {
+        let mut change = None;
+        ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                if self.update_bookmarks(ui, current_bookmark, on_bookmark_selected) {
+                    change = Some(AnnotationsChange::Bookmarks);
+                }
+                ui.separator();
+                if self.update_notes(ui, current_type_name) {
+                    change = Some(AnnotationsChange::Notes);
+                }
+            });
+
+        change
+    }
+
+    /// Render the bookmark list. Returns `true` if bookmarks were modified.
+    fn update_bookmarks<CB: FnMut(&Bookmark)>(
+        &mut self,
+        ui: &mut egui::Ui,
+        current_bookmark: Option<Bookmark>,
+        on_bookmark_selected: &mut CB,
+    ) -> bool {
+        let mut bookmarks_changed = false;
+        ui.horizontal(|ui| {
+            ui.strong("Bookmarks");
+            let is_bookmarked = current_bookmark
+                .as_ref()
+                .is_some_and(|bookmark| self.annotations.is_bookmarked(bookmark));
+            let button_label = if is_bookmarked {
+                "Remove bookmark"
+            } else {
+                "Bookmark current item"
+            };
+            if ui
+                .add_enabled(current_bookmark.is_some(), egui::Button::new(button_label))
+                .clicked()
+            {
+                if let Some(current_bookmark) = current_bookmark {
+                    if is_bookmarked {
+                        self.annotations.remove_bookmark(&current_bookmark);
+                    } else {
+                        self.annotations.add_bookmark(current_bookmark);
+                    }
+                    bookmarks_changed = true;
+                }
+            }
+        });
+        ui.add_space(4.0);
+
+        if self.annotations.bookmarks().is_empty() {
+            // Display a default message to make it obvious the list is empty
+            ui.label("No bookmarks");
+            return bookmarks_changed;
+        }
+
+        let mut removed_bookmark = None;
+        for bookmark in self.annotations.bookmarks() {
+            ui.horizontal(|ui| {
+                if ui.small_button("✖").on_hover_text("Remove").clicked() {
+                    removed_bookmark = Some(bookmark.clone());
+                }
+                if ui
+                    .selectable_label(
+                        false,
+                        format!("{}: {}", bookmark_kind(bookmark), bookmark.name()),
+                    )
+                    .clicked()
+                {
+                    on_bookmark_selected(bookmark);
+                }
+            });
+        }
+        if let Some(removed_bookmark) = removed_bookmark {
+            self.annotations.remove_bookmark(&removed_bookmark);
+            bookmarks_changed = true;
+        }
+
+        bookmarks_changed
+    }
+
+    /// Render the note editor and the note list. Returns `true` if notes were
+    /// modified.
+    fn update_notes(&mut self, ui: &mut egui::Ui, current_type_name: Option<&str>) -> bool {
+        let mut notes_changed = false;
+        ui.strong("Notes");
+        ui.add_space(4.0);
+
+        // Note editor
+        ui.horizontal(|ui| {
+            ui.label("Type");
+            if ui
+                .add_enabled(
+                    current_type_name.is_some(),
+                    egui::Button::new("Use current"),
+                )
+                .clicked()
+            {
+                if let Some(current_type_name) = current_type_name {
+                    self.load_note(current_type_name, None);
+                }
+            }
+        });
+        ui.text_edit_singleline(&mut self.note_type_name);
+        ui.label("Field (optional)");
+        ui.text_edit_singleline(&mut self.note_field_name);
+        ui.label("Note");
+        ui.text_edit_multiline(&mut self.note_text);
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(
+                    !self.note_type_name.trim().is_empty(),
+                    egui::Button::new("Save"),
+                )
+                .on_hover_text("Saving an empty note deletes it")
+                .clicked()
+            {
+                self.annotations.set_note(
+                    self.note_type_name.trim(),
+                    Some(self.note_field_name.as_str()),
+                    &self.note_text,
+                );
+                notes_changed = true;
+            }
+            if ui.button("Clear").clicked() {
+                self.note_type_name.clear();
+                self.note_field_name.clear();
+                self.note_text.clear();
+            }
+        });
+        ui.add_space(4.0);
+
+        // Note list
+        let mut edited_note = None;
+        let mut removed_note = None;
+        for (note_target, text) in self.annotations.notes() {
+            let note_name = match &note_target.field_name {
+                Some(field_name) => format!("{}::{field_name}", note_target.type_name),
+                None => note_target.type_name.clone(),
+            };
+            ui.horizontal(|ui| {
+                if ui.small_button("✖").on_hover_text("Delete").clicked() {
+                    removed_note = Some(note_target.clone());
+                }
+                if ui
+                    .selectable_label(false, note_name)
+                    .on_hover_text(text)
+                    .clicked()
+                {
+                    edited_note = Some(note_target.clone());
+                }
+            });
+        }
+        if let Some(edited_note) = edited_note {
+            self.load_note(&edited_note.type_name, edited_note.field_name.as_deref());
+        }
+        if let Some(removed_note) = removed_note {
+            self.annotations.set_note(
+                &removed_note.type_name,
+                removed_note.field_name.as_deref(),
+                "",
+            );
+            notes_changed = true;
+        }
+
+        notes_changed
+    }
+
+    /// Load the note attached to the given type (or field) into the editor
+    fn load_note(&mut self, type_name: &str, field_name: Option<&str>) {
+        self.note_text = self
+            .annotations
+            .note(type_name, field_name)
+            .unwrap_or_default()
+            .to_string();
+        self.note_type_name = type_name.to_string();
+        self.note_field_name = field_name.unwrap_or_default().to_string();
+    }
+}
+
+impl Default for BookmarksComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn bookmark_kind(bookmark: &Bookmark) -> &'static str {
+    match bookmark {
+        Bookmark::Type { .. } => "Type",
+        Bookmark::Symbol { .. } => "Symbol",
+        Bookmark::Module { .. } => "Module",
+    }
+}
@@ -0,0 +1,124 @@
+use eframe::egui::{self, ScrollArea};
+
+use resym_core::{
+    class_hierarchy::{ClassHierarchyNode, ClassHierarchyTree},
+    pdb_file::TypeIndex,
+};
+
+/// UI component in charge of rendering the base and derived classes of a class
+/// as trees
+pub struct ClassHierarchyComponent {
+    class_hierarchy: Option<ClassHierarchyTree>,
+    /// Index of the currently selected class
+    selected_type_index: Option<TypeIndex>,
+}
+
+impl ClassHierarchyComponent {
+    pub fn new() -> Self {
+        Self {
+            class_hierarchy: None,
+            selected_type_index: None,
+        }
+    }
+
+    /// Update the hierarchy that the trees display
+    pub fn set_class_hierarchy(&mut self, class_hierarchy: Option<ClassHierarchyTree>) {
+        self.class_hierarchy = class_hierarchy;
+        self.selected_type_index = None;
+    }
+
+    /// Update/render the UI component
+    pub fn update<CB: FnMut(&str, TypeIndex)>(
+        &mut self,
+        ui: &mut egui::Ui,
+        on_type_selected: &mut CB,
+    ) {
+        let Self {
+            class_hierarchy,
+            selected_type_index,
+        } = self;
+        let class_hierarchy = if let Some(class_hierarchy) = class_hierarchy {
+            class_hierarchy
+        } else {
+            // Display a default message to make it obvious the tree is empty
+            ui.label("No results");
+            return;
+        };
+
+        ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                if ui
+                    .selectable_label(
+                        *selected_type_index == Some(class_hierarchy.type_index),
+                        &class_hierarchy.type_name,
+                    )
+                    .clicked()
+                {
+                    *selected_type_index = Some(class_hierarchy.type_index);
+                    on_type_selected(&class_hierarchy.type_name, class_hierarchy.type_index);
+                }
+
+                for (section_name, nodes) in [
+                    ("Base classes", &class_hierarchy.base_classes),
+                    ("Derived classes", &class_hierarchy.derived_classes),
+                ] {
+                    let id = ui.id().with(section_name);
+                    egui::CollapsingHeader::new(format!("{section_name} ({})", nodes.len()))
+                        .id_salt(id)
+                        .default_open(true)
+                        .show(ui, |ui| {
+                            update_class_hierarchy_nodes(
+                                ui,
+                                id,
+                                nodes,
+                                selected_type_index,
+                                on_type_selected,
+                            );
+                        });
+                }
+            });
+    }
+}
+
+fn update_class_hierarchy_nodes<CB: FnMut(&str, TypeIndex)>(
+    ui: &mut egui::Ui,
+    parent_id: egui::Id,
+    nodes: &[ClassHierarchyNode],
+    selected_type_index: &mut Option<TypeIndex>,
+    on_type_selected: &mut CB,
+) {
+    for node in nodes {
+        let label = if node.is_virtual {
+            format!("virtual {}", node.type_name)
+        } else {
+            node.type_name.clone()
+        };
+        let mut update_node_label = |ui: &mut egui::Ui| {
+            if ui
+                .selectable_label(*selected_type_index == Some(node.type_index), &label)
+                .clicked()
+            {
+                *selected_type_index = Some(node.type_index);
+                on_type_selected(&node.type_name, node.type_index);
+            }
+        };
+
+        if node.children.is_empty() {
+            update_node_label(ui);
+        } else {
+            let id = parent_id.with(node.type_index);
+            egui::collapsing_header::CollapsingState::load_with_default_open(ui.ctx(), id, true)
+                .show_header(ui, update_node_label)
+                .body(|ui| {
+                    update_class_hierarchy_nodes(
+                        ui,
+                        id,
+                        &node.children,
+                        selected_type_index,
+                        on_type_selected,
+                    );
+                });
+        }
+    }
+}
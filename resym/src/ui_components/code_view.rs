@@ -1,20 +1,258 @@
-use eframe::egui;
-use resym_core::syntax_highlighting::CodeTheme;
+use std::{collections::HashMap, ops::Range};
 
-use crate::{mode::ResymAppMode, settings::ResymAppSettings, syntax_highlighting::highlight_code};
+use eframe::{
+    egui,
+    epaint::text::{cursor::CCursor, Galley},
+};
+use resym_core::{
+    diffing::{inline_changes, side_by_side_rows, DiffChange, DiffIndices, SideBySideRow},
+    pdb_file::{TypeDetails, TypeDetailsList, TypeIndex},
+    syntax_highlighting::CodeTheme,
+};
 
-pub struct CodeViewComponent {}
+use super::FindBarComponent;
+use crate::{
+    mode::ResymAppMode,
+    settings::ResymAppSettings,
+    syntax_highlighting::{
+        highlight_changed_ranges, highlight_line, highlight_search_matches, LineDescriptions,
+        SearchMatches,
+    },
+};
+
+/// Occurrence of a known type name in the reconstructed output
+struct TypeLink {
+    /// Byte range of the type name in the reconstructed output
+    range: Range<usize>,
+    type_name: String,
+    type_index: TypeIndex,
+}
+
+/// Lines of the text content, computed once per content update so that only
+/// the visible lines have to be processed on each frame
+#[derive(Default)]
+struct ContentLines {
+    /// Hash of the mode (and thus of the content) the lines were computed
+    /// for, used to detect content updates
+    content_hash: Option<u64>,
+    /// Byte range of each line in the content, without line endings
+    line_ranges: Vec<Range<usize>>,
+    /// Line numbers of each line, for each line number column (i.e., one
+    /// column when browsing, old and new line numbers when comparing)
+    line_number_columns: Vec<Vec<String>>,
+    /// Lines of the diff aligned in two columns, when comparing
+    side_by_side_rows: Vec<SideBySideRow>,
+    /// Side-by-side row of each line of the diff, when comparing
+    side_by_side_row_indices: Vec<usize>,
+    /// First line of each block of changed lines, when comparing
+    change_starts: Vec<usize>,
+    /// First side-by-side row of each block of changed rows, when comparing
+    side_by_side_change_starts: Vec<usize>,
+}
+
+impl ContentLines {
+    /// Update the lines if the content changed.
+    /// Returns `true` if the content changed.
+    fn update(&mut self, current_mode: &ResymAppMode, content: &str) -> bool {
+        let content_hash = egui::util::hash(current_mode);
+        if self.content_hash == Some(content_hash) {
+            return false;
+        }
+        self.content_hash = Some(content_hash);
+
+        let mut line_start = 0;
+        self.line_ranges = content
+            .split_inclusive('\n')
+            .map(|line| {
+                let line_range = line_start..line_start + line.trim_end_matches(['\r', '\n']).len();
+                line_start += line.len();
+                line_range
+            })
+            .collect();
+
+        // Note: line numbers are stored per line, to avoid formatting them on
+        // each frame
+        let format_line_index =
+            |line_index: Option<usize>| line_index.map(|i| (i + 1).to_string()).unwrap_or_default();
+        self.line_number_columns = match current_mode {
+            ResymAppMode::Browsing(line_numbers, ..) => {
+                vec![line_numbers.lines().map(str::to_string).collect()]
+            }
+            ResymAppMode::Comparing(_, line_descriptions, _) => vec![
+                line_descriptions
+                    .iter()
+                    .map(|((old_index, _), _)| format_line_index(*old_index))
+                    .collect(),
+                line_descriptions
+                    .iter()
+                    .map(|((_, new_index), _)| format_line_index(*new_index))
+                    .collect(),
+            ],
+            ResymAppMode::Idle => vec![],
+        };
+
+        if let ResymAppMode::Comparing(_, line_descriptions, _) = current_mode {
+            self.side_by_side_rows = side_by_side_rows(line_descriptions);
+            self.side_by_side_row_indices = vec![0; line_descriptions.len()];
+            for (row_index, row) in self.side_by_side_rows.iter().enumerate() {
+                for line_index in [row.old_line, row.new_line].into_iter().flatten() {
+                    self.side_by_side_row_indices[line_index] = row_index;
+                }
+            }
+            self.change_starts = block_starts(
+                line_descriptions
+                    .iter()
+                    .map(|(_, line_change)| *line_change != DiffChange::Equal),
+            );
+            self.side_by_side_change_starts = block_starts(
+                self.side_by_side_rows
+                    .iter()
+                    .map(|row| !is_unchanged_row(row)),
+            );
+        } else {
+            self.side_by_side_rows.clear();
+            self.side_by_side_row_indices.clear();
+            self.change_starts.clear();
+            self.side_by_side_change_starts.clear();
+        }
+
+        true
+    }
+
+    /// Return the index of the line containing the given byte offset
+    fn line_index(&self, byte_index: usize) -> usize {
+        self.line_ranges
+            .partition_point(|line_range| line_range.end < byte_index)
+            .min(self.line_ranges.len().saturating_sub(1))
+    }
+}
+
+/// Side of a side-by-side diff
+#[derive(Clone, Copy, Hash)]
+enum DiffSide {
+    Old,
+    New,
+}
+
+/// Parameters shared by all the lines rendered during a frame
+struct LineRenderContext<'a> {
+    app_settings: &'a ResymAppSettings,
+    theme: CodeTheme,
+    content: &'a str,
+    font_id: egui::FontId,
+    row_height: f32,
+    /// Width of the line number columns, if displayed
+    line_number_width: Option<f32>,
+}
+
+pub struct CodeViewComponent {
+    /// Clickable type names, sorted by position in the reconstructed output
+    type_links: Vec<TypeLink>,
+    /// Details displayed when hovering type names, if received already
+    type_details: HashMap<TypeIndex, TypeDetails>,
+    /// Scroll position of the text content, as of the last frame
+    scroll_offset: egui::Vec2,
+    /// Scroll position to apply on the next frame, if any
+    pending_scroll_offset: Option<egui::Vec2>,
+    content_lines: ContentLines,
+    find_bar: FindBarComponent,
+    /// Display diffs in two columns instead of a single unified one
+    side_by_side: bool,
+    /// Index of the block of changed lines last navigated to, if any
+    selected_change: Option<usize>,
+}
 
 impl CodeViewComponent {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            type_links: vec![],
+            type_details: HashMap::new(),
+            scroll_offset: egui::Vec2::ZERO,
+            pending_scroll_offset: None,
+            content_lines: ContentLines::default(),
+            find_bar: FindBarComponent::new(),
+            side_by_side: true,
+            selected_change: None,
+        }
+    }
+
+    /// Show the find bar, to search for text in the content
+    pub fn open_find_bar(&mut self) {
+        self.find_bar.open();
+    }
+
+    /// Return the scroll position of the text content
+    pub fn scroll_offset(&self) -> egui::Vec2 {
+        self.scroll_offset
+    }
+
+    /// Scroll the text content to the given position on the next frame
+    pub fn scroll_to(&mut self, scroll_offset: egui::Vec2) {
+        self.pending_scroll_offset = Some(scroll_offset);
     }
 
-    pub fn update(
+    /// Update the type names that should be clickable in the given
+    /// reconstructed output
+    pub fn set_type_links(
+        &mut self,
+        reconstructed_content: &str,
+        type_list: &[(String, TypeIndex)],
+    ) {
+        self.type_links.clear();
+        self.type_details.clear();
+
+        for (type_name, type_index) in type_list {
+            if type_name.is_empty() {
+                continue;
+            }
+            for (offset, _) in reconstructed_content.match_indices(type_name.as_str()) {
+                let range = offset..offset + type_name.len();
+                if is_identifier_boundary(reconstructed_content, &range) {
+                    self.type_links.push(TypeLink {
+                        range,
+                        type_name: type_name.clone(),
+                        type_index: *type_index,
+                    });
+                }
+            }
+        }
+
+        // Sort links and drop overlapping ones, preferring the longest names
+        // (e.g., `Foo::Bar` over `Bar`)
+        self.type_links.sort_by(|lhs, rhs| {
+            lhs.range
+                .start
+                .cmp(&rhs.range.start)
+                .then(rhs.range.end.cmp(&lhs.range.end))
+        });
+        let mut last_link_end = 0;
+        self.type_links.retain(|type_link| {
+            if type_link.range.start < last_link_end {
+                false
+            } else {
+                last_link_end = type_link.range.end;
+                true
+            }
+        });
+    }
+
+    /// Update the details displayed when hovering type names
+    pub fn set_type_details(&mut self, type_details_list: TypeDetailsList) {
+        self.type_details = type_details_list
+            .into_iter()
+            .map(|type_details| (type_details.type_index, type_details))
+            .collect();
+    }
+
+    /// Update/render the UI component.
+    /// `on_type_selected` is invoked with `true` as its last argument when the
+    /// type should be opened in a new tab (i.e., on Ctrl+click).
+    pub fn update<CB: FnMut(&str, TypeIndex, bool)>(
         &mut self,
         app_settings: &ResymAppSettings,
         current_mode: &ResymAppMode,
         ui: &mut egui::Ui,
+        on_type_selected: &mut CB,
     ) {
         const LANGUAGE_SYNTAX: &str = "cpp";
         let theme = if app_settings.use_light_theme {
@@ -23,122 +261,564 @@ impl CodeViewComponent {
             CodeTheme::dark(app_settings.font_size, LANGUAGE_SYNTAX.to_string())
         };
 
-        let line_desc = if let ResymAppMode::Comparing(_, _, _, line_changes, _) = current_mode {
-            Some(line_changes)
+        let (content, line_desc): (&str, Option<&LineDescriptions>) = match current_mode {
+            ResymAppMode::Browsing(_, _, reconstructed_type_content) => {
+                (reconstructed_type_content, None)
+            }
+            ResymAppMode::Comparing(_, line_descriptions, reconstructed_type_diff) => {
+                (reconstructed_type_diff, Some(line_descriptions))
+            }
+            ResymAppMode::Idle => ("", None),
+        };
+        if self.content_lines.update(current_mode, content) {
+            self.selected_change = None;
+        }
+        let side_by_side = self.side_by_side && line_desc.is_some();
+
+        let font_id = egui::FontId::monospace(app_settings.font_size as f32);
+        let row_height = ui.fonts(|fonts| fonts.row_height(&font_id));
+
+        // Diff toolbar
+        let mut scroll_target_row = None;
+        if line_desc.is_some() {
+            scroll_target_row = self.update_diff_toolbar(ui);
+        }
+
+        // Find bar
+        if let Some(byte_index) = self.find_bar.update(ui, content) {
+            let line_index = self.content_lines.line_index(byte_index);
+            scroll_target_row = Some(if side_by_side {
+                self.content_lines
+                    .side_by_side_row_indices
+                    .get(line_index)
+                    .copied()
+                    .unwrap_or_default()
+            } else {
+                line_index
+            });
+        }
+
+        // Center the row to scroll to vertically, if any
+        if let Some(row_index) = scroll_target_row {
+            let offset_y = row_index as f32 * row_height - ui.available_height() / 2.0;
+            self.pending_scroll_offset = Some(egui::vec2(self.scroll_offset.x, offset_y.max(0.0)));
+        }
+
+        // Compute the line number columns' width from the number of digits
+        let line_number_width = if app_settings.print_line_numbers {
+            let last_line_number = match current_mode {
+                ResymAppMode::Browsing(_, last_line_number, _)
+                | ResymAppMode::Comparing(last_line_number, ..) => *last_line_number,
+                ResymAppMode::Idle => 0,
+            };
+            let char_count = last_line_number.checked_ilog10().unwrap_or(0) + 1;
+            let digit_width = ui.fonts(|fonts| fonts.glyph_width(&font_id, '0'));
+            Some(char_count as f32 * digit_width)
         } else {
             None
         };
 
-        // Layouter that'll disable wrapping and apply syntax highlighting if needed
-        let mut layouter = |ui: &egui::Ui, string: &str, _wrap_width: f32| {
-            let layout_job = highlight_code(
-                ui.ctx(),
-                &theme,
-                string,
-                app_settings.enable_syntax_hightlighting,
-                line_desc,
-            );
-            ui.fonts(|fonts| fonts.layout_job(layout_job))
+        let render_context = LineRenderContext {
+            app_settings,
+            theme,
+            content,
+            font_id,
+            row_height,
+            line_number_width,
         };
+        // Type dump area.
+        // Note: only the visible lines are laid out and highlighted, which keeps
+        // the view responsive with very large outputs.
+        ui.scope(|ui| {
+            ui.spacing_mut().item_spacing.y = 0.0;
+            if side_by_side {
+                self.update_side_by_side_diff(ui, &render_context, line_desc, on_type_selected);
+            } else {
+                self.update_unified_content(ui, &render_context, line_desc, on_type_selected);
+            }
+        });
+    }
 
-        // Type dump area
-        egui::ScrollArea::both()
-            .auto_shrink([false, false])
-            .show(ui, |ui| {
-                // TODO(ergrelet): see if there's a better way to compute this width.
-                let line_number_digit_width = 2 + app_settings.font_size as u32;
-                let (num_colums, min_column_width) = if app_settings.print_line_numbers {
-                    match current_mode {
-                        ResymAppMode::Comparing(_, _, last_line_number, ..) => {
-                            // Compute the columns' sizes from the number of digits
-                            let char_count = last_line_number.checked_ilog10().unwrap_or(1) + 1;
-                            let line_number_width = (char_count * line_number_digit_width) as f32;
-
-                            // Old index + new index + code editor
-                            (3, line_number_width)
-                        }
-                        ResymAppMode::Browsing(_, last_line_number, _) => {
-                            // Compute the columns' sizes from the number of digits
-                            let char_count = last_line_number.checked_ilog10().unwrap_or(1) + 1;
-                            let line_number_width = (char_count * line_number_digit_width) as f32;
+    /// Render the diff navigation bar.
+    /// Returns the row that the code view should scroll to, if any.
+    fn update_diff_toolbar(&mut self, ui: &mut egui::Ui) -> Option<usize> {
+        let mut scroll_target_row = None;
+        ui.horizontal(|ui| {
+            if ui
+                .checkbox(&mut self.side_by_side, "Side by side")
+                .changed()
+            {
+                // Note: changes are counted differently in each view
+                self.selected_change = None;
+            }
+            ui.separator();
 
-                            // Line numbers + code editor
-                            (2, line_number_width)
-                        }
-                        _ => {
-                            // Code editor only
-                            (1, 0.0)
-                        }
+            let change_starts = if self.side_by_side {
+                &self.content_lines.side_by_side_change_starts
+            } else {
+                &self.content_lines.change_starts
+            };
+            let change_count = change_starts.len();
+            let mut step = None;
+            if ui
+                .add_enabled(change_count > 0, egui::Button::new("⬆"))
+                .on_hover_text("Previous change")
+                .clicked()
+            {
+                step = Some(-1);
+            }
+            if ui
+                .add_enabled(change_count > 0, egui::Button::new("⬇"))
+                .on_hover_text("Next change")
+                .clicked()
+            {
+                step = Some(1);
+            }
+            if let Some(step) = step {
+                // Select the next (or previous) change, wrapping around
+                let selected_change = match self.selected_change {
+                    Some(selected_change) => {
+                        (selected_change as isize + step).rem_euclid(change_count as isize) as usize
                     }
-                } else {
-                    // Code editor only
-                    (1, 0.0)
+                    None if step > 0 => 0,
+                    None => change_count - 1,
                 };
+                self.selected_change = Some(selected_change);
+                scroll_target_row = change_starts.get(selected_change).copied();
+            }
 
-                egui::Grid::new("code_editor_grid")
-                    .num_columns(num_colums)
-                    .min_col_width(min_column_width)
-                    .show(ui, |ui| {
-                        match current_mode {
-                            ResymAppMode::Comparing(
-                                line_numbers_old,
-                                line_numbers_new,
-                                _,
-                                _,
-                                reconstructed_type_diff,
-                            ) => {
-                                // Line numbers
-                                if app_settings.print_line_numbers {
-                                    ui.add(
-                                        egui::TextEdit::multiline(&mut line_numbers_old.as_str())
-                                            .font(egui::FontId::monospace(
-                                                app_settings.font_size as f32,
-                                            ))
-                                            .interactive(false)
-                                            .desired_width(min_column_width),
-                                    );
-                                    ui.add(
-                                        egui::TextEdit::multiline(&mut line_numbers_new.as_str())
-                                            .font(egui::FontId::monospace(
-                                                app_settings.font_size as f32,
-                                            ))
-                                            .interactive(false)
-                                            .desired_width(min_column_width),
-                                    );
-                                }
-                                // Text content
-                                ui.add(
-                                    egui::TextEdit::multiline(
-                                        &mut reconstructed_type_diff.as_str(),
-                                    )
-                                    .code_editor()
-                                    .layouter(&mut layouter),
-                                );
-                            }
-                            ResymAppMode::Browsing(line_numbers, _, reconstructed_type_content) => {
-                                // Line numbers
-                                if app_settings.print_line_numbers {
-                                    ui.add(
-                                        egui::TextEdit::multiline(&mut line_numbers.as_str())
-                                            .font(egui::FontId::monospace(
-                                                app_settings.font_size as f32,
-                                            ))
-                                            .interactive(false)
-                                            .desired_width(min_column_width),
-                                    );
-                                }
-                                // Text content
-                                ui.add(
-                                    egui::TextEdit::multiline(
-                                        &mut reconstructed_type_content.as_str(),
-                                    )
-                                    .code_editor()
-                                    .layouter(&mut layouter),
-                                );
-                            }
-                            ResymAppMode::Idle => {}
+            match self.selected_change {
+                Some(selected_change) if selected_change < change_count => {
+                    ui.label(format!("Change {}/{change_count}", selected_change + 1));
+                }
+                _ => {
+                    ui.label(format!("{change_count} changes"));
+                }
+            }
+        });
+        ui.separator();
+
+        scroll_target_row
+    }
+
+    /// Render the text content, with diffs displayed in a single column
+    fn update_unified_content<CB: FnMut(&str, TypeIndex, bool)>(
+        &mut self,
+        ui: &mut egui::Ui,
+        render_context: &LineRenderContext,
+        line_desc: Option<&LineDescriptions>,
+        on_type_selected: &mut CB,
+    ) {
+        let mut scroll_area = egui::ScrollArea::both().auto_shrink([false, false]);
+        if let Some(scroll_offset) = self.pending_scroll_offset.take() {
+            scroll_area = scroll_area.scroll_offset(scroll_offset);
+        }
+        let line_count = self.content_lines.line_ranges.len();
+        let scroll_area_output = scroll_area.show_rows(
+            ui,
+            render_context.row_height,
+            line_count,
+            |ui, row_range| {
+                for line_index in row_range {
+                    let line_change = line_desc.and_then(|line_changes| {
+                        line_changes
+                            .get(line_index)
+                            .map(|(_, line_change)| *line_change)
+                    });
+                    let line_numbers: Vec<&str> = self
+                        .content_lines
+                        .line_number_columns
+                        .iter()
+                        .map(|line_numbers| {
+                            line_numbers
+                                .get(line_index)
+                                .map(String::as_str)
+                                .unwrap_or_default()
+                        })
+                        .collect();
+                    self.update_line(
+                        ui,
+                        render_context,
+                        self.content_lines.line_ranges[line_index].clone(),
+                        line_change,
+                        &line_numbers,
+                        &[],
+                        on_type_selected,
+                    );
+                }
+            },
+        );
+        self.scroll_offset = scroll_area_output.state.offset;
+    }
+
+    /// Render a diff in two columns, scrolled vertically in sync
+    fn update_side_by_side_diff<CB: FnMut(&str, TypeIndex, bool)>(
+        &mut self,
+        ui: &mut egui::Ui,
+        render_context: &LineRenderContext,
+        line_desc: Option<&LineDescriptions>,
+        on_type_selected: &mut CB,
+    ) {
+        let line_changes = line_desc.map(Vec::as_slice).unwrap_or_default();
+        let offset_y = self
+            .pending_scroll_offset
+            .take()
+            .unwrap_or(self.scroll_offset)
+            .y;
+        let mut new_offset_y = offset_y;
+        let row_count = self.content_lines.side_by_side_rows.len();
+        ui.columns(2, |columns| {
+            for (side, ui) in [DiffSide::Old, DiffSide::New].into_iter().zip(columns) {
+                // Note: the same vertical offset is applied to both sides,
+                // the one that changed (if any) is applied to the other side
+                // on the next frame
+                let scroll_area_output = egui::ScrollArea::both()
+                    .id_salt(side)
+                    .auto_shrink([false, false])
+                    .vertical_scroll_offset(offset_y)
+                    .show_rows(ui, render_context.row_height, row_count, |ui, row_range| {
+                        for row_index in row_range {
+                            let row = &self.content_lines.side_by_side_rows[row_index];
+                            self.update_side_by_side_line(
+                                ui,
+                                render_context,
+                                line_changes,
+                                row,
+                                side,
+                                on_type_selected,
+                            );
                         }
                     });
+                if scroll_area_output.state.offset.y != offset_y {
+                    new_offset_y = scroll_area_output.state.offset.y;
+                }
+            }
+        });
+        if new_offset_y != offset_y {
+            ui.ctx().request_repaint();
+        }
+        self.scroll_offset = egui::vec2(0.0, new_offset_y);
+    }
+
+    /// Render one side of a row of a side-by-side diff
+    fn update_side_by_side_line<CB: FnMut(&str, TypeIndex, bool)>(
+        &self,
+        ui: &mut egui::Ui,
+        render_context: &LineRenderContext,
+        line_changes: &[(DiffIndices, DiffChange)],
+        row: &SideBySideRow,
+        side: DiffSide,
+        on_type_selected: &mut CB,
+    ) {
+        let (line_index, column_index) = match side {
+            DiffSide::Old => (row.old_line, 0),
+            DiffSide::New => (row.new_line, 1),
+        };
+        let Some(line_index) = line_index else {
+            // Fill the space left by lines that only exist on the other side
+            let (rect, _) = ui.allocate_exact_size(
+                egui::vec2(ui.available_width(), render_context.row_height),
+                egui::Sense::hover(),
+            );
+            ui.painter()
+                .rect_filled(rect, 0.0, ui.visuals().faint_bg_color);
+            return;
+        };
+
+        // Skip the diff's `+`/`-` prefix
+        let text_range = |line_index: usize| {
+            let line_range = &self.content_lines.line_ranges[line_index];
+            (line_range.start + 1).min(line_range.end)..line_range.end
+        };
+        let line_range = text_range(line_index);
+        let line_change = line_changes
+            .get(line_index)
+            .map(|(_, line_change)| *line_change);
+
+        // Highlight the characters that changed, for lines that have been
+        // modified
+        let changed_ranges = match (row.old_line, row.new_line) {
+            (Some(old_line), Some(new_line)) if old_line != new_line => {
+                let content = render_context.content;
+                let (old_ranges, new_ranges) = inline_changes(
+                    &content[text_range(old_line)],
+                    &content[text_range(new_line)],
+                );
+                match side {
+                    DiffSide::Old => old_ranges,
+                    DiffSide::New => new_ranges,
+                }
+            }
+            _ => vec![],
+        };
+
+        let line_number = self
+            .content_lines
+            .line_number_columns
+            .get(column_index)
+            .and_then(|line_numbers| line_numbers.get(line_index))
+            .map(String::as_str)
+            .unwrap_or_default();
+        self.update_line(
+            ui,
+            render_context,
+            line_range,
+            line_change,
+            &[line_number],
+            &changed_ranges,
+            on_type_selected,
+        );
+    }
+
+    /// Render a line of the text content, preceded by its line numbers.
+    /// `changed_ranges` are relative to the start of the line.
+    #[allow(clippy::too_many_arguments)]
+    fn update_line<CB: FnMut(&str, TypeIndex, bool)>(
+        &self,
+        ui: &mut egui::Ui,
+        render_context: &LineRenderContext,
+        line_range: Range<usize>,
+        line_change: Option<DiffChange>,
+        line_numbers: &[&str],
+        changed_ranges: &[Range<usize>],
+        on_type_selected: &mut CB,
+    ) {
+        let app_settings = render_context.app_settings;
+        let content = render_context.content;
+        let line = &content[line_range.clone()];
+        let mut layout_job = highlight_line(
+            ui.ctx(),
+            &render_context.theme,
+            line,
+            app_settings.enable_syntax_hightlighting,
+            line_change,
+        );
+        if let Some(line_change) = line_change {
+            if !changed_ranges.is_empty() {
+                highlight_changed_ranges(&mut layout_job, changed_ranges, line_change);
+            }
+        }
+        if let Some(search_matches) = self.find_bar.search_matches() {
+            let line_matches = line_search_matches(search_matches, &line_range);
+            if !line_matches.ranges.is_empty() {
+                highlight_search_matches(
+                    &mut layout_job,
+                    &line_matches,
+                    !app_settings.use_light_theme,
+                );
+            }
+        }
+        let galley = ui.fonts(|fonts| fonts.layout_job(layout_job));
+
+        ui.horizontal(|ui| {
+            // Line numbers
+            if let Some(line_number_width) = render_context.line_number_width {
+                for line_number in line_numbers {
+                    line_number_label(
+                        ui,
+                        line_number,
+                        &render_context.font_id,
+                        line_number_width,
+                        render_context.row_height,
+                    );
+                }
+            }
+
+            // Text content.
+            // Note: lines are selectable labels, so text can be selected
+            // across lines. As only the visible lines are rendered, selections
+            // can't extend beyond them, "Copy all" is available for that.
+            let response = ui.add(
+                egui::Label::new(galley.clone())
+                    .selectable(true)
+                    .sense(egui::Sense::click()),
+            );
+            self.update_type_links(
+                ui,
+                line,
+                line_range.start,
+                &galley,
+                response.rect.min,
+                &response,
+                on_type_selected,
+            );
+            response.context_menu(|ui| {
+                if ui.button("Copy line").clicked() {
+                    ui.ctx().copy_text(line.to_string());
+                    ui.close_menu();
+                }
+                if ui.button("Copy all").clicked() {
+                    ui.ctx().copy_text(content.to_string());
+                    ui.close_menu();
+                }
             });
+        });
+    }
+}
+
+impl CodeViewComponent {
+    /// Handle hovering and clicking on type names in a line of the text content
+    #[allow(clippy::too_many_arguments)]
+    fn update_type_links<CB: FnMut(&str, TypeIndex, bool)>(
+        &self,
+        ui: &egui::Ui,
+        line: &str,
+        line_start: usize,
+        galley: &Galley,
+        galley_pos: egui::Pos2,
+        response: &egui::Response,
+        on_type_selected: &mut CB,
+    ) {
+        let Some(pointer_pos) = response.hover_pos() else {
+            return;
+        };
+        let Some(type_link) = self.type_link_at(line, line_start, galley, pointer_pos - galley_pos)
+        else {
+            return;
+        };
+
+        // Underline the hovered type name
+        let start_rect = char_rect(galley, line, type_link.range.start - line_start);
+        let end_rect = char_rect(galley, line, type_link.range.end - line_start);
+        ui.painter().hline(
+            (galley_pos.x + start_rect.left())..=(galley_pos.x + end_rect.left()),
+            galley_pos.y + start_rect.bottom(),
+            ui.visuals().hyperlink_color,
+        );
+        ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+
+        if response.clicked() {
+            let open_in_new_tab = ui.input(|input| input.modifiers.command);
+            on_type_selected(&type_link.type_name, type_link.type_index, open_in_new_tab);
+        }
+
+        // Display the type's details in a tooltip
+        response.clone().on_hover_ui_at_pointer(|ui| {
+            match self.type_details.get(&type_link.type_index) {
+                Some(type_details) => {
+                    ui.strong(&type_details.type_name);
+                    ui.label(match type_details.size {
+                        Some(size) => format!("Size: 0x{size:x} bytes"),
+                        None => "Size unknown".to_string(),
+                    });
+                    ui.label(match &type_details.definition_location {
+                        Some((source_file, source_line)) => {
+                            format!("Defined at: {source_file}:{source_line}")
+                        }
+                        None => "Definition location unknown".to_string(),
+                    });
+                    ui.label("Click to open, Ctrl+click to open in a new tab");
+                }
+                None => {
+                    ui.strong(&type_link.type_name);
+                    ui.label("Click to open, Ctrl+click to open in a new tab");
+                }
+            }
+        });
+    }
+
+    /// Find the type name located at the given position in a line of the text
+    /// content, starting at byte offset `line_start`
+    fn type_link_at(
+        &self,
+        line: &str,
+        line_start: usize,
+        galley: &Galley,
+        pos: egui::Vec2,
+    ) -> Option<&TypeLink> {
+        let char_index = galley.cursor_from_pos(pos).ccursor.index;
+        let byte_index = line_start
+            + line
+                .char_indices()
+                .nth(char_index)
+                .map(|(byte_index, _)| byte_index)?;
+
+        let link_index = self
+            .type_links
+            .partition_point(|type_link| type_link.range.end <= byte_index);
+        self.type_links.get(link_index).filter(|type_link| {
+            type_link.range.contains(&byte_index) && type_link.range.end <= line_start + line.len()
+        })
+    }
+}
+
+/// Display a right-aligned line number
+fn line_number_label(
+    ui: &mut egui::Ui,
+    line_number: &str,
+    font_id: &egui::FontId,
+    width: f32,
+    row_height: f32,
+) {
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(width, row_height), egui::Sense::hover());
+    ui.painter().text(
+        rect.right_top(),
+        egui::Align2::RIGHT_TOP,
+        line_number,
+        font_id.clone(),
+        ui.visuals().weak_text_color(),
+    );
+}
+
+/// Return the first index of each block of consecutive `true` values
+fn block_starts(values: impl Iterator<Item = bool>) -> Vec<usize> {
+    let mut previous_value = false;
+    values
+        .enumerate()
+        .filter_map(|(index, value)| {
+            let is_block_start = value && !previous_value;
+            previous_value = value;
+            is_block_start.then_some(index)
+        })
+        .collect()
+}
+
+/// Check if the given side-by-side row displays the same line on both sides
+fn is_unchanged_row(row: &SideBySideRow) -> bool {
+    row.old_line.is_some() && row.old_line == row.new_line
+}
+
+/// Return the search matches located in the given line, relative to the
+/// start of the line
+fn line_search_matches(search_matches: &SearchMatches, line_range: &Range<usize>) -> SearchMatches {
+    let mut line_matches = SearchMatches::default();
+    let first_match_index = search_matches
+        .ranges
+        .partition_point(|range| range.end <= line_range.start);
+    for (match_index, range) in search_matches
+        .ranges
+        .iter()
+        .enumerate()
+        .skip(first_match_index)
+    {
+        if range.start >= line_range.end {
+            break;
+        }
+        if search_matches.selected_index == Some(match_index) {
+            line_matches.selected_index = Some(line_matches.ranges.len());
+        }
+        line_matches.ranges.push(
+            range.start.max(line_range.start) - line_range.start
+                ..range.end.min(line_range.end) - line_range.start,
+        );
     }
+
+    line_matches
+}
+
+/// Return the rectangle of the character located at the given byte offset in
+/// the text content, relative to the galley
+fn char_rect(galley: &Galley, content: &str, byte_index: usize) -> egui::Rect {
+    let char_index = content[..byte_index].chars().count();
+    galley.pos_from_cursor(&galley.from_ccursor(CCursor::new(char_index)))
+}
+
+/// Check that the given range doesn't start or end in the middle of an
+/// identifier
+fn is_identifier_boundary(content: &str, range: &Range<usize>) -> bool {
+    let is_identifier_char = |c: char| c.is_alphanumeric() || c == '_';
+    let previous_char = content[..range.start].chars().next_back();
+    let next_char = content[range.end..].chars().next();
+
+    !previous_char.is_some_and(is_identifier_char) && !next_char.is_some_and(is_identifier_char)
 }
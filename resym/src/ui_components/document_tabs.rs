@@ -0,0 +1,344 @@
+use eframe::egui;
+use resym_core::pdb_file::TypeList;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    mode::ResymAppMode,
+    navigation_history::{NavigationHistory, NavigationTarget},
+    resym_app::LeftPanelTab,
+};
+
+/// Document displayed in the central panel
+#[derive(Default)]
+pub struct DocumentTab {
+    /// Items successively opened in this tab
+    pub navigation_history: NavigationHistory,
+    /// Pinned tabs keep their content, new items are opened in new tabs
+    /// instead
+    pub pinned: bool,
+    /// Types that are clickable in the tab's content
+    pub type_links: TypeList,
+    /// Whether the tab's content has to be reconstructed again (e.g., after
+    /// loading another PDB)
+    pub needs_reload: bool,
+    /// Content of the tab, stored here while the tab is inactive (the active
+    /// tab's content lives in the application's current mode)
+    content: Option<ResymAppMode>,
+}
+
+impl DocumentTab {
+    /// Item currently displayed in the tab, if any
+    pub fn target(&self) -> Option<&NavigationTarget> {
+        self.navigation_history
+            .current_entry()
+            .map(|navigation_entry| &navigation_entry.target)
+    }
+
+    pub fn title(&self) -> &str {
+        match self.target() {
+            Some(NavigationTarget::Type { type_name, .. }) => type_name,
+            Some(NavigationTarget::Symbol { symbol_name, .. }) => symbol_name,
+            Some(NavigationTarget::Module { module_path, .. }) => module_path,
+            None => "Empty",
+        }
+    }
+}
+
+/// State of a tab, persisted across sessions
+#[derive(Serialize, Deserialize)]
+pub struct PersistedDocumentTab {
+    target: NavigationTarget,
+    pinned: bool,
+}
+
+/// Action triggered from the tab bar
+enum DocumentTabAction {
+    Activate(usize),
+    Close(usize),
+    CloseOthers(usize),
+    TogglePin(usize),
+    Move(usize, usize),
+}
+
+/// UI component in charge of rendering the tab bar of the central panel and
+/// of keeping track of the content of each tab
+pub struct DocumentTabsComponent {
+    /// Open tabs, there's always at least one
+    tabs: Vec<DocumentTab>,
+    active_tab_index: usize,
+    /// Tab to activate on the next call to `activate_requested_tab`
+    requested_tab_index: Option<usize>,
+}
+
+impl DocumentTabsComponent {
+    pub fn new(persisted_tabs: Vec<PersistedDocumentTab>) -> Self {
+        let mut tabs: Vec<DocumentTab> = persisted_tabs
+            .into_iter()
+            .map(|persisted_tab| {
+                let mut tab = DocumentTab {
+                    pinned: persisted_tab.pinned,
+                    needs_reload: true,
+                    ..Default::default()
+                };
+                // Note: assume items were opened from the matching left
+                // panel tab
+                let left_panel_tab = match persisted_tab.target {
+                    NavigationTarget::Type { .. } => LeftPanelTab::TypeSearch,
+                    NavigationTarget::Symbol { .. } => LeftPanelTab::SymbolSearch,
+                    NavigationTarget::Module { .. } => LeftPanelTab::ModuleBrowsing,
+                };
+                tab.navigation_history
+                    .push(persisted_tab.target, left_panel_tab);
+                tab
+            })
+            .collect();
+        if tabs.is_empty() {
+            tabs.push(DocumentTab::default());
+        }
+
+        Self {
+            tabs,
+            active_tab_index: 0,
+            requested_tab_index: None,
+        }
+    }
+
+    /// Return the state of the open tabs that should be persisted
+    pub fn persisted_tabs(&self) -> Vec<PersistedDocumentTab> {
+        self.tabs
+            .iter()
+            .filter_map(|tab| {
+                Some(PersistedDocumentTab {
+                    target: tab.target()?.clone(),
+                    pinned: tab.pinned,
+                })
+            })
+            .collect()
+    }
+
+    /// Return the position of the active tab among the persisted tabs
+    pub fn persisted_active_tab_index(&self) -> usize {
+        self.tabs[..self.active_tab_index]
+            .iter()
+            .filter(|tab| tab.target().is_some())
+            .count()
+    }
+
+    /// Request the activation of the given tab (see `activate_requested_tab`)
+    pub fn request_tab(&mut self, tab_index: usize) {
+        self.requested_tab_index = Some(tab_index);
+    }
+
+    pub fn active_tab(&self) -> &DocumentTab {
+        &self.tabs[self.active_tab_index]
+    }
+
+    pub fn active_tab_mut(&mut self) -> &mut DocumentTab {
+        &mut self.tabs[self.active_tab_index]
+    }
+
+    /// Record that the given item is being opened.
+    /// The item replaces the active tab's content, unless the tab is pinned or
+    /// `in_new_tab` is set, in which case it's opened in a new tab.
+    pub fn open(
+        &mut self,
+        target: NavigationTarget,
+        left_panel_tab: LeftPanelTab,
+        in_new_tab: bool,
+    ) {
+        if in_new_tab || self.active_tab().pinned {
+            let mut tab = DocumentTab::default();
+            tab.navigation_history.push(target, left_panel_tab);
+
+            let new_tab_index = self.active_tab_index + 1;
+            self.tabs.insert(new_tab_index, tab);
+            self.requested_tab_index = Some(new_tab_index);
+        } else {
+            self.active_tab_mut()
+                .navigation_history
+                .push(target, left_panel_tab);
+        }
+    }
+
+    /// Discard the content of all tabs, to reconstruct it on activation
+    pub fn invalidate_contents(&mut self) {
+        for tab in &mut self.tabs {
+            tab.content = None;
+            tab.type_links.clear();
+            tab.needs_reload = tab.target().is_some();
+        }
+    }
+
+    /// Activate the tab requested since the last call, if any.
+    /// `current_content` is the active tab's content, which is swapped with
+    /// the newly activated tab's content.
+    /// Returns `true` if the active tab changed.
+    pub fn activate_requested_tab(&mut self, current_content: &mut ResymAppMode) -> bool {
+        let Some(requested_tab_index) = self.requested_tab_index.take() else {
+            return false;
+        };
+        if requested_tab_index == self.active_tab_index || requested_tab_index >= self.tabs.len() {
+            return false;
+        }
+
+        let new_content = self.tabs[requested_tab_index]
+            .content
+            .take()
+            .unwrap_or_else(|| current_content.without_content());
+        let previous_content = std::mem::replace(current_content, new_content);
+        self.active_tab_mut().content = Some(previous_content);
+        self.active_tab_index = requested_tab_index;
+
+        true
+    }
+
+    /// Update/render the UI component.
+    /// Returns `true` if the active tab changed.
+    pub fn update(&mut self, ui: &mut egui::Ui, current_content: &mut ResymAppMode) -> bool {
+        let mut action = None;
+        egui::ScrollArea::horizontal()
+            .id_salt("document_tabs")
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    for (tab_index, tab) in self.tabs.iter().enumerate() {
+                        if let Some(tab_action) = self.update_tab(ui, tab_index, tab) {
+                            action = Some(tab_action);
+                        }
+                    }
+                });
+            });
+
+        match action {
+            Some(DocumentTabAction::Activate(tab_index)) => {
+                self.requested_tab_index = Some(tab_index);
+                self.activate_requested_tab(current_content)
+            }
+            Some(DocumentTabAction::Close(tab_index)) => self.close_tab(tab_index, current_content),
+            Some(DocumentTabAction::CloseOthers(tab_index)) => {
+                // Note: pinned tabs are kept open
+                let mut tab_changed = false;
+                for closed_tab_index in (0..self.tabs.len()).rev() {
+                    if closed_tab_index != tab_index && !self.tabs[closed_tab_index].pinned {
+                        tab_changed |= self.close_tab(closed_tab_index, current_content);
+                    }
+                }
+                tab_changed
+            }
+            Some(DocumentTabAction::TogglePin(tab_index)) => {
+                self.tabs[tab_index].pinned = !self.tabs[tab_index].pinned;
+                false
+            }
+            Some(DocumentTabAction::Move(from_index, to_index)) => {
+                self.move_tab(from_index, to_index);
+                false
+            }
+            None => false,
+        }
+    }
+
+    fn update_tab(
+        &self,
+        ui: &mut egui::Ui,
+        tab_index: usize,
+        tab: &DocumentTab,
+    ) -> Option<DocumentTabAction> {
+        let mut action = None;
+        let is_active = tab_index == self.active_tab_index;
+        let label = if tab.pinned {
+            format!("📌 {}", tab.title())
+        } else {
+            tab.title().to_string()
+        };
+
+        let response = ui
+            .add(egui::SelectableLabel::new(is_active, label))
+            .interact(egui::Sense::drag())
+            .on_hover_text(tab.title());
+        if response.clicked() {
+            action = Some(DocumentTabAction::Activate(tab_index));
+        }
+        if response.middle_clicked() {
+            action = Some(DocumentTabAction::Close(tab_index));
+        }
+
+        // Reorder tabs with drag and drop
+        response.dnd_set_drag_payload(tab_index);
+        if let Some(dragged_tab_index) = response.dnd_release_payload::<usize>() {
+            action = Some(DocumentTabAction::Move(*dragged_tab_index, tab_index));
+        }
+
+        response.context_menu(|ui| {
+            if ui
+                .button(if tab.pinned { "Unpin tab" } else { "Pin tab" })
+                .clicked()
+            {
+                ui.close_menu();
+                action = Some(DocumentTabAction::TogglePin(tab_index));
+            }
+            if ui.button("Close tab").clicked() {
+                ui.close_menu();
+                action = Some(DocumentTabAction::Close(tab_index));
+            }
+            if ui.button("Close other tabs").clicked() {
+                ui.close_menu();
+                action = Some(DocumentTabAction::CloseOthers(tab_index));
+            }
+        });
+
+        // Pinned tabs can only be closed from the context menu
+        if !tab.pinned && ui.small_button("🗙").on_hover_text("Close").clicked() {
+            action = Some(DocumentTabAction::Close(tab_index));
+        }
+        ui.separator();
+
+        action
+    }
+
+    /// Close the given tab.
+    /// Returns `true` if the active tab changed.
+    fn close_tab(&mut self, tab_index: usize, current_content: &mut ResymAppMode) -> bool {
+        if tab_index >= self.tabs.len() {
+            return false;
+        }
+
+        self.tabs.remove(tab_index);
+        if tab_index < self.active_tab_index {
+            self.active_tab_index -= 1;
+            false
+        } else if tab_index == self.active_tab_index {
+            // The active tab's content is discarded and replaced with the
+            // content of its neighbor, if any
+            if self.tabs.is_empty() {
+                self.tabs.push(DocumentTab::default());
+            }
+            self.active_tab_index = self.active_tab_index.min(self.tabs.len() - 1);
+            *current_content = self
+                .active_tab_mut()
+                .content
+                .take()
+                .unwrap_or_else(|| current_content.without_content());
+
+            true
+        } else {
+            false
+        }
+    }
+
+    fn move_tab(&mut self, from_index: usize, to_index: usize) {
+        if from_index == to_index || from_index >= self.tabs.len() || to_index >= self.tabs.len() {
+            return;
+        }
+
+        let tab = self.tabs.remove(from_index);
+        self.tabs.insert(to_index, tab);
+
+        // Keep track of the active tab
+        if self.active_tab_index == from_index {
+            self.active_tab_index = to_index;
+        } else if from_index < self.active_tab_index && to_index >= self.active_tab_index {
+            self.active_tab_index -= 1;
+        } else if from_index > self.active_tab_index && to_index <= self.active_tab_index {
+            self.active_tab_index += 1;
+        }
+    }
+}
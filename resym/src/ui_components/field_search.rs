@@ -0,0 +1,98 @@
+use eframe::egui;
+
+use resym_core::{
+    field_search::{parse_field_offset, FieldInfoList, FieldSearchQuery},
+    pdb_file::TypeIndex,
+};
+
+use super::{IndexListComponent, IndexListOrdering};
+
+/// UI component in charge of searching the fields of all types and
+/// displaying the results
+pub struct FieldSearchComponent {
+    field_name_filter: String,
+    field_type_filter: String,
+    /// Offset filter, as typed by the user (decimal or hexadecimal)
+    offset_filter: String,
+    result_list: IndexListComponent<usize>,
+    field_list: FieldInfoList,
+}
+
+impl FieldSearchComponent {
+    pub fn new() -> Self {
+        Self {
+            field_name_filter: String::default(),
+            field_type_filter: String::default(),
+            offset_filter: String::default(),
+            result_list: IndexListComponent::new(IndexListOrdering::None),
+            field_list: vec![],
+        }
+    }
+
+    /// Update the list of fields displayed as the result of the last search
+    pub fn update_field_list(&mut self, field_list: FieldInfoList) {
+        let index_list = field_list
+            .iter()
+            .enumerate()
+            .map(|(field_index, field)| {
+                (
+                    format!(
+                        "{}::{} (+{:#x}): {}",
+                        field.type_name, field.field_name, field.offset, field.field_type_name
+                    ),
+                    field_index,
+                )
+            })
+            .collect();
+        self.result_list.update_index_list(index_list);
+        self.field_list = field_list;
+    }
+
+    /// Update/render the UI component. Returns a query to run when one of the
+    /// filters changes.
+    pub fn update<CB: FnMut(&str, TypeIndex)>(
+        &mut self,
+        ui: &mut egui::Ui,
+        on_type_selected: &mut CB,
+    ) -> Option<FieldSearchQuery> {
+        let mut query_changed = false;
+        ui.label("Field name");
+        query_changed |= ui
+            .text_edit_singleline(&mut self.field_name_filter)
+            .changed();
+        ui.label("Field type");
+        query_changed |= ui
+            .text_edit_singleline(&mut self.field_type_filter)
+            .changed();
+        ui.label("Offset");
+        query_changed |= ui.text_edit_singleline(&mut self.offset_filter).changed();
+
+        // An empty offset filter matches any offset
+        let offset = if self.offset_filter.trim().is_empty() {
+            Ok(None)
+        } else {
+            parse_field_offset(&self.offset_filter).map(Some)
+        };
+        if offset.is_err() {
+            ui.colored_label(ui.visuals().error_fg_color, "Invalid offset");
+        }
+        ui.separator();
+        ui.add_space(4.0);
+
+        let field_list = &self.field_list;
+        self.result_list.update(ui, &mut |_, field_index| {
+            if let Some(field) = field_list.get(field_index) {
+                on_type_selected(&field.type_name, field.type_index);
+            }
+        });
+
+        match offset {
+            Ok(offset) if query_changed => Some(FieldSearchQuery {
+                field_name_filter: self.field_name_filter.clone(),
+                field_type_filter: self.field_type_filter.clone(),
+                offset,
+            }),
+            _ => None,
+        }
+    }
+}
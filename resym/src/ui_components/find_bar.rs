@@ -0,0 +1,199 @@
+use eframe::egui;
+use resym_core::{
+    options::FilterOptions,
+    text_search::{find_text_matches, line_start_offset},
+};
+
+use crate::syntax_highlighting::SearchMatches;
+
+/// UI component in charge of searching for text in the code view
+pub struct FindBarComponent {
+    is_open: bool,
+    request_focus: bool,
+    query: String,
+    case_insensitive: bool,
+    use_regex: bool,
+    line_number: String,
+    search_matches: SearchMatches,
+    /// Error message to display if the query is invalid
+    search_error: Option<String>,
+    /// Address and length of the content the matches were computed for, used
+    /// to detect content updates without comparing the whole content
+    searched_content: Option<(usize, usize)>,
+}
+
+impl FindBarComponent {
+    pub fn new() -> Self {
+        Self {
+            is_open: false,
+            request_focus: false,
+            query: String::default(),
+            case_insensitive: true,
+            use_regex: false,
+            line_number: String::default(),
+            search_matches: SearchMatches::default(),
+            search_error: None,
+            searched_content: None,
+        }
+    }
+
+    /// Show the find bar and give focus to the query field
+    pub fn open(&mut self) {
+        self.is_open = true;
+        self.request_focus = true;
+    }
+
+    /// Matches to highlight in the code view, if any
+    pub fn search_matches(&self) -> Option<&SearchMatches> {
+        if self.is_open && !self.search_matches.ranges.is_empty() {
+            Some(&self.search_matches)
+        } else {
+            None
+        }
+    }
+
+    /// Update/render the UI component.
+    /// Returns the byte offset that the code view should scroll to, if any.
+    pub fn update(&mut self, ui: &mut egui::Ui, content: &str) -> Option<usize> {
+        if !self.is_open {
+            return None;
+        }
+
+        let content_fingerprint = (content.as_ptr() as usize, content.len());
+        let content_changed = self.searched_content != Some(content_fingerprint);
+        let mut query_changed = false;
+        let mut scroll_target = None;
+        ui.horizontal(|ui| {
+            ui.label("Find");
+            let query_response =
+                ui.add(egui::TextEdit::singleline(&mut self.query).desired_width(200.0));
+            if self.request_focus {
+                query_response.request_focus();
+                self.request_focus = false;
+            }
+            query_changed |= query_response.changed();
+            query_changed |= ui
+                .checkbox(&mut self.case_insensitive, "Case insensitive")
+                .changed();
+            query_changed |= ui.checkbox(&mut self.use_regex, "Regex").changed();
+            if content_changed || query_changed {
+                self.update_search_matches(content);
+                self.searched_content = Some(content_fingerprint);
+            }
+
+            // Enter selects the next match and Shift+Enter the previous one
+            let enter_pressed = query_response.lost_focus()
+                && ui.input(|input_state| input_state.key_pressed(egui::Key::Enter));
+            let shift_pressed = ui.input(|input_state| input_state.modifiers.shift);
+            if enter_pressed {
+                query_response.request_focus();
+            }
+            if ui
+                .button("⬆")
+                .on_hover_text("Previous match (Shift+Enter)")
+                .clicked()
+                || (enter_pressed && shift_pressed)
+            {
+                scroll_target = self.select_match(-1);
+            }
+            if ui.button("⬇").on_hover_text("Next match (Enter)").clicked()
+                || (enter_pressed && !shift_pressed)
+            {
+                scroll_target = self.select_match(1);
+            }
+            if query_changed {
+                // Go to the first match on query updates
+                scroll_target = self.select_match(0);
+            }
+
+            // Match count
+            match (&self.search_error, self.search_matches.selected_index) {
+                (Some(search_error), _) => {
+                    ui.colored_label(ui.visuals().error_fg_color, search_error);
+                }
+                (None, Some(selected_index)) => {
+                    ui.label(format!(
+                        "{}/{}",
+                        selected_index + 1,
+                        self.search_matches.ranges.len()
+                    ));
+                }
+                (None, None) if !self.search_matches.ranges.is_empty() => {
+                    ui.label(format!("{} matches", self.search_matches.ranges.len()));
+                }
+                (None, None) if !self.query.is_empty() => {
+                    ui.label("No results");
+                }
+                (None, None) => {}
+            }
+
+            ui.separator();
+
+            // Jump to line
+            ui.label("Line");
+            let line_response =
+                ui.add(egui::TextEdit::singleline(&mut self.line_number).desired_width(60.0));
+            let line_enter_pressed = line_response.lost_focus()
+                && ui.input(|input_state| input_state.key_pressed(egui::Key::Enter));
+            if ui.button("Go").clicked() || line_enter_pressed {
+                match self.line_number.trim().parse::<usize>() {
+                    Ok(line_number) => {
+                        scroll_target = line_start_offset(content, line_number);
+                        if scroll_target.is_none() {
+                            log::warn!("Line {line_number} doesn't exist");
+                        }
+                    }
+                    Err(_) => log::warn!("Invalid line number: '{}'", self.line_number),
+                }
+            }
+
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.small_button("🗙").on_hover_text("Close (Esc)").clicked() {
+                    self.is_open = false;
+                }
+            });
+        });
+        if ui.input(|input_state| input_state.key_pressed(egui::Key::Escape)) {
+            self.is_open = false;
+        }
+        ui.separator();
+
+        scroll_target
+    }
+
+    fn update_search_matches(&mut self, content: &str) {
+        let options = FilterOptions::new()
+            .case_insensitive(self.case_insensitive)
+            .use_regex(self.use_regex);
+        let (ranges, search_error) = match find_text_matches(content, &self.query, &options) {
+            Ok(ranges) => (ranges, None),
+            Err(err) => (vec![], Some(err.to_string())),
+        };
+
+        self.search_matches = SearchMatches {
+            ranges,
+            selected_index: None,
+        };
+        self.search_error = search_error;
+    }
+
+    /// Select the match located `step` matches away from the selected one
+    /// (wrapping around), or the first match if none is selected.
+    /// Returns the byte offset of the newly selected match.
+    fn select_match(&mut self, step: isize) -> Option<usize> {
+        let match_count = self.search_matches.ranges.len();
+        if match_count == 0 {
+            return None;
+        }
+
+        let selected_index = match self.search_matches.selected_index {
+            Some(selected_index) => {
+                (selected_index as isize + step).rem_euclid(match_count as isize) as usize
+            }
+            None => 0,
+        };
+        self.search_matches.selected_index = Some(selected_index);
+
+        Some(self.search_matches.ranges[selected_index].start)
+    }
+}
@@ -0,0 +1,171 @@
+use eframe::egui;
+
+use resym_core::{
+    field_search::parse_field_offset,
+    layout_search::{FieldConstraint, LayoutMatchList, LayoutQuery},
+    pdb_file::TypeIndex,
+};
+
+use super::{IndexListComponent, IndexListOrdering};
+
+/// Maximum number of types listed as the result of a layout search
+const MAX_RESULT_COUNT: usize = 100;
+
+/// Field constraint, as typed by the user
+#[derive(Default)]
+struct FieldConstraintInput {
+    offset: String,
+    field_type: String,
+}
+
+/// UI component in charge of searching types by layout (i.e., size and
+/// fields at given offsets), in its own window
+pub struct LayoutSearchComponent {
+    window_open: bool,
+    size: String,
+    field_constraints: Vec<FieldConstraintInput>,
+    result_list: IndexListComponent<TypeIndex>,
+    layout_match_list: LayoutMatchList,
+}
+
+impl LayoutSearchComponent {
+    pub fn new() -> Self {
+        Self {
+            window_open: false,
+            size: String::default(),
+            field_constraints: vec![FieldConstraintInput::default()],
+            result_list: IndexListComponent::new(IndexListOrdering::None),
+            layout_match_list: vec![],
+        }
+    }
+
+    pub fn open(&mut self) {
+        self.window_open = true;
+    }
+
+    /// Update the list of types displayed as the result of the last search
+    pub fn update_layout_match_list(&mut self, layout_match_list: LayoutMatchList) {
+        let index_list = layout_match_list
+            .iter()
+            .map(|layout_match| {
+                (
+                    format!(
+                        "[{}/{}] {} (size: {:#x})",
+                        layout_match.matched_constraint_count,
+                        layout_match.constraint_count,
+                        layout_match.type_name,
+                        layout_match.size
+                    ),
+                    layout_match.type_index,
+                )
+            })
+            .collect();
+        self.result_list.update_index_list(index_list);
+        self.layout_match_list = layout_match_list;
+    }
+
+    /// Update/render the UI component. Returns the query to run and the
+    /// maximum number of results when the user asks for it.
+    pub fn update<CB: FnMut(&str, TypeIndex)>(
+        &mut self,
+        ctx: &egui::Context,
+        on_type_selected: &mut CB,
+    ) -> Option<(LayoutQuery, usize)> {
+        let mut query = None;
+        let Self {
+            window_open,
+            size,
+            field_constraints,
+            result_list,
+            layout_match_list,
+        } = self;
+        egui::Window::new("Match layout")
+            .open(window_open)
+            .default_size([400.0, 400.0])
+            .collapsible(false)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Size");
+                    ui.text_edit_singleline(size);
+                });
+                ui.label("Fields (offset and type: empty for any, `ptr` or a type name)");
+                let mut removed_constraint = None;
+                for (constraint_index, constraint) in field_constraints.iter_mut().enumerate() {
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::TextEdit::singleline(&mut constraint.offset)
+                                .hint_text("0x10")
+                                .desired_width(80.0),
+                        );
+                        ui.add(
+                            egui::TextEdit::singleline(&mut constraint.field_type)
+                                .hint_text("uint32_t"),
+                        );
+                        if ui.button("🗑").clicked() {
+                            removed_constraint = Some(constraint_index);
+                        }
+                    });
+                }
+                if let Some(constraint_index) = removed_constraint {
+                    field_constraints.remove(constraint_index);
+                }
+
+                let parsed_query = build_query(size, field_constraints);
+                ui.horizontal(|ui| {
+                    if ui.button("➕ Add field").clicked() {
+                        field_constraints.push(FieldConstraintInput::default());
+                    }
+                    if ui
+                        .add_enabled(
+                            parsed_query
+                                .as_ref()
+                                .is_ok_and(|query| query.constraint_count() > 0),
+                            egui::Button::new("Search"),
+                        )
+                        .clicked()
+                    {
+                        query = parsed_query.as_ref().ok().cloned();
+                    }
+                    if let Err(err) = &parsed_query {
+                        ui.colored_label(ui.visuals().error_fg_color, err);
+                    }
+                });
+                ui.separator();
+
+                let layout_match_list = &*layout_match_list;
+                result_list.update(ui, &mut |_, type_index| {
+                    if let Some(layout_match) = layout_match_list
+                        .iter()
+                        .find(|layout_match| layout_match.type_index == type_index)
+                    {
+                        on_type_selected(&layout_match.type_name, type_index);
+                    }
+                });
+            });
+
+        query.map(|query| (query, MAX_RESULT_COUNT))
+    }
+}
+
+/// Build a query from the user's input. Empty constraints are ignored.
+fn build_query(
+    size: &str,
+    field_constraints: &[FieldConstraintInput],
+) -> Result<LayoutQuery, String> {
+    let size = if size.trim().is_empty() {
+        None
+    } else {
+        Some(parse_field_offset(size).map_err(|_| "Invalid size".to_string())?)
+    };
+    let fields = field_constraints
+        .iter()
+        .filter(|constraint| !constraint.offset.trim().is_empty())
+        .map(|constraint| {
+            format!("{}:{}", constraint.offset, constraint.field_type)
+                .parse::<FieldConstraint>()
+                .map_err(|_| format!("Invalid offset: {}", constraint.offset.trim()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(LayoutQuery { size, fields })
+}
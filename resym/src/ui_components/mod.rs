@@ -1,17 +1,37 @@
+mod bookmarks;
+mod class_hierarchy;
 mod code_view;
 mod console;
+mod document_tabs;
+mod field_search;
+mod find_bar;
 mod index_list;
+mod layout_search;
 mod module_tree;
 #[cfg(feature = "http")]
 mod open_url;
+mod progress;
 mod settings;
 mod text_search;
+mod type_diff_list;
+mod type_graph;
+mod type_layout;
 
+pub use bookmarks::*;
+pub use class_hierarchy::*;
 pub use code_view::*;
 pub use console::*;
+pub use document_tabs::*;
+pub use field_search::*;
+pub use find_bar::*;
 pub use index_list::*;
+pub use layout_search::*;
 pub use module_tree::*;
 #[cfg(feature = "http")]
 pub use open_url::*;
+pub use progress::*;
 pub use settings::*;
 pub use text_search::*;
+pub use type_diff_list::*;
+pub use type_graph::*;
+pub use type_layout::*;
@@ -1,5 +1,4 @@
 use eframe::egui;
-use resym_core::backend::{Backend, BackendCommand};
 
 use crate::resym_app::ResymPDBSlots;
 
@@ -25,31 +24,28 @@ impl OpenURLComponent {
         self.url_text.clear();
     }
 
-    pub fn update(&mut self, ctx: &egui::Context, backend: &Backend) {
-        if let Some(pdb_slot) = self.pdb_slot {
-            egui::Window::new("Open URL")
-                .anchor(egui::Align2::CENTER_CENTER, [0.0; 2])
-                .auto_sized()
-                .collapsible(false)
-                .show(ctx, |ui| {
-                    ui.label("URL to open:");
-                    ui.text_edit_singleline(&mut self.url_text);
-                    ui.horizontal(|ui| {
-                        if ui.button("Cancel").clicked() {
-                            self.close();
-                        } else if ui.button("Open").clicked() {
-                            self.start_open_pdb_from_url(backend, pdb_slot, self.url_text.clone());
-                            self.close();
-                        }
-                    })
-                });
-        }
-    }
+    /// Update/render the UI component.
+    /// Returns the slot and URL of the PDB file to open, once confirmed.
+    pub fn update(&mut self, ctx: &egui::Context) -> Option<(ResymPDBSlots, String)> {
+        let pdb_slot = self.pdb_slot?;
+        let mut opened_url = None;
+        egui::Window::new("Open URL")
+            .anchor(egui::Align2::CENTER_CENTER, [0.0; 2])
+            .auto_sized()
+            .collapsible(false)
+            .show(ctx, |ui| {
+                ui.label("URL to open:");
+                ui.text_edit_singleline(&mut self.url_text);
+                ui.horizontal(|ui| {
+                    if ui.button("Cancel").clicked() {
+                        self.close();
+                    } else if ui.button("Open").clicked() {
+                        opened_url = Some((pdb_slot, self.url_text.clone()));
+                        self.close();
+                    }
+                })
+            });
 
-    fn start_open_pdb_from_url(&self, backend: &Backend, pdb_slot: ResymPDBSlots, url: String) {
-        if let Err(err) = backend.send_command(BackendCommand::LoadPDBFromURL(pdb_slot.into(), url))
-        {
-            log::error!("Failed to load URL: {err}");
-        }
+        opened_url
     }
 }
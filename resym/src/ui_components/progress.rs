@@ -0,0 +1,54 @@
+use std::collections::BTreeMap;
+
+use eframe::egui;
+use resym_core::{
+    backend::{Backend, BackendCommand},
+    progress::{Progress, RequestId},
+};
+
+/// UI component that displays the progress of long-running requests, and
+/// enables users to cancel them
+#[derive(Default)]
+pub struct ProgressComponent {
+    running_requests: BTreeMap<RequestId, Progress>,
+}
+
+impl ProgressComponent {
+    pub fn update_progress(&mut self, request_id: RequestId, progress: Progress) {
+        match progress {
+            Progress::Done => {
+                self.running_requests.remove(&request_id);
+            }
+            Progress::Running { .. } => {
+                self.running_requests.insert(request_id, progress);
+            }
+        }
+    }
+
+    pub fn update(&mut self, ui: &mut egui::Ui, backend: &Backend) {
+        // Only display the most recent request
+        let (request_id, progress) = match self.running_requests.last_key_value() {
+            Some(running_request) => running_request,
+            None => {
+                return;
+            }
+        };
+
+        ui.horizontal(|ui| {
+            if ui.button("Cancel").clicked() {
+                if let Err(err) = backend.send_command(BackendCommand::Cancel(*request_id)) {
+                    log::error!("Failed to cancel request: {err}");
+                }
+            }
+
+            let description = match progress {
+                Progress::Running { description, .. } => description.as_str(),
+                Progress::Done => "",
+            };
+            ui.add(egui::ProgressBar::new(progress.fraction()).text(format!(
+                "{description} ({:.0}%)",
+                progress.fraction() * 100.0
+            )));
+        });
+    }
+}
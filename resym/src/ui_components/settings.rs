@@ -1,4 +1,6 @@
 use eframe::egui;
+#[cfg(not(target_arch = "wasm32"))]
+use resym_core::index_cache::IndexCache;
 use resym_core::pdb_types::PrimitiveReconstructionFlavor;
 
 use crate::settings::ResymAppSettings;
@@ -118,6 +120,35 @@ impl SettingsComponent {
                     &mut self.app_settings.print_line_numbers,
                     "Print line numbers",
                 );
+
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    ui.add_space(INTER_SECTION_SPACING);
+                    ui.label("Symbol search path");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.app_settings.symbol_path).hint_text(
+                            "srv*C:\\symbols*https://msdl.microsoft.com/download/symbols",
+                        ),
+                    )
+                    .on_hover_text(
+                        "Used to find PDB files matching PE images. Defaults to _NT_SYMBOL_PATH.",
+                    );
+
+                    ui.add_space(INTER_SECTION_SPACING);
+                    ui.label("Index cache");
+                    ui.checkbox(
+                        &mut self.app_settings.enable_index_cache,
+                        "Cache PDB indices on disk",
+                    );
+                    if ui.button("Clear index cache").clicked() {
+                        match IndexCache::from_env_or_default().clear() {
+                            Ok(entry_count) => {
+                                log::info!("{entry_count} index cache entries removed")
+                            }
+                            Err(err) => log::error!("Failed to clear the index cache: {err}"),
+                        }
+                    }
+                }
             });
     }
 }
@@ -11,6 +11,15 @@ impl TextSearchComponent {
         }
     }
 
+    pub fn search_filter(&self) -> &str {
+        &self.search_filter
+    }
+
+    /// Replace the search query, without notifying anyone
+    pub fn set_search_filter(&mut self, search_filter: String) {
+        self.search_filter = search_filter;
+    }
+
     /// Update/render the UI component
     pub fn update<CB: Fn(&str)>(&mut self, ui: &mut egui::Ui, on_query_update: &CB) {
         if ui.text_edit_singleline(&mut self.search_filter).changed() {
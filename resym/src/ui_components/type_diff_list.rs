@@ -0,0 +1,141 @@
+use std::collections::HashSet;
+
+use eframe::egui::{self, ScrollArea, TextStyle};
+
+use resym_core::diffing::{TypeDiffList, TypeDiffStatus};
+
+/// UI component in charge of rendering the list of types found in the compared
+/// PDB files, along with their status
+pub struct TypeDiffListComponent {
+    type_diff_list: TypeDiffList,
+    /// Positions (in `type_diff_list`) of the types matching the status filter
+    filtered_rows: Vec<usize>,
+    /// Position (in `type_diff_list`) of the selected type, if any
+    selected_row: Option<usize>,
+    hidden_statuses: HashSet<TypeDiffStatus>,
+    /// Number of types for each status, in the order of `TypeDiffStatus::ALL`
+    status_counts: [usize; TypeDiffStatus::ALL.len()],
+}
+
+impl TypeDiffListComponent {
+    pub fn new() -> Self {
+        Self {
+            type_diff_list: vec![],
+            filtered_rows: vec![],
+            selected_row: None,
+            // Only display types that changed by default
+            hidden_statuses: HashSet::from([TypeDiffStatus::Unchanged]),
+            status_counts: Default::default(),
+        }
+    }
+
+    pub fn update_type_diff_list(&mut self, type_diff_list: TypeDiffList) {
+        self.type_diff_list = type_diff_list;
+        self.selected_row = None;
+        self.status_counts = TypeDiffStatus::ALL.map(|status| {
+            self.type_diff_list
+                .iter()
+                .filter(|(_, type_status)| *type_status == status)
+                .count()
+        });
+        self.update_filtered_rows();
+    }
+
+    /// Update/render the UI component.
+    /// `on_type_selected` is invoked with the name of the type that was clicked.
+    pub fn update<CB: FnMut(&str)>(&mut self, ui: &mut egui::Ui, on_type_selected: &mut CB) {
+        // Status filter
+        let mut filter_changed = false;
+        ui.horizontal_wrapped(|ui| {
+            for (status, status_count) in TypeDiffStatus::ALL.into_iter().zip(self.status_counts) {
+                let mut shown = !self.hidden_statuses.contains(&status);
+                let label =
+                    egui::RichText::new(format!("{} ({status_count})", status_label(status)))
+                        .color(status_color(status, ui.visuals()));
+                if ui.checkbox(&mut shown, label).changed() {
+                    if shown {
+                        self.hidden_statuses.remove(&status);
+                    } else {
+                        self.hidden_statuses.insert(status);
+                    }
+                    filter_changed = true;
+                }
+            }
+        });
+        if filter_changed {
+            self.update_filtered_rows();
+        }
+        ui.add_space(4.0);
+
+        let num_rows = self.filtered_rows.len();
+        const TEXT_STYLE: TextStyle = TextStyle::Body;
+        let row_height = ui.text_style_height(&TEXT_STYLE);
+        ui.with_layout(
+            egui::Layout::top_down(egui::Align::Min).with_cross_justify(true),
+            |ui| {
+                if num_rows == 0 {
+                    // Display a default message to make it obvious the list is empty
+                    ui.label("No results");
+                    return;
+                }
+
+                ScrollArea::vertical()
+                    .auto_shrink([false, false])
+                    .show_rows(ui, row_height, num_rows, |ui, row_range| {
+                        for row_index in row_range {
+                            let type_position = self.filtered_rows[row_index];
+                            let (type_name, status) = &self.type_diff_list[type_position];
+
+                            let label = egui::RichText::new(type_name)
+                                .color(status_color(*status, ui.visuals()));
+                            if ui
+                                .selectable_label(self.selected_row == Some(type_position), label)
+                                .on_hover_text(status_label(*status))
+                                .clicked()
+                            {
+                                self.selected_row = Some(type_position);
+                                on_type_selected(type_name);
+                            }
+                        }
+                    });
+            },
+        );
+    }
+
+    fn update_filtered_rows(&mut self) {
+        self.filtered_rows = self
+            .type_diff_list
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, status))| !self.hidden_statuses.contains(status))
+            .map(|(type_position, _)| type_position)
+            .collect();
+    }
+}
+
+impl Default for TypeDiffListComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Color used to display types with the given status
+fn status_color(status: TypeDiffStatus, visuals: &egui::Visuals) -> egui::Color32 {
+    match (status, visuals.dark_mode) {
+        (TypeDiffStatus::Added, true) => egui::Color32::from_rgb(0x60, 0xc0, 0x60),
+        (TypeDiffStatus::Added, false) => egui::Color32::from_rgb(0x10, 0x80, 0x10),
+        (TypeDiffStatus::Removed, true) => egui::Color32::from_rgb(0xe0, 0x60, 0x60),
+        (TypeDiffStatus::Removed, false) => egui::Color32::from_rgb(0xa0, 0x10, 0x10),
+        (TypeDiffStatus::Modified, _) => visuals.warn_fg_color,
+        (TypeDiffStatus::Unchanged, _) => visuals.text_color(),
+    }
+}
+
+fn status_label(status: TypeDiffStatus) -> &'static str {
+    match status {
+        TypeDiffStatus::Added => "Added",
+        TypeDiffStatus::Removed => "Removed",
+        TypeDiffStatus::Modified => "Modified",
+        TypeDiffStatus::Unchanged => "Unchanged",
+    }
+}
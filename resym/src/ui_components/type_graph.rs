@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+
+use eframe::egui;
+
+use resym_core::{
+    pdb_file::TypeIndex,
+    type_graph::{TypeGraphQuery, TypeGraphQueryResult},
+};
+
+use super::{IndexListComponent, IndexListOrdering};
+
+/// Kinds of queries that can be run on the type dependency graph
+#[derive(Clone, Copy, PartialEq)]
+enum TypeGraphQueryKind {
+    Dependencies,
+    Dependents,
+    Path,
+    Cycles,
+}
+
+/// UI component in charge of querying the type dependency graph and
+/// displaying the results
+pub struct TypeGraphComponent {
+    query_kind: TypeGraphQueryKind,
+    /// Maximum depth for dependency queries (0 means unlimited)
+    max_depth: usize,
+    /// Name of the type to reach for path queries
+    target_type_name: String,
+    follow_pointers: bool,
+    /// Only look for cycles going through the selected type
+    selected_type_cycles_only: bool,
+    /// Kind of the last query run, used to label its results
+    result_kind: TypeGraphQueryKind,
+    result_list: IndexListComponent<TypeIndex>,
+    /// Names of the types in the result list (labels contain extra details)
+    result_type_names: HashMap<TypeIndex, String>,
+}
+
+impl TypeGraphComponent {
+    pub fn new() -> Self {
+        Self {
+            query_kind: TypeGraphQueryKind::Dependencies,
+            max_depth: 0,
+            target_type_name: String::default(),
+            follow_pointers: false,
+            selected_type_cycles_only: false,
+            result_kind: TypeGraphQueryKind::Dependencies,
+            result_list: IndexListComponent::new(IndexListOrdering::None),
+            result_type_names: HashMap::new(),
+        }
+    }
+
+    /// Update the list of types displayed as the result of the last query
+    pub fn update_query_result(&mut self, query_result: TypeGraphQueryResult) {
+        let is_cycle_list = self.result_kind == TypeGraphQueryKind::Cycles;
+        self.result_type_names = query_result
+            .iter()
+            .flatten()
+            .map(|node| (node.type_index, node.type_name.clone()))
+            .collect();
+        let index_list = query_result
+            .into_iter()
+            .enumerate()
+            .flat_map(|(list_index, nodes)| {
+                nodes.into_iter().map(move |node| {
+                    let pointer_suffix = if node.is_pointer { " (pointer)" } else { "" };
+                    let label = if is_cycle_list {
+                        format!(
+                            "Cycle #{} [{}] {}{}",
+                            list_index + 1,
+                            node.depth,
+                            node.type_name,
+                            pointer_suffix
+                        )
+                    } else {
+                        format!("[{}] {}{}", node.depth, node.type_name, pointer_suffix)
+                    };
+                    (label, node.type_index)
+                })
+            })
+            .collect();
+        self.result_list.update_index_list(index_list);
+    }
+
+    /// Update/render the UI component. Returns a query to run when the user
+    /// asks for it. Queries apply to the currently selected type.
+    pub fn update<CB: FnMut(&str, TypeIndex)>(
+        &mut self,
+        ui: &mut egui::Ui,
+        selected_type_name: Option<&str>,
+        on_type_selected: &mut CB,
+    ) -> Option<TypeGraphQuery> {
+        let mut query = None;
+        ui.horizontal(|ui| {
+            ui.selectable_value(
+                &mut self.query_kind,
+                TypeGraphQueryKind::Dependencies,
+                "Dependencies",
+            );
+            ui.selectable_value(
+                &mut self.query_kind,
+                TypeGraphQueryKind::Dependents,
+                "Dependents",
+            );
+            ui.selectable_value(&mut self.query_kind, TypeGraphQueryKind::Path, "Path to");
+            ui.selectable_value(&mut self.query_kind, TypeGraphQueryKind::Cycles, "Cycles");
+            ui.separator();
+
+            match self.query_kind {
+                TypeGraphQueryKind::Dependencies | TypeGraphQueryKind::Dependents => {
+                    ui.label("Max depth (0 for unlimited)");
+                    ui.add(egui::DragValue::new(&mut self.max_depth).range(0..=usize::MAX));
+                }
+                TypeGraphQueryKind::Path => {
+                    ui.text_edit_singleline(&mut self.target_type_name);
+                    ui.checkbox(&mut self.follow_pointers, "Follow pointers");
+                }
+                TypeGraphQueryKind::Cycles => {
+                    ui.checkbox(
+                        &mut self.selected_type_cycles_only,
+                        "Through selected type only",
+                    );
+                }
+            }
+
+            // All queries but the "all cycles" one need a selected type
+            let can_run_query = selected_type_name.is_some()
+                || (self.query_kind == TypeGraphQueryKind::Cycles
+                    && !self.selected_type_cycles_only);
+            if ui
+                .add_enabled(can_run_query, egui::Button::new("Run"))
+                .clicked()
+            {
+                self.result_kind = self.query_kind;
+                query = Some(self.build_query(selected_type_name));
+            }
+        });
+        ui.separator();
+
+        let result_type_names = &self.result_type_names;
+        self.result_list.update(ui, &mut |_, type_index| {
+            let type_name = result_type_names
+                .get(&type_index)
+                .map(String::as_str)
+                .unwrap_or_default();
+            on_type_selected(type_name, type_index);
+        });
+
+        query
+    }
+
+    fn build_query(&self, selected_type_name: Option<&str>) -> TypeGraphQuery {
+        let type_name = selected_type_name.unwrap_or_default().to_string();
+        let max_depth = if self.max_depth == 0 {
+            None
+        } else {
+            Some(self.max_depth)
+        };
+        match self.query_kind {
+            TypeGraphQueryKind::Dependencies => TypeGraphQuery::Dependencies {
+                type_name,
+                max_depth,
+            },
+            TypeGraphQueryKind::Dependents => TypeGraphQuery::Dependents {
+                type_name,
+                max_depth,
+            },
+            TypeGraphQueryKind::Path => TypeGraphQuery::Path {
+                from_type_name: type_name,
+                to_type_name: self.target_type_name.trim().to_string(),
+                follow_pointers: self.follow_pointers,
+            },
+            TypeGraphQueryKind::Cycles => TypeGraphQuery::Cycles {
+                type_name: if self.selected_type_cycles_only {
+                    Some(type_name)
+                } else {
+                    None
+                },
+            },
+        }
+    }
+}
@@ -0,0 +1,303 @@
+use eframe::egui::{self, ScrollArea};
+
+use resym_core::{
+    pdb_file::TypeIndex,
+    type_layout::{LayoutEntry, LayoutEntryKind, TypeLayout},
+};
+
+/// Width of a byte in the grid, in points
+const BYTE_WIDTH: f32 = 28.0;
+/// Height of a row of entries in the grid, in points
+const LANE_HEIGHT: f32 = 20.0;
+/// Width of the column containing the offsets of the grid's rows, in points
+const OFFSET_COLUMN_WIDTH: f32 = 64.0;
+const BYTES_PER_ROW_CHOICES: [u64; 3] = [8, 16, 32];
+
+/// UI component in charge of rendering the memory layout of a class, struct or
+/// union as a grid of bytes
+pub struct TypeLayoutComponent {
+    type_layout: Option<TypeLayout>,
+    bytes_per_row: u64,
+}
+
+impl TypeLayoutComponent {
+    pub fn new() -> Self {
+        Self {
+            type_layout: None,
+            bytes_per_row: 16,
+        }
+    }
+
+    /// Update the layout that the grid displays
+    pub fn set_type_layout(&mut self, type_layout: Option<TypeLayout>) {
+        self.type_layout = type_layout;
+    }
+
+    /// Update/render the UI component.
+    /// `on_type_selected` is invoked when clicking on a member whose type is a
+    /// user-defined type.
+    pub fn update<CB: FnMut(&str, TypeIndex)>(
+        &mut self,
+        ui: &mut egui::Ui,
+        on_type_selected: &mut CB,
+    ) {
+        let Some(type_layout) = &self.type_layout else {
+            // Display a default message to make it obvious the grid is empty
+            ui.label("No results");
+            return;
+        };
+
+        ui.horizontal(|ui| {
+            ui.strong(&type_layout.type_name);
+            ui.label(format!(
+                "({}, 0x{:x} bytes, {} padding bytes)",
+                if type_layout.is_union {
+                    "union"
+                } else {
+                    "class/struct"
+                },
+                type_layout.size,
+                type_layout
+                    .padding
+                    .iter()
+                    .map(|range| range.end - range.start)
+                    .sum::<u64>()
+            ));
+            ui.separator();
+            ui.label("Bytes per row");
+            for bytes_per_row in BYTES_PER_ROW_CHOICES {
+                ui.selectable_value(
+                    &mut self.bytes_per_row,
+                    bytes_per_row,
+                    bytes_per_row.to_string(),
+                );
+            }
+        });
+        ui.separator();
+
+        let bytes_per_row = self.bytes_per_row;
+        let row_count = type_layout.size.div_ceil(bytes_per_row).max(1) as usize;
+        let lane_count = type_layout.lane_count.max(1);
+        let row_height = lane_count as f32 * LANE_HEIGHT;
+        ScrollArea::both().auto_shrink([false, false]).show_rows(
+            ui,
+            row_height,
+            row_count,
+            |ui, row_range| {
+                for row_index in row_range {
+                    update_layout_row(
+                        ui,
+                        type_layout,
+                        row_index as u64 * bytes_per_row,
+                        bytes_per_row,
+                        row_height,
+                        on_type_selected,
+                    );
+                }
+            },
+        );
+    }
+}
+
+/// Render the part of the layout that covers `bytes_per_row` bytes starting at
+/// `row_offset`
+fn update_layout_row<CB: FnMut(&str, TypeIndex)>(
+    ui: &mut egui::Ui,
+    type_layout: &TypeLayout,
+    row_offset: u64,
+    bytes_per_row: u64,
+    row_height: f32,
+    on_type_selected: &mut CB,
+) {
+    let row_bits = row_offset * 8..(row_offset + bytes_per_row) * 8;
+    let (row_rect, _) = ui.allocate_exact_size(
+        egui::vec2(
+            OFFSET_COLUMN_WIDTH + bytes_per_row as f32 * BYTE_WIDTH,
+            row_height,
+        ),
+        egui::Sense::hover(),
+    );
+    let painter = ui.painter_at(row_rect);
+    let visuals = ui.visuals().clone();
+    let font_id = egui::TextStyle::Monospace.resolve(ui.style());
+
+    // Row offset
+    painter.text(
+        row_rect.left_top() + egui::vec2(0.0, 2.0),
+        egui::Align2::LEFT_TOP,
+        format!("{row_offset:#06x}"),
+        font_id.clone(),
+        visuals.weak_text_color(),
+    );
+
+    // Return the rectangle covering the given range of bits (clipped to the
+    // row) in the given lane
+    let grid_left = row_rect.left() + OFFSET_COLUMN_WIDTH;
+    let bits_rect = |start_bit: u64, end_bit: u64, lane: usize| {
+        let start_bit = start_bit.clamp(row_bits.start, row_bits.end) - row_bits.start;
+        let end_bit = end_bit.clamp(row_bits.start, row_bits.end) - row_bits.start;
+        let top = row_rect.top() + lane as f32 * LANE_HEIGHT;
+        egui::Rect::from_min_max(
+            egui::pos2(grid_left + start_bit as f32 * BYTE_WIDTH / 8.0, top),
+            egui::pos2(
+                grid_left + end_bit as f32 * BYTE_WIDTH / 8.0,
+                top + LANE_HEIGHT,
+            ),
+        )
+        .shrink(1.0)
+    };
+
+    // Bytes outside of the type
+    let type_end_bit = type_layout.size * 8;
+    if type_end_bit < row_bits.end {
+        painter.rect_filled(
+            bits_rect(type_end_bit, row_bits.end, 0)
+                .with_max_y(row_rect.bottom())
+                .expand(1.0),
+            0.0,
+            visuals.extreme_bg_color,
+        );
+    }
+
+    // Padding holes
+    let padding_color = if visuals.dark_mode {
+        egui::Color32::from_rgb(0x50, 0x10, 0x10)
+    } else {
+        egui::Color32::from_rgb(0xff, 0xc0, 0xc0)
+    };
+    for padding_range in &type_layout.padding {
+        if padding_range.end * 8 <= row_bits.start || padding_range.start * 8 >= row_bits.end {
+            continue;
+        }
+        let rect = bits_rect(padding_range.start * 8, padding_range.end * 8, 0)
+            .with_max_y(row_rect.bottom() - 1.0);
+        painter.rect_filled(rect, 2.0, padding_color);
+        // Note: only display the label if there's enough room for it
+        if rect.width() > BYTE_WIDTH {
+            paint_label(
+                &painter,
+                rect,
+                "padding",
+                &font_id,
+                visuals.weak_text_color(),
+            );
+        }
+        ui.interact(
+            rect,
+            ui.id().with(("padding", padding_range.start, row_offset)),
+            egui::Sense::hover(),
+        )
+        .on_hover_ui_at_pointer(|ui| {
+            ui.strong("Padding");
+            ui.label(format!(
+                "Offset: {:#x}, size: {} bytes",
+                padding_range.start,
+                padding_range.end - padding_range.start
+            ));
+        });
+    }
+
+    for (entry_index, entry) in type_layout.entries.iter().enumerate() {
+        let bit_range = entry.bit_range();
+        let unit_end_bit = (entry.offset + entry.size) * 8;
+        if unit_end_bit <= row_bits.start || entry.offset * 8 >= row_bits.end {
+            continue;
+        }
+
+        // Unused bits of bit-field allocation units are displayed as padding
+        if entry.bitfield_info.is_some() {
+            painter.rect_filled(
+                bits_rect(entry.offset * 8, unit_end_bit, entry.lane),
+                2.0,
+                padding_color,
+            );
+        }
+        if bit_range.end <= row_bits.start || bit_range.start >= row_bits.end {
+            continue;
+        }
+
+        let rect = bits_rect(bit_range.start, bit_range.end, entry.lane);
+        painter.rect_filled(rect, 2.0, entry_color(entry_index, visuals.dark_mode));
+        paint_label(
+            &painter,
+            rect,
+            &entry.name,
+            &font_id,
+            visuals.strong_text_color(),
+        );
+
+        let response = ui
+            .interact(
+                rect,
+                ui.id().with(("entry", entry_index, row_offset)),
+                egui::Sense::click(),
+            )
+            .on_hover_ui_at_pointer(|ui| update_entry_tooltip(ui, entry));
+        if response.hovered() {
+            painter.rect_stroke(
+                rect,
+                2.0,
+                visuals.selection.stroke,
+                egui::StrokeKind::Inside,
+            );
+        }
+        if let Some((type_name, type_index)) = &entry.linked_type {
+            let response = response.on_hover_cursor(egui::CursorIcon::PointingHand);
+            if response.clicked() {
+                on_type_selected(type_name, *type_index);
+            }
+        }
+    }
+}
+
+fn update_entry_tooltip(ui: &mut egui::Ui, entry: &LayoutEntry) {
+    match entry.kind {
+        LayoutEntryKind::VirtualTablePointer => {
+            ui.strong("Virtual function table pointer");
+        }
+        LayoutEntryKind::BaseClass => {
+            ui.strong(format!("Base class {}", entry.type_name));
+        }
+        LayoutEntryKind::Field => {
+            ui.strong(format!("{} {}", entry.type_name, entry.name));
+        }
+    }
+    ui.label(format!(
+        "Offset: {:#x}, size: {} bytes",
+        entry.offset, entry.size
+    ));
+    if let Some((bit_offset, bit_size)) = entry.bitfield_info {
+        ui.label(format!("Bit position: {bit_offset}, bit size: {bit_size}"));
+    }
+    if let Some((type_name, _)) = &entry.linked_type {
+        ui.label(format!("Click to open {type_name}"));
+    }
+}
+
+/// Paint the given text centered in `rect`, clipped to it
+fn paint_label(
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    text: &str,
+    font_id: &egui::FontId,
+    color: egui::Color32,
+) {
+    painter.with_clip_rect(rect).text(
+        rect.center(),
+        egui::Align2::CENTER_CENTER,
+        text,
+        font_id.clone(),
+        color,
+    );
+}
+
+/// Color of the given entry, chosen so that consecutive entries are easy to
+/// tell apart
+fn entry_color(entry_index: usize, dark_mode: bool) -> egui::Color32 {
+    // Golden ratio, to spread hues evenly
+    const HUE_STEP: f32 = 0.618_034;
+    let hue = (entry_index as f32 * HUE_STEP).fract();
+    let (saturation, value) = if dark_mode { (0.6, 0.45) } else { (0.35, 0.95) };
+
+    egui::ecolor::Hsva::new(hue, saturation, value, 1.0).into()
+}
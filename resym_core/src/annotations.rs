@@ -0,0 +1,549 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::pe_file::pdb_signature;
+use crate::{
+    error::{Result, ResymCoreError},
+    pdb_file::{ModuleIndex, SymbolIndex, TypeIndex},
+};
+
+/// Extension of annotation files
+#[cfg(not(target_arch = "wasm32"))]
+const ANNOTATION_FILE_EXTENSION: &str = "notes";
+/// Environment variable used to override the location of annotation files
+#[cfg(not(target_arch = "wasm32"))]
+pub const ANNOTATION_DIR_ENV_VAR: &str = "RESYM_ANNOTATION_DIR";
+/// First line of annotation files
+const ANNOTATION_FILE_HEADER: &str = "# resym annotations";
+
+/// Item of a PDB file bookmarked by the user.
+/// Note: indices are only valid for the PDB file the bookmark was created for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Bookmark {
+    Type {
+        type_name: String,
+        type_index: TypeIndex,
+    },
+    Symbol {
+        symbol_name: String,
+        symbol_index: Option<SymbolIndex>,
+    },
+    Module {
+        module_path: String,
+        module_index: ModuleIndex,
+    },
+}
+
+impl Bookmark {
+    pub fn name(&self) -> &str {
+        match self {
+            Bookmark::Type { type_name, .. } => type_name,
+            Bookmark::Symbol { symbol_name, .. } => symbol_name,
+            Bookmark::Module { module_path, .. } => module_path,
+        }
+    }
+
+    /// Check if both bookmarks designate the same item, regardless of indices
+    fn same_item(&self, other: &Bookmark) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other) && self.name() == other.name()
+    }
+}
+
+/// Item a note is attached to: a type or one of its fields (or enumerators)
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NoteTarget {
+    pub type_name: String,
+    pub field_name: Option<String>,
+}
+
+/// Bookmarks and free-text notes attached to the items of a PDB file
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Annotations {
+    bookmarks: Vec<Bookmark>,
+    notes: BTreeMap<NoteTarget, String>,
+}
+
+impl Annotations {
+    pub fn is_empty(&self) -> bool {
+        self.bookmarks.is_empty() && self.notes.is_empty()
+    }
+
+    /// Bookmarks, in the order they've been added
+    pub fn bookmarks(&self) -> &[Bookmark] {
+        &self.bookmarks
+    }
+
+    pub fn is_bookmarked(&self, bookmark: &Bookmark) -> bool {
+        self.bookmarks
+            .iter()
+            .any(|existing_bookmark| existing_bookmark.same_item(bookmark))
+    }
+
+    /// Add the given bookmark, replacing any bookmark of the same item
+    pub fn add_bookmark(&mut self, bookmark: Bookmark) {
+        match self
+            .bookmarks
+            .iter_mut()
+            .find(|existing_bookmark| existing_bookmark.same_item(&bookmark))
+        {
+            Some(existing_bookmark) => *existing_bookmark = bookmark,
+            None => self.bookmarks.push(bookmark),
+        }
+    }
+
+    pub fn remove_bookmark(&mut self, bookmark: &Bookmark) {
+        self.bookmarks
+            .retain(|existing_bookmark| !existing_bookmark.same_item(bookmark));
+    }
+
+    /// Notes, ordered by type name and field name
+    pub fn notes(&self) -> impl Iterator<Item = (&NoteTarget, &str)> {
+        self.notes
+            .iter()
+            .map(|(note_target, text)| (note_target, text.as_str()))
+    }
+
+    /// Return the note attached to the given type (or to one of its fields)
+    pub fn note(&self, type_name: &str, field_name: Option<&str>) -> Option<&str> {
+        self.notes
+            .get(&NoteTarget {
+                type_name: type_name.to_string(),
+                field_name: field_name.map(str::to_string),
+            })
+            .map(String::as_str)
+    }
+
+    /// Attach a note to the given type (or to one of its fields), replacing
+    /// the existing one. Blank notes remove the existing note.
+    pub fn set_note(&mut self, type_name: &str, field_name: Option<&str>, text: &str) {
+        let note_target = NoteTarget {
+            type_name: type_name.to_string(),
+            field_name: field_name
+                .map(str::trim)
+                .filter(|field_name| !field_name.is_empty())
+                .map(str::to_string),
+        };
+        let text = text.trim_end();
+        if text.trim_start().is_empty() {
+            self.notes.remove(&note_target);
+        } else {
+            self.notes.insert(note_target, text.to_string());
+        }
+    }
+
+    /// Parse annotations from their textual representation (i.e., the content
+    /// of annotation files)
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut annotations = Annotations::default();
+        for (line_index, line) in text.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let parse_error = |reason: &str| {
+                ResymCoreError::ParseAnnotationsError(format!("line {}: {reason}", line_index + 1))
+            };
+
+            let fields: Vec<&str> = line.split('\t').collect();
+            match fields.as_slice() {
+                ["type", type_index, type_name] => annotations.add_bookmark(Bookmark::Type {
+                    type_name: type_name.to_string(),
+                    type_index: parse_integer(type_index)
+                        .ok_or_else(|| parse_error("invalid type index"))?,
+                }),
+                ["symbol", symbol_index, symbol_name] => {
+                    let symbol_index = if *symbol_index == "-" {
+                        None
+                    } else {
+                        let (module_index, symbol_offset) = symbol_index
+                            .split_once(':')
+                            .and_then(|(module_index, symbol_offset)| {
+                                Some((parse_integer(module_index)?, parse_integer(symbol_offset)?))
+                            })
+                            .ok_or_else(|| parse_error("invalid symbol index"))?;
+                        Some((module_index, symbol_offset))
+                    };
+                    annotations.add_bookmark(Bookmark::Symbol {
+                        symbol_name: symbol_name.to_string(),
+                        symbol_index,
+                    });
+                }
+                ["module", module_index, module_path] => {
+                    annotations.add_bookmark(Bookmark::Module {
+                        module_path: module_path.to_string(),
+                        module_index: parse_integer(module_index)
+                            .ok_or_else(|| parse_error("invalid module index"))?,
+                    })
+                }
+                ["note", type_name, field_name, text] => annotations.set_note(
+                    type_name,
+                    Some(*field_name),
+                    &unescape_note(text).ok_or_else(|| parse_error("invalid escape sequence"))?,
+                ),
+                _ => return Err(parse_error("unknown entry")),
+            }
+        }
+
+        Ok(annotations)
+    }
+
+    /// Load annotations from the given file
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_from_file(file_path: &Path) -> Result<Self> {
+        Self::parse(&fs::read_to_string(file_path)?)
+    }
+
+    /// Save annotations to the given file, replacing its content
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save_to_file(&self, file_path: &Path) -> Result<()> {
+        if let Some(parent_dir) = file_path.parent() {
+            fs::create_dir_all(parent_dir)?;
+        }
+
+        // Note: write to a temporary file first, so that existing annotations
+        // aren't lost if writing fails
+        let temporary_file_path = file_path.with_extension(format!(
+            "{ANNOTATION_FILE_EXTENSION}.{}",
+            std::process::id()
+        ));
+        fs::write(&temporary_file_path, self.to_string())?;
+        fs::rename(&temporary_file_path, file_path)?;
+
+        Ok(())
+    }
+
+    /// Insert notes as comments into the given reconstructed output, above
+    /// the definitions of the types and fields they're attached to
+    pub fn annotate(&self, reconstructed_output: &str) -> String {
+        if self.notes.is_empty() {
+            return reconstructed_output.to_string();
+        }
+
+        let notes: HashMap<(&str, Option<&str>), &str> = self
+            .notes
+            .iter()
+            .map(|(note_target, text)| {
+                (
+                    (
+                        note_target.type_name.as_str(),
+                        note_target.field_name.as_deref(),
+                    ),
+                    text.as_str(),
+                )
+            })
+            .collect();
+        let write_note = |output: &mut String, indentation: &str, text: &str| {
+            for note_line in text.lines() {
+                output.push_str(indentation);
+                output.push_str("// ");
+                output.push_str(note_line);
+                output.push('\n');
+            }
+        };
+
+        let mut output = String::with_capacity(reconstructed_output.len());
+        // Blocks being written, along with the name of the type they define and
+        // whether it's an enum (`None` for unnamed structs and unions)
+        let mut block_stack: Vec<Option<(&str, bool)>> = vec![];
+        for line in reconstructed_output.split_inclusive('\n') {
+            let trimmed_line = line.trim();
+            let indentation = &line[..line.len() - line.trim_start().len()];
+            if trimmed_line.starts_with('}') {
+                block_stack.pop();
+            } else if let Some(type_definition) = parse_type_definition(trimmed_line) {
+                if let Some((type_name, _)) = type_definition {
+                    if let Some(text) = notes.get(&(type_name, None)) {
+                        write_note(&mut output, indentation, text);
+                    }
+                }
+                block_stack.push(type_definition);
+            } else if let Some((type_name, is_enum)) = block_stack.iter().rev().flatten().next() {
+                let field_name = if *is_enum {
+                    parse_enumerator_name(trimmed_line)
+                } else {
+                    parse_field_name(trimmed_line)
+                };
+                if let Some(text) =
+                    field_name.and_then(|field_name| notes.get(&(*type_name, Some(field_name))))
+                {
+                    write_note(&mut output, indentation, text);
+                }
+            }
+            output.push_str(line);
+        }
+
+        output
+    }
+}
+
+impl fmt::Display for Annotations {
+    /// Format annotations the way they're stored in annotation files
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{ANNOTATION_FILE_HEADER}")?;
+        for bookmark in &self.bookmarks {
+            match bookmark {
+                Bookmark::Type {
+                    type_name,
+                    type_index,
+                } => writeln!(f, "type\t{type_index:#x}\t{type_name}")?,
+                Bookmark::Symbol {
+                    symbol_name,
+                    symbol_index: Some((module_index, symbol_offset)),
+                } => writeln!(
+                    f,
+                    "symbol\t{module_index}:{symbol_offset:#x}\t{symbol_name}"
+                )?,
+                Bookmark::Symbol {
+                    symbol_name,
+                    symbol_index: None,
+                } => writeln!(f, "symbol\t-\t{symbol_name}")?,
+                Bookmark::Module {
+                    module_path,
+                    module_index,
+                } => writeln!(f, "module\t{module_index}\t{module_path}")?,
+            }
+        }
+        for (note_target, text) in &self.notes {
+            writeln!(
+                f,
+                "note\t{}\t{}\t{}",
+                note_target.type_name,
+                note_target.field_name.as_deref().unwrap_or_default(),
+                escape_note(text)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Location of the annotation files attached to PDB files, keyed by PDB GUID
+/// and age
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnnotationStore {
+    annotation_dir: PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl AnnotationStore {
+    pub fn new(annotation_dir: PathBuf) -> Self {
+        Self { annotation_dir }
+    }
+
+    /// Create `AnnotationStore` from the `RESYM_ANNOTATION_DIR` environment
+    /// variable, or use the default directory if it isn't set
+    pub fn from_env() -> Self {
+        match std::env::var_os(ANNOTATION_DIR_ENV_VAR) {
+            Some(annotation_dir) if !annotation_dir.is_empty() => Self::new(annotation_dir.into()),
+            _ => Self::new(Self::default_annotation_dir()),
+        }
+    }
+
+    /// Return the platform's data directory (e.g., `%APPDATA%` on Windows or
+    /// `$XDG_DATA_HOME` on Linux)
+    pub fn default_annotation_dir() -> PathBuf {
+        let platform_data_dir = if cfg!(windows) {
+            std::env::var_os("APPDATA").map(PathBuf::from)
+        } else if cfg!(target_os = "macos") {
+            std::env::var_os("HOME")
+                .map(|home| PathBuf::from(home).join("Library/Application Support"))
+        } else {
+            std::env::var_os("XDG_DATA_HOME")
+                .map(PathBuf::from)
+                .or_else(|| {
+                    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share"))
+                })
+        };
+
+        platform_data_dir
+            .unwrap_or_else(std::env::temp_dir)
+            .join("resym")
+            .join("annotations")
+    }
+
+    pub fn annotation_dir(&self) -> &Path {
+        &self.annotation_dir
+    }
+
+    /// Return the path of the annotation file attached to the given PDB file
+    pub fn annotation_file_path(&self, pdb_guid: &[u8; 16], pdb_age: u32) -> PathBuf {
+        self.annotation_dir.join(format!(
+            "{}.{ANNOTATION_FILE_EXTENSION}",
+            pdb_signature(pdb_guid, pdb_age)
+        ))
+    }
+
+    /// Load the annotations attached to the given PDB file. Returns empty
+    /// annotations if there's none.
+    pub fn load(&self, pdb_guid: &[u8; 16], pdb_age: u32) -> Result<Annotations> {
+        match Annotations::load_from_file(&self.annotation_file_path(pdb_guid, pdb_age)) {
+            Err(ResymCoreError::IoError(err)) if err.kind() == std::io::ErrorKind::NotFound => {
+                Ok(Annotations::default())
+            }
+            result => result,
+        }
+    }
+
+    /// Store the annotations attached to the given PDB file. Empty
+    /// annotations remove the annotation file.
+    pub fn store(
+        &self,
+        pdb_guid: &[u8; 16],
+        pdb_age: u32,
+        annotations: &Annotations,
+    ) -> Result<()> {
+        let annotation_file_path = self.annotation_file_path(pdb_guid, pdb_age);
+        if annotations.is_empty() {
+            match fs::remove_file(annotation_file_path) {
+                Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err.into()),
+                _ => Ok(()),
+            }
+        } else {
+            annotations.save_to_file(&annotation_file_path)
+        }
+    }
+}
+
+/// Parse a decimal or hexadecimal (i.e., `0x`-prefixed) integer
+fn parse_integer<I: TryFrom<u64>>(text: &str) -> Option<I> {
+    let value = match text.strip_prefix("0x") {
+        Some(hex_text) => u64::from_str_radix(hex_text, 16).ok()?,
+        None => text.parse().ok()?,
+    };
+
+    value.try_into().ok()
+}
+
+fn escape_note(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace('\t', "\\t")
+}
+
+fn unescape_note(text: &str) -> Option<String> {
+    let mut unescaped_text = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            unescaped_text.push(match chars.next()? {
+                'n' => '\n',
+                't' => '\t',
+                '\\' => '\\',
+                _ => return None,
+            });
+        } else {
+            unescaped_text.push(c);
+        }
+    }
+
+    Some(unescaped_text)
+}
+
+/// Parse the first line of a type definition (e.g., `struct Foo : public Bar {`).
+/// Returns the name of the type (`None` for unnamed structs and unions) and
+/// whether it's an enum, or `None` if the line doesn't start a definition.
+fn parse_type_definition(line: &str) -> Option<Option<(&str, bool)>> {
+    // Note: definitions may be followed by a comment (e.g., the type's size)
+    if !line.contains('{') {
+        return None;
+    }
+    let (keyword, declaration) = line.split_once(' ')?;
+    let is_enum = match keyword {
+        "struct" | "class" | "union" | "interface" => false,
+        "enum" => true,
+        _ => return None,
+    };
+    // Note: names end before base classes or the underlying type of enums
+    let type_name_end = declaration
+        .find(" : ")
+        .or_else(|| declaration.find(" {"))
+        .unwrap_or(declaration.len());
+    let type_name = declaration[..type_name_end].trim();
+    if type_name.is_empty() || type_name.starts_with('{') {
+        return Some(None);
+    }
+
+    Some(Some((type_name, is_enum)))
+}
+
+/// Parse the name of the field declared in the given line (e.g.,
+/// `/* 0x0008 */ public: int32_t field[4];`)
+fn parse_field_name(line: &str) -> Option<&str> {
+    // Note: only fields (which are prefixed with their offset) are considered
+    let (_, declaration) = line.strip_prefix("/* 0x")?.split_once("*/")?;
+    let declaration = declaration.split(" /*").next()?;
+
+    declarator_name(declaration)
+}
+
+/// Return the name declared in the given declaration (e.g., `int32_t field[4];`
+/// or `void (* callback)(int32_t);`)
+fn declarator_name(declaration: &str) -> Option<&str> {
+    let bytes = declaration.as_bytes();
+    // Note: delimiters found in template arguments or parameter lists are
+    // ignored
+    let mut depth = 0usize;
+    let mut group_start = None;
+    for (index, byte) in bytes.iter().enumerate() {
+        match byte {
+            b'<' => depth += 1,
+            b'(' => {
+                if depth == 0 {
+                    group_start = Some(index + 1);
+                }
+                depth += 1;
+            }
+            b'>' => depth = depth.saturating_sub(1),
+            b')' => {
+                depth = depth.saturating_sub(1);
+                // The name of function pointers is declared in the first
+                // parenthesized group (e.g., `(* callback[2])`)
+                if let (0, Some(group_start)) = (depth, group_start) {
+                    return declarator_name(&declaration[group_start..index]);
+                }
+            }
+            // Names end before the array dimensions, the bit size of
+            // bit-fields or the end of the declaration.
+            // Note: colons of access specifiers aren't preceded by a space
+            b'[' | b';' if depth == 0 => {
+                return identifier_suffix(&declaration[..index]);
+            }
+            b':' if depth == 0 && index > 0 && bytes[index - 1] == b' ' => {
+                return identifier_suffix(&declaration[..index]);
+            }
+            _ => {}
+        }
+    }
+
+    identifier_suffix(declaration)
+}
+
+/// Parse the name of the enumerator declared in the given line (e.g.,
+/// `VALUE = 0x01,`)
+fn parse_enumerator_name(line: &str) -> Option<&str> {
+    let (name, _) = line.split_once(" = ")?;
+
+    identifier_suffix(name)
+}
+
+/// Return the identifier located at the end of the given text, if any
+fn identifier_suffix(text: &str) -> Option<&str> {
+    let text = text.trim_end();
+    let identifier_start = text
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| c.is_alphanumeric() || *c == '_')
+        .last()
+        .map_or(text.len(), |(index, _)| index);
+    let identifier = &text[identifier_start..];
+
+    (!identifier.is_empty()).then_some(identifier)
+}
@@ -1,42 +1,64 @@
 use crossbeam_channel::{Receiver, Sender};
-#[cfg(target_arch = "wasm32")]
-use instant::Instant;
+use dashmap::DashMap;
 #[cfg(feature = "rayon")]
-use rayon::{
-    iter::{IntoParallelRefIterator, ParallelIterator},
-    prelude::ParallelSliceMut,
-    ThreadPool,
-};
+use rayon::ThreadPool;
 
-use core::fmt;
 #[cfg(all(not(feature = "rayon"), not(target_arch = "wasm32")))]
 use std::thread::{self, JoinHandle};
 use std::{
     collections::{BTreeSet, HashMap},
-    io,
-    sync::Arc,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
 };
-#[cfg(not(target_arch = "wasm32"))]
-use std::{path::PathBuf, time::Instant};
 #[cfg(all(not(feature = "rayon"), target_arch = "wasm32"))]
 use wasm_thread::{self as thread, JoinHandle};
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::{
+    annotations::{AnnotationStore, Annotations},
+    index_cache::IndexCache,
+    symbol_store::SymbolPath,
+};
 use crate::{
-    diffing::{diff_module_by_path, diff_symbol_by_name, diff_type_by_name},
     error::{Result, ResymCoreError},
-    frontend::{FrontendCommand, FrontendController, ReconstructedType},
-    par_iter_if_available, par_sort_by_if_available,
-    pdb_file::{self, ModuleList, PDBDataSource, PdbFile, SymbolList, SymbolListView, TypeList},
-    pdb_types::{include_headers_for_flavor, PrimitiveReconstructionFlavor},
-    PKG_VERSION,
+    field_search::FieldSearchQuery,
+    frontend::{FrontendCommand, FrontendController},
+    layout_search::LayoutQuery,
+    options::{FilterOptions, ReconstructionOptions, TypeGraphExportOptions},
+    pdb_file::{self, PdbFile},
+    pe_file::{self, PeFile},
+    progress::{CancellationToken, Progress, ProgressReporter, RequestId},
+    session::Session,
+    size_report::SizeReportGrouping,
+    spawn_if_available,
+    type_graph::TypeGraphQuery,
 };
 
 pub type PDBSlot = usize;
 
 pub enum BackendCommand {
     /// Load a PDB file given its path as a `PathBuf`.
+    /// If the path points to a PE image, the matching PDB file is looked up
+    /// in the symbol search path and the image is loaded alongside it.
     #[cfg(not(target_arch = "wasm32"))]
     LoadPDBFromPath(PDBSlot, PathBuf),
+    /// Set the symbol search path used to locate PDB files matching PE images.
+    #[cfg(not(target_arch = "wasm32"))]
+    SetSymbolPath(SymbolPath),
+    /// Set the on-disk cache used to persist PDB indices (`None` disables it).
+    #[cfg(not(target_arch = "wasm32"))]
+    SetIndexCache(Option<IndexCache>),
+    /// Load the bookmarks and notes attached to the PDB file loaded in the
+    /// given slot.
+    #[cfg(not(target_arch = "wasm32"))]
+    LoadAnnotations(PDBSlot),
+    /// Store the bookmarks and notes attached to the PDB file loaded in the
+    /// given slot, replacing the existing ones.
+    #[cfg(not(target_arch = "wasm32"))]
+    StoreAnnotations(PDBSlot, Annotations),
     /// Load a PDB file given its name and content as a `Vec<u8>`.
     LoadPDBFromVec(PDBSlot, String, Vec<u8>),
     /// Load a PDB file given its name and content as an `Arc<[u8]>`.
@@ -46,103 +68,224 @@ pub enum BackendCommand {
     LoadPDBFromURL(PDBSlot, String),
     /// Unload a PDB file given its slot.
     UnloadPDB(PDBSlot),
+    /// Load the PE image matching the PDB file loaded in the given slot, given
+    /// its path as a `PathBuf`.
+    #[cfg(not(target_arch = "wasm32"))]
+    LoadPEFromPath(PDBSlot, PathBuf),
+    /// Load the PE image matching the PDB file loaded in the given slot, given
+    /// its name and content as a `Vec<u8>`.
+    LoadPEFromVec(PDBSlot, String, Vec<u8>),
     /// Reconstruct a type given its type index for a given PDB.
-    ReconstructTypeByIndex(
-        PDBSlot,
-        pdb_file::TypeIndex,
-        PrimitiveReconstructionFlavor,
-        bool,
-        bool,
-        bool,
-        bool,
-        bool,
-    ),
+    ReconstructTypeByIndex(PDBSlot, pdb_file::TypeIndex, ReconstructionOptions),
     /// Reconstruct a type given its name for a given PDB.
-    ReconstructTypeByName(
-        PDBSlot,
-        String,
-        PrimitiveReconstructionFlavor,
-        bool,
-        bool,
-        bool,
-        bool,
-        bool,
-    ),
+    ReconstructTypeByName(PDBSlot, String, ReconstructionOptions),
     /// Reconstruct all types found in a given PDB.
-    ReconstructAllTypes(
-        PDBSlot,
-        PrimitiveReconstructionFlavor,
-        bool,
-        bool,
-        bool,
-        bool,
-    ),
+    ReconstructAllTypes(PDBSlot, ReconstructionOptions),
     /// Retrieve a list of types that match the given filter for a given PDB.
-    ListTypes(PDBSlot, String, bool, bool, bool),
+    ListTypes(PDBSlot, String, FilterOptions),
     /// Retrieve a list of types that match the given filter for multiple PDBs
     /// and merge the result.
-    ListTypesMerged(Vec<PDBSlot>, String, bool, bool, bool),
+    ListTypesMerged(Vec<PDBSlot>, String, FilterOptions),
+    /// Retrieve a list of types that match the given filter for two PDBs,
+    /// along with their status (i.e., added, removed, modified or unchanged).
+    DiffTypeList(
+        PDBSlot,
+        PDBSlot,
+        String,
+        FilterOptions,
+        ReconstructionOptions,
+    ),
     /// Retrieve a list of symbols that match the given filter for multiple PDBs
     /// and merge the result.
-    ListSymbols(PDBSlot, String, bool, bool, bool),
+    ListSymbols(PDBSlot, String, FilterOptions),
     /// Retrieve a list of symbols that match the given filter for multiple PDBs
     /// and merge the result.
-    ListSymbolsMerged(Vec<PDBSlot>, String, bool, bool, bool),
+    ListSymbolsMerged(Vec<PDBSlot>, String, FilterOptions),
     /// Reconstruct a symbol given its index for a given PDB.
-    ReconstructSymbolByIndex(
-        PDBSlot,
-        pdb_file::SymbolIndex,
-        PrimitiveReconstructionFlavor,
-        bool,
-        bool,
-    ),
+    ReconstructSymbolByIndex(PDBSlot, pdb_file::SymbolIndex, ReconstructionOptions),
     /// Reconstruct a symbol given its name for a given PDB.
-    ReconstructSymbolByName(PDBSlot, String, PrimitiveReconstructionFlavor, bool, bool),
+    ReconstructSymbolByName(PDBSlot, String, ReconstructionOptions),
     /// Reconstruct all symbols found in a given PDB.
-    ReconstructAllSymbols(PDBSlot, PrimitiveReconstructionFlavor, bool, bool),
+    ReconstructAllSymbols(PDBSlot, ReconstructionOptions),
     /// Retrieve a list of modules that match the given filter for multiple PDBs
     /// and merge the result.
-    ListModules(PDBSlot, String, bool, bool),
+    ListModules(PDBSlot, String, FilterOptions),
     /// Reconstruct a module given its index for a given PDB.
-    ReconstructModuleByIndex(PDBSlot, usize, PrimitiveReconstructionFlavor, bool, bool),
+    ReconstructModuleByIndex(PDBSlot, usize, ReconstructionOptions),
     /// Reconstruct the diff of a type given its name.
-    DiffTypeByName(
-        PDBSlot,
-        PDBSlot,
-        String,
-        PrimitiveReconstructionFlavor,
-        bool,
-        bool,
-        bool,
-        bool,
-        bool,
-    ),
+    DiffTypeByName(PDBSlot, PDBSlot, String, ReconstructionOptions),
     /// Reconstruct the diff of a symbol given its name.
-    DiffSymbolByName(
-        PDBSlot,
-        PDBSlot,
-        String,
-        PrimitiveReconstructionFlavor,
-        bool,
-        bool,
-    ),
+    DiffSymbolByName(PDBSlot, PDBSlot, String, ReconstructionOptions),
     /// Reconstruct the diff of a module given its path.
-    DiffModuleByPath(
-        PDBSlot,
-        PDBSlot,
-        String,
-        PrimitiveReconstructionFlavor,
-        bool,
-        bool,
-    ),
+    DiffModuleByPath(PDBSlot, PDBSlot, String, ReconstructionOptions),
     /// Retrieve a list of all types that reference the given type
     ListTypeCrossReferences(PDBSlot, pdb_file::TypeIndex),
+    /// Retrieve the size and the definition location of the given types
+    ListTypeDetails(PDBSlot, pdb_file::TypeList),
+    /// Compute the memory layout of a class, struct or union given its type
+    /// index, for a given PDB.
+    ComputeTypeLayout(PDBSlot, pdb_file::TypeIndex),
+    /// Compute a report of code and data sizes, aggregated following the given
+    /// criterion, for a given PDB.
+    ComputeSizeReport(PDBSlot, SizeReportGrouping),
+    /// Retrieve the locations at which the given function has been inlined,
+    /// followed by the functions that have been inlined into it
+    ListInlineSites(PDBSlot, String),
+    /// Query the graph of dependencies between types for a given PDB.
+    QueryTypeGraph(PDBSlot, TypeGraphQuery),
+    /// Export the graph of the types needed by a given type, for a given PDB.
+    ExportTypeGraph(PDBSlot, String, TypeGraphExportOptions),
+    /// Retrieve the base and derived classes of a class given its name, for a
+    /// given PDB.
+    ListClassHierarchy(PDBSlot, String),
+    /// Search the fields of all complete types, for a given PDB.
+    FindFields(PDBSlot, FieldSearchQuery, FilterOptions),
+    /// Search complete types by layout, for a given PDB. Results are ranked
+    /// and limited to the given number of types, if any.
+    MatchLayout(PDBSlot, LayoutQuery, Option<usize>),
+    /// Cancel a request given its identifier. Long-running requests stop at
+    /// the next opportunity, and the results of cancelled requests are
+    /// discarded.
+    Cancel(RequestId),
+}
+
+/// Group of commands whose results replace one another in the frontend.
+/// Sending a command cancels the pending commands of the same group, for the
+/// same PDB slot.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum CommandGroup {
+    TypeList,
+    SymbolList,
+    ModuleList,
+    /// Reconstruction of single types and symbols
+    Reconstruction,
+    ModuleReconstruction,
+    TypeGraph,
+    FieldSearch,
+    LayoutSearch,
+    TypeLayout,
+}
+
+impl BackendCommand {
+    /// Return the group of the command, along with the PDB slot it applies
+    /// to. Commands involving several PDBs are associated with the first one.
+    fn group(&self) -> Option<(CommandGroup, PDBSlot)> {
+        let command_group = match self {
+            BackendCommand::ListTypes(pdb_slot, ..)
+            | BackendCommand::DiffTypeList(pdb_slot, ..) => (CommandGroup::TypeList, *pdb_slot),
+            BackendCommand::ListTypesMerged(pdb_slots, ..) => {
+                (CommandGroup::TypeList, *pdb_slots.first()?)
+            }
+            BackendCommand::ListSymbols(pdb_slot, ..) => (CommandGroup::SymbolList, *pdb_slot),
+            BackendCommand::ListSymbolsMerged(pdb_slots, ..) => {
+                (CommandGroup::SymbolList, *pdb_slots.first()?)
+            }
+            BackendCommand::ListModules(pdb_slot, ..) => (CommandGroup::ModuleList, *pdb_slot),
+            // Note: reconstructing all types or symbols (e.g., to export them)
+            // isn't superseded by other commands
+            BackendCommand::ReconstructTypeByIndex(pdb_slot, ..)
+            | BackendCommand::ReconstructTypeByName(pdb_slot, ..)
+            | BackendCommand::ReconstructSymbolByIndex(pdb_slot, ..)
+            | BackendCommand::ReconstructSymbolByName(pdb_slot, ..)
+            | BackendCommand::DiffTypeByName(pdb_slot, ..)
+            | BackendCommand::DiffSymbolByName(pdb_slot, ..) => {
+                (CommandGroup::Reconstruction, *pdb_slot)
+            }
+            BackendCommand::ReconstructModuleByIndex(pdb_slot, ..)
+            | BackendCommand::DiffModuleByPath(pdb_slot, ..) => {
+                (CommandGroup::ModuleReconstruction, *pdb_slot)
+            }
+            BackendCommand::QueryTypeGraph(pdb_slot, ..)
+            | BackendCommand::ExportTypeGraph(pdb_slot, ..) => (CommandGroup::TypeGraph, *pdb_slot),
+            BackendCommand::FindFields(pdb_slot, ..) => (CommandGroup::FieldSearch, *pdb_slot),
+            BackendCommand::MatchLayout(pdb_slot, ..) => (CommandGroup::LayoutSearch, *pdb_slot),
+            BackendCommand::ComputeTypeLayout(pdb_slot, ..) => {
+                (CommandGroup::TypeLayout, *pdb_slot)
+            }
+            _ => return None,
+        };
+
+        Some(command_group)
+    }
+}
+
+/// Command sent to the worker thread, along with the identifier and the
+/// cancellation token of the corresponding request
+struct BackendRequest {
+    id: RequestId,
+    cancellation_token: CancellationToken,
+    command: BackendCommand,
+}
+
+/// State associated with the request being processed. Once processed, the
+/// request is removed from the pending requests.
+struct RequestContext<F: FrontendController> {
+    id: RequestId,
+    progress: ProgressReporter,
+    frontend_controller: Arc<F>,
+    pending_requests: Arc<DashMap<RequestId, CancellationToken>>,
+}
+
+impl<F: FrontendController + Send + Sync + 'static> RequestContext<F> {
+    fn new(
+        id: RequestId,
+        cancellation_token: CancellationToken,
+        frontend_controller: Arc<F>,
+        pending_requests: Arc<DashMap<RequestId, CancellationToken>>,
+    ) -> Self {
+        let progress = {
+            let frontend_controller = frontend_controller.clone();
+            ProgressReporter::new(cancellation_token, move |progress| {
+                if let Err(err) =
+                    frontend_controller.send_command(FrontendCommand::Progress(id, progress))
+                {
+                    log::warn!("Failed to report progress: {err}");
+                }
+            })
+        };
+
+        Self {
+            id,
+            progress,
+            frontend_controller,
+            pending_requests,
+        }
+    }
+}
+
+impl<F: FrontendController> RequestContext<F> {
+    /// Send the request's result to the frontend. Results of cancelled (or
+    /// superseded) requests are discarded.
+    fn send_result(&self, command: FrontendCommand) -> Result<()> {
+        if self.progress.is_cancelled() {
+            log::debug!("Discarding result of cancelled request #{}", self.id);
+            return Ok(());
+        }
+
+        self.frontend_controller.send_command(command)
+    }
+}
+
+impl<F: FrontendController> Drop for RequestContext<F> {
+    fn drop(&mut self) {
+        self.pending_requests.remove(&self.id);
+        if self.progress.has_reported() {
+            let _ = self
+                .frontend_controller
+                .send_command(FrontendCommand::Progress(self.id, Progress::Done));
+        }
+    }
 }
 
 /// Struct that represents the backend. The backend is responsible
 /// for the actual PDB processing (e.g., type listing and reconstruction).
 pub struct Backend {
-    tx_worker: Sender<BackendCommand>,
+    tx_worker: Sender<BackendRequest>,
+    /// Cancellation tokens of the requests that haven't been processed yet
+    pending_requests: Arc<DashMap<RequestId, CancellationToken>>,
+    /// Most recent request of each command group, for each PDB slot
+    latest_requests: DashMap<(CommandGroup, PDBSlot), RequestId>,
+    next_request_id: AtomicU64,
     #[cfg(feature = "rayon")]
     _worker_thread_pool: ThreadPool,
     #[cfg(not(feature = "rayon"))]
@@ -155,17 +298,24 @@ impl Backend {
     pub fn new(
         frontend_controller: Arc<impl FrontendController + Send + Sync + 'static>,
     ) -> Result<Self> {
-        let (tx_worker, rx_worker) = crossbeam_channel::unbounded::<BackendCommand>();
+        let (tx_worker, rx_worker) = crossbeam_channel::unbounded::<BackendRequest>();
+        let pending_requests: Arc<DashMap<RequestId, CancellationToken>> = Default::default();
 
         // Start a thread pool with as many threads as there are CPUs on the machine,
         // minus one (because we account for the GUI thread).
-        // Note: Calling `num_threads` with 0 is valid.
+        // Note: The pool needs at least two threads, as one of them is
+        // occupied by the command dispatcher.
         let cpu_count = num_cpus::get();
         let thread_pool = rayon::ThreadPoolBuilder::new()
-            .num_threads(cpu_count - 1)
+            .num_threads(std::cmp::max(cpu_count.saturating_sub(1), 2))
             .build()?;
+        let pending_requests_worker = pending_requests.clone();
         thread_pool.spawn(move || {
-            let exit_result = worker_thread_routine(rx_worker, frontend_controller.clone());
+            let exit_result = worker_thread_routine(
+                rx_worker,
+                pending_requests_worker,
+                frontend_controller.clone(),
+            );
             if let Err(err) = exit_result {
                 log::error!("Background thread aborted: {}", err);
             }
@@ -174,6 +324,9 @@ impl Backend {
 
         Ok(Self {
             tx_worker,
+            pending_requests,
+            latest_requests: DashMap::default(),
+            next_request_id: AtomicU64::new(0),
             _worker_thread_pool: thread_pool,
         })
     }
@@ -183,11 +336,17 @@ impl Backend {
     pub fn new(
         frontend_controller: Arc<impl FrontendController + Send + Sync + 'static>,
     ) -> Result<Self> {
-        let (tx_worker, rx_worker) = crossbeam_channel::unbounded::<BackendCommand>();
+        let (tx_worker, rx_worker) = crossbeam_channel::unbounded::<BackendRequest>();
+        let pending_requests: Arc<DashMap<RequestId, CancellationToken>> = Default::default();
 
         // Start a new thread
+        let pending_requests_worker = pending_requests.clone();
         let worker_thread = thread::spawn(move || {
-            let exit_result = worker_thread_routine(rx_worker, frontend_controller.clone());
+            let exit_result = worker_thread_routine(
+                rx_worker,
+                pending_requests_worker,
+                frontend_controller.clone(),
+            );
             if let Err(err) = exit_result {
                 log::error!("Background thread aborted: {}", err);
             }
@@ -196,56 +355,174 @@ impl Backend {
 
         Ok(Self {
             tx_worker,
+            pending_requests,
+            latest_requests: DashMap::default(),
+            next_request_id: AtomicU64::new(0),
             _worker_thread: worker_thread,
         })
     }
 
-    pub fn send_command(&self, command: BackendCommand) -> Result<()> {
+    /// Send a command to the backend. Returns the identifier given to the
+    /// corresponding request, which can be used to cancel it.
+    pub fn send_command(&self, command: BackendCommand) -> Result<RequestId> {
+        let request_id = self.next_request_id.fetch_add(1, Ordering::Relaxed);
+
+        // Note: cancellation requests are handled right away, as the worker
+        // thread is likely busy processing the request to cancel
+        if let BackendCommand::Cancel(cancelled_request_id) = command {
+            if let Some(cancellation_token) = self.pending_requests.get(&cancelled_request_id) {
+                log::info!("Cancelling request #{cancelled_request_id} ...");
+                cancellation_token.cancel();
+            }
+            return Ok(request_id);
+        }
+
+        // Newer commands supersede pending commands of the same group, for
+        // the same PDB (e.g., filtering the type list while the user is typing)
+        if let Some(command_group) = command.group() {
+            if let Some(superseded_request_id) =
+                self.latest_requests.insert(command_group, request_id)
+            {
+                if let Some(cancellation_token) = self.pending_requests.get(&superseded_request_id)
+                {
+                    cancellation_token.cancel();
+                }
+            }
+        }
+
+        let cancellation_token = CancellationToken::default();
+        self.pending_requests
+            .insert(request_id, cancellation_token.clone());
         self.tx_worker
-            .send(command)
-            .map_err(|err| ResymCoreError::CrossbeamError(err.to_string()))
+            .send(BackendRequest {
+                id: request_id,
+                cancellation_token,
+                command,
+            })
+            .map_err(|err| {
+                self.pending_requests.remove(&request_id);
+                ResymCoreError::CrossbeamError(err.to_string())
+            })?;
+
+        Ok(request_id)
     }
 }
 
 /// Main backend routine. This processes commands sent by the frontend and sends
 /// results back.
 fn worker_thread_routine(
-    rx_worker: Receiver<BackendCommand>,
+    rx_worker: Receiver<BackendRequest>,
+    pending_requests: Arc<DashMap<RequestId, CancellationToken>>,
     frontend_controller: Arc<impl FrontendController + Send + Sync + 'static>,
 ) -> Result<()> {
-    let mut pdb_files: HashMap<PDBSlot, PdbFile<PDBDataSource>> = HashMap::new();
-    while let Ok(command) = rx_worker.recv() {
-        match command {
+    let mut sessions: HashMap<PDBSlot, Arc<Session>> = HashMap::new();
+    #[cfg(not(target_arch = "wasm32"))]
+    let mut symbol_path = SymbolPath::from_env();
+    #[cfg(not(target_arch = "wasm32"))]
+    let mut index_cache = IndexCache::from_env();
+    #[cfg(not(target_arch = "wasm32"))]
+    let annotation_store = AnnotationStore::from_env();
+    // Sessions loaded in the background are sent back to this thread, which
+    // owns the loaded sessions
+    #[cfg_attr(target_arch = "wasm32", allow(unused_variables))]
+    let (tx_loaded_session, rx_loaded_session) = crossbeam_channel::unbounded::<LoadedSession<_>>();
+    loop {
+        let request = crossbeam_channel::select! {
+            recv(rx_worker) -> request => match request {
+                Ok(request) => request,
+                Err(_) => break,
+            },
+            recv(rx_loaded_session) -> loaded_session => {
+                if let Ok((request_context, pdb_slot, pdb_file_path, load_result)) = loaded_session
+                {
+                    install_loaded_session(
+                        &mut sessions,
+                        &request_context,
+                        pdb_slot,
+                        &pdb_file_path,
+                        load_result,
+                    )?;
+                }
+                continue;
+            }
+        };
+        let request_context = RequestContext::new(
+            request.id,
+            request.cancellation_token,
+            frontend_controller.clone(),
+            pending_requests.clone(),
+        );
+        // Skip requests cancelled before being processed
+        if request_context.progress.is_cancelled() {
+            log::info!("Request #{} has been cancelled", request.id);
+            continue;
+        }
+
+        // Note: commands that modify the backend's state (e.g., loading PDB
+        // files) are processed sequentially, in the order they were sent
+        match request.command {
             #[cfg(not(target_arch = "wasm32"))]
             BackendCommand::LoadPDBFromPath(pdb_slot, pdb_file_path) => {
                 log::info!("Loading a new PDB file ...");
-                match PdbFile::load_from_file(&pdb_file_path) {
-                    Err(err) => frontend_controller
-                        .send_command(FrontendCommand::LoadPDBResult(Err(err)))?,
-                    Ok(loaded_pdb_file) => {
-                        frontend_controller
-                            .send_command(FrontendCommand::LoadPDBResult(Ok(pdb_slot)))?;
-                        if let Some(pdb_file) = pdb_files.insert(pdb_slot, loaded_pdb_file) {
-                            log::info!("'{}' has been unloaded.", pdb_file.file_path.display());
-                        }
-                        log::info!(
-                            "'{}' has been loaded successfully!",
-                            pdb_file_path.display()
+                // Note: PDB files matching PE images might have to be
+                // downloaded, look them up in the background
+                if pe_file::is_pe_image_file(&pdb_file_path).unwrap_or(false) {
+                    let symbol_path = symbol_path.clone();
+                    let index_cache = index_cache.clone();
+                    let tx_loaded_session = tx_loaded_session.clone();
+                    spawn_if_available!(move || {
+                        let load_result = Session::open_with(
+                            &pdb_file_path,
+                            &symbol_path,
+                            index_cache,
+                            &request_context.progress,
                         );
-                    }
+                        if let Err(err) = tx_loaded_session.send((
+                            request_context,
+                            pdb_slot,
+                            pdb_file_path,
+                            load_result,
+                        )) {
+                            log::error!("Failed to send loaded session: {err}");
+                        }
+                    });
+                } else {
+                    let load_result = Session::open_with(
+                        &pdb_file_path,
+                        &symbol_path,
+                        index_cache.clone(),
+                        &request_context.progress,
+                    );
+                    install_loaded_session(
+                        &mut sessions,
+                        &request_context,
+                        pdb_slot,
+                        &pdb_file_path,
+                        load_result,
+                    )?;
                 }
             }
 
             BackendCommand::LoadPDBFromVec(pdb_slot, pdb_name, pdb_data) => {
                 log::info!("Loading a new PDB file ...");
-                match PdbFile::load_from_bytes_as_vec(pdb_name.clone(), pdb_data) {
-                    Err(err) => frontend_controller
-                        .send_command(FrontendCommand::LoadPDBResult(Err(err)))?,
+                match PdbFile::load_from_bytes_as_vec(
+                    pdb_name.clone(),
+                    pdb_data,
+                    &request_context.progress,
+                ) {
+                    Err(err) => {
+                        request_context.send_result(FrontendCommand::LoadPDBResult(Err(err)))?
+                    }
                     Ok(loaded_pdb_file) => {
-                        frontend_controller
-                            .send_command(FrontendCommand::LoadPDBResult(Ok(pdb_slot)))?;
-                        if let Some(pdb_file) = pdb_files.insert(pdb_slot, loaded_pdb_file) {
-                            log::info!("'{}' has been unloaded.", pdb_file.file_path.display());
+                        request_context
+                            .send_result(FrontendCommand::LoadPDBResult(Ok(pdb_slot)))?;
+                        if let Some(session) =
+                            sessions.insert(pdb_slot, Arc::new(Session::from(loaded_pdb_file)))
+                        {
+                            log::info!(
+                                "'{}' has been unloaded.",
+                                session.pdb_file().file_path.display()
+                            );
                         }
                         log::info!("'{}' has been loaded successfully!", pdb_name);
                     }
@@ -254,14 +531,24 @@ fn worker_thread_routine(
 
             BackendCommand::LoadPDBFromArray(pdb_slot, pdb_name, pdb_data) => {
                 log::info!("Loading a new PDB file ...");
-                match PdbFile::load_from_bytes_as_array(pdb_name.clone(), pdb_data) {
-                    Err(err) => frontend_controller
-                        .send_command(FrontendCommand::LoadPDBResult(Err(err)))?,
+                match PdbFile::load_from_bytes_as_array(
+                    pdb_name.clone(),
+                    pdb_data,
+                    &request_context.progress,
+                ) {
+                    Err(err) => {
+                        request_context.send_result(FrontendCommand::LoadPDBResult(Err(err)))?
+                    }
                     Ok(loaded_pdb_file) => {
-                        frontend_controller
-                            .send_command(FrontendCommand::LoadPDBResult(Ok(pdb_slot)))?;
-                        if let Some(pdb_file) = pdb_files.insert(pdb_slot, loaded_pdb_file) {
-                            log::info!("'{}' has been unloaded.", pdb_file.file_path.display());
+                        request_context
+                            .send_result(FrontendCommand::LoadPDBResult(Ok(pdb_slot)))?;
+                        if let Some(session) =
+                            sessions.insert(pdb_slot, Arc::new(Session::from(loaded_pdb_file)))
+                        {
+                            log::info!(
+                                "'{}' has been unloaded.",
+                                session.pdb_file().file_path.display()
+                            );
                         }
                         log::info!("'{}' has been loaded successfully!", pdb_name);
                     }
@@ -277,25 +564,22 @@ fn worker_thread_routine(
                     Ok(url) => {
                         let url_path = url.path();
                         if let Some(pdb_name) = url_path.split('/').last() {
-                            let frontend_controller = frontend_controller.clone();
                             let pdb_name = pdb_name.to_string();
                             let request = ehttp::Request::get(url);
                             ehttp::fetch(request, move |result: ehttp::Result<ehttp::Response>| {
                                 match result {
-                                    Err(err) => frontend_controller
-                                        .send_command(FrontendCommand::LoadPDBResult(Err(
+                                    Err(err) => request_context
+                                        .send_result(FrontendCommand::LoadPDBResult(Err(
                                             ResymCoreError::EHttpError(err),
                                         )))
                                         .expect("frontend unavailable"),
-                                    Ok(response) => {
-                                        frontend_controller
-                                            .send_command(FrontendCommand::LoadURLResult(Ok((
-                                                pdb_slot,
-                                                pdb_name,
-                                                response.bytes,
-                                            ))))
-                                            .expect("frontend unavailable");
-                                    }
+                                    Ok(response) => request_context
+                                        .send_result(FrontendCommand::LoadURLResult(Ok((
+                                            pdb_slot,
+                                            pdb_name,
+                                            response.bytes,
+                                        ))))
+                                        .expect("frontend unavailable"),
                                 }
                             });
                         } else {
@@ -305,869 +589,438 @@ fn worker_thread_routine(
                 }
             }
 
-            BackendCommand::UnloadPDB(pdb_slot) => match pdb_files.remove(&pdb_slot) {
+            BackendCommand::UnloadPDB(pdb_slot) => match sessions.remove(&pdb_slot) {
                 None => {
                     log::error!("Trying to unload an inexistent PDB");
                 }
-                Some(pdb_file) => {
-                    log::info!("'{}' has been unloaded.", pdb_file.file_path.display());
+                Some(session) => {
+                    log::info!(
+                        "'{}' has been unloaded.",
+                        session.pdb_file().file_path.display()
+                    );
                 }
             },
 
-            BackendCommand::ReconstructTypeByIndex(
-                pdb_slot,
-                type_index,
-                primitives_flavor,
-                print_header,
-                reconstruct_dependencies,
-                print_access_specifiers,
-                integers_as_hexadecimal,
-                ignore_std_types,
-            ) => {
-                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
-                    let reconstructed_type_result = reconstruct_type_by_index_command(
-                        pdb_file,
-                        type_index,
-                        primitives_flavor,
-                        print_header,
-                        reconstruct_dependencies,
-                        print_access_specifiers,
-                        integers_as_hexadecimal,
-                        ignore_std_types,
-                    );
-                    frontend_controller.send_command(FrontendCommand::ReconstructTypeResult(
-                        reconstructed_type_result,
-                    ))?;
-                }
+            #[cfg(not(target_arch = "wasm32"))]
+            BackendCommand::SetSymbolPath(new_symbol_path) => {
+                symbol_path = new_symbol_path;
             }
 
-            BackendCommand::ReconstructTypeByName(
-                pdb_slot,
-                type_name,
-                primitives_flavor,
-                print_header,
-                reconstruct_dependencies,
-                print_access_specifiers,
-                integers_as_hexadecimal,
-                ignore_std_types,
-            ) => {
-                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
-                    let reconstructed_type_result = reconstruct_type_by_name_command(
-                        pdb_file,
-                        &type_name,
-                        primitives_flavor,
-                        print_header,
-                        reconstruct_dependencies,
-                        print_access_specifiers,
-                        integers_as_hexadecimal,
-                        ignore_std_types,
-                    );
-                    frontend_controller.send_command(FrontendCommand::ReconstructTypeResult(
-                        reconstructed_type_result,
-                    ))?;
-                }
+            #[cfg(not(target_arch = "wasm32"))]
+            BackendCommand::SetIndexCache(new_index_cache) => {
+                index_cache = new_index_cache;
             }
 
-            BackendCommand::ReconstructAllTypes(
-                pdb_slot,
-                primitives_flavor,
-                print_header,
-                print_access_specifiers,
-                integers_as_hexadecimal,
-                ignore_std_types,
-            ) => {
-                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
-                    let reconstructed_type_result = reconstruct_all_types_command(
-                        pdb_file,
-                        primitives_flavor,
-                        print_header,
-                        print_access_specifiers,
-                        integers_as_hexadecimal,
-                        ignore_std_types,
-                    );
-                    frontend_controller.send_command(FrontendCommand::ReconstructTypeResult(
-                        // Note: do not return any "xrefs from" when reconstructing all types
-                        reconstructed_type_result.map(|data| (data, vec![])),
+            // Note: annotations are loaded and stored sequentially, so that
+            // they're always stored in the order they were sent
+            #[cfg(not(target_arch = "wasm32"))]
+            BackendCommand::LoadAnnotations(pdb_slot) => {
+                if let Some(session) = sessions.get(&pdb_slot) {
+                    let load_result = session
+                        .pdb_file()
+                        .guid_and_age()
+                        .and_then(|(pdb_guid, pdb_age)| annotation_store.load(&pdb_guid, pdb_age));
+                    request_context.send_result(FrontendCommand::LoadAnnotationsResult(
+                        load_result.map(|annotations| (pdb_slot, annotations)),
                     ))?;
                 }
             }
 
-            BackendCommand::ListTypes(
-                pdb_slot,
-                search_filter,
-                case_insensitive_search,
-                use_regex,
-                ignore_std_types,
-            ) => {
-                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
-                    let filtered_type_list = update_type_filter_command(
-                        pdb_file,
-                        &search_filter,
-                        case_insensitive_search,
-                        use_regex,
-                        ignore_std_types,
-                        true,
-                    );
-                    frontend_controller
-                        .send_command(FrontendCommand::ListTypesResult(filtered_type_list))?;
+            #[cfg(not(target_arch = "wasm32"))]
+            BackendCommand::StoreAnnotations(pdb_slot, annotations) => {
+                if let Some(session) = sessions.get(&pdb_slot) {
+                    let store_result =
+                        session
+                            .pdb_file()
+                            .guid_and_age()
+                            .and_then(|(pdb_guid, pdb_age)| {
+                                annotation_store.store(&pdb_guid, pdb_age, &annotations)
+                            });
+                    if let Err(err) = store_result {
+                        log::error!("Failed to store annotations: {err}");
+                    }
                 }
             }
 
-            BackendCommand::ListTypesMerged(
-                pdb_slots,
-                search_filter,
-                case_insensitive_search,
-                use_regex,
-                ignore_std_types,
-            ) => {
-                let mut filtered_type_set = BTreeSet::default();
-                for pdb_slot in pdb_slots {
-                    if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
-                        let filtered_type_list = update_type_filter_command(
-                            pdb_file,
-                            &search_filter,
-                            case_insensitive_search,
-                            use_regex,
-                            ignore_std_types,
-                            false,
-                        );
-                        filtered_type_set.extend(filtered_type_list.into_iter().map(|(s, _)| {
-                            // Collapse all type indices to `default`. When merging
-                            // type lists, we can only count on type names to
-                            // represent the types.
-                            (s, Default::default())
-                        }));
-                    }
+            #[cfg(not(target_arch = "wasm32"))]
+            BackendCommand::LoadPEFromPath(pdb_slot, pe_file_path) => {
+                log::info!("Loading a PE file ...");
+                let load_result = PeFile::load_from_file(&pe_file_path)
+                    .and_then(|pe_file| load_pe_command(&sessions, pdb_slot, pe_file));
+                if load_result.is_ok() {
+                    log::info!("'{}' has been loaded successfully!", pe_file_path.display());
                 }
-                frontend_controller.send_command(FrontendCommand::ListTypesResult(
-                    filtered_type_set.into_iter().collect(),
-                ))?;
+                request_context.send_result(FrontendCommand::LoadPEResult(load_result))?;
             }
 
-            BackendCommand::ListSymbols(
-                pdb_slot,
-                search_filter,
-                case_insensitive_search,
-                use_regex,
-                ignore_std_types,
-            ) => {
-                if let Some(pdb_file) = pdb_files.get_mut(&pdb_slot) {
-                    let filtered_symbol_list = update_symbol_filter_command(
-                        pdb_file,
-                        &search_filter,
-                        case_insensitive_search,
-                        use_regex,
-                        ignore_std_types,
-                    );
-                    frontend_controller
-                        .send_command(FrontendCommand::ListSymbolsResult(filtered_symbol_list))?;
+            BackendCommand::LoadPEFromVec(pdb_slot, pe_name, pe_data) => {
+                log::info!("Loading a PE file ...");
+                let load_result = PeFile::load_from_bytes(pe_name.clone().into(), pe_data)
+                    .and_then(|pe_file| load_pe_command(&sessions, pdb_slot, pe_file));
+                if load_result.is_ok() {
+                    log::info!("'{}' has been loaded successfully!", pe_name);
                 }
+                request_context.send_result(FrontendCommand::LoadPEResult(load_result))?;
             }
 
-            BackendCommand::ListSymbolsMerged(
-                pdb_slots,
-                search_filter,
-                case_insensitive_search,
-                use_regex,
-                ignore_std_types,
-            ) => {
-                let mut filtered_symbol_set = BTreeSet::default();
-                for pdb_slot in pdb_slots {
-                    if let Some(pdb_file) = pdb_files.get_mut(&pdb_slot) {
-                        let filtered_symbol_list = update_symbol_filter_command(
-                            pdb_file,
-                            &search_filter,
-                            case_insensitive_search,
-                            use_regex,
-                            ignore_std_types,
-                        );
-                        filtered_symbol_set.extend(filtered_symbol_list.into_iter().map(
-                            |(s, _)| {
-                                // Collapse all type indices to `default`. When merging
-                                // type lists, we can only count on type names to
-                                // represent the types.
-                                (s, Default::default())
-                            },
-                        ));
+            BackendCommand::Cancel(_) => {
+                // Note: cancellation requests are handled by `Backend::send_command`
+            }
+
+            // Other commands don't modify the backend's state, process them
+            // concurrently
+            command => {
+                let sessions = sessions.clone();
+                spawn_if_available!(move || {
+                    if let Err(err) =
+                        process_read_only_command(command, &sessions, &request_context)
+                    {
+                        log::error!("Failed to send result to the frontend: {}", err);
                     }
-                }
-                frontend_controller.send_command(FrontendCommand::ListSymbolsResult(
-                    filtered_symbol_set.into_iter().collect(),
-                ))?;
+                });
             }
+        }
+    }
 
-            BackendCommand::ReconstructSymbolByIndex(
-                pdb_slot,
-                symbol_index,
-                primitives_flavor,
-                print_header,
-                print_access_specifiers,
-            ) => {
-                if let Some(pdb_file) = pdb_files.get_mut(&pdb_slot) {
-                    let result = reconstruct_symbol_by_index_command(
-                        pdb_file,
-                        symbol_index,
-                        primitives_flavor,
-                        print_header,
-                        print_access_specifiers,
-                    );
-                    frontend_controller
-                        .send_command(FrontendCommand::ReconstructSymbolResult(result))?;
-                }
+    Ok(())
+}
+
+/// Process commands that don't modify the backend's state. Those can be
+/// processed concurrently.
+fn process_read_only_command(
+    command: BackendCommand,
+    sessions: &HashMap<PDBSlot, Arc<Session>>,
+    request_context: &RequestContext<impl FrontendController>,
+) -> Result<()> {
+    // Skip requests cancelled or superseded while waiting to be processed
+    if request_context.progress.is_cancelled() {
+        return Ok(());
+    }
+
+    match command {
+        BackendCommand::ReconstructTypeByIndex(pdb_slot, type_index, options) => {
+            if let Some(session) = sessions.get(&pdb_slot) {
+                let reconstructed_type_result =
+                    session.reconstruct_type_by_index(type_index, &options);
+                request_context.send_result(FrontendCommand::ReconstructTypeResult(
+                    reconstructed_type_result,
+                ))?;
             }
+        }
 
-            BackendCommand::ReconstructSymbolByName(
-                pdb_slot,
-                symbol_name,
-                primitives_flavor,
-                print_header,
-                print_access_specifiers,
-            ) => {
-                if let Some(pdb_file) = pdb_files.get_mut(&pdb_slot) {
-                    let result = reconstruct_symbol_by_name_command(
-                        pdb_file,
-                        symbol_name,
-                        primitives_flavor,
-                        print_header,
-                        print_access_specifiers,
-                    );
-                    frontend_controller
-                        .send_command(FrontendCommand::ReconstructSymbolResult(result))?;
-                }
+        BackendCommand::ReconstructTypeByName(pdb_slot, type_name, options) => {
+            if let Some(session) = sessions.get(&pdb_slot) {
+                let reconstructed_type_result =
+                    session.reconstruct_type_by_name(&type_name, &options);
+                request_context.send_result(FrontendCommand::ReconstructTypeResult(
+                    reconstructed_type_result,
+                ))?;
             }
+        }
 
-            BackendCommand::ReconstructAllSymbols(
-                pdb_slot,
-                primitives_flavor,
-                print_header,
-                print_access_specifiers,
-            ) => {
-                if let Some(pdb_file) = pdb_files.get_mut(&pdb_slot) {
-                    let result = reconstruct_all_symbols_command(
-                        pdb_file,
-                        primitives_flavor,
-                        print_header,
-                        print_access_specifiers,
-                    );
-                    frontend_controller
-                        .send_command(FrontendCommand::ReconstructSymbolResult(result))?;
-                }
+        BackendCommand::ReconstructAllTypes(pdb_slot, options) => {
+            if let Some(session) = sessions.get(&pdb_slot) {
+                let reconstructed_type_result = session
+                    .reconstruct_all_types_with_progress(&options, &request_context.progress);
+                request_context.send_result(FrontendCommand::ReconstructTypeResult(
+                    // Note: do not return any "xrefs from" when reconstructing all types
+                    reconstructed_type_result.map(|data| (data, vec![])),
+                ))?;
             }
+        }
 
-            BackendCommand::DiffSymbolByName(
-                pdb_from_slot,
-                pdb_to_slot,
-                symbol_name,
-                primitives_flavor,
-                print_header,
-                print_access_specifiers,
-            ) => {
-                if let Some(pdb_file_from) = pdb_files.get(&pdb_from_slot) {
-                    if let Some(pdb_file_to) = pdb_files.get(&pdb_to_slot) {
-                        let symbol_diff_result = diff_symbol_by_name(
-                            pdb_file_from,
-                            pdb_file_to,
-                            &symbol_name,
-                            primitives_flavor,
-                            print_header,
-                            print_access_specifiers,
-                        );
-                        frontend_controller
-                            .send_command(FrontendCommand::DiffResult(symbol_diff_result))?;
-                    }
-                }
+        BackendCommand::ListTypes(pdb_slot, search_filter, options) => {
+            if let Some(session) = sessions.get(&pdb_slot) {
+                let filtered_type_list = session.list_types(&search_filter, &options);
+                request_context
+                    .send_result(FrontendCommand::ListTypesResult(filtered_type_list))?;
             }
+        }
 
-            BackendCommand::ReconstructModuleByIndex(
-                pdb_slot,
-                module_index,
-                primitives_flavor,
-                print_header,
-                print_access_specifiers,
-            ) => {
-                if let Some(pdb_file) = pdb_files.get_mut(&pdb_slot) {
-                    let reconstructed_module_result = reconstruct_module_by_index_command(
-                        pdb_file,
-                        module_index,
-                        primitives_flavor,
-                        false,
-                        print_header,
-                        print_access_specifiers,
-                    );
-                    frontend_controller.send_command(FrontendCommand::ReconstructModuleResult(
-                        reconstructed_module_result,
-                    ))?;
+        BackendCommand::ListTypesMerged(pdb_slots, search_filter, options) => {
+            let mut filtered_type_set = BTreeSet::default();
+            for pdb_slot in pdb_slots {
+                if let Some(session) = sessions.get(&pdb_slot) {
+                    let filtered_type_list = session.filter_types(&search_filter, &options, false);
+                    filtered_type_set.extend(filtered_type_list.into_iter().map(|(s, _)| {
+                        // Collapse all type indices to `default`. When merging
+                        // type lists, we can only count on type names to
+                        // represent the types.
+                        (s, Default::default())
+                    }));
                 }
             }
+            request_context.send_result(FrontendCommand::ListTypesResult(
+                filtered_type_set.into_iter().collect(),
+            ))?;
+        }
 
-            BackendCommand::ListModules(
-                pdb_slot,
-                search_filter,
-                case_insensitive_search,
-                use_regex,
-            ) => {
-                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
-                    let module_list = list_modules_command(
-                        pdb_file,
+        BackendCommand::DiffTypeList(
+            pdb_from_slot,
+            pdb_to_slot,
+            search_filter,
+            filter_options,
+            options,
+        ) => {
+            if let Some(session_from) = sessions.get(&pdb_from_slot) {
+                if let Some(session_to) = sessions.get(&pdb_to_slot) {
+                    let type_diff_list_result = session_from.diff_type_list_with_progress(
+                        session_to,
                         &search_filter,
-                        case_insensitive_search,
-                        use_regex,
+                        &filter_options,
+                        &options,
+                        &request_context.progress,
                     );
-                    frontend_controller
-                        .send_command(FrontendCommand::ListModulesResult(module_list))?;
+                    request_context
+                        .send_result(FrontendCommand::DiffTypeListResult(type_diff_list_result))?;
                 }
             }
+        }
 
-            BackendCommand::DiffTypeByName(
-                pdb_from_slot,
-                pdb_to_slot,
-                type_name,
-                primitives_flavor,
-                print_header,
-                reconstruct_dependencies,
-                print_access_specifiers,
-                integers_as_hexadecimal,
-                ignore_std_types,
-            ) => {
-                if let Some(pdb_file_from) = pdb_files.get(&pdb_from_slot) {
-                    if let Some(pdb_file_to) = pdb_files.get(&pdb_to_slot) {
-                        let type_diff_result = diff_type_by_name(
-                            pdb_file_from,
-                            pdb_file_to,
-                            &type_name,
-                            primitives_flavor,
-                            print_header,
-                            reconstruct_dependencies,
-                            print_access_specifiers,
-                            integers_as_hexadecimal,
-                            ignore_std_types,
-                        );
-                        frontend_controller
-                            .send_command(FrontendCommand::DiffResult(type_diff_result))?;
-                    }
-                }
+        BackendCommand::ListSymbols(pdb_slot, search_filter, options) => {
+            if let Some(session) = sessions.get(&pdb_slot) {
+                let filtered_symbol_list = session.list_symbols_with_progress(
+                    &search_filter,
+                    &options,
+                    &request_context.progress,
+                );
+                request_context
+                    .send_result(FrontendCommand::ListSymbolsResult(filtered_symbol_list))?;
             }
+        }
 
-            BackendCommand::DiffModuleByPath(
-                pdb_from_slot,
-                pdb_to_slot,
-                module_path,
-                primitives_flavor,
-                print_header,
-                print_access_specifiers,
-            ) => {
-                if let Some(pdb_file_from) = pdb_files.get(&pdb_from_slot) {
-                    if let Some(pdb_file_to) = pdb_files.get(&pdb_to_slot) {
-                        let module_diff_result = diff_module_by_path(
-                            pdb_file_from,
-                            pdb_file_to,
-                            &module_path,
-                            primitives_flavor,
-                            print_header,
-                            print_access_specifiers,
-                        );
-                        frontend_controller
-                            .send_command(FrontendCommand::DiffResult(module_diff_result))?;
-                    }
+        BackendCommand::ListSymbolsMerged(pdb_slots, search_filter, options) => {
+            let mut filtered_symbol_set = BTreeSet::default();
+            for pdb_slot in pdb_slots {
+                if let Some(session) = sessions.get(&pdb_slot) {
+                    let filtered_symbol_list = session.list_symbols_with_progress(
+                        &search_filter,
+                        &options,
+                        &request_context.progress,
+                    );
+                    filtered_symbol_set.extend(filtered_symbol_list.into_iter().map(|(s, _)| {
+                        // Collapse all type indices to `default`. When merging
+                        // type lists, we can only count on type names to
+                        // represent the types.
+                        (s, Default::default())
+                    }));
                 }
             }
+            request_context.send_result(FrontendCommand::ListSymbolsResult(
+                filtered_symbol_set.into_iter().collect(),
+            ))?;
+        }
 
-            BackendCommand::ListTypeCrossReferences(pdb_slot, type_index) => {
-                if let Some(pdb_file) = pdb_files.get(&pdb_slot) {
-                    let xref_list = list_type_xrefs_command(pdb_file, type_index);
-                    frontend_controller
-                        .send_command(FrontendCommand::ListTypeCrossReferencesResult(xref_list))?;
-                }
+        BackendCommand::ReconstructSymbolByIndex(pdb_slot, symbol_index, options) => {
+            if let Some(session) = sessions.get(&pdb_slot) {
+                let result = session.reconstruct_symbol_by_index(symbol_index, &options);
+                request_context.send_result(FrontendCommand::ReconstructSymbolResult(result))?;
             }
         }
-    }
 
-    Ok(())
-}
-
-fn reconstruct_type_by_index_command<'p, T>(
-    pdb_file: &PdbFile<'p, T>,
-    type_index: pdb_file::TypeIndex,
-    primitives_flavor: PrimitiveReconstructionFlavor,
-    print_header: bool,
-    reconstruct_dependencies: bool,
-    print_access_specifiers: bool,
-    integers_as_hexadecimal: bool,
-    ignore_std_types: bool,
-) -> Result<ReconstructedType>
-where
-    T: io::Seek + io::Read + std::fmt::Debug + 'p,
-{
-    let (data, xrefs_from) = pdb_file.reconstruct_type_by_index(
-        type_index,
-        primitives_flavor,
-        reconstruct_dependencies,
-        print_access_specifiers,
-        integers_as_hexadecimal,
-        ignore_std_types,
-    )?;
-    if print_header {
-        let file_header = generate_file_header(pdb_file, primitives_flavor, true, ignore_std_types);
-        Ok((format!("{file_header}{data}"), xrefs_from))
-    } else {
-        Ok((data, xrefs_from))
-    }
-}
-
-fn reconstruct_type_by_name_command<'p, T>(
-    pdb_file: &PdbFile<'p, T>,
-    type_name: &str,
-    primitives_flavor: PrimitiveReconstructionFlavor,
-    print_header: bool,
-    reconstruct_dependencies: bool,
-    print_access_specifiers: bool,
-    integers_as_hexadecimal: bool,
-    ignore_std_types: bool,
-) -> Result<ReconstructedType>
-where
-    T: io::Seek + io::Read + std::fmt::Debug + 'p,
-{
-    let (data, xrefs_from) = pdb_file.reconstruct_type_by_name(
-        type_name,
-        primitives_flavor,
-        reconstruct_dependencies,
-        print_access_specifiers,
-        integers_as_hexadecimal,
-        ignore_std_types,
-    )?;
-    if print_header {
-        let file_header = generate_file_header(pdb_file, primitives_flavor, true, ignore_std_types);
-        Ok((format!("{file_header}{data}"), xrefs_from))
-    } else {
-        Ok((data, xrefs_from))
-    }
-}
+        BackendCommand::ReconstructSymbolByName(pdb_slot, symbol_name, options) => {
+            if let Some(session) = sessions.get(&pdb_slot) {
+                let result = session.reconstruct_symbol_by_name(&symbol_name, &options);
+                request_context.send_result(FrontendCommand::ReconstructSymbolResult(result))?;
+            }
+        }
 
-fn reconstruct_all_types_command<'p, T>(
-    pdb_file: &PdbFile<'p, T>,
-    primitives_flavor: PrimitiveReconstructionFlavor,
-    print_header: bool,
-    print_access_specifiers: bool,
-    integers_as_hexadecimal: bool,
-    ignore_std_types: bool,
-) -> Result<String>
-where
-    T: io::Seek + io::Read + std::fmt::Debug + 'p,
-{
-    let data = pdb_file.reconstruct_all_types(
-        primitives_flavor,
-        print_access_specifiers,
-        integers_as_hexadecimal,
-        ignore_std_types,
-    )?;
-    if print_header {
-        let file_header = generate_file_header(pdb_file, primitives_flavor, true, ignore_std_types);
-        Ok(format!("{file_header}{data}"))
-    } else {
-        Ok(data)
-    }
-}
+        BackendCommand::ReconstructAllSymbols(pdb_slot, options) => {
+            if let Some(session) = sessions.get(&pdb_slot) {
+                let result = session
+                    .reconstruct_all_symbols_with_progress(&options, &request_context.progress);
+                request_context.send_result(FrontendCommand::ReconstructSymbolResult(result))?;
+            }
+        }
 
-fn reconstruct_symbol_by_index_command<'p, T>(
-    pdb_file: &mut PdbFile<'p, T>,
-    symbol_index: pdb_file::SymbolIndex,
-    primitives_flavor: PrimitiveReconstructionFlavor,
-    print_header: bool,
-    print_access_specifiers: bool,
-) -> Result<String>
-where
-    T: io::Seek + io::Read + std::fmt::Debug + 'p,
-{
-    let data = pdb_file.reconstruct_symbol_by_index(
-        symbol_index,
-        primitives_flavor,
-        print_access_specifiers,
-    )?;
-    if print_header {
-        let file_header = generate_file_header(pdb_file, primitives_flavor, true, false);
-        Ok(format!("{file_header}\n{data}"))
-    } else {
-        Ok(data)
-    }
-}
+        BackendCommand::DiffSymbolByName(pdb_from_slot, pdb_to_slot, symbol_name, options) => {
+            if let Some(session_from) = sessions.get(&pdb_from_slot) {
+                if let Some(session_to) = sessions.get(&pdb_to_slot) {
+                    let symbol_diff_result =
+                        session_from.diff_symbol_by_name(session_to, &symbol_name, &options);
+                    request_context.send_result(FrontendCommand::DiffResult(symbol_diff_result))?;
+                }
+            }
+        }
 
-fn reconstruct_symbol_by_name_command<'p, T>(
-    pdb_file: &mut PdbFile<'p, T>,
-    symbol_name: String,
-    primitives_flavor: PrimitiveReconstructionFlavor,
-    print_header: bool,
-    print_access_specifiers: bool,
-) -> Result<String>
-where
-    T: io::Seek + io::Read + std::fmt::Debug + 'p,
-{
-    let data = pdb_file.reconstruct_symbol_by_name(
-        &symbol_name,
-        primitives_flavor,
-        print_access_specifiers,
-    )?;
-    if print_header {
-        let file_header = generate_file_header(pdb_file, primitives_flavor, true, false);
-        Ok(format!("{file_header}\n{data}"))
-    } else {
-        Ok(data)
-    }
-}
+        BackendCommand::ReconstructModuleByIndex(pdb_slot, module_index, options) => {
+            if let Some(session) = sessions.get(&pdb_slot) {
+                let reconstructed_module_result =
+                    session.reconstruct_module_by_index(module_index, &options);
+                request_context.send_result(FrontendCommand::ReconstructModuleResult(
+                    reconstructed_module_result,
+                ))?;
+            }
+        }
 
-fn reconstruct_all_symbols_command<'p, T>(
-    pdb_file: &PdbFile<'p, T>,
-    primitives_flavor: PrimitiveReconstructionFlavor,
-    print_header: bool,
-    print_access_specifiers: bool,
-) -> Result<String>
-where
-    T: io::Seek + io::Read + std::fmt::Debug + 'p,
-{
-    let data = pdb_file.reconstruct_all_symbols(primitives_flavor, print_access_specifiers)?;
-    if print_header {
-        let file_header = generate_file_header(pdb_file, primitives_flavor, true, false);
-        Ok(format!("{file_header}{data}"))
-    } else {
-        Ok(data)
-    }
-}
+        BackendCommand::ListModules(pdb_slot, search_filter, options) => {
+            if let Some(session) = sessions.get(&pdb_slot) {
+                let module_list = session.list_modules(&search_filter, &options);
+                request_context.send_result(FrontendCommand::ListModulesResult(module_list))?;
+            }
+        }
 
-fn reconstruct_module_by_index_command<'p, T>(
-    pdb_file: &mut PdbFile<'p, T>,
-    module_index: pdb_file::ModuleIndex,
-    primitives_flavor: PrimitiveReconstructionFlavor,
-    ignore_std_types: bool,
-    print_header: bool,
-    print_access_specifiers: bool,
-) -> Result<String>
-where
-    T: io::Seek + io::Read + std::fmt::Debug + 'p,
-{
-    let data = pdb_file.reconstruct_module_by_index(
-        module_index,
-        primitives_flavor,
-        print_access_specifiers,
-    )?;
-    if print_header {
-        let file_header = generate_file_header(pdb_file, primitives_flavor, true, ignore_std_types);
-        Ok(format!("{file_header}\n{data}"))
-    } else {
-        Ok(data)
-    }
-}
+        BackendCommand::DiffTypeByName(pdb_from_slot, pdb_to_slot, type_name, options) => {
+            if let Some(session_from) = sessions.get(&pdb_from_slot) {
+                if let Some(session_to) = sessions.get(&pdb_to_slot) {
+                    let type_diff_result =
+                        session_from.diff_type_by_name(session_to, &type_name, &options);
+                    request_context.send_result(FrontendCommand::DiffResult(type_diff_result))?;
+                }
+            }
+        }
 
-fn generate_file_header<T>(
-    pdb_file: &PdbFile<T>,
-    primitives_flavor: PrimitiveReconstructionFlavor,
-    include_header_files: bool,
-    ignore_std_types: bool,
-) -> String
-where
-    T: io::Seek + io::Read,
-{
-    format!(
-        concat!(
-            "//\n",
-            "// Information extracted with resym v{}\n",
-            "//\n",
-            "// PDB file: {}\n",
-            "// Image architecture: {}\n",
-            "//\n",
-            "{}"
-        ),
-        PKG_VERSION,
-        pdb_file.file_path.display(),
-        pdb_file.machine_type,
-        if include_header_files {
-            format!(
-                "\n{}",
-                include_headers_for_flavor(primitives_flavor, ignore_std_types)
-            )
-        } else {
-            "".to_string()
+        BackendCommand::DiffModuleByPath(pdb_from_slot, pdb_to_slot, module_path, options) => {
+            if let Some(session_from) = sessions.get(&pdb_from_slot) {
+                if let Some(session_to) = sessions.get(&pdb_to_slot) {
+                    let module_diff_result =
+                        session_from.diff_module_by_path(session_to, &module_path, &options);
+                    request_context.send_result(FrontendCommand::DiffResult(module_diff_result))?;
+                }
+            }
         }
-    )
-}
 
-fn update_type_filter_command<T>(
-    pdb_file: &PdbFile<T>,
-    search_filter: &str,
-    case_insensitive_search: bool,
-    use_regex: bool,
-    ignore_std_types: bool,
-    sort_by_index: bool,
-) -> TypeList
-where
-    T: io::Seek + io::Read,
-{
-    let filter_start = Instant::now();
-
-    // Filter out std types if needed
-    let filtered_type_list = if ignore_std_types {
-        filter_std_types(&pdb_file.complete_type_list)
-    } else {
-        pdb_file.complete_type_list.clone()
-    };
-
-    // Filter types following the search filter
-    let mut filtered_type_list = if search_filter.is_empty() {
-        // No need to filter
-        filtered_type_list
-    } else if use_regex {
-        filter_types_regex(&filtered_type_list, search_filter, case_insensitive_search)
-    } else {
-        filter_types_regular(&filtered_type_list, search_filter, case_insensitive_search)
-    };
-    if sort_by_index {
-        // Order types by type index, so the order is deterministic
-        // (i.e., independent from DashMap's hash function)
-        par_sort_by_if_available!(filtered_type_list, |lhs, rhs| lhs.1.cmp(&rhs.1));
-    }
+        BackendCommand::ListTypeCrossReferences(pdb_slot, type_index) => {
+            if let Some(session) = sessions.get(&pdb_slot) {
+                let xref_list =
+                    session.list_type_xrefs_with_progress(type_index, &request_context.progress);
+                request_context
+                    .send_result(FrontendCommand::ListTypeCrossReferencesResult(xref_list))?;
+            }
+        }
 
-    log::debug!(
-        "Type filtering took {} ms",
-        filter_start.elapsed().as_millis()
-    );
+        BackendCommand::ListTypeDetails(pdb_slot, type_list) => {
+            if let Some(session) = sessions.get(&pdb_slot) {
+                let type_details = session.type_details(&type_list);
+                request_context
+                    .send_result(FrontendCommand::ListTypeDetailsResult(type_details))?;
+            }
+        }
 
-    filtered_type_list
-}
+        BackendCommand::ComputeTypeLayout(pdb_slot, type_index) => {
+            if let Some(session) = sessions.get(&pdb_slot) {
+                let type_layout = session.type_layout(type_index);
+                request_context.send_result(FrontendCommand::TypeLayoutResult(type_layout))?;
+            }
+        }
 
-/// Filter type list with a regular expression
-fn filter_types_regex(
-    type_list: &[(String, u32)],
-    search_filter: &str,
-    case_insensitive_search: bool,
-) -> TypeList {
-    match regex::RegexBuilder::new(search_filter)
-        .case_insensitive(case_insensitive_search)
-        .build()
-    {
-        // In case of error, return an empty result
-        Err(_) => vec![],
-        Ok(regex) => par_iter_if_available!(type_list)
-            .filter(|r| regex.find(&r.0).is_some())
-            .cloned()
-            .collect(),
-    }
-}
+        BackendCommand::ComputeSizeReport(pdb_slot, grouping) => {
+            if let Some(session) = sessions.get(&pdb_slot) {
+                let size_report = session.size_report(grouping);
+                request_context.send_result(FrontendCommand::SizeReportResult(size_report))?;
+            }
+        }
 
-/// Filter type list with a plain (sub-)string
-fn filter_types_regular(
-    type_list: &[(String, u32)],
-    search_filter: &str,
-    case_insensitive_search: bool,
-) -> TypeList {
-    if case_insensitive_search {
-        let search_filter = search_filter.to_lowercase();
-        par_iter_if_available!(type_list)
-            .filter(|r| r.0.to_lowercase().contains(&search_filter))
-            .cloned()
-            .collect()
-    } else {
-        par_iter_if_available!(type_list)
-            .filter(|r| r.0.contains(search_filter))
-            .cloned()
-            .collect()
-    }
-}
+        BackendCommand::ListInlineSites(pdb_slot, function_name) => {
+            if let Some(session) = sessions.get(&pdb_slot) {
+                let inline_site_list = session.list_inline_sites(&function_name);
+                request_context
+                    .send_result(FrontendCommand::ListInlineSitesResult(inline_site_list))?;
+            }
+        }
 
-/// Filter type list to remove types in the `std` namespace
-fn filter_std_types(type_list: &[(String, pdb_file::TypeIndex)]) -> TypeList {
-    par_iter_if_available!(type_list)
-        .filter(|r| !r.0.starts_with("std::"))
-        .cloned()
-        .collect()
-}
+        BackendCommand::QueryTypeGraph(pdb_slot, query) => {
+            if let Some(session) = sessions.get(&pdb_slot) {
+                let query_result =
+                    session.query_type_graph_with_progress(&query, &request_context.progress);
+                request_context.send_result(FrontendCommand::TypeGraphQueryResult(query_result))?;
+            }
+        }
 
-fn update_symbol_filter_command<T>(
-    pdb_file: &mut PdbFile<T>,
-    search_filter: &str,
-    case_insensitive_search: bool,
-    use_regex: bool,
-    ignore_std_symbols: bool,
-) -> SymbolList
-where
-    T: io::Seek + io::Read + fmt::Debug,
-{
-    let filter_start = Instant::now();
-
-    match pdb_file.symbol_list() {
-        Err(_) => SymbolList::default(),
-        Ok(symbol_list) => {
-            // Filter out std types if needed
-            let filtered_symbol_list = if ignore_std_symbols {
-                filter_std_symbols(&symbol_list)
-            } else {
-                symbol_list
-            };
-
-            let filtered_symbol_list = if search_filter.is_empty() {
-                // No need to filter
-                filtered_symbol_list
-            } else if use_regex {
-                filter_symbols_regex(
-                    &filtered_symbol_list,
-                    search_filter,
-                    case_insensitive_search,
-                )
-            } else {
-                filter_symbols_regular(
-                    &filtered_symbol_list,
-                    search_filter,
-                    case_insensitive_search,
-                )
-            };
-
-            log::debug!(
-                "Symbol filtering took {} ms",
-                filter_start.elapsed().as_millis()
-            );
+        BackendCommand::ExportTypeGraph(pdb_slot, type_name, options) => {
+            if let Some(session) = sessions.get(&pdb_slot) {
+                let export_result = session.export_type_graph_with_progress(
+                    &type_name,
+                    &options,
+                    &request_context.progress,
+                );
+                request_context
+                    .send_result(FrontendCommand::TypeGraphExportResult(export_result))?;
+            }
+        }
 
-            // Convert refs to symbol info into clones and return it
-            par_iter_if_available!(filtered_symbol_list)
-                .map(|e| (e.0.clone(), e.1))
-                .collect()
+        BackendCommand::ListClassHierarchy(pdb_slot, type_name) => {
+            if let Some(session) = sessions.get(&pdb_slot) {
+                let class_hierarchy =
+                    session.class_hierarchy_with_progress(&type_name, &request_context.progress);
+                request_context
+                    .send_result(FrontendCommand::ClassHierarchyResult(class_hierarchy))?;
+            }
         }
-    }
-}
 
-/// Filter symbol list to remove types in the `std` namespace
-fn filter_std_symbols<'s>(
-    symbol_list: &'s [&(String, pdb_file::SymbolIndex)],
-) -> SymbolListView<'s> {
-    par_iter_if_available!(symbol_list)
-        .filter(|r| !r.0.starts_with("std::"))
-        .cloned()
-        .collect()
-}
+        BackendCommand::FindFields(pdb_slot, query, filter_options) => {
+            if let Some(session) = sessions.get(&pdb_slot) {
+                let field_list = session.find_fields_with_progress(
+                    &query,
+                    &filter_options,
+                    &request_context.progress,
+                );
+                request_context.send_result(FrontendCommand::FindFieldsResult(field_list))?;
+            }
+        }
 
-/// Filter type list with a regular expression
-fn filter_symbols_regex<'s>(
-    symbol_list: &'s [&(String, pdb_file::SymbolIndex)],
-    search_filter: &str,
-    case_insensitive_search: bool,
-) -> SymbolListView<'s> {
-    match regex::RegexBuilder::new(search_filter)
-        .case_insensitive(case_insensitive_search)
-        .build()
-    {
-        // In case of error, return an empty result
-        Err(_) => vec![],
-        Ok(regex) => par_iter_if_available!(symbol_list)
-            .filter(|r| regex.find(&r.0).is_some())
-            .cloned()
-            .collect(),
-    }
-}
+        BackendCommand::MatchLayout(pdb_slot, query, max_results) => {
+            if let Some(session) = sessions.get(&pdb_slot) {
+                let layout_match_list = session.match_layout_with_progress(
+                    &query,
+                    max_results,
+                    &request_context.progress,
+                );
+                request_context
+                    .send_result(FrontendCommand::MatchLayoutResult(layout_match_list))?;
+            }
+        }
 
-/// Filter type list with a plain (sub-)string
-fn filter_symbols_regular<'s>(
-    symbol_list: &'s [&(String, pdb_file::SymbolIndex)],
-    search_filter: &str,
-    case_insensitive_search: bool,
-) -> SymbolListView<'s> {
-    if case_insensitive_search {
-        let search_filter = search_filter.to_lowercase();
-        par_iter_if_available!(symbol_list)
-            .filter(|r| r.0.to_lowercase().contains(&search_filter))
-            .cloned()
-            .collect()
-    } else {
-        par_iter_if_available!(symbol_list)
-            .filter(|r| r.0.contains(search_filter))
-            .cloned()
-            .collect()
+        _ => {
+            log::error!("Unexpected command received");
+        }
     }
-}
 
-fn list_modules_command<'p, T>(
-    pdb_file: &PdbFile<'p, T>,
-    search_filter: &str,
-    case_insensitive_search: bool,
-    use_regex: bool,
-) -> Result<ModuleList>
-where
-    T: io::Seek + io::Read + std::fmt::Debug + 'p,
-{
-    let filter_start = Instant::now();
-
-    let filtered_module_list = if search_filter.is_empty() {
-        // No need to filter
-        pdb_file.module_list()?
-    } else if use_regex {
-        filter_modules_regex(
-            &pdb_file.module_list()?,
-            search_filter,
-            case_insensitive_search,
-        )
-    } else {
-        filter_modules_regular(
-            &pdb_file.module_list()?,
-            search_filter,
-            case_insensitive_search,
-        )
-    };
-
-    log::debug!(
-        "Module filtering took {} ms",
-        filter_start.elapsed().as_millis()
-    );
-
-    Ok(filtered_module_list)
+    Ok(())
 }
 
-/// Filter module list with a regular expression
-fn filter_modules_regex(
-    module_list: &[(String, usize)],
-    search_filter: &str,
-    case_insensitive_search: bool,
-) -> Vec<(String, usize)> {
-    match regex::RegexBuilder::new(search_filter)
-        .case_insensitive(case_insensitive_search)
-        .build()
-    {
-        // In case of error, return an empty result
-        Err(_) => vec![],
-        Ok(regex) => par_iter_if_available!(module_list)
-            .filter(|r| regex.find(&r.0).is_some())
-            .cloned()
-            .collect(),
+/// Session loaded in the background, along with the request it was loaded
+/// for, the slot it should be loaded into and the path it was loaded from
+type LoadedSession<F> = (RequestContext<F>, PDBSlot, PathBuf, Result<Session>);
+
+/// Send the result of loading a session to the frontend and, if it has been
+/// loaded successfully, replace the session loaded in the given slot
+fn install_loaded_session(
+    sessions: &mut HashMap<PDBSlot, Arc<Session>>,
+    request_context: &RequestContext<impl FrontendController>,
+    pdb_slot: PDBSlot,
+    pdb_file_path: &Path,
+    load_result: Result<Session>,
+) -> Result<()> {
+    match load_result {
+        Err(err) => request_context.send_result(FrontendCommand::LoadPDBResult(Err(err)))?,
+        Ok(loaded_session) => {
+            request_context.send_result(FrontendCommand::LoadPDBResult(Ok(pdb_slot)))?;
+            if let Some(session) = sessions.insert(pdb_slot, Arc::new(loaded_session)) {
+                log::info!(
+                    "'{}' has been unloaded.",
+                    session.pdb_file().file_path.display()
+                );
+            }
+            log::info!(
+                "'{}' has been loaded successfully!",
+                pdb_file_path.display()
+            );
+        }
     }
-}
 
-/// Filter module list with a plain (sub-)string
-fn filter_modules_regular(
-    module_list: &[(String, usize)],
-    search_filter: &str,
-    case_insensitive_search: bool,
-) -> Vec<(String, usize)> {
-    if case_insensitive_search {
-        let search_filter = search_filter.to_lowercase();
-        par_iter_if_available!(module_list)
-            .filter(|r| r.0.to_lowercase().contains(&search_filter))
-            .cloned()
-            .collect()
-    } else {
-        par_iter_if_available!(module_list)
-            .filter(|r| r.0.contains(search_filter))
-            .cloned()
-            .collect()
-    }
+    Ok(())
 }
 
-fn list_type_xrefs_command<'p, T>(
-    pdb_file: &PdbFile<'p, T>,
-    type_index: pdb_file::TypeIndex,
-) -> Result<TypeList>
-where
-    T: io::Seek + io::Read + std::fmt::Debug + 'p,
-{
-    let xref_start = Instant::now();
-    let xref_list = pdb_file.get_xrefs_for_type(type_index)?;
-    log::debug!(
-        "Xref resolution took {} ms",
-        xref_start.elapsed().as_millis()
-    );
-
-    Ok(xref_list)
+fn load_pe_command(
+    sessions: &HashMap<PDBSlot, Arc<Session>>,
+    pdb_slot: PDBSlot,
+    pe_file: PeFile,
+) -> Result<PDBSlot> {
+    let session = sessions.get(&pdb_slot).ok_or_else(|| {
+        ResymCoreError::InvalidParameterError(format!("no PDB loaded in slot {pdb_slot}"))
+    })?;
+    session.load_pe_file(pe_file)?;
+
+    Ok(pdb_slot)
 }
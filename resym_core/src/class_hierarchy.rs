@@ -0,0 +1,158 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crate::pdb_file::TypeIndex;
+
+/// Inheritance relationship with a base or derived class
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InheritanceEdge {
+    pub type_index: TypeIndex,
+    /// `true` for virtual inheritance
+    pub is_virtual: bool,
+}
+
+/// Class in a tree of base or derived classes
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClassHierarchyNode {
+    pub type_name: String,
+    pub type_index: TypeIndex,
+    /// `true` for virtual inheritance
+    pub is_virtual: bool,
+    /// Base classes (in ancestor trees) or derived classes (in descendant
+    /// trees) of this class
+    pub children: Vec<ClassHierarchyNode>,
+}
+
+/// Hierarchy of a given class: its base classes and the classes that derive
+/// from it, transitively
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClassHierarchyTree {
+    pub type_name: String,
+    pub type_index: TypeIndex,
+    pub base_classes: Vec<ClassHierarchyNode>,
+    pub derived_classes: Vec<ClassHierarchyNode>,
+}
+
+/// Index of the inheritance relationships between complete types
+#[derive(Debug, Default)]
+pub struct ClassHierarchy {
+    base_classes: BTreeMap<TypeIndex, Vec<InheritanceEdge>>,
+    derived_classes: BTreeMap<TypeIndex, Vec<InheritanceEdge>>,
+}
+
+impl ClassHierarchy {
+    /// Create an index from a list of `(derived, base, is_virtual)`
+    /// relationships. Base classes are expected in declaration order.
+    pub fn from_edges(edges: impl IntoIterator<Item = (TypeIndex, TypeIndex, bool)>) -> Self {
+        let mut hierarchy = Self::default();
+        for (derived, base, is_virtual) in edges {
+            let base_classes = hierarchy.base_classes.entry(derived).or_default();
+            if base_classes.iter().any(|edge| edge.type_index == base) {
+                continue;
+            }
+            base_classes.push(InheritanceEdge {
+                type_index: base,
+                is_virtual,
+            });
+            hierarchy
+                .derived_classes
+                .entry(base)
+                .or_default()
+                .push(InheritanceEdge {
+                    type_index: derived,
+                    is_virtual,
+                });
+        }
+        // Sort derived classes so that traversals are deterministic
+        for edges in hierarchy.derived_classes.values_mut() {
+            edges.sort_unstable_by_key(|edge| edge.type_index);
+        }
+
+        hierarchy
+    }
+
+    /// Direct base classes of the given class, in declaration order
+    pub fn base_classes(&self, type_index: TypeIndex) -> &[InheritanceEdge] {
+        self.base_classes
+            .get(&type_index)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Classes that directly derive from the given class
+    pub fn derived_classes(&self, type_index: TypeIndex) -> &[InheritanceEdge] {
+        self.derived_classes
+            .get(&type_index)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// All `(derived, base)` relationships
+    pub fn edges(&self) -> impl Iterator<Item = (TypeIndex, InheritanceEdge)> + '_ {
+        self.base_classes
+            .iter()
+            .flat_map(|(derived, edges)| edges.iter().map(|edge| (*derived, *edge)))
+    }
+
+    /// Base and derived classes of the given class, as trees
+    pub fn tree(
+        &self,
+        type_index: TypeIndex,
+        type_name: impl Fn(TypeIndex) -> String,
+    ) -> ClassHierarchyTree {
+        let mut visited_types = HashSet::from([type_index]);
+        let base_classes = build_subtrees(
+            &self.base_classes,
+            type_index,
+            &type_name,
+            &mut visited_types,
+        );
+        let mut visited_types = HashSet::from([type_index]);
+        let derived_classes = build_subtrees(
+            &self.derived_classes,
+            type_index,
+            &type_name,
+            &mut visited_types,
+        );
+
+        ClassHierarchyTree {
+            type_name: type_name(type_index),
+            type_index,
+            base_classes,
+            derived_classes,
+        }
+    }
+}
+
+fn build_subtrees(
+    adjacency_map: &BTreeMap<TypeIndex, Vec<InheritanceEdge>>,
+    type_index: TypeIndex,
+    type_name: &impl Fn(TypeIndex) -> String,
+    visited_types: &mut HashSet<TypeIndex>,
+) -> Vec<ClassHierarchyNode> {
+    let edges = adjacency_map
+        .get(&type_index)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    edges
+        .iter()
+        .map(|edge| {
+            // Note: classes can appear several times (e.g., with diamond
+            // inheritance), but never below themselves
+            let children = if visited_types.insert(edge.type_index) {
+                let children =
+                    build_subtrees(adjacency_map, edge.type_index, type_name, visited_types);
+                visited_types.remove(&edge.type_index);
+                children
+            } else {
+                vec![]
+            };
+
+            ClassHierarchyNode {
+                type_name: type_name(edge.type_index),
+                type_index: edge.type_index,
+                is_virtual: edge.is_virtual,
+                children,
+            }
+        })
+        .collect()
+}
@@ -1,15 +1,25 @@
+use dashmap::DashMap;
 #[cfg(target_arch = "wasm32")]
 use instant::Instant;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use similar::{ChangeTag, TextDiff};
 
 #[cfg(not(target_arch = "wasm32"))]
 use std::time::Instant;
-use std::{fmt::Write, io};
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    ops::Range,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 
 use crate::{
     error::{Result, ResymCoreError},
-    pdb_file::PdbFile,
+    par_iter_if_available,
+    pdb_file::{PdbFile, TypeIndex},
     pdb_types::PrimitiveReconstructionFlavor,
+    progress::ProgressReporter,
     PKG_VERSION,
 };
 
@@ -27,10 +37,118 @@ pub struct DiffLine {
     pub line: String,
 }
 
+/// Status of a type, when comparing two PDB files
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TypeDiffStatus {
+    /// The type only exists in the new PDB file
+    Added,
+    /// The type only exists in the old PDB file
+    Removed,
+    /// The type exists in both PDB files, with different reconstructions
+    Modified,
+    Unchanged,
+}
+
+impl TypeDiffStatus {
+    pub const ALL: [TypeDiffStatus; 4] = [
+        TypeDiffStatus::Added,
+        TypeDiffStatus::Removed,
+        TypeDiffStatus::Modified,
+        TypeDiffStatus::Unchanged,
+    ];
+}
+
+/// List of type names along with their status, ordered by name
+pub type TypeDiffList = Vec<(String, TypeDiffStatus)>;
+/// Statuses of the types present in two PDB files, indexed by type name.
+/// Statuses depend on the reconstruction options used to compare types.
+pub type TypeDiffStatusCache = DashMap<String, TypeDiffStatus>;
+
+/// Row of a side-by-side diff. Lines are designated by their position in the
+/// diff (i.e., in `Diff::metadata`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SideBySideRow {
+    /// Line displayed on the old side, if any
+    pub old_line: Option<usize>,
+    /// Line displayed on the new side, if any
+    pub new_line: Option<usize>,
+}
+
+/// Align the lines of a diff in two columns. Unchanged lines are displayed on
+/// both sides, while deleted lines are displayed next to the lines inserted in
+/// their place.
+pub fn side_by_side_rows(metadata: &[(DiffIndices, DiffChange)]) -> Vec<SideBySideRow> {
+    let mut rows = Vec::with_capacity(metadata.len());
+    let mut deleted_lines = vec![];
+    let mut inserted_lines = vec![];
+    let flush_changes = |rows: &mut Vec<SideBySideRow>,
+                         deleted_lines: &mut Vec<usize>,
+                         inserted_lines: &mut Vec<usize>| {
+        let row_count = deleted_lines.len().max(inserted_lines.len());
+        rows.extend((0..row_count).map(|row_index| SideBySideRow {
+            old_line: deleted_lines.get(row_index).copied(),
+            new_line: inserted_lines.get(row_index).copied(),
+        }));
+        deleted_lines.clear();
+        inserted_lines.clear();
+    };
+
+    for (line_index, (_, change)) in metadata.iter().enumerate() {
+        match change {
+            DiffChange::Equal => {
+                flush_changes(&mut rows, &mut deleted_lines, &mut inserted_lines);
+                rows.push(SideBySideRow {
+                    old_line: Some(line_index),
+                    new_line: Some(line_index),
+                });
+            }
+            DiffChange::Delete => deleted_lines.push(line_index),
+            DiffChange::Insert => inserted_lines.push(line_index),
+        }
+    }
+    flush_changes(&mut rows, &mut deleted_lines, &mut inserted_lines);
+
+    rows
+}
+
+/// Compute the byte ranges of the characters that differ between two versions
+/// of a line, in the old and in the new version respectively
+pub fn inline_changes(old_line: &str, new_line: &str) -> (Vec<Range<usize>>, Vec<Range<usize>>) {
+    let mut old_ranges: Vec<Range<usize>> = vec![];
+    let mut new_ranges: Vec<Range<usize>> = vec![];
+    // Extend the last range if contiguous, to avoid splitting words into
+    // single characters
+    let push_range = |ranges: &mut Vec<Range<usize>>, range: Range<usize>| match ranges.last_mut() {
+        Some(last_range) if last_range.end == range.start => last_range.end = range.end,
+        _ => ranges.push(range),
+    };
+
+    let (mut old_offset, mut new_offset) = (0, 0);
+    for change in TextDiff::from_chars(old_line, new_line).iter_all_changes() {
+        let change_len = change.value().len();
+        match change.tag() {
+            ChangeTag::Equal => {
+                old_offset += change_len;
+                new_offset += change_len;
+            }
+            ChangeTag::Delete => {
+                push_range(&mut old_ranges, old_offset..old_offset + change_len);
+                old_offset += change_len;
+            }
+            ChangeTag::Insert => {
+                push_range(&mut new_ranges, new_offset..new_offset + change_len);
+                new_offset += change_len;
+            }
+        }
+    }
+
+    (old_ranges, new_ranges)
+}
+
 #[allow(clippy::too_many_arguments)]
-pub fn diff_type_by_name<'p, T>(
-    pdb_file_from: &PdbFile<'p, T>,
-    pdb_file_to: &PdbFile<'p, T>,
+pub fn diff_type_by_name(
+    pdb_file_from: &PdbFile,
+    pdb_file_to: &PdbFile,
     type_name: &str,
     primitives_flavor: PrimitiveReconstructionFlavor,
     print_header: bool,
@@ -38,10 +156,7 @@ pub fn diff_type_by_name<'p, T>(
     print_access_specifiers: bool,
     integers_as_hexadecimal: bool,
     ignore_std_types: bool,
-) -> Result<Diff>
-where
-    T: io::Seek + io::Read + std::fmt::Debug + 'p,
-{
+) -> Result<Diff> {
     let diff_start = Instant::now();
 
     // Prepend header if needed
@@ -89,17 +204,102 @@ where
     Ok(diff)
 }
 
-pub fn diff_module_by_path<'p, T>(
-    pdb_file_from: &PdbFile<'p, T>,
-    pdb_file_to: &PdbFile<'p, T>,
+/// Compare the given lists of types (e.g., filtered type lists) of two PDB
+/// files. Types present in both files are compared by reconstructing them
+/// (without their dependencies) from each file, unless their status is
+/// already in `status_cache`.
+#[allow(clippy::too_many_arguments)]
+pub fn diff_type_list(
+    pdb_file_from: &PdbFile,
+    pdb_file_to: &PdbFile,
+    type_list_from: &[(String, TypeIndex)],
+    type_list_to: &[(String, TypeIndex)],
+    primitives_flavor: PrimitiveReconstructionFlavor,
+    print_access_specifiers: bool,
+    integers_as_hexadecimal: bool,
+    status_cache: &TypeDiffStatusCache,
+    progress: &ProgressReporter,
+) -> Result<TypeDiffList> {
+    let diff_start = Instant::now();
+
+    // Merge type lists by name
+    let mut merged_type_list: BTreeMap<&str, (Option<TypeIndex>, Option<TypeIndex>)> =
+        BTreeMap::new();
+    for (type_name, type_index) in type_list_from {
+        merged_type_list.entry(type_name.as_str()).or_default().0 = Some(*type_index);
+    }
+    for (type_name, type_index) in type_list_to {
+        merged_type_list.entry(type_name.as_str()).or_default().1 = Some(*type_index);
+    }
+
+    let reconstruct_type = |pdb_file: &PdbFile, type_index: TypeIndex| {
+        pdb_file
+            .reconstruct_type_by_index(
+                type_index,
+                primitives_flavor,
+                false,
+                print_access_specifiers,
+                integers_as_hexadecimal,
+                false,
+            )
+            .map(|(reconstructed_type, _)| reconstructed_type)
+            // Note: types that cannot be reconstructed in both files are
+            // considered unchanged
+            .ok()
+    };
+    let merged_type_list: Vec<_> = merged_type_list.into_iter().collect();
+    let type_count = merged_type_list.len();
+    let compared_type_count = AtomicUsize::new(0);
+    let type_diff_list = par_iter_if_available!(merged_type_list)
+        .map(|(type_name, type_indices)| {
+            progress.check_cancelled()?;
+            progress.report(
+                "Comparing types",
+                compared_type_count.fetch_add(1, Ordering::Relaxed),
+                type_count,
+            );
+
+            let status = match *type_indices {
+                (Some(type_index_from), Some(type_index_to)) => {
+                    let cached_status = status_cache.get(*type_name).map(|status| *status);
+                    match cached_status {
+                        Some(status) => status,
+                        None => {
+                            let status = if reconstruct_type(pdb_file_from, type_index_from)
+                                == reconstruct_type(pdb_file_to, type_index_to)
+                            {
+                                TypeDiffStatus::Unchanged
+                            } else {
+                                TypeDiffStatus::Modified
+                            };
+                            status_cache.insert(type_name.to_string(), status);
+                            status
+                        }
+                    }
+                }
+                (Some(_), None) => TypeDiffStatus::Removed,
+                (None, _) => TypeDiffStatus::Added,
+            };
+
+            Ok((type_name.to_string(), status))
+        })
+        .collect::<Result<TypeDiffList>>()?;
+    log::debug!(
+        "Type list diffing took {} ms",
+        diff_start.elapsed().as_millis()
+    );
+
+    Ok(type_diff_list)
+}
+
+pub fn diff_module_by_path(
+    pdb_file_from: &PdbFile,
+    pdb_file_to: &PdbFile,
     module_path: &str,
     primitives_flavor: PrimitiveReconstructionFlavor,
     print_header: bool,
     print_access_specifiers: bool,
-) -> Result<Diff>
-where
-    T: io::Seek + io::Read + std::fmt::Debug + 'p,
-{
+) -> Result<Diff> {
     let diff_start = Instant::now();
 
     // Prepend header if needed
@@ -138,17 +338,14 @@ where
     Ok(diff)
 }
 
-pub fn diff_symbol_by_name<'p, T>(
-    pdb_file_from: &PdbFile<'p, T>,
-    pdb_file_to: &PdbFile<'p, T>,
+pub fn diff_symbol_by_name(
+    pdb_file_from: &PdbFile,
+    pdb_file_to: &PdbFile,
     symbol_name: &str,
     primitives_flavor: PrimitiveReconstructionFlavor,
     print_header: bool,
     print_access_specifiers: bool,
-) -> Result<Diff>
-where
-    T: io::Seek + io::Read + std::fmt::Debug + 'p,
-{
+) -> Result<Diff> {
     let diff_start = Instant::now();
 
     // Prepend header if needed
@@ -185,13 +382,7 @@ where
     Ok(diff)
 }
 
-fn generate_diff_header<'p, T>(
-    pdb_file_from: &PdbFile<'p, T>,
-    pdb_file_to: &PdbFile<'p, T>,
-) -> String
-where
-    T: io::Seek + io::Read + 'p,
-{
+fn generate_diff_header(pdb_file_from: &PdbFile, pdb_file_to: &PdbFile) -> String {
     format!(
         concat!(
             "//\n",
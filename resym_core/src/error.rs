@@ -17,11 +17,20 @@ pub enum ResymCoreError {
     #[error("pdb error: {0}")]
     PdbError(#[from] pdb::Error),
 
+    /// Error reported from `object`.
+    #[error("PE error: {0}")]
+    PeError(#[from] object::read::Error),
+
     /// Error reported from `rayon`.
     #[cfg(feature = "rayon")]
     #[error("rayon error: {0}")]
     RayonError(#[from] rayon::ThreadPoolBuildError),
 
+    /// Error reported from `bincode`.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[error("bincode error: {0}")]
+    BincodeError(#[from] bincode::Error),
+
     /// Error reported from `crossbeam_channel`.
     #[error("crossbeam error: {0}")]
     CrossbeamError(String),
@@ -35,6 +44,10 @@ pub enum ResymCoreError {
     #[error("http error: {0}")]
     EHttpError(String),
 
+    /// Error returned when a request has been cancelled by the frontend.
+    #[error("operation cancelled")]
+    CancelledError,
+
     /// Error returned when `resym_core` cannot process the request because of
     /// of an invalid parameter.
     #[error("invalid parameter: {0}")]
@@ -60,10 +73,41 @@ pub enum ResymCoreError {
     #[error("module info not found: {0}")]
     ModuleInfoNotFoundError(String),
 
+    /// Error returned when a PE image doesn't match the PDB file it's loaded
+    /// alongside.
+    #[error("PE image doesn't match the PDB: {0}")]
+    PeMismatchError(String),
+
+    /// Error returned when a PE image contains inconsistent information
+    /// (e.g., addresses that overflow).
+    #[error("malformed PE image: {0}")]
+    MalformedPeError(String),
+
+    /// Error returned when the PDB file matching a PE image cannot be found
+    /// in the symbol search path.
+    #[error("PDB not found: {0}")]
+    PdbNotFoundError(String),
+
     /// Error returned when parsing a `PrimitiveReconstructionFlavor` from a string fails.
     #[error("invalid primitive type flavor: {0}")]
     ParsePrimitiveFlavorError(String),
 
+    /// Error returned when parsing a `SizeReportGrouping` from a string fails.
+    #[error("invalid size report grouping: {0}")]
+    ParseSizeReportGroupingError(String),
+
+    /// Error returned when parsing a `TypeGraphFormat` from a string fails.
+    #[error("invalid type graph format: {0}")]
+    ParseTypeGraphFormatError(String),
+
+    /// Error returned when parsing a `FieldConstraint` from a string fails.
+    #[error("invalid field constraint: {0}")]
+    ParseFieldConstraintError(String),
+
+    /// Error returned when parsing `Annotations` from a string fails.
+    #[error("invalid annotations: {0}")]
+    ParseAnnotationsError(String),
+
     /// Error returned when `resym_core` cannot process the request because of
     /// unimplemented features.
     #[error("feature not implemented: {0}")]
@@ -0,0 +1,116 @@
+use crate::{
+    error::{Result, ResymCoreError},
+    options::FilterOptions,
+    pdb_file::TypeIndex,
+};
+
+/// Field of a complete type (i.e., a non-static data member)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldInfo {
+    /// Name of the type that contains the field
+    pub type_name: String,
+    pub type_index: TypeIndex,
+    pub field_name: String,
+    /// Name of the field's type (e.g., `char[16]`)
+    pub field_type_name: String,
+    /// Offset of the field in bytes, within the containing type
+    pub offset: u64,
+    /// `true` if the field is a pointer (or a reference)
+    pub is_pointer: bool,
+}
+
+pub type FieldInfoList = Vec<FieldInfo>;
+
+/// Criteria used to search fields. Empty filters match all fields.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FieldSearchQuery {
+    pub field_name_filter: String,
+    pub field_type_filter: String,
+    /// Only match fields located at the given offset
+    pub offset: Option<u64>,
+}
+
+/// Parse a field offset, given in decimal or in hexadecimal (with a `0x`
+/// prefix)
+pub fn parse_field_offset(s: &str) -> Result<u64> {
+    let s = s.trim();
+    let parse_result = if let Some(hex_offset) = s.strip_prefix("0x").or(s.strip_prefix("0X")) {
+        u64::from_str_radix(hex_offset, 16)
+    } else {
+        s.parse()
+    };
+
+    parse_result.map_err(|_| ResymCoreError::InvalidParameterError(format!("invalid offset: {s}")))
+}
+
+/// Filter fields following the given query. Results are sorted by containing
+/// type name and offset.
+pub fn filter_fields(
+    field_list: &[FieldInfo],
+    query: &FieldSearchQuery,
+    options: &FilterOptions,
+) -> FieldInfoList {
+    let (name_matcher, type_matcher) = match (
+        NameMatcher::new(&query.field_name_filter, options),
+        NameMatcher::new(&query.field_type_filter, options),
+    ) {
+        (Some(name_matcher), Some(type_matcher)) => (name_matcher, type_matcher),
+        // In case of error, return an empty result
+        _ => return vec![],
+    };
+
+    let mut filtered_field_list: FieldInfoList = field_list
+        .iter()
+        .filter(|field| {
+            !(options.ignore_std_types && field.type_name.starts_with("std::"))
+                && query.offset.map_or(true, |offset| field.offset == offset)
+                && name_matcher.is_match(&field.field_name)
+                && type_matcher.is_match(&field.field_type_name)
+        })
+        .cloned()
+        .collect();
+    filtered_field_list.sort_by(|lhs, rhs| {
+        (&lhs.type_name, lhs.offset, &lhs.field_name).cmp(&(
+            &rhs.type_name,
+            rhs.offset,
+            &rhs.field_name,
+        ))
+    });
+
+    filtered_field_list
+}
+
+/// Match names with a plain (sub-)string or a regular expression
+enum NameMatcher {
+    Any,
+    Substring(String, bool),
+    Regex(regex::Regex),
+}
+
+impl NameMatcher {
+    /// Returns `None` if the regular expression is invalid
+    fn new(filter: &str, options: &FilterOptions) -> Option<Self> {
+        if filter.is_empty() {
+            Some(NameMatcher::Any)
+        } else if options.use_regex {
+            regex::RegexBuilder::new(filter)
+                .case_insensitive(options.case_insensitive)
+                .build()
+                .ok()
+                .map(NameMatcher::Regex)
+        } else if options.case_insensitive {
+            Some(NameMatcher::Substring(filter.to_lowercase(), true))
+        } else {
+            Some(NameMatcher::Substring(filter.to_string(), false))
+        }
+    }
+
+    fn is_match(&self, name: &str) -> bool {
+        match self {
+            NameMatcher::Any => true,
+            NameMatcher::Substring(filter, true) => name.to_lowercase().contains(filter),
+            NameMatcher::Substring(filter, false) => name.contains(filter.as_str()),
+            NameMatcher::Regex(regex) => regex.is_match(name),
+        }
+    }
+}
@@ -1,8 +1,17 @@
 use crate::{
+    annotations::Annotations,
     backend::PDBSlot,
-    diffing::Diff,
+    class_hierarchy::ClassHierarchyTree,
+    diffing::{Diff, TypeDiffList},
     error::Result,
-    pdb_file::{ModuleList, SymbolList, TypeList},
+    field_search::FieldInfoList,
+    inline_sites::InlineSiteList,
+    layout_search::LayoutMatchList,
+    pdb_file::{ModuleList, SymbolList, TypeDetailsList, TypeList},
+    progress::{Progress, RequestId},
+    size_report::SizeReport,
+    type_graph::TypeGraphQueryResult,
+    type_layout::TypeLayout,
 };
 
 /// Tuple containing the reconstructed type as a `String`
@@ -14,6 +23,10 @@ pub enum FrontendCommand {
     /// Send result from `LoadURL` backend command.
     /// Contains last path segment (i.e., file name) as a `String` and data as `Vec<u8>`.
     LoadURLResult(Result<(PDBSlot, String, Vec<u8>)>),
+    /// Send result from `LoadPEFromPath` and `LoadPEFromVec` backend commands.
+    LoadPEResult(Result<PDBSlot>),
+    /// Send result from `LoadAnnotations` backend command.
+    LoadAnnotationsResult(Result<(PDBSlot, Annotations)>),
 
     // Types
     ListTypesResult(TypeList),
@@ -29,8 +42,27 @@ pub enum FrontendCommand {
 
     // Diff
     DiffResult(Result<Diff>),
+    DiffTypeListResult(Result<TypeDiffList>),
     // Xrefs
     ListTypeCrossReferencesResult(Result<TypeList>),
+    ListTypeDetailsResult(Result<TypeDetailsList>),
+    // Memory layout
+    TypeLayoutResult(Result<TypeLayout>),
+    // Size report
+    SizeReportResult(Result<SizeReport>),
+    // Inline sites
+    ListInlineSitesResult(Result<InlineSiteList>),
+    // Type graph
+    TypeGraphQueryResult(Result<TypeGraphQueryResult>),
+    TypeGraphExportResult(Result<String>),
+    // Class hierarchy
+    ClassHierarchyResult(Result<ClassHierarchyTree>),
+    // Field search
+    FindFieldsResult(Result<FieldInfoList>),
+    // Layout search
+    MatchLayoutResult(Result<LayoutMatchList>),
+    /// Report the progress of a long-running request
+    Progress(RequestId, Progress),
 }
 
 pub trait FrontendController {
@@ -0,0 +1,206 @@
+use serde::{Deserialize, Serialize};
+
+use std::{
+    fs,
+    io::{BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use crate::{
+    error::Result,
+    pdb_file::{SymbolList, TypeIndex, TypeList},
+    pe_file::pdb_signature,
+    PKG_VERSION,
+};
+
+/// Version of the index cache's format. Must be bumped whenever the format or
+/// the content of indices changes, to invalidate existing caches.
+const INDEX_CACHE_FORMAT_VERSION: u32 = 1;
+/// Extension of index cache files
+const INDEX_CACHE_FILE_EXTENSION: &str = "idx";
+/// Environment variable used to enable the index cache and set its location.
+/// The cache is disabled if it isn't set, or set to an empty value.
+pub const INDEX_CACHE_DIR_ENV_VAR: &str = "RESYM_INDEX_CACHE_DIR";
+/// Identifier given to each temporary file written by this process
+static NEXT_TEMPORARY_FILE_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Information identifying a PDB file, used to check that cached indices
+/// still match the PDB file they've been computed from
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexCacheKey {
+    pub pdb_guid: [u8; 16],
+    pub pdb_age: u32,
+    pub pdb_file_size: u64,
+}
+
+/// Header of index cache files
+#[derive(Serialize, Deserialize)]
+struct IndexCacheHeader {
+    format_version: u32,
+    resym_version: String,
+    key: IndexCacheKey,
+}
+
+/// Indices computed from a PDB file, which are expensive to compute on huge
+/// PDB files
+#[derive(Default, Serialize, Deserialize)]
+pub(crate) struct PdbIndices {
+    pub complete_type_list: TypeList,
+    pub forwarder_to_complete_type: Vec<(TypeIndex, TypeIndex)>,
+    /// Empty if the symbol list hasn't been computed yet
+    pub symbol_list: SymbolList,
+    /// Empty if the cross-reference map hasn't been computed yet
+    pub xref_to_map: Vec<(TypeIndex, Vec<TypeIndex>)>,
+}
+
+/// On-disk cache of PDB indices, keyed by PDB GUID and age
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IndexCache {
+    cache_dir: PathBuf,
+}
+
+impl IndexCache {
+    pub fn new(cache_dir: PathBuf) -> Self {
+        Self { cache_dir }
+    }
+
+    /// Create `IndexCache` from the `RESYM_INDEX_CACHE_DIR` environment
+    /// variable. Returns `None` if the variable isn't set or is empty, as the
+    /// cache is opt-in.
+    pub fn from_env() -> Option<Self> {
+        match std::env::var_os(INDEX_CACHE_DIR_ENV_VAR) {
+            Some(cache_dir) if !cache_dir.is_empty() => Some(Self::new(cache_dir.into())),
+            _ => None,
+        }
+    }
+
+    /// Create `IndexCache` from the `RESYM_INDEX_CACHE_DIR` environment
+    /// variable, or use the default cache directory if it isn't set
+    pub fn from_env_or_default() -> Self {
+        Self::from_env().unwrap_or_else(|| Self::new(Self::default_cache_dir()))
+    }
+
+    /// Return the platform's cache directory (e.g., `%LOCALAPPDATA%` on
+    /// Windows or `$XDG_CACHE_HOME` on Linux)
+    pub fn default_cache_dir() -> PathBuf {
+        let platform_cache_dir = if cfg!(windows) {
+            std::env::var_os("LOCALAPPDATA").map(PathBuf::from)
+        } else if cfg!(target_os = "macos") {
+            std::env::var_os("HOME").map(|home| PathBuf::from(home).join("Library/Caches"))
+        } else {
+            std::env::var_os("XDG_CACHE_HOME")
+                .map(PathBuf::from)
+                .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        };
+
+        platform_cache_dir
+            .unwrap_or_else(std::env::temp_dir)
+            .join("resym")
+            .join("index_cache")
+    }
+
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    /// Load the indices cached for the given PDB file, if any.
+    /// Outdated or corrupted cache entries are ignored.
+    pub(crate) fn load(&self, key: &IndexCacheKey) -> Option<PdbIndices> {
+        let cache_file_path = self.cache_file_path(key);
+        let mut cache_file = BufReader::new(fs::File::open(&cache_file_path).ok()?);
+
+        let header: IndexCacheHeader = match bincode::deserialize_from(&mut cache_file) {
+            Ok(header) => header,
+            Err(err) => {
+                log::warn!("Ignoring corrupted index cache entry: {err}");
+                return None;
+            }
+        };
+        if header.format_version != INDEX_CACHE_FORMAT_VERSION
+            || header.resym_version != PKG_VERSION
+            || header.key != *key
+        {
+            log::info!("Ignoring outdated index cache entry");
+            return None;
+        }
+
+        match bincode::deserialize_from(&mut cache_file) {
+            Ok(indices) => {
+                log::debug!("Index cache loaded from '{}'", cache_file_path.display());
+                Some(indices)
+            }
+            Err(err) => {
+                log::warn!("Ignoring corrupted index cache entry: {err}");
+                None
+            }
+        }
+    }
+
+    /// Store the indices computed for the given PDB file, replacing any
+    /// previous cache entry
+    pub(crate) fn store(&self, key: &IndexCacheKey, indices: &PdbIndices) -> Result<()> {
+        fs::create_dir_all(&self.cache_dir)?;
+
+        // Note: write to a temporary file first, so that concurrent readers
+        // never see partially written entries. Temporary files are unique to
+        // each call, as entries may be stored concurrently (by this process
+        // or by others).
+        let cache_file_path = self.cache_file_path(key);
+        let temporary_file_path = cache_file_path.with_extension(format!(
+            "{INDEX_CACHE_FILE_EXTENSION}.{}.{}",
+            std::process::id(),
+            NEXT_TEMPORARY_FILE_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+        {
+            let mut cache_file = BufWriter::new(fs::File::create(&temporary_file_path)?);
+            let header = IndexCacheHeader {
+                format_version: INDEX_CACHE_FORMAT_VERSION,
+                resym_version: PKG_VERSION.to_string(),
+                key: key.clone(),
+            };
+            bincode::serialize_into(&mut cache_file, &header)?;
+            bincode::serialize_into(&mut cache_file, indices)?;
+            cache_file.flush()?;
+        }
+        fs::rename(&temporary_file_path, &cache_file_path)?;
+        log::debug!("Index cache stored to '{}'", cache_file_path.display());
+
+        Ok(())
+    }
+
+    /// Remove the entry cached for the given PDB file, if any
+    pub fn invalidate(&self, key: &IndexCacheKey) -> Result<()> {
+        match fs::remove_file(self.cache_file_path(key)) {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err.into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Remove all cached entries. Returns the number of entries removed.
+    pub fn clear(&self) -> Result<usize> {
+        let cache_dir_entries = match fs::read_dir(&self.cache_dir) {
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+            cache_dir_entries => cache_dir_entries?,
+        };
+
+        let mut removed_entry_count = 0;
+        for cache_dir_entry in cache_dir_entries {
+            let cache_file_path = cache_dir_entry?.path();
+            if cache_file_path.extension() == Some(INDEX_CACHE_FILE_EXTENSION.as_ref()) {
+                fs::remove_file(cache_file_path)?;
+                removed_entry_count += 1;
+            }
+        }
+
+        Ok(removed_entry_count)
+    }
+
+    fn cache_file_path(&self, key: &IndexCacheKey) -> PathBuf {
+        self.cache_dir.join(format!(
+            "{}.{}",
+            pdb_signature(&key.pdb_guid, key.pdb_age),
+            INDEX_CACHE_FILE_EXTENSION
+        ))
+    }
+}
@@ -0,0 +1,58 @@
+use crate::pdb_file::SymbolIndex;
+
+/// Range of code generated for an inlined call
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InlineSiteRange {
+    /// Relative virtual address of the first instruction
+    pub rva: u32,
+    /// Number of bytes of code
+    pub length: u32,
+}
+
+/// Location at which a function has been inlined (i.e., `S_INLINESITE`)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InlineSite {
+    /// Name of the inlined function
+    pub inlinee_name: String,
+    /// Name of the function the inlinee has been inlined into
+    pub caller_name: String,
+    /// Index of the procedure symbol of the function the inlinee has been
+    /// inlined into
+    pub caller_index: SymbolIndex,
+    /// Code generated for the inlined call, ordered by address
+    pub ranges: Vec<InlineSiteRange>,
+    /// Path of the source file the inlinee's code comes from, if known
+    pub source_file: Option<String>,
+    /// First line of the inlinee's source code, if known
+    pub source_line: Option<u32>,
+}
+
+impl InlineSite {
+    /// Return the address of the first instruction generated for this
+    /// inlined call, if any
+    pub fn rva(&self) -> Option<u32> {
+        self.ranges.first().map(|range| range.rva)
+    }
+}
+
+pub type InlineSiteList = Vec<InlineSite>;
+
+/// Merge contiguous code ranges together. Ranges are sorted by address in
+/// the process.
+pub(crate) fn merge_inline_site_ranges(mut ranges: Vec<InlineSiteRange>) -> Vec<InlineSiteRange> {
+    ranges.sort_unstable_by_key(|range| range.rva);
+
+    let mut merged_ranges: Vec<InlineSiteRange> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged_ranges.last_mut() {
+            Some(last_range) if last_range.rva + last_range.length >= range.rva => {
+                let range_end =
+                    std::cmp::max(last_range.rva + last_range.length, range.rva + range.length);
+                last_range.length = range_end - last_range.rva;
+            }
+            _ => merged_ranges.push(range),
+        }
+    }
+
+    merged_ranges
+}
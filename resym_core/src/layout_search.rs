@@ -0,0 +1,148 @@
+use std::str::FromStr;
+
+use crate::{
+    error::{Result, ResymCoreError},
+    field_search::{parse_field_offset, FieldInfo},
+    pdb_file::TypeIndex,
+};
+
+/// Expected type of a field in a layout query
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FieldTypeConstraint {
+    /// Any field starting at the given offset
+    Any,
+    /// Any pointer (or reference)
+    Pointer,
+    /// Type with the given name (e.g., `uint32_t`), ignoring case
+    TypeName(String),
+}
+
+/// Field expected at a given offset in a layout query
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldConstraint {
+    pub offset: u64,
+    pub field_type: FieldTypeConstraint,
+}
+
+/// Parse constraints formatted as `<offset>[:<type>]`, where the offset is
+/// given in decimal or hexadecimal and the type is either `ptr` (any pointer)
+/// or a type name (e.g., `0x10:uint32_t`). Without a type, any field matches.
+impl FromStr for FieldConstraint {
+    type Err = ResymCoreError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (offset, field_type) = match s.split_once(':') {
+            Some((offset, field_type)) => (offset, field_type.trim()),
+            None => (s, ""),
+        };
+        let offset = parse_field_offset(offset)
+            .map_err(|_| ResymCoreError::ParseFieldConstraintError(s.to_string()))?;
+        let field_type = match field_type {
+            "" | "*" => FieldTypeConstraint::Any,
+            "ptr" | "pointer" => FieldTypeConstraint::Pointer,
+            type_name => FieldTypeConstraint::TypeName(type_name.to_string()),
+        };
+
+        Ok(Self { offset, field_type })
+    }
+}
+
+/// Structural query, matching types by size and field layout
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LayoutQuery {
+    /// Expected size of the type in bytes
+    pub size: Option<u64>,
+    pub fields: Vec<FieldConstraint>,
+}
+
+impl LayoutQuery {
+    /// Number of constraints in the query
+    pub fn constraint_count(&self) -> usize {
+        usize::from(self.size.is_some()) + self.fields.len()
+    }
+}
+
+/// Type matching (part of) a layout query
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LayoutMatch {
+    pub type_name: String,
+    pub type_index: TypeIndex,
+    pub size: u64,
+    /// Number of constraints satisfied by the type
+    pub matched_constraint_count: usize,
+    pub constraint_count: usize,
+}
+
+pub type LayoutMatchList = Vec<LayoutMatch>;
+
+/// Rank types following the number of constraints they satisfy, then the
+/// difference between their size and the expected one. Types that don't
+/// satisfy any constraint are left out.
+pub fn match_layouts<'f>(
+    type_layouts: impl IntoIterator<Item = (&'f str, TypeIndex, u64, &'f [FieldInfo])>,
+    query: &LayoutQuery,
+    max_results: Option<usize>,
+) -> Result<LayoutMatchList> {
+    let constraint_count = query.constraint_count();
+    if constraint_count == 0 {
+        return Err(ResymCoreError::InvalidParameterError(
+            "layout queries need at least one constraint".to_string(),
+        ));
+    }
+
+    let mut layout_matches: LayoutMatchList = type_layouts
+        .into_iter()
+        .filter_map(|(type_name, type_index, size, fields)| {
+            let size_matched = query.size == Some(size);
+            let matched_field_count = query
+                .fields
+                .iter()
+                .filter(|constraint| {
+                    fields
+                        .iter()
+                        .any(|field| field_matches_constraint(field, constraint))
+                })
+                .count();
+            let matched_constraint_count = usize::from(size_matched) + matched_field_count;
+            if matched_constraint_count == 0 {
+                return None;
+            }
+
+            Some(LayoutMatch {
+                type_name: type_name.to_string(),
+                type_index,
+                size,
+                matched_constraint_count,
+                constraint_count,
+            })
+        })
+        .collect();
+    let size_distance = |layout_match: &LayoutMatch| {
+        query
+            .size
+            .map(|size| size.abs_diff(layout_match.size))
+            .unwrap_or_default()
+    };
+    layout_matches.sort_by(|lhs, rhs| {
+        rhs.matched_constraint_count
+            .cmp(&lhs.matched_constraint_count)
+            .then_with(|| size_distance(lhs).cmp(&size_distance(rhs)))
+            .then_with(|| lhs.type_name.cmp(&rhs.type_name))
+    });
+    if let Some(max_results) = max_results {
+        layout_matches.truncate(max_results);
+    }
+
+    Ok(layout_matches)
+}
+
+fn field_matches_constraint(field: &FieldInfo, constraint: &FieldConstraint) -> bool {
+    field.offset == constraint.offset
+        && match &constraint.field_type {
+            FieldTypeConstraint::Any => true,
+            FieldTypeConstraint::Pointer => field.is_pointer,
+            FieldTypeConstraint::TypeName(type_name) => {
+                field.field_type_name.eq_ignore_ascii_case(type_name)
+            }
+        }
+}
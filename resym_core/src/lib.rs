@@ -1,11 +1,29 @@
+pub mod annotations;
 pub mod backend;
+pub mod class_hierarchy;
 pub mod diffing;
 mod error;
+pub mod field_search;
 pub mod frontend;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod index_cache;
+pub mod inline_sites;
+pub mod layout_search;
+pub mod options;
 pub mod pdb_file;
+mod pdb_streams;
 pub mod pdb_types;
+pub mod pe_file;
+pub mod progress;
 pub mod rayon_utils;
+pub mod session;
+pub mod size_report;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod symbol_store;
 pub mod syntax_highlighting;
+pub mod text_search;
+pub mod type_graph;
+pub mod type_layout;
 
 pub use error::*;
 
@@ -0,0 +1,148 @@
+use crate::{pdb_types::PrimitiveReconstructionFlavor, type_graph::TypeGraphFormat};
+
+/// Options controlling how types, symbols and modules are reconstructed.
+///
+/// ```
+/// use resym_core::{options::ReconstructionOptions, pdb_types::PrimitiveReconstructionFlavor};
+///
+/// let options = ReconstructionOptions::new()
+///     .primitives_flavor(PrimitiveReconstructionFlavor::Microsoft)
+///     .reconstruct_dependencies(true)
+///     .print_access_specifiers(true);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReconstructionOptions {
+    pub(crate) primitives_flavor: PrimitiveReconstructionFlavor,
+    pub(crate) print_header: bool,
+    pub(crate) reconstruct_dependencies: bool,
+    pub(crate) print_access_specifiers: bool,
+    pub(crate) integers_as_hexadecimal: bool,
+    pub(crate) ignore_std_types: bool,
+}
+
+impl Default for ReconstructionOptions {
+    fn default() -> Self {
+        Self {
+            primitives_flavor: PrimitiveReconstructionFlavor::Portable,
+            print_header: false,
+            reconstruct_dependencies: false,
+            print_access_specifiers: false,
+            integers_as_hexadecimal: true,
+            ignore_std_types: false,
+        }
+    }
+}
+
+impl ReconstructionOptions {
+    /// Create options with default values (i.e., portable primitive types,
+    /// hexadecimal integers and nothing else)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Representation used for primitive types
+    pub fn primitives_flavor(mut self, primitives_flavor: PrimitiveReconstructionFlavor) -> Self {
+        self.primitives_flavor = primitives_flavor;
+        self
+    }
+
+    /// Prepend a header describing the PDB file (and the headers to include)
+    /// to the output
+    pub fn print_header(mut self, print_header: bool) -> Self {
+        self.print_header = print_header;
+        self
+    }
+
+    /// Also reconstruct the types the requested type depends on
+    pub fn reconstruct_dependencies(mut self, reconstruct_dependencies: bool) -> Self {
+        self.reconstruct_dependencies = reconstruct_dependencies;
+        self
+    }
+
+    /// Print `public`, `protected` and `private` access specifiers
+    pub fn print_access_specifiers(mut self, print_access_specifiers: bool) -> Self {
+        self.print_access_specifiers = print_access_specifiers;
+        self
+    }
+
+    /// Print integer values (e.g., enumerators) in hexadecimal
+    pub fn integers_as_hexadecimal(mut self, integers_as_hexadecimal: bool) -> Self {
+        self.integers_as_hexadecimal = integers_as_hexadecimal;
+        self
+    }
+
+    /// Don't reconstruct types in the `std` namespace
+    pub fn ignore_std_types(mut self, ignore_std_types: bool) -> Self {
+        self.ignore_std_types = ignore_std_types;
+        self
+    }
+}
+
+/// Options controlling how type, symbol and module lists are filtered
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FilterOptions {
+    pub(crate) case_insensitive: bool,
+    pub(crate) use_regex: bool,
+    pub(crate) ignore_std_types: bool,
+}
+
+impl FilterOptions {
+    /// Create options with default values (i.e., case-sensitive substring
+    /// search)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ignore case when matching names against the search filter
+    pub fn case_insensitive(mut self, case_insensitive: bool) -> Self {
+        self.case_insensitive = case_insensitive;
+        self
+    }
+
+    /// Interpret the search filter as a regular expression
+    pub fn use_regex(mut self, use_regex: bool) -> Self {
+        self.use_regex = use_regex;
+        self
+    }
+
+    /// Exclude types and symbols in the `std` namespace (ignored for modules)
+    pub fn ignore_std_types(mut self, ignore_std_types: bool) -> Self {
+        self.ignore_std_types = ignore_std_types;
+        self
+    }
+}
+
+/// Options controlling how type dependency graphs are exported
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TypeGraphExportOptions {
+    pub(crate) format: TypeGraphFormat,
+    pub(crate) max_depth: Option<usize>,
+    pub(crate) ignore_std_types: bool,
+}
+
+impl TypeGraphExportOptions {
+    /// Create options with default values (i.e., the complete graph, in the
+    /// DOT format)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Output format
+    pub fn format(mut self, format: TypeGraphFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Only include types that are at most `max_depth` levels away from the
+    /// exported type
+    pub fn max_depth(mut self, max_depth: Option<usize>) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Leave out types in the `std` namespace
+    pub fn ignore_std_types(mut self, ignore_std_types: bool) -> Self {
+        self.ignore_std_types = ignore_std_types;
+        self
+    }
+}
@@ -10,18 +10,30 @@ use std::{
     fmt::Write,
     io::{self, Read, Seek},
     path::PathBuf,
-    sync::{Arc, RwLock},
+    rc::Rc,
+    sync::{Arc, OnceLock, RwLock},
 };
 #[cfg(not(target_arch = "wasm32"))]
 use std::{fs::File, path::Path, time::Instant};
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::index_cache::{IndexCache, IndexCacheKey, PdbIndices};
 use crate::{
+    class_hierarchy::ClassHierarchy,
     error::{Result, ResymCoreError},
+    field_search::{FieldInfo, FieldInfoList},
     frontend::ReconstructedType,
+    inline_sites::{merge_inline_site_ranges, InlineSite, InlineSiteList, InlineSiteRange},
     par_iter_if_available,
+    pdb_streams::{PdbStreamCache, PdbStreams},
     pdb_types::{
         self, is_unnamed_type, type_name, DataFormatConfiguration, PrimitiveReconstructionFlavor,
     },
+    pe_file::PeFile,
+    progress::ProgressReporter,
+    size_report::SymbolSize,
+    type_graph::TypeGraph,
+    type_layout::{LayoutEntry, LayoutEntryKind, TypeLayout},
 };
 
 pub type TypeIndex = u32;
@@ -33,12 +45,28 @@ pub type SymbolListView<'t> = Vec<&'t (String, SymbolIndex)>;
 pub type ModuleIndex = usize;
 pub type ModuleList = Vec<(String, ModuleIndex)>;
 
+/// Size and definition location of a type
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypeDetails {
+    pub type_name: String,
+    pub type_index: TypeIndex,
+    /// Size of the type in bytes, if it can be computed
+    pub size: Option<u64>,
+    /// Source file and line at which the type is defined, if known
+    pub definition_location: Option<(String, u32)>,
+}
+pub type TypeDetailsList = Vec<TypeDetails>;
+
 const GLOBAL_MODULE_INDEX: usize = usize::MAX;
 
 /// Wrapper for different buffer types processed by `resym`
 #[derive(Debug)]
 pub enum PDBDataSource {
     File(std::fs::File),
+    /// File mapped into memory, used to avoid issuing a syscall for each read
+    /// on huge PDB files
+    #[cfg(not(target_arch = "wasm32"))]
+    Mmap(io::Cursor<memmap2::Mmap>),
     Vec(io::Cursor<Vec<u8>>),
     SharedArray(io::Cursor<Arc<[u8]>>),
 }
@@ -47,6 +75,8 @@ impl Seek for PDBDataSource {
     fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
         match self {
             PDBDataSource::File(file) => file.seek(pos),
+            #[cfg(not(target_arch = "wasm32"))]
+            PDBDataSource::Mmap(mmap) => mmap.seek(pos),
             PDBDataSource::Vec(vec) => vec.seek(pos),
             PDBDataSource::SharedArray(array) => array.seek(pos),
         }
@@ -57,6 +87,8 @@ impl Read for PDBDataSource {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         match self {
             PDBDataSource::File(file) => file.read(buf),
+            #[cfg(not(target_arch = "wasm32"))]
+            PDBDataSource::Mmap(mmap) => mmap.read(buf),
             PDBDataSource::Vec(vec) => vec.read(buf),
             PDBDataSource::SharedArray(array) => array.read(buf),
         }
@@ -83,82 +115,91 @@ impl Ord for PrioritizedSymbol {
     }
 }
 
-pub struct PdbFile<'p, T>
-where
-    T: io::Seek + io::Read + 'p,
-{
+pub struct PdbFile {
     pub complete_type_list: Vec<(String, TypeIndex)>,
     pub forwarder_to_complete_type: Arc<DashMap<pdb::TypeIndex, pdb::TypeIndex>>,
-    pub symbol_list: SymbolList,
+    /// List of symbols, computed on first use
+    symbol_list: OnceLock<SymbolList>,
     pub machine_type: pdb::MachineType,
-    pub type_information: pdb::TypeInformation<'p>,
-    pub debug_information: pdb::DebugInformation<'p>,
-    pub global_symbols: pdb::SymbolTable<'p>,
     pub sections: Vec<pdb::ImageSectionHeader>,
     pub file_path: PathBuf,
     pub xref_to_map: RwLock<DashMap<TypeIndex, Vec<TypeIndex>>>,
-    pdb: RwLock<pdb::PDB<'p, T>>,
+    /// Locations at which functions have been inlined, computed on first use
+    inline_site_list: OnceLock<InlineSiteList>,
+    /// Dependency graph of complete types, computed on first use
+    type_graph: OnceLock<TypeGraph>,
+    /// Inheritance relationships between complete types, computed on first use
+    class_hierarchy: OnceLock<ClassHierarchy>,
+    /// Fields of all complete types, computed on first use
+    field_list: OnceLock<FieldInfoList>,
+    /// Source locations of type definitions, computed on first use
+    type_definition_locations: OnceLock<HashMap<TypeIndex, (String, u32)>>,
+    /// PE image matching the PDB, if loaded
+    pe_file: RwLock<Option<Arc<PeFile>>>,
+    function_names_by_rva: RwLock<Option<HashMap<u32, String>>>,
+    /// On-disk cache used to persist indices, if enabled
+    #[cfg(not(target_arch = "wasm32"))]
+    index_cache: Option<(IndexCache, IndexCacheKey)>,
+    /// Streams of the PDB file, opened by each thread that accesses them
+    streams: PdbStreamCache,
 }
 
-#[cfg(not(target_arch = "wasm32"))]
-impl<'p> PdbFile<'p, File> {
+impl PdbFile {
     /// Create `PdbFile` from an `std::path::Path`
-    pub fn load_from_file(pdb_file_path: &Path) -> Result<PdbFile<'p, PDBDataSource>> {
-        let file = PDBDataSource::File(File::open(pdb_file_path)?);
-        let mut pdb = pdb::PDB::open(file)?;
-        let type_information = pdb.type_information()?;
-        let debug_information = pdb.debug_information()?;
-        let global_symbols = pdb.global_symbols()?;
-        let sections = pdb.sections().unwrap_or_default().unwrap_or_default();
-        let machine_type = pdb.debug_information()?.machine_type()?;
-
-        let mut pdb_file = PdbFile {
-            complete_type_list: Default::default(),
-            forwarder_to_complete_type: Arc::new(DashMap::default()),
-            symbol_list: Default::default(),
-            machine_type,
-            type_information,
-            debug_information,
-            global_symbols,
-            sections,
-            file_path: pdb_file_path.to_owned(),
-            xref_to_map: DashMap::default().into(),
-            pdb: pdb.into(),
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_from_file(pdb_file_path: &Path) -> Result<PdbFile> {
+        Self::load_from_file_with_index_cache(pdb_file_path, None, &Default::default())
+    }
+
+    /// Create `PdbFile` from an `std::path::Path`. Indices are loaded from
+    /// `index_cache` when available, and stored into it otherwise.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_from_file_with_index_cache(
+        pdb_file_path: &Path,
+        index_cache: Option<IndexCache>,
+        progress: &ProgressReporter,
+    ) -> Result<PdbFile> {
+        let file = File::open(pdb_file_path)?;
+        let pdb_file_size = file.metadata()?.len();
+        // Map the file into memory when possible, reading huge PDB files
+        // through `std::fs::File` is noticeably slower.
+        // Safety: the mapping is read-only. As with any memory-mapped file,
+        // the file must not be truncated while it's loaded.
+        let data_source = match unsafe { memmap2::Mmap::map(&file) } {
+            Ok(mmap) => PDBDataSource::Mmap(io::Cursor::new(mmap)),
+            Err(err) => {
+                log::warn!("Failed to map PDB file into memory: {err}");
+                PDBDataSource::File(file)
+            }
         };
-        pdb_file.load_symbols()?;
+
+        let mut pdb_file = Self::open(pdb_file_path.to_owned(), data_source)?;
+        if let Some(index_cache) = index_cache {
+            let (pdb_guid, pdb_age) = pdb_file.guid_and_age()?;
+            let index_cache_key = IndexCacheKey {
+                pdb_guid,
+                pdb_age,
+                pdb_file_size,
+            };
+            pdb_file.index_cache = Some((index_cache, index_cache_key));
+        }
+        if !pdb_file.load_indices_from_cache() {
+            pdb_file.load_symbols(progress)?;
+            pdb_file.store_indices_to_cache();
+        }
 
         Ok(pdb_file)
     }
-}
 
-impl<'p> PdbFile<'p, PDBDataSource> {
     /// Create `PdbFile` from a `String` and a `Vec<u8>`
     pub fn load_from_bytes_as_vec(
         pdb_file_name: String,
         pdb_file_data: Vec<u8>,
-    ) -> Result<PdbFile<'p, PDBDataSource>> {
-        let reader = PDBDataSource::Vec(io::Cursor::new(pdb_file_data));
-        let mut pdb = pdb::PDB::open(reader)?;
-        let type_information = pdb.type_information()?;
-        let debug_information = pdb.debug_information()?;
-        let global_symbols = pdb.global_symbols()?;
-        let sections = pdb.sections().unwrap_or_default().unwrap_or_default();
-        let machine_type = pdb.debug_information()?.machine_type()?;
-
-        let mut pdb_file = PdbFile {
-            complete_type_list: Default::default(),
-            forwarder_to_complete_type: Arc::new(DashMap::default()),
-            symbol_list: Default::default(),
-            machine_type,
-            type_information,
-            debug_information,
-            global_symbols,
-            sections,
-            file_path: pdb_file_name.into(),
-            xref_to_map: DashMap::default().into(),
-            pdb: pdb.into(),
-        };
-        pdb_file.load_symbols()?;
+        progress: &ProgressReporter,
+    ) -> Result<PdbFile> {
+        let data_source = PDBDataSource::Vec(io::Cursor::new(pdb_file_data));
+        let mut pdb_file = Self::open(pdb_file_name.into(), data_source)?;
+        pdb_file.load_symbols(progress)?;
 
         Ok(pdb_file)
     }
@@ -167,49 +208,147 @@ impl<'p> PdbFile<'p, PDBDataSource> {
     pub fn load_from_bytes_as_array(
         pdb_file_name: String,
         pdb_file_data: Arc<[u8]>,
-    ) -> Result<PdbFile<'p, PDBDataSource>> {
-        let reader = PDBDataSource::SharedArray(io::Cursor::new(pdb_file_data));
-        let mut pdb = pdb::PDB::open(reader)?;
-        let type_information = pdb.type_information()?;
-        let debug_information = pdb.debug_information()?;
-        let global_symbols = pdb.global_symbols()?;
-        let sections = pdb.sections().unwrap_or_default().unwrap_or_default();
-        let machine_type = pdb.debug_information()?.machine_type()?;
-
-        let mut pdb_file = PdbFile {
+        progress: &ProgressReporter,
+    ) -> Result<PdbFile> {
+        let data_source = PDBDataSource::SharedArray(io::Cursor::new(pdb_file_data));
+        let mut pdb_file = Self::open(pdb_file_name.into(), data_source)?;
+        pdb_file.load_symbols(progress)?;
+
+        Ok(pdb_file)
+    }
+
+    /// Open the PDB file read from `data_source`, without computing indices
+    fn open(file_path: PathBuf, data_source: PDBDataSource) -> Result<PdbFile> {
+        let streams = PdbStreamCache::new(data_source);
+        let (sections, machine_type) = {
+            let pdb_streams = streams.get()?;
+            let sections = pdb_streams
+                .with_pdb(|pdb| pdb.sections())
+                .unwrap_or_default()
+                .unwrap_or_default();
+            (sections, pdb_streams.debug_information.machine_type()?)
+        };
+
+        Ok(PdbFile {
             complete_type_list: Default::default(),
             forwarder_to_complete_type: Arc::new(DashMap::default()),
             symbol_list: Default::default(),
             machine_type,
-            type_information,
-            debug_information,
-            global_symbols,
             sections,
-            file_path: pdb_file_name.into(),
+            file_path,
             xref_to_map: DashMap::default().into(),
-            pdb: pdb.into(),
+            inline_site_list: OnceLock::new(),
+            type_graph: OnceLock::new(),
+            class_hierarchy: OnceLock::new(),
+            field_list: OnceLock::new(),
+            type_definition_locations: OnceLock::new(),
+            pe_file: Default::default(),
+            function_names_by_rva: Default::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            index_cache: None,
+            streams,
+        })
+    }
+
+    /// Return the streams of the PDB file opened by the current thread
+    pub(crate) fn streams(&self) -> Result<Rc<PdbStreams>> {
+        self.streams.get()
+    }
+
+    /// Fill indices with the content of the on-disk index cache.
+    /// Returns `false` if the cache is disabled or doesn't contain a valid
+    /// entry for this PDB file.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_indices_from_cache(&mut self) -> bool {
+        let indices = match &self.index_cache {
+            Some((index_cache, index_cache_key)) => match index_cache.load(index_cache_key) {
+                Some(indices) => indices,
+                None => {
+                    return false;
+                }
+            },
+            None => {
+                return false;
+            }
         };
-        pdb_file.load_symbols()?;
 
-        Ok(pdb_file)
+        self.complete_type_list = indices.complete_type_list;
+        self.forwarder_to_complete_type = Arc::new(
+            indices
+                .forwarder_to_complete_type
+                .into_iter()
+                .map(|(fwd_type_index, complete_type_index)| {
+                    (
+                        pdb::TypeIndex(fwd_type_index),
+                        pdb::TypeIndex(complete_type_index),
+                    )
+                })
+                .collect(),
+        );
+        // Note: the symbol list is only cached once it's been computed
+        self.symbol_list = if indices.symbol_list.is_empty() {
+            OnceLock::new()
+        } else {
+            OnceLock::from(indices.symbol_list)
+        };
+        self.xref_to_map = RwLock::new(indices.xref_to_map.into_iter().collect());
+
+        true
     }
-}
 
-impl<'p, T> PdbFile<'p, T>
-where
-    T: io::Seek + io::Read + std::fmt::Debug + 'p,
-{
-    fn load_symbols(&mut self) -> Result<()> {
+    /// Store indices computed so far into the on-disk index cache, if enabled.
+    /// Failures aren't fatal and are only logged.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn store_indices_to_cache(&self) {
+        let (index_cache, index_cache_key) = match &self.index_cache {
+            Some(index_cache) => index_cache,
+            None => {
+                return;
+            }
+        };
+
+        // Note: sort map entries, so that cache entries don't depend on the
+        // maps' iteration order
+        let mut forwarder_to_complete_type: Vec<_> = self
+            .forwarder_to_complete_type
+            .iter()
+            .map(|entry| (entry.key().0, entry.value().0))
+            .collect();
+        forwarder_to_complete_type.sort_unstable();
+        let mut xref_to_map: Vec<_> = self
+            .xref_to_map
+            .read()
+            .expect("lock shouldn't be poisoned")
+            .iter()
+            .map(|entry| (*entry.key(), entry.value().clone()))
+            .collect();
+        xref_to_map.sort_unstable_by_key(|(type_index, _)| *type_index);
+
+        let indices = PdbIndices {
+            complete_type_list: self.complete_type_list.clone(),
+            forwarder_to_complete_type,
+            symbol_list: self.symbol_list.get().cloned().unwrap_or_default(),
+            xref_to_map,
+        };
+        if let Err(err) = index_cache.store(index_cache_key, &indices) {
+            log::warn!("Failed to update index cache: {err}");
+        }
+    }
+
+    fn load_symbols(&mut self, progress: &ProgressReporter) -> Result<()> {
         // Build the list of complete types
         let complete_symbol_map: DashMap<String, pdb::TypeIndex> = DashMap::default();
         let mut forwarders = vec![];
         let pdb_start = Instant::now();
 
-        let mut type_finder = self.type_information.finder();
-        let mut type_info_iter = self.type_information.iter();
+        let streams = self.streams()?;
+        let type_count = streams.type_information.len();
+        let mut processed_type_count = 0;
+        let mut type_info_iter = streams.type_information.iter();
         while let Some(type_info) = type_info_iter.next()? {
-            // keep building the index
-            type_finder.update(&type_info_iter);
+            progress.check_cancelled()?;
+            progress.report("Loading types", processed_type_count, type_count);
+            processed_type_count += 1;
 
             let type_index = type_info.index();
             if let Ok(type_data) = type_info.parse() {
@@ -295,97 +434,61 @@ where
         integers_as_hexadecimal: bool,
         ignore_std_types: bool,
     ) -> Result<ReconstructedType> {
-        // Populate our `TypeFinder` and find the right type index
-        let mut type_index = TypeIndex::default();
-        let mut type_finder = self.type_information.finder();
+        // Find the right type index.
+        // Note: if a name is defined more than once, the last definition is
+        // used.
+        let type_index = match self
+            .complete_type_list
+            .iter()
+            .rev()
+            .find(|(complete_type_name, _)| complete_type_name == type_name)
         {
-            let mut type_iter = self.type_information.iter();
-            while let Some(item) = type_iter.next()? {
-                type_finder.update(&type_iter);
-
-                let item_type_index = item.index();
-                if let Ok(type_data) = item.parse() {
-                    match type_data {
-                        pdb::TypeData::Class(data) => {
-                            if data.properties.forward_reference() {
-                                // Ignore incomplete type
-                                continue;
-                            }
-
-                            // Rename anonymous tags to something unique
-                            let class_name = data.name.to_string();
-                            if is_unnamed_type(&class_name) {
-                                if type_name == format!("_unnamed_{item_type_index}") {
-                                    type_index = item_type_index.0;
-                                }
-                            } else if class_name == type_name {
-                                type_index = item_type_index.0;
-                            } else if let Some(unique_name) = data.unique_name {
-                                if unique_name.to_string() == type_name {
-                                    type_index = item_type_index.0;
-                                }
-                            }
-                        }
-                        pdb::TypeData::Union(data) => {
-                            if data.properties.forward_reference() {
-                                // Ignore incomplete type
-                                continue;
-                            }
+            Some((_, type_index)) => Some(*type_index),
+            // Decorated names (e.g., `.?AUFoo@@`) aren't part of the type list
+            None if type_name.starts_with(".?A") => self.find_type_by_unique_name(type_name)?,
+            None => None,
+        };
 
-                            // Rename anonymous tags to something unique
-                            let union_name = data.name.to_string();
-                            if is_unnamed_type(&union_name) {
-                                if type_name == format!("_unnamed_{item_type_index}") {
-                                    type_index = item_type_index.0;
-                                }
-                            } else if data.name.to_string() == type_name {
-                                type_index = item_type_index.0;
-                            } else if let Some(unique_name) = data.unique_name {
-                                if unique_name.to_string() == type_name {
-                                    type_index = item_type_index.0;
-                                }
-                            }
-                        }
-                        pdb::TypeData::Enumeration(data) => {
-                            if data.properties.forward_reference() {
-                                // Ignore incomplete type
-                                continue;
-                            }
+        match type_index {
+            None => Err(ResymCoreError::TypeNameNotFoundError(type_name.to_owned())),
+            Some(type_index) => {
+                let streams = self.streams()?;
+                self.reconstruct_type_by_type_index_internal(
+                    streams.type_finder()?,
+                    type_index,
+                    primitives_flavor,
+                    reconstruct_dependencies,
+                    print_access_specifiers,
+                    integers_as_hexadecimal,
+                    ignore_std_types,
+                )
+            }
+        }
+    }
 
-                            // Rename anonymous tags to something unique
-                            let enum_name = data.name.to_string();
-                            if is_unnamed_type(&enum_name) {
-                                if type_name == format!("_unnamed_{item_type_index}") {
-                                    type_index = item_type_index.0;
-                                }
-                            } else if data.name.to_string() == type_name {
-                                type_index = item_type_index.0;
-                            } else if let Some(unique_name) = data.unique_name {
-                                if unique_name.to_string() == type_name {
-                                    type_index = item_type_index.0;
-                                }
-                            }
-                        }
-                        // Ignore
-                        _ => {}
-                    }
+    /// Find the complete type with the given decorated name, if any
+    fn find_type_by_unique_name(&self, unique_name: &str) -> Result<Option<TypeIndex>> {
+        let streams = self.streams()?;
+        let mut type_index = None;
+        let mut type_iter = streams.type_information.iter();
+        while let Some(item) = type_iter.next()? {
+            let (properties, item_unique_name) = match item.parse() {
+                Ok(pdb::TypeData::Class(data)) => (data.properties, data.unique_name),
+                Ok(pdb::TypeData::Union(data)) => (data.properties, data.unique_name),
+                Ok(pdb::TypeData::Enumeration(data)) => (data.properties, data.unique_name),
+                _ => {
+                    continue;
                 }
+            };
+            // Ignore incomplete types
+            if !properties.forward_reference()
+                && item_unique_name.is_some_and(|name| name.to_string() == unique_name)
+            {
+                type_index = Some(item.index().0);
             }
         }
 
-        if type_index == TypeIndex::default() {
-            Err(ResymCoreError::TypeNameNotFoundError(type_name.to_owned()))
-        } else {
-            self.reconstruct_type_by_type_index_internal(
-                &type_finder,
-                type_index,
-                primitives_flavor,
-                reconstruct_dependencies,
-                print_access_specifiers,
-                integers_as_hexadecimal,
-                ignore_std_types,
-            )
-        }
+        Ok(type_index)
     }
 
     pub fn reconstruct_type_by_index(
@@ -397,17 +500,11 @@ where
         integers_as_hexadecimal: bool,
         ignore_std_types: bool,
     ) -> Result<ReconstructedType> {
-        // Populate our `TypeFinder`
-        let mut type_finder = self.type_information.finder();
-        {
-            let mut type_iter = self.type_information.iter();
-            while (type_iter.next()?).is_some() {
-                type_finder.update(&type_iter);
-            }
-        }
+        let streams = self.streams()?;
+        let type_finder = streams.type_finder()?;
 
         self.reconstruct_type_by_type_index_internal(
-            &type_finder,
+            type_finder,
             type_index,
             primitives_flavor,
             reconstruct_dependencies,
@@ -417,20 +514,24 @@ where
         )
     }
 
-    pub fn symbol_list(&mut self) -> Result<SymbolListView> {
+    pub fn symbol_list(&self, progress: &ProgressReporter) -> Result<SymbolListView> {
         // If cache is populated, return the cached list
-        if !self.symbol_list.is_empty() {
-            return Ok(self.symbol_list.iter().collect());
+        if let Some(symbol_list) = self.symbol_list.get() {
+            return Ok(symbol_list.iter().collect());
         }
 
+        let streams = self.streams()?;
         let mut symbol_heap: BinaryHeap<PrioritizedSymbol> = BinaryHeap::new();
 
         // Modules' private symbols
         {
-            let mut modules = self.debug_information.modules()?.enumerate();
-            let mut pdb = self.pdb.write().expect("lock shouldn't be poisoned");
+            let module_count = streams.debug_information.modules()?.count()?;
+            let mut modules = streams.debug_information.modules()?.enumerate();
             while let Some((module_index, module)) = modules.next()? {
-                let module_info = match pdb.module_info(&module)? {
+                progress.check_cancelled()?;
+                progress.report("Listing symbols", module_index, module_count);
+
+                let module_info = match streams.with_pdb(|pdb| pdb.module_info(&module))? {
                     Some(info) => info,
                     None => {
                         continue;
@@ -451,7 +552,7 @@ where
         }
 
         // Global symbols
-        let mut symbol_table = self.global_symbols.iter();
+        let mut symbol_table = streams.global_symbols.iter();
         while let Some(symbol) = symbol_table.next()? {
             if let Some(symbol_name) = get_symbol_name(&symbol) {
                 symbol_heap.push(PrioritizedSymbol {
@@ -463,8 +564,7 @@ where
         }
 
         let mut symbol_names = HashSet::new();
-        // Populate cache with result
-        self.symbol_list = symbol_heap
+        let symbol_list = symbol_heap
             .into_sorted_vec()
             .into_iter()
             .filter_map(|s| {
@@ -476,12 +576,19 @@ where
                 }
             })
             .collect();
+        // Populate cache with result
+        // Note: the list might have been computed concurrently, in which case
+        // the first one is kept
+        let symbol_list = self.symbol_list.get_or_init(|| symbol_list);
+        #[cfg(not(target_arch = "wasm32"))]
+        self.store_indices_to_cache();
 
-        Ok(self.symbol_list.iter().collect())
+        Ok(symbol_list.iter().collect())
     }
 
     pub fn module_list(&self) -> Result<ModuleList> {
-        let module_list = self
+        let streams = self.streams()?;
+        let module_list = streams
             .debug_information
             .modules()?
             .enumerate()
@@ -490,30 +597,57 @@ where
         Ok(module_list.collect()?)
     }
 
+    /// Load the PE image matching this PDB, in order to enrich symbol
+    /// reconstruction with information taken from the image.
+    /// Fails if the image's CodeView information doesn't reference this PDB.
+    pub fn load_pe_file(&self, pe_file: PeFile) -> Result<()> {
+        let (pdb_guid, pdb_age) = self.guid_and_age()?;
+        pe_file.check_matches_pdb(pdb_guid, pdb_age)?;
+
+        *self.pe_file.write().expect("lock shouldn't be poisoned") = Some(Arc::new(pe_file));
+        Ok(())
+    }
+
+    /// Return the PE image matching this PDB, if loaded
+    pub fn pe_file(&self) -> Option<Arc<PeFile>> {
+        self.pe_file
+            .read()
+            .expect("lock shouldn't be poisoned")
+            .clone()
+    }
+
+    /// Return the PDB's GUID (in its on-disk layout) and age, as referenced
+    /// by the matching PE image
+    pub fn guid_and_age(&self) -> Result<([u8; 16], u32)> {
+        let streams = self.streams()?;
+        let pdb_information = streams.with_pdb(|pdb| pdb.pdb_information())?;
+        // Note: the DBI stream's age is the one referenced by images
+        let pdb_age = streams
+            .debug_information
+            .age()
+            .unwrap_or(pdb_information.age);
+
+        Ok((pdb_information.guid.to_bytes_le(), pdb_age))
+    }
+
     pub fn reconstruct_symbol_by_index(
         &self,
         symbol_index: SymbolIndex,
         primitives_flavor: PrimitiveReconstructionFlavor,
         print_access_specifiers: bool,
     ) -> Result<String> {
-        // Populate our `TypeFinder`
-        let mut type_finder = self.type_information.finder();
-        {
-            let mut type_iter = self.type_information.iter();
-            while (type_iter.next()?).is_some() {
-                type_finder.update(&type_iter);
-            }
-        }
+        let streams = self.streams()?;
+        let type_finder = streams.type_finder()?;
 
         // Check which module the symbol is from
         if symbol_index.0 == GLOBAL_MODULE_INDEX {
             // Global symbols
-            let mut symbol_table = self.global_symbols.iter();
+            let mut symbol_table = streams.global_symbols.iter();
             while let Some(symbol) = symbol_table.next()? {
                 if symbol.index().0 == symbol_index.1 {
                     return Ok(self
                         .reconstruct_symbol(
-                            &type_finder,
+                            type_finder,
                             &symbol,
                             primitives_flavor,
                             print_access_specifiers,
@@ -521,16 +655,15 @@ where
                         .unwrap_or_default());
                 }
             }
-        } else if let Some(module) = self.debug_information.modules()?.nth(symbol_index.0)? {
+        } else if let Some(module) = streams.debug_information.modules()?.nth(symbol_index.0)? {
             // Modules' private symbols
-            let mut pdb = self.pdb.write().expect("lock shouldn't be poisoned");
-            if let Some(module_info) = pdb.module_info(&module)? {
+            if let Some(module_info) = streams.with_pdb(|pdb| pdb.module_info(&module))? {
                 let mut module_symbols = module_info.symbols_at(symbol_index.1.into())?;
                 while let Some(symbol) = module_symbols.next()? {
                     if symbol.index().0 == symbol_index.1 {
                         return Ok(self
                             .reconstruct_symbol(
-                                &type_finder,
+                                type_finder,
                                 &symbol,
                                 primitives_flavor,
                                 print_access_specifiers,
@@ -553,23 +686,17 @@ where
         primitives_flavor: PrimitiveReconstructionFlavor,
         print_access_specifiers: bool,
     ) -> Result<String> {
-        // Populate our `TypeFinder`
-        let mut type_finder = self.type_information.finder();
-        {
-            let mut type_iter = self.type_information.iter();
-            while (type_iter.next()?).is_some() {
-                type_finder.update(&type_iter);
-            }
-        }
+        let streams = self.streams()?;
+        let type_finder = streams.type_finder()?;
 
         // Global symbols
-        let mut symbol_table = self.global_symbols.iter();
+        let mut symbol_table = streams.global_symbols.iter();
         while let Some(symbol) = symbol_table.next()? {
             if let Some(current_symbol_name) = get_symbol_name(&symbol) {
                 if current_symbol_name == symbol_name {
                     return Ok(self
                         .reconstruct_symbol(
-                            &type_finder,
+                            type_finder,
                             &symbol,
                             primitives_flavor,
                             print_access_specifiers,
@@ -581,17 +708,16 @@ where
 
         // Modules' private symbols
         {
-            let mut pdb = self.pdb.write().expect("lock shouldn't be poisoned");
-            let mut modules = self.debug_information.modules()?;
+            let mut modules = streams.debug_information.modules()?;
             while let Some(module) = modules.next()? {
-                if let Some(module_info) = pdb.module_info(&module)? {
+                if let Some(module_info) = streams.with_pdb(|pdb| pdb.module_info(&module))? {
                     let mut module_symbols = module_info.symbols()?;
                     while let Some(symbol) = module_symbols.next()? {
                         if let Some(current_symbol_name) = get_symbol_name(&symbol) {
                             if current_symbol_name == symbol_name {
                                 return Ok(self
                                     .reconstruct_symbol(
-                                        &type_finder,
+                                        type_finder,
                                         &symbol,
                                         primitives_flavor,
                                         print_access_specifiers,
@@ -614,24 +740,20 @@ where
         &self,
         primitives_flavor: PrimitiveReconstructionFlavor,
         print_access_specifiers: bool,
+        progress: &ProgressReporter,
     ) -> Result<String> {
-        // Populate our `TypeFinder`
-        let mut type_finder = self.type_information.finder();
-        {
-            let mut type_iter = self.type_information.iter();
-            while (type_iter.next()?).is_some() {
-                type_finder.update(&type_iter);
-            }
-        }
+        let streams = self.streams()?;
+        let type_finder = streams.type_finder()?;
 
         let mut reconstruction_output = String::new();
 
         // Global symbols
-        let mut symbol_table = self.global_symbols.iter();
+        let mut symbol_table = streams.global_symbols.iter();
         while let Some(symbol) = symbol_table.next()? {
+            progress.check_cancelled()?;
             if get_symbol_name(&symbol).is_some() {
                 if let Some(reconstructed_symbol) = self.reconstruct_symbol(
-                    &type_finder,
+                    type_finder,
                     &symbol,
                     primitives_flavor,
                     print_access_specifiers,
@@ -643,15 +765,18 @@ where
 
         // Modules' private symbols
         {
-            let mut pdb = self.pdb.write().expect("lock shouldn't be poisoned");
-            let mut modules = self.debug_information.modules()?;
-            while let Some(module) = modules.next()? {
-                if let Some(module_info) = pdb.module_info(&module)? {
+            let module_count = streams.debug_information.modules()?.count()?;
+            let mut modules = streams.debug_information.modules()?.enumerate();
+            while let Some((module_index, module)) = modules.next()? {
+                progress.check_cancelled()?;
+                progress.report("Reconstructing symbols", module_index, module_count);
+
+                if let Some(module_info) = streams.with_pdb(|pdb| pdb.module_info(&module))? {
                     let mut module_symbols = module_info.symbols()?;
                     while let Some(symbol) = module_symbols.next()? {
                         if get_symbol_name(&symbol).is_some() {
                             if let Some(reconstructed_symbol) = self.reconstruct_symbol(
-                                &type_finder,
+                                type_finder,
                                 &symbol,
                                 primitives_flavor,
                                 print_access_specifiers,
@@ -674,7 +799,8 @@ where
         print_access_specifiers: bool,
     ) -> Result<String> {
         // Find index for module
-        let mut modules = self.debug_information.modules()?;
+        let streams = self.streams()?;
+        let mut modules = streams.debug_information.modules()?;
         let module_index = modules.position(|module| Ok(module.module_name() == module_path))?;
 
         match module_index {
@@ -696,16 +822,14 @@ where
         primitives_flavor: PrimitiveReconstructionFlavor,
         print_access_specifiers: bool,
     ) -> Result<String> {
-        let mut modules = self.debug_information.modules()?;
+        let streams = self.streams()?;
+        let mut modules = streams.debug_information.modules()?;
         let module = modules.nth(module_index)?.ok_or_else(|| {
             ResymCoreError::ModuleInfoNotFoundError(format!("Module #{} not found", module_index))
         })?;
 
-        let module_info = self
-            .pdb
-            .write()
-            .expect("lock shouldn't be poisoned")
-            .module_info(&module)?
+        let module_info = streams
+            .with_pdb(|pdb| pdb.module_info(&module))?
             .ok_or_else(|| {
                 ResymCoreError::ModuleInfoNotFoundError(format!(
                     "No module information present for '{}'",
@@ -713,19 +837,12 @@ where
                 ))
             })?;
 
-        // Populate our `TypeFinder`
-        let mut type_finder = self.type_information.finder();
-        {
-            let mut type_iter = self.type_information.iter();
-            while (type_iter.next()?).is_some() {
-                type_finder.update(&type_iter);
-            }
-        }
+        let type_finder = streams.type_finder()?;
 
         let mut result = String::default();
         module_info.symbols()?.for_each(|symbol| {
             let reconstructed_symbol = self.reconstruct_symbol(
-                &type_finder,
+                type_finder,
                 &symbol,
                 primitives_flavor,
                 print_access_specifiers,
@@ -857,21 +974,22 @@ where
         print_access_specifiers: bool,
         integers_as_hexadecimal: bool,
         ignore_std_types: bool,
+        progress: &ProgressReporter,
     ) -> Result<String> {
+        let streams = self.streams()?;
         let mut type_data = pdb_types::Data::new(ignore_std_types);
         let mut processed_types = Vec::new();
         let mut type_dependency_map: HashMap<TypeIndex, Vec<(TypeIndex, bool)>> = HashMap::new();
         {
-            let mut type_finder = self.type_information.finder();
-            // Populate our `TypeFinder`
-            let mut type_iter = self.type_information.iter();
-            while (type_iter.next()?).is_some() {
-                type_finder.update(&type_iter);
-            }
+            let type_finder = streams.type_finder()?;
 
             // Add the requested types
-            let mut type_iter = self.type_information.iter();
-            while let Some(item) = type_iter.next()? {
+            let type_count = streams.type_information.len();
+            let mut type_iter = streams.type_information.iter().enumerate();
+            while let Some((item_position, item)) = type_iter.next()? {
+                progress.check_cancelled()?;
+                progress.report("Reconstructing types", item_position, type_count);
+
                 let mut needed_types = pdb_types::NeededTypeSet::new();
                 // Note(ergelet): try to get the complete type's index here.
                 // This avoids adding empty "forward reference" type index which
@@ -882,7 +1000,7 @@ where
                     .map(|e| *e)
                     .unwrap_or_else(|| item.index());
                 let result = type_data.add(
-                    &type_finder,
+                    type_finder,
                     &self.forwarder_to_complete_type,
                     complete_type_index,
                     &primitives_flavor,
@@ -906,7 +1024,7 @@ where
                     for (type_index, is_pointer) in &needed_types {
                         // Add forward declaration for types referenced by pointers
                         if *is_pointer {
-                            type_data.add_as_forward_declaration(&type_finder, *type_index)?;
+                            type_data.add_as_forward_declaration(type_finder, *type_index)?;
                         }
 
                         // Update type dependency map
@@ -939,7 +1057,130 @@ where
         Ok(reconstruction_output)
     }
 
-    pub fn get_xrefs_for_type(&self, type_index: TypeIndex) -> Result<TypeList> {
+    /// Gather code and data sizes for all procedures and global variables.
+    /// Note: global variables are deduplicated by address, since they can be
+    /// present in both the global symbol table and in modules' symbol streams.
+    pub fn symbol_sizes(&self) -> Result<Vec<SymbolSize>> {
+        let streams = self.streams()?;
+        let type_finder = streams.type_finder()?;
+
+        let section_contributions = self.sorted_section_contributions()?;
+        let mut symbol_sizes = vec![];
+        let mut processed_data_offsets = HashSet::new();
+
+        // Modules' private symbols
+        {
+            // Note: the string table is only needed to resolve source file names
+            let string_table = streams.with_pdb(|pdb| pdb.string_table()).ok();
+            let mut modules = streams.debug_information.modules()?.enumerate();
+            while let Some((module_index, module)) = modules.next()? {
+                let module_info = match streams.with_pdb(|pdb| pdb.module_info(&module))? {
+                    Some(info) => info,
+                    None => {
+                        continue;
+                    }
+                };
+                let line_program = module_info.line_program().ok();
+
+                let mut module_symbols = module_info.symbols()?;
+                while let Some(symbol) = module_symbols.next()? {
+                    match symbol.parse() {
+                        Ok(pdb::SymbolData::Procedure(procedure)) => {
+                            let source_file = line_program.as_ref().and_then(|line_program| {
+                                procedure_source_file(
+                                    line_program,
+                                    string_table.as_ref()?,
+                                    procedure.offset,
+                                )
+                            });
+                            symbol_sizes.push(SymbolSize {
+                                name: procedure.name.to_string().into_owned(),
+                                module_index: Some(module_index),
+                                source_file,
+                                code_size: procedure.len.into(),
+                                data_size: 0,
+                            });
+                        }
+                        Ok(pdb::SymbolData::Data(data))
+                            if data.offset.section != 0
+                                && processed_data_offsets
+                                    .insert((data.offset.section, data.offset.offset)) =>
+                        {
+                            symbol_sizes.push(SymbolSize {
+                                name: data.name.to_string().into_owned(),
+                                module_index: Some(module_index),
+                                source_file: None,
+                                code_size: 0,
+                                data_size: pdb_types::type_size(type_finder, data.type_index)
+                                    .unwrap_or_default()
+                                    as u64,
+                            });
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        // Global symbols
+        let mut symbol_table = streams.global_symbols.iter();
+        while let Some(symbol) = symbol_table.next()? {
+            if let Ok(pdb::SymbolData::Data(data)) = symbol.parse() {
+                if data.offset.section != 0
+                    && processed_data_offsets.insert((data.offset.section, data.offset.offset))
+                {
+                    symbol_sizes.push(SymbolSize {
+                        name: data.name.to_string().into_owned(),
+                        // Global symbols aren't tied to a module, use section
+                        // contributions to find which module they come from
+                        module_index: module_index_from_section_contributions(
+                            &section_contributions,
+                            &data.offset,
+                        ),
+                        source_file: None,
+                        code_size: 0,
+                        data_size: pdb_types::type_size(type_finder, data.type_index)
+                            .unwrap_or_default() as u64,
+                    });
+                }
+            }
+        }
+
+        Ok(symbol_sizes)
+    }
+
+    /// Compute the number of bytes each module contributes to the image's
+    /// sections, according to the linker.
+    pub fn module_section_contributions(&self) -> Result<BTreeMap<ModuleIndex, u64>> {
+        let mut module_contributions = BTreeMap::new();
+        let streams = self.streams()?;
+        let mut section_contributions = streams.debug_information.section_contributions()?;
+        while let Some(contribution) = section_contributions.next()? {
+            *module_contributions.entry(contribution.module).or_default() +=
+                u64::from(contribution.size);
+        }
+
+        Ok(module_contributions)
+    }
+
+    fn sorted_section_contributions(&self) -> Result<Vec<pdb::DBISectionContribution>> {
+        let mut section_contributions: Vec<pdb::DBISectionContribution> = self
+            .streams()?
+            .debug_information
+            .section_contributions()?
+            .collect()?;
+        section_contributions.sort_unstable_by_key(|contribution| {
+            (contribution.offset.section, contribution.offset.offset)
+        });
+
+        Ok(section_contributions)
+    }
+
+    pub fn get_xrefs_for_type(
+        &self,
+        type_index: TypeIndex,
+        progress: &ProgressReporter,
+    ) -> Result<TypeList> {
         // Generate xref cache if empty
         if self
             .xref_to_map
@@ -947,25 +1188,23 @@ where
             .expect("lock shouldn't be poisoned")
             .is_empty()
         {
-            // Populate our `TypeFinder`
-            let mut type_finder = self.type_information.finder();
-            {
-                let mut type_iter = self.type_information.iter();
-                while (type_iter.next()?).is_some() {
-                    type_finder.update(&type_iter);
-                }
-            }
+            let streams = self.streams()?;
+            let type_finder = streams.type_finder()?;
 
             // Iterate through all types
             let xref_map: DashMap<TypeIndex, Vec<TypeIndex>> = DashMap::default();
-            let mut type_iter = self.type_information.iter();
-            while let Some(type_item) = type_iter.next()? {
+            let type_count = streams.type_information.len();
+            let mut type_iter = streams.type_information.iter().enumerate();
+            while let Some((type_position, type_item)) = type_iter.next()? {
+                progress.check_cancelled()?;
+                progress.report("Computing cross-references", type_position, type_count);
+
                 let current_type_index = type_item.index();
                 // Reconstruct type and retrieve referenced types
                 let mut type_data = pdb_types::Data::new(false);
                 let mut needed_types = pdb_types::NeededTypeSet::new();
                 let result = type_data.add(
-                    &type_finder,
+                    type_finder,
                     &self.forwarder_to_complete_type,
                     current_type_index,
                     &PrimitiveReconstructionFlavor::Raw,
@@ -999,6 +1238,8 @@ where
             if let Ok(mut xref_map_ref) = self.xref_to_map.write() {
                 *xref_map_ref = xref_map;
             }
+            #[cfg(not(target_arch = "wasm32"))]
+            self.store_indices_to_cache();
         }
 
         // Query xref cache
@@ -1018,6 +1259,577 @@ where
         }
     }
 
+    /// Return the dependency graph of complete types, computing it on first
+    /// use
+    pub fn type_graph(&self, progress: &ProgressReporter) -> Result<&TypeGraph> {
+        if let Some(type_graph) = self.type_graph.get() {
+            return Ok(type_graph);
+        }
+
+        let graph_start = Instant::now();
+        let streams = self.streams()?;
+        let type_finder = streams.type_finder()?;
+        let complete_type_set: HashSet<TypeIndex> = self
+            .complete_type_list
+            .iter()
+            .map(|(_, type_index)| *type_index)
+            .collect();
+        let mut edges = vec![];
+        let type_count = self.complete_type_list.len();
+        for (type_position, (_, type_index)) in self.complete_type_list.iter().enumerate() {
+            progress.check_cancelled()?;
+            progress.report("Building type graph", type_position, type_count);
+
+            // Reconstruct type and retrieve referenced types
+            let mut type_data = pdb_types::Data::new(false);
+            let mut needed_types = pdb_types::NeededTypeSet::new();
+            if let Err(err) = type_data.add(
+                type_finder,
+                &self.forwarder_to_complete_type,
+                (*type_index).into(),
+                &PrimitiveReconstructionFlavor::Raw,
+                &mut needed_types,
+            ) {
+                match err {
+                    ResymCoreError::PdbError(err) => {
+                        // Ignore this kind of error since some particular PDB features might not be supported.
+                        log::warn!("Failed to reconstruct type with index {type_index}: {err}")
+                    }
+                    _ => return Err(err),
+                }
+            }
+
+            for (needed_type_index, is_pointer) in needed_types {
+                let needed_type_index = self
+                    .forwarder_to_complete_type
+                    .get(&needed_type_index)
+                    .map(|complete_type_index| *complete_type_index)
+                    .unwrap_or(needed_type_index)
+                    .0;
+                if complete_type_set.contains(&needed_type_index) {
+                    edges.push((*type_index, needed_type_index, is_pointer));
+                }
+            }
+        }
+        log::debug!(
+            "Type graph computation took {} ms",
+            graph_start.elapsed().as_millis()
+        );
+
+        let base_classes = self
+            .class_hierarchy(progress)?
+            .edges()
+            .map(|(derived, edge)| (derived, edge.type_index));
+
+        Ok(self
+            .type_graph
+            .get_or_init(|| TypeGraph::from_edges(edges).with_base_classes(base_classes)))
+    }
+
+    /// Return the inheritance relationships between complete types, computing
+    /// them on first use
+    pub fn class_hierarchy(&self, progress: &ProgressReporter) -> Result<&ClassHierarchy> {
+        if let Some(class_hierarchy) = self.class_hierarchy.get() {
+            return Ok(class_hierarchy);
+        }
+
+        let hierarchy_start = Instant::now();
+        let streams = self.streams()?;
+        let type_finder = streams.type_finder()?;
+        let complete_type_set: HashSet<TypeIndex> = self
+            .complete_type_list
+            .iter()
+            .map(|(_, type_index)| *type_index)
+            .collect();
+        let mut edges = vec![];
+        let type_count = self.complete_type_list.len();
+        for (type_position, (_, type_index)) in self.complete_type_list.iter().enumerate() {
+            progress.check_cancelled()?;
+            progress.report("Building class hierarchy", type_position, type_count);
+
+            let fields = match type_finder.find((*type_index).into())?.parse() {
+                Ok(pdb::TypeData::Class(data)) => data.fields,
+                _ => None,
+            };
+            // Walk the field list (and its continuations) looking for base
+            // classes
+            let mut next_field_list = fields;
+            while let Some(field_list_index) = next_field_list.take() {
+                let field_list = match type_finder.find(field_list_index)?.parse() {
+                    Ok(pdb::TypeData::FieldList(data)) => data,
+                    _ => break,
+                };
+                for field in &field_list.fields {
+                    let (base_class_index, is_virtual) = match field {
+                        pdb::TypeData::BaseClass(data) => (data.base_class, false),
+                        // Note: indirect virtual base classes are listed too,
+                        // ignore them
+                        pdb::TypeData::VirtualBaseClass(data) if data.direct => {
+                            (data.base_class, true)
+                        }
+                        _ => continue,
+                    };
+                    let base_class_index = self
+                        .forwarder_to_complete_type
+                        .get(&base_class_index)
+                        .map(|complete_type_index| *complete_type_index)
+                        .unwrap_or(base_class_index)
+                        .0;
+                    if complete_type_set.contains(&base_class_index) {
+                        edges.push((*type_index, base_class_index, is_virtual));
+                    }
+                }
+                next_field_list = field_list.continuation;
+            }
+        }
+        log::debug!(
+            "Class hierarchy computation took {} ms",
+            hierarchy_start.elapsed().as_millis()
+        );
+
+        Ok(self
+            .class_hierarchy
+            .get_or_init(|| ClassHierarchy::from_edges(edges)))
+    }
+
+    /// Return the fields of all complete classes, structs and unions,
+    /// computing them on first use
+    pub fn field_list(&self, progress: &ProgressReporter) -> Result<&FieldInfoList> {
+        if let Some(field_list) = self.field_list.get() {
+            return Ok(field_list);
+        }
+
+        let field_list_start = Instant::now();
+        let streams = self.streams()?;
+        let type_finder = streams.type_finder()?;
+        let mut field_list = vec![];
+        let type_count = self.complete_type_list.len();
+        for (type_position, (containing_type_name, type_index)) in
+            self.complete_type_list.iter().enumerate()
+        {
+            progress.check_cancelled()?;
+            progress.report("Indexing fields", type_position, type_count);
+
+            let fields = match type_finder.find((*type_index).into())?.parse() {
+                Ok(pdb::TypeData::Class(data)) => data.fields,
+                Ok(pdb::TypeData::Union(data)) => Some(data.fields),
+                _ => None,
+            };
+            // Walk the field list (and its continuations) looking for data
+            // members
+            let mut next_field_list = fields;
+            while let Some(field_list_index) = next_field_list.take() {
+                let pdb_field_list = match type_finder.find(field_list_index)?.parse() {
+                    Ok(pdb::TypeData::FieldList(data)) => data,
+                    _ => break,
+                };
+                for field in &pdb_field_list.fields {
+                    let data = match field {
+                        pdb::TypeData::Member(data) => data,
+                        _ => continue,
+                    };
+                    let mut needed_types = pdb_types::NeededTypeSet::new();
+                    let field_type_name = match type_name(
+                        type_finder,
+                        &self.forwarder_to_complete_type,
+                        data.field_type,
+                        &PrimitiveReconstructionFlavor::Portable,
+                        &mut needed_types,
+                    ) {
+                        Ok((type_left, type_right)) => format!("{type_left}{type_right}"),
+                        Err(ResymCoreError::PdbError(err)) => {
+                            // Ignore this kind of error since some particular PDB features might not be supported.
+                            log::warn!(
+                                "Failed to resolve type of field {}::{}: {err}",
+                                containing_type_name,
+                                data.name
+                            );
+                            continue;
+                        }
+                        Err(err) => return Err(err),
+                    };
+                    let is_pointer = pdb_types::is_pointer_type(type_finder, data.field_type)
+                        .unwrap_or_default();
+                    field_list.push(FieldInfo {
+                        type_name: containing_type_name.clone(),
+                        type_index: *type_index,
+                        field_name: data.name.to_string().into_owned(),
+                        field_type_name,
+                        offset: data.offset,
+                        is_pointer,
+                    });
+                }
+                next_field_list = pdb_field_list.continuation;
+            }
+        }
+        log::debug!(
+            "Field indexing took {} ms",
+            field_list_start.elapsed().as_millis()
+        );
+
+        Ok(self.field_list.get_or_init(|| field_list))
+    }
+
+    /// Return the size and the definition location of the given types
+    pub fn type_details(&self, type_list: &[(String, TypeIndex)]) -> Result<TypeDetailsList> {
+        let streams = self.streams()?;
+        let type_finder = streams.type_finder()?;
+        let type_definition_locations = self.type_definition_locations()?;
+
+        let type_details = type_list
+            .iter()
+            .map(|(type_name, type_index)| TypeDetails {
+                type_name: type_name.clone(),
+                type_index: *type_index,
+                size: pdb_types::type_size(type_finder, (*type_index).into())
+                    .ok()
+                    .map(|size| size as u64),
+                definition_location: type_definition_locations.get(type_index).cloned(),
+            })
+            .collect();
+
+        Ok(type_details)
+    }
+
+    /// Return the memory layout of the given class, struct or union
+    pub fn type_layout(&self, type_index: TypeIndex) -> Result<TypeLayout> {
+        let streams = self.streams()?;
+        let type_finder = streams.type_finder()?;
+        let complete_type_index = pdb_types::resolve_complete_type_index(
+            &self.forwarder_to_complete_type,
+            type_index.into(),
+        );
+        let (type_name_str, is_union, fields) = match type_finder
+            .find(complete_type_index)?
+            .parse()?
+        {
+            pdb::TypeData::Class(data) => (data.name.to_string().into_owned(), false, data.fields),
+            pdb::TypeData::Union(data) => {
+                (data.name.to_string().into_owned(), true, Some(data.fields))
+            }
+            _ => {
+                return Err(ResymCoreError::InvalidParameterError(format!(
+                    "type {type_index:#x} isn't a class, a struct or a union"
+                )));
+            }
+        };
+        let size = pdb_types::type_size(type_finder, complete_type_index)? as u64;
+
+        // Walk the field list (and its continuations) looking for the members
+        // that occupy memory
+        let mut entries = vec![];
+        let mut next_field_list = fields;
+        while let Some(field_list_index) = next_field_list.take() {
+            let pdb_field_list = match type_finder.find(field_list_index)?.parse() {
+                Ok(pdb::TypeData::FieldList(data)) => data,
+                _ => break,
+            };
+            for field in &pdb_field_list.fields {
+                let (kind, name, offset, entry_type_index) = match field {
+                    pdb::TypeData::Member(data) => (
+                        LayoutEntryKind::Field,
+                        Some(data.name.to_string().into_owned()),
+                        data.offset,
+                        data.field_type,
+                    ),
+                    pdb::TypeData::BaseClass(data) => (
+                        LayoutEntryKind::BaseClass,
+                        None,
+                        u64::from(data.offset),
+                        data.base_class,
+                    ),
+                    pdb::TypeData::VirtualFunctionTablePointer(data) => (
+                        LayoutEntryKind::VirtualTablePointer,
+                        Some("vfptr".to_string()),
+                        0,
+                        data.table,
+                    ),
+                    // Note: virtual base classes are located at runtime
+                    _ => continue,
+                };
+                let entry_type_index = pdb_types::resolve_complete_type_index(
+                    &self.forwarder_to_complete_type,
+                    entry_type_index,
+                );
+                let entry_type_name = if kind == LayoutEntryKind::VirtualTablePointer {
+                    String::default()
+                } else {
+                    let mut needed_types = pdb_types::NeededTypeSet::new();
+                    match type_name(
+                        type_finder,
+                        &self.forwarder_to_complete_type,
+                        entry_type_index,
+                        &PrimitiveReconstructionFlavor::Portable,
+                        &mut needed_types,
+                    ) {
+                        Ok((type_left, type_right)) => format!("{type_left}{type_right}"),
+                        Err(ResymCoreError::PdbError(err)) => {
+                            // Ignore this kind of error since some particular PDB features might not be supported.
+                            log::warn!(
+                                "Failed to resolve type of member of {type_name_str}: {err}"
+                            );
+                            continue;
+                        }
+                        Err(err) => return Err(err),
+                    }
+                };
+
+                // Bit-field members are sized after their allocation unit
+                let (size_type_index, bitfield_info) =
+                    match type_finder.find(entry_type_index)?.parse()? {
+                        pdb::TypeData::Bitfield(data) => {
+                            (data.underlying_type, Some((data.position, data.length)))
+                        }
+                        _ => (entry_type_index, None),
+                    };
+                let entry_size = pdb_types::type_size(type_finder, size_type_index)? as u64;
+
+                let linked_type =
+                    pdb_types::underlying_udt_type_index(type_finder, entry_type_index)?
+                        .map(|udt_type_index| {
+                            pdb_types::resolve_complete_type_index(
+                                &self.forwarder_to_complete_type,
+                                udt_type_index,
+                            )
+                        })
+                        .and_then(|udt_type_index| {
+                            let mut needed_types = pdb_types::NeededTypeSet::new();
+                            type_name(
+                                type_finder,
+                                &self.forwarder_to_complete_type,
+                                udt_type_index,
+                                &PrimitiveReconstructionFlavor::Portable,
+                                &mut needed_types,
+                            )
+                            .ok()
+                            .map(|(udt_type_name, _)| (udt_type_name, udt_type_index.0))
+                        });
+
+                entries.push(LayoutEntry {
+                    kind,
+                    // Base classes are named after their type
+                    name: name.unwrap_or_else(|| entry_type_name.clone()),
+                    type_name: entry_type_name,
+                    linked_type,
+                    offset,
+                    size: entry_size,
+                    bitfield_info,
+                    lane: 0,
+                });
+            }
+            next_field_list = pdb_field_list.continuation;
+        }
+
+        Ok(TypeLayout::new(
+            type_name_str,
+            complete_type_index.0,
+            size,
+            is_union,
+            entries,
+        ))
+    }
+
+    /// Return the source locations at which user-defined types are defined,
+    /// computing them on first use
+    fn type_definition_locations(&self) -> Result<&HashMap<TypeIndex, (String, u32)>> {
+        if let Some(type_definition_locations) = self.type_definition_locations.get() {
+            return Ok(type_definition_locations);
+        }
+
+        let streams = self.streams()?;
+        // Note: the string table is only needed to resolve source file names
+        // referenced by other modules
+        let string_table = streams.with_pdb(|pdb| pdb.string_table()).ok();
+        // Populate our `IdFinder`, used to resolve local source file names,
+        // while looking for the source lines of user-defined types
+        let id_information = streams.with_pdb(|pdb| pdb.id_information())?;
+        let mut id_finder = id_information.finder();
+        let mut type_source_lines = vec![];
+        {
+            let mut id_iter = id_information.iter();
+            while let Some(id) = id_iter.next()? {
+                id_finder.update(&id_iter);
+                if let Ok(pdb::IdData::UserDefinedTypeSource(data)) = id.parse() {
+                    type_source_lines.push(data);
+                }
+            }
+        }
+
+        let mut type_definition_locations = HashMap::new();
+        for type_source_line in type_source_lines {
+            let source_file = match type_source_line.source_file {
+                pdb::UserDefinedTypeSourceFileRef::Local(id_index) => {
+                    match id_finder.find(id_index).and_then(|id| id.parse()) {
+                        Ok(pdb::IdData::String(data)) => Some(data.name.to_string().into_owned()),
+                        _ => None,
+                    }
+                }
+                pdb::UserDefinedTypeSourceFileRef::Remote(_, string_ref) => string_table
+                    .as_ref()
+                    .and_then(|string_table| string_ref.to_string_lossy(string_table).ok())
+                    .map(|file_name| file_name.into_owned()),
+            };
+            if let Some(source_file) = source_file {
+                type_definition_locations
+                    .insert(type_source_line.udt.0, (source_file, type_source_line.line));
+            }
+        }
+
+        Ok(self
+            .type_definition_locations
+            .get_or_init(|| type_definition_locations))
+    }
+
+    /// Retrieve all the locations at which functions have been inlined,
+    /// computing them on first use
+    pub fn inline_sites(&self) -> Result<&InlineSiteList> {
+        if let Some(inline_site_list) = self.inline_site_list.get() {
+            return Ok(inline_site_list);
+        }
+
+        let inline_site_list = self.collect_inline_sites()?;
+        Ok(self.inline_site_list.get_or_init(|| inline_site_list))
+    }
+
+    /// Retrieve all the locations at which the given function has been inlined
+    pub fn get_call_sites_for_inlinee(&self, function_name: &str) -> Result<InlineSiteList> {
+        Ok(self
+            .inline_sites()?
+            .iter()
+            .filter(|inline_site| inline_site.inlinee_name == function_name)
+            .cloned()
+            .collect())
+    }
+
+    /// Retrieve all the functions that have been inlined into the given function
+    pub fn get_inlinees_for_function(&self, function_name: &str) -> Result<InlineSiteList> {
+        Ok(self
+            .inline_sites()?
+            .iter()
+            .filter(|inline_site| inline_site.caller_name == function_name)
+            .cloned()
+            .collect())
+    }
+
+    fn collect_inline_sites(&self) -> Result<InlineSiteList> {
+        let streams = self.streams()?;
+        let type_finder = streams.type_finder()?;
+
+        // Populate our `IdFinder`, used to resolve inlinees' names
+        let id_information = streams.with_pdb(|pdb| pdb.id_information())?;
+        let mut id_finder = id_information.finder();
+        {
+            let mut id_iter = id_information.iter();
+            while (id_iter.next()?).is_some() {
+                id_finder.update(&id_iter);
+            }
+        }
+        // Note: the string table is only needed to resolve source file names
+        let string_table = streams.with_pdb(|pdb| pdb.string_table()).ok();
+
+        let mut inline_sites = vec![];
+        let mut inlinee_names: HashMap<pdb::IdIndex, String> = HashMap::new();
+        let mut modules = streams.debug_information.modules()?.enumerate();
+        while let Some((module_index, module)) = modules.next()? {
+            let module_info = match streams.with_pdb(|pdb| pdb.module_info(&module))? {
+                Some(info) => info,
+                None => {
+                    continue;
+                }
+            };
+            let line_program = module_info.line_program().ok();
+            let inlinees: HashMap<pdb::IdIndex, pdb::Inlinee> = module_info
+                .inlinees()?
+                .map(|inlinee| Ok((inlinee.index(), inlinee)))
+                .collect()?;
+            if inlinees.is_empty() {
+                // No inline sites in this module
+                continue;
+            }
+
+            // Scopes opened by the symbols visited so far (e.g., procedures,
+            // blocks or inline sites), along with the procedure each of them
+            // belongs to, if any.
+            // Note: nested inline sites are attributed to the procedure their
+            // code has been generated in.
+            let mut scope_stack: Vec<Option<(pdb::ProcedureSymbol, SymbolIndex)>> = vec![];
+            let mut module_symbols = module_info.symbols()?;
+            while let Some(symbol) = module_symbols.next()? {
+                let symbol_data = symbol.parse();
+                if symbol.ends_scope() {
+                    scope_stack.pop();
+                } else if symbol.starts_scope() {
+                    let scope_procedure = match &symbol_data {
+                        Ok(pdb::SymbolData::Procedure(procedure)) => {
+                            Some((*procedure, (module_index, symbol.index().0)))
+                        }
+                        _ => scope_stack.last().copied().flatten(),
+                    };
+                    scope_stack.push(scope_procedure);
+                }
+
+                if let Ok(pdb::SymbolData::InlineSite(inline_site)) = symbol_data {
+                    // Note: the inline site's own scope has just been pushed
+                    let (procedure, procedure_index) = match scope_stack.last() {
+                        Some(Some(current_procedure)) => current_procedure,
+                        _ => {
+                            continue;
+                        }
+                    };
+                    let inlinee_name = inlinee_names
+                        .entry(inline_site.inlinee)
+                        .or_insert_with(|| {
+                            inlinee_name(
+                                &id_finder,
+                                type_finder,
+                                &self.forwarder_to_complete_type,
+                                inline_site.inlinee,
+                            )
+                        })
+                        .clone();
+
+                    // Gather code ranges and source location from the
+                    // inlinee's line information
+                    let mut ranges = vec![];
+                    let mut first_line: Option<pdb::LineInfo> = None;
+                    if let Some(inlinee) = inlinees.get(&inline_site.inlinee) {
+                        let mut lines = inlinee.lines(procedure.offset, &inline_site);
+                        while let Some(line_info) = lines.next()? {
+                            if let Some(rva) = symbol_rva(&line_info.offset, &self.sections) {
+                                ranges.push(InlineSiteRange {
+                                    rva,
+                                    length: line_info.length.unwrap_or_default(),
+                                });
+                            }
+                            match &first_line {
+                                Some(first_line) if first_line.offset <= line_info.offset => {}
+                                _ => first_line = Some(line_info),
+                            }
+                        }
+                    }
+                    let source_file = first_line.as_ref().and_then(|line_info| {
+                        source_file_name(
+                            line_program.as_ref()?,
+                            string_table.as_ref()?,
+                            line_info.file_index,
+                        )
+                    });
+
+                    inline_sites.push(InlineSite {
+                        inlinee_name,
+                        caller_name: procedure.name.to_string().into_owned(),
+                        caller_index: *procedure_index,
+                        ranges: merge_inline_site_ranges(ranges),
+                        source_file,
+                        source_line: first_line.as_ref().map(|line_info| line_info.line_start),
+                    });
+                }
+            }
+        }
+
+        Ok(inline_sites)
+    }
+
     fn type_list_from_type_indices(&self, type_indices: &[TypeIndex]) -> TypeList {
         par_iter_if_available!(self.complete_type_list)
             .filter_map(|(type_name, type_index)| {
@@ -1030,6 +1842,203 @@ where
             .collect()
     }
 
+    /// Information taken from the PE image (if loaded) about the function
+    /// located at the given offset (e.g., export ordinal)
+    fn image_info_for_function(&self, offset: &pdb::PdbInternalSectionOffset) -> String {
+        let pe_file = self.pe_file();
+        let export = pe_file
+            .as_ref()
+            .and_then(|pe_file| pe_file.export_at_rva(symbol_rva(offset, &self.sections)?));
+        match export {
+            Some(export) => format!(" Export=#{}", export.ordinal),
+            None => String::default(),
+        }
+    }
+
+    /// Information taken from the PE image (if loaded) about the variable
+    /// located at the given offset (e.g., export ordinal or imported symbol)
+    fn image_info_for_data(&self, offset: &pdb::PdbInternalSectionOffset) -> String {
+        let (pe_file, rva) = match (self.pe_file(), symbol_rva(offset, &self.sections)) {
+            (Some(pe_file), Some(rva)) => (pe_file, rva),
+            _ => {
+                return String::default();
+            }
+        };
+
+        if let Some(import) = pe_file.import_at_iat_rva(rva) {
+            match (&import.name, import.ordinal) {
+                (Some(import_name), _) => format!(" Import={}!{}", import.module_name, import_name),
+                (None, Some(ordinal)) => format!(" Import={}!#{}", import.module_name, ordinal),
+                (None, None) => format!(" Import={}", import.module_name),
+            }
+        } else if let Some(export) = pe_file.export_at_rva(rva) {
+            format!(" Export=#{}", export.ordinal)
+        } else {
+            String::default()
+        }
+    }
+
+    /// Information taken from the PE image (if loaded) about the constant
+    /// located at the given offset (i.e., its value)
+    fn image_info_for_constant(
+        &self,
+        type_finder: &pdb::TypeFinder<'_>,
+        offset: &pdb::PdbInternalSectionOffset,
+        type_index: pdb::TypeIndex,
+    ) -> String {
+        /// Maximum number of bytes dumped for a single constant
+        const MAX_CONSTANT_SIZE: usize = 64;
+
+        let mut image_info = self.image_info_for_data(offset);
+        let pe_file = self.pe_file();
+        let constant_bytes = pe_file.as_ref().and_then(|pe_file| {
+            let constant_size = pdb_types::type_size(type_finder, type_index).ok()?;
+            pe_file.read_bytes_at_rva(
+                symbol_rva(offset, &self.sections)?,
+                std::cmp::min(constant_size, MAX_CONSTANT_SIZE),
+            )
+        });
+        if let Some(constant_bytes) = constant_bytes {
+            let _r = write!(&mut image_info, " Value={}", format_bytes(constant_bytes));
+        }
+
+        image_info
+    }
+
+    /// Information taken from the PE image (if loaded) about the public data
+    /// symbol located at the given offset (e.g., string literals or vtables)
+    fn image_info_for_public_data(
+        &self,
+        offset: &pdb::PdbInternalSectionOffset,
+        symbol_name: &str,
+    ) -> String {
+        /// Maximum number of entries dumped for a single vtable
+        const MAX_VTABLE_ENTRIES: u32 = 1024;
+
+        let mut image_info = self.image_info_for_data(offset);
+        let (pe_file, rva) = match (self.pe_file(), symbol_rva(offset, &self.sections)) {
+            (Some(pe_file), Some(rva)) => (pe_file, rva),
+            _ => {
+                return image_info;
+            }
+        };
+
+        if let Some(char_size) = string_literal_char_size(symbol_name) {
+            // String literals
+            if let Some(string) = pe_file.read_string_at_rva(rva, char_size) {
+                let _r = write!(&mut image_info, " Value={:?}", string);
+            }
+        } else if symbol_name.starts_with("??_7") {
+            // Virtual function tables
+            let function_names_by_rva = self.function_names_by_rva();
+            for entry_index in 0..MAX_VTABLE_ENTRIES {
+                let function_rva = (entry_index * pe_file.pointer_size)
+                    .checked_add(rva)
+                    .and_then(|entry_rva| pe_file.read_pointer_at_rva(entry_rva));
+                match function_rva {
+                    Some(function_rva) if pe_file.is_executable_rva(function_rva) => {
+                        let _r = write!(
+                            &mut image_info,
+                            "\n//   [{}] RVA=0x{:x} {}",
+                            entry_index,
+                            function_rva,
+                            function_names_by_rva
+                                .get(&function_rva)
+                                .map(String::as_str)
+                                .unwrap_or("(unknown function)"),
+                        );
+                    }
+                    // End of the table
+                    _ => break,
+                }
+            }
+        }
+
+        image_info
+    }
+
+    /// Return a map of function names, indexed by address. Used to resolve
+    /// vtable entries.
+    fn function_names_by_rva(&self) -> HashMap<u32, String> {
+        // Generate function name cache if needed
+        if self
+            .function_names_by_rva
+            .read()
+            .expect("lock shouldn't be poisoned")
+            .is_none()
+        {
+            let function_names_by_rva = self.collect_function_names_by_rva().unwrap_or_default();
+            if let Ok(mut function_names_by_rva_ref) = self.function_names_by_rva.write() {
+                *function_names_by_rva_ref = Some(function_names_by_rva);
+            }
+        }
+
+        self.function_names_by_rva
+            .read()
+            .expect("lock shouldn't be poisoned")
+            .clone()
+            .unwrap_or_default()
+    }
+
+    fn collect_function_names_by_rva(&self) -> Result<HashMap<u32, String>> {
+        let mut function_names_by_rva = HashMap::new();
+        let mut trampolines = vec![];
+
+        // Public symbols
+        let streams = self.streams()?;
+        let mut symbol_table = streams.global_symbols.iter();
+        while let Some(symbol) = symbol_table.next()? {
+            if let Ok(pdb::SymbolData::Public(data)) = symbol.parse() {
+                if let (true, Some(rva)) = (data.function, symbol_rva(&data.offset, &self.sections))
+                {
+                    let function_name = demangle_symbol_name(data.name.to_string(), false)
+                        .unwrap_or_else(|| data.name.to_string().into_owned());
+                    function_names_by_rva.insert(rva, function_name);
+                }
+            }
+        }
+
+        // Modules' private symbols (i.e., procedures and trampolines)
+        {
+            let mut modules = streams.debug_information.modules()?;
+            while let Some(module) = modules.next()? {
+                if let Some(module_info) = streams.with_pdb(|pdb| pdb.module_info(&module))? {
+                    let mut module_symbols = module_info.symbols()?;
+                    while let Some(symbol) = module_symbols.next()? {
+                        match symbol.parse() {
+                            Ok(pdb::SymbolData::Procedure(procedure)) => {
+                                if let Some(rva) = symbol_rva(&procedure.offset, &self.sections) {
+                                    function_names_by_rva
+                                        .insert(rva, procedure.name.to_string().into_owned());
+                                }
+                            }
+                            Ok(pdb::SymbolData::Trampoline(trampoline)) => {
+                                trampolines.push((trampoline.thunk, trampoline.target));
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+        }
+
+        // Resolve incremental linking thunks to their targets' names
+        for (thunk_offset, target_offset) in trampolines {
+            if let (Some(thunk_rva), Some(target_rva)) = (
+                symbol_rva(&thunk_offset, &self.sections),
+                symbol_rva(&target_offset, &self.sections),
+            ) {
+                if let Some(target_name) = function_names_by_rva.get(&target_rva).cloned() {
+                    function_names_by_rva
+                        .entry(thunk_rva)
+                        .or_insert(target_name);
+                }
+            }
+        }
+
+        Ok(function_names_by_rva)
+    }
+
     fn reconstruct_symbol(
         &self,
         type_finder: &pdb::ItemFinder<'_, pdb::TypeIndex>,
@@ -1074,21 +2083,23 @@ where
                     &mut needed_types,
                 ) {
                     let static_prefix = if procedure.global { "" } else { "static " };
+                    let image_info = self.image_info_for_function(&procedure.offset);
                     if type_name.0 == "..." {
                         // No type
                         Some(format!(
-                            "{}void {}(); // {}CodeSize=0x{:x} (missing type information)",
-                            static_prefix, procedure.name, symbol_rva, procedure.len,
+                            "{}void {}(); // {}CodeSize=0x{:x}{} (missing type information)",
+                            static_prefix, procedure.name, symbol_rva, procedure.len, image_info,
                         ))
                     } else {
                         Some(format!(
-                            "{}{}{}{}; // {}CodeSize=0x{:x}",
+                            "{}{}{}{}; // {}CodeSize=0x{:x}{}",
                             static_prefix,
                             type_name.0,
                             procedure.name,
                             type_name.1,
                             symbol_rva,
                             procedure.len,
+                            image_info,
                         ))
                     }
                 } else {
@@ -1109,6 +2120,14 @@ where
                     &mut needed_types,
                 ) {
                     let static_prefix = if data.global { "" } else { "static " };
+                    // Only dump the value of constants, as the content of
+                    // other variables is only relevant at runtime
+                    let image_info = if type_name.0.starts_with("const ") {
+                        self.image_info_for_constant(type_finder, &data.offset, data.type_index)
+                    } else {
+                        self.image_info_for_data(&data.offset)
+                    };
+                    let symbol_rva = append_image_info(symbol_rva, &image_info);
                     if let Some(demangled_symbol) =
                         demangle_symbol_name(data.name.to_string(), print_access_specifiers)
                     {
@@ -1133,6 +2152,128 @@ where
                 }
             }
 
+            // Constants
+            pdb::SymbolData::Constant(constant) => {
+                if let Ok(type_name) = type_name(
+                    type_finder,
+                    &self.forwarder_to_complete_type,
+                    constant.type_index,
+                    &primitives_flavor,
+                    &mut needed_types,
+                ) {
+                    if type_name.0 == "..." {
+                        // No type
+                        Some(format!(
+                            "constexpr auto {} = {}; // (missing type information)",
+                            constant.name, constant.value,
+                        ))
+                    } else {
+                        // `constexpr` implies `const`
+                        let type_left = type_name.0.strip_prefix("const ").unwrap_or(&type_name.0);
+                        let value = if pdb_types::is_enum_type(type_finder, constant.type_index)
+                            .unwrap_or_default()
+                        {
+                            // Integers can't be implicitly converted to enums
+                            format!("static_cast<{}>({})", type_left, constant.value)
+                        } else {
+                            constant_value_literal(type_left, &constant.value)
+                        };
+                        Some(format!(
+                            "constexpr {} {}{} = {};",
+                            type_left, constant.name, type_name.1, value,
+                        ))
+                    }
+                } else {
+                    None
+                }
+            }
+
+            // Thread-local variables
+            pdb::SymbolData::ThreadStorage(thread_storage) => {
+                let tls_offset = format!("TLS offset=0x{:x}", thread_storage.offset.offset);
+                if let Ok(type_name) = type_name(
+                    type_finder,
+                    &self.forwarder_to_complete_type,
+                    thread_storage.type_index,
+                    &primitives_flavor,
+                    &mut needed_types,
+                ) {
+                    let static_prefix = if thread_storage.global { "" } else { "static " };
+                    if type_name.0 == "..." {
+                        // No type
+                        Some(format!(
+                            "{}thread_local char {}; // {} (missing type information)",
+                            static_prefix, thread_storage.name, tls_offset,
+                        ))
+                    } else {
+                        Some(format!(
+                            "{}thread_local {} {}{}; // {}",
+                            static_prefix,
+                            type_name.0,
+                            thread_storage.name,
+                            type_name.1,
+                            tls_offset,
+                        ))
+                    }
+                } else {
+                    None
+                }
+            }
+
+            // Thunks
+            pdb::SymbolData::Thunk(thunk) => {
+                let symbol_rva = symbol_rva(&thunk.offset, &self.sections)
+                    .map(|offset| format!("RVA=0x{:x} ", offset))
+                    .unwrap_or_default();
+                let thunk_kind = match thunk.kind {
+                    pdb::ThunkKind::Adjustor(_) => "this adjustor thunk".to_string(),
+                    pdb::ThunkKind::VCall(vtable_offset) => {
+                        format!("vcall thunk, vtable offset=0x{:x}", vtable_offset)
+                    }
+                    pdb::ThunkKind::PCode => "pcode thunk".to_string(),
+                    pdb::ThunkKind::Load => "delay load thunk".to_string(),
+                    _ => "thunk".to_string(),
+                };
+                Some(format!(
+                    "void {}(); // {}CodeSize=0x{:x} ({})",
+                    thunk.name, symbol_rva, thunk.len, thunk_kind,
+                ))
+            }
+
+            // Trampolines
+            pdb::SymbolData::Trampoline(trampoline) => {
+                let target_rva = symbol_rva(&trampoline.target, &self.sections)
+                    .map(|offset| format!(" to RVA=0x{:x}", offset))
+                    .unwrap_or_default();
+                let symbol_rva = symbol_rva(&trampoline.thunk, &self.sections)
+                    .map(|offset| format!("RVA=0x{:x} ", offset))
+                    .unwrap_or_default();
+                let trampoline_type = match trampoline.tramp_type {
+                    pdb::TrampolineType::Incremental => "incremental trampoline",
+                    pdb::TrampolineType::BranchIsland => "branch island",
+                    _ => "trampoline",
+                };
+                Some(format!(
+                    "void {}(); // {}CodeSize=0x{:x} ({}{})",
+                    trampoline_name(&trampoline),
+                    symbol_rva,
+                    trampoline.size,
+                    trampoline_type,
+                    target_rva,
+                ))
+            }
+
+            // Code labels
+            pdb::SymbolData::Label(label) => {
+                if is_compiler_generated_label(&label.name.to_string()) {
+                    return None;
+                }
+                let symbol_rva = symbol_rva(&label.offset, &self.sections)
+                    .map(|offset| format!("RVA=0x{:x} ", offset))
+                    .unwrap_or_default();
+                Some(format!("{}:; // {}(label)", label.name, symbol_rva))
+            }
+
             pdb::SymbolData::UsingNamespace(namespace) => {
                 Some(format!("using namespace {};", namespace.name))
             }
@@ -1145,9 +2286,15 @@ where
 
             // Public symbols
             pdb::SymbolData::Public(data) => {
+                let image_info = if data.function {
+                    self.image_info_for_function(&data.offset)
+                } else {
+                    self.image_info_for_public_data(&data.offset, &data.name.to_string())
+                };
                 let symbol_rva = symbol_rva(&data.offset, &self.sections)
                     .map(|offset| format!("RVA=0x{:x} ", offset))
                     .unwrap_or_default();
+                let symbol_rva = append_image_info(symbol_rva, &image_info);
                 Some(
                     if let Some(demangled_symbol) =
                         demangle_symbol_name(data.name.to_string(), print_access_specifiers)
@@ -1236,6 +2383,31 @@ fn compute_type_depth_map(
     inverted_type_depth_map
 }
 
+/// Format the value of a constant as a C++ literal of the given type
+fn constant_value_literal(type_name: &str, value: &pdb::Variant) -> String {
+    if type_name != "bool" {
+        return value.to_string();
+    }
+
+    let is_true = match *value {
+        pdb::Variant::U8(v) => v != 0,
+        pdb::Variant::U16(v) => v != 0,
+        pdb::Variant::U32(v) => v != 0,
+        pdb::Variant::U64(v) => v != 0,
+        pdb::Variant::I8(v) => v != 0,
+        pdb::Variant::I16(v) => v != 0,
+        pdb::Variant::I32(v) => v != 0,
+        pdb::Variant::I64(v) => v != 0,
+    };
+    is_true.to_string()
+}
+
+/// Indicate if the given label has been generated by the compiler (e.g.,
+/// `$LN5` or `$LN5@function`)
+fn is_compiler_generated_label(label_name: &str) -> bool {
+    label_name.starts_with('$')
+}
+
 fn get_symbol_name(symbol: &pdb::Symbol) -> Option<String> {
     const UNNAMED_CONSTANT_PREFIXES: [&str; 5] = ["`", "??_", "__@@_PchSym_", "__real@", "__xmm@"];
     const UNNAMED_CONSTANT_SUFFIXES: [&str; 1] = ["@@9@9"];
@@ -1255,6 +2427,20 @@ fn get_symbol_name(symbol: &pdb::Symbol) -> Option<String> {
         // Exported symbols
         pdb::SymbolData::Export(data) => Some(data.name.to_string().to_string()),
 
+        // Constants
+        pdb::SymbolData::Constant(constant) => Some(constant.name.to_string().to_string()),
+
+        // Thread-local variables
+        pdb::SymbolData::ThreadStorage(thread_storage) => {
+            Some(thread_storage.name.to_string().to_string())
+        }
+
+        // Thunks, trampolines and code labels
+        pdb::SymbolData::Thunk(thunk) => Some(thunk.name.to_string().to_string()),
+        pdb::SymbolData::Trampoline(trampoline) => Some(trampoline_name(&trampoline)),
+        pdb::SymbolData::Label(label) => Some(label.name.to_string().to_string())
+            .filter(|label_name| !is_compiler_generated_label(label_name)),
+
         _ => {
             // ignore everything else
             None
@@ -1277,6 +2463,47 @@ fn get_symbol_name(symbol: &pdb::Symbol) -> Option<String> {
     })
 }
 
+/// Append information taken from the PE image (e.g., ` Export=#1`) to a
+/// symbol's location (e.g., `RVA=0x1000 `), keeping the trailing separator
+fn append_image_info(symbol_location: String, image_info: &str) -> String {
+    if image_info.is_empty() {
+        symbol_location
+    } else {
+        format!("{}{} ", symbol_location.trim_end(), image_info)
+    }
+}
+
+/// Return the size of characters of a string literal (i.e., `??_C@_0` for
+/// `char` and `??_C@_1` for `wchar_t`), given its mangled name
+fn string_literal_char_size(symbol_name: &str) -> Option<usize> {
+    if symbol_name.starts_with("??_C@_0") {
+        Some(1)
+    } else if symbol_name.starts_with("??_C@_1") {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+/// Format bytes as a list of hexadecimal values (e.g., `[2a 00 00 00]`)
+fn format_bytes(bytes: &[u8]) -> String {
+    let bytes = bytes
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    format!("[{}]", bytes)
+}
+
+/// Trampolines are unnamed, generate a name from their location
+fn trampoline_name(trampoline: &pdb::TrampolineSymbol) -> String {
+    format!(
+        "__trampoline_{:x}_{:x}",
+        trampoline.thunk.section, trampoline.thunk.offset
+    )
+}
+
 fn symbol_rva(
     symbol_offset: &pdb::PdbInternalSectionOffset,
     sections: &[pdb::ImageSectionHeader],
@@ -1292,6 +2519,101 @@ fn symbol_rva(
     }
 }
 
+/// Find the module that contains the given offset, from a list of section
+/// contributions sorted by offset.
+fn module_index_from_section_contributions(
+    sorted_section_contributions: &[pdb::DBISectionContribution],
+    symbol_offset: &pdb::PdbInternalSectionOffset,
+) -> Option<ModuleIndex> {
+    let key = (symbol_offset.section, symbol_offset.offset);
+    let contribution_pos = sorted_section_contributions.partition_point(|contribution| {
+        (contribution.offset.section, contribution.offset.offset) <= key
+    });
+
+    // Check the last contribution that starts before the symbol
+    let contribution = sorted_section_contributions.get(contribution_pos.checked_sub(1)?)?;
+    if contribution.offset.section == symbol_offset.section
+        && symbol_offset.offset - contribution.offset.offset < contribution.size
+    {
+        Some(contribution.module)
+    } else {
+        None
+    }
+}
+
+/// Find the source file a procedure has been compiled from, using its
+/// module's line information.
+fn procedure_source_file(
+    line_program: &pdb::LineProgram,
+    string_table: &pdb::StringTable,
+    procedure_offset: pdb::PdbInternalSectionOffset,
+) -> Option<String> {
+    let line_info = line_program
+        .lines_for_symbol(procedure_offset)
+        .next()
+        .ok()??;
+
+    source_file_name(line_program, string_table, line_info.file_index)
+}
+
+/// Resolve the path of a source file referenced by a module's line information
+fn source_file_name(
+    line_program: &pdb::LineProgram,
+    string_table: &pdb::StringTable,
+    file_index: pdb::FileIndex,
+) -> Option<String> {
+    let file_info = line_program.get_file_info(file_index).ok()?;
+
+    file_info
+        .name
+        .to_string_lossy(string_table)
+        .ok()
+        .map(|file_name| file_name.into_owned())
+}
+
+/// Resolve the qualified name of an inlined function, given its IPI record
+/// (i.e., `LF_FUNC_ID` or `LF_MFUNC_ID`)
+fn inlinee_name(
+    id_finder: &pdb::ItemFinder<'_, pdb::IdIndex>,
+    type_finder: &pdb::ItemFinder<'_, pdb::TypeIndex>,
+    type_forwarder: &pdb_types::TypeForwarder,
+    id_index: pdb::IdIndex,
+) -> String {
+    let id_data = id_finder.find(id_index).and_then(|id| id.parse());
+    match id_data {
+        Ok(pdb::IdData::Function(function)) => {
+            // Functions are scoped by a string (e.g., their namespace)
+            let scope = function.scope.and_then(|scope| {
+                match id_finder.find(scope).and_then(|id| id.parse()) {
+                    Ok(pdb::IdData::String(scope_string)) => {
+                        Some(scope_string.name.to_string().into_owned())
+                    }
+                    _ => None,
+                }
+            });
+            match scope {
+                Some(scope) => format!("{}::{}", scope, function.name),
+                None => function.name.to_string().into_owned(),
+            }
+        }
+        Ok(pdb::IdData::MemberFunction(member_function)) => {
+            // Member functions are scoped by their class
+            let mut needed_types = pdb_types::NeededTypeSet::new();
+            match type_name(
+                type_finder,
+                type_forwarder,
+                member_function.parent,
+                &PrimitiveReconstructionFlavor::Portable,
+                &mut needed_types,
+            ) {
+                Ok((class_name, _)) => format!("{}::{}", class_name, member_function.name),
+                Err(_) => member_function.name.to_string().into_owned(),
+            }
+        }
+        _ => format!("inlinee_0x{:x}", id_index.0),
+    }
+}
+
 fn demangle_symbol_name(
     symbol_name: impl AsRef<str>,
     print_access_specifiers: bool,
@@ -1320,11 +2642,17 @@ fn symbol_priority(symbol: &pdb::Symbol) -> u16 {
             // Functions and methods, user types, global variables
             pdb::SymbolData::Procedure(_)
             | pdb::SymbolData::UserDefinedType(_)
-            | pdb::SymbolData::Data(_) => 0,
+            | pdb::SymbolData::Data(_)
+            | pdb::SymbolData::Constant(_)
+            | pdb::SymbolData::ThreadStorage(_) => 0,
             // Public symbols
             pdb::SymbolData::Public(_) => 1,
             // Exported symbols
             pdb::SymbolData::Export(_) => 2,
+            // Thunks, trampolines and code labels
+            pdb::SymbolData::Thunk(_)
+            | pdb::SymbolData::Trampoline(_)
+            | pdb::SymbolData::Label(_) => 3,
             _ => 10,
         }
     } else {
@@ -0,0 +1,280 @@
+//! Access to the streams of PDB files from multiple threads.
+//!
+//! `pdb`'s streams (and the parsers built on top of them) can't be sent to or
+//! shared with other threads. Instead, each thread opens its own streams, from
+//! a source that is shared by all threads and which hands out the same views
+//! of the file's content to all of them, so that the data isn't duplicated.
+//! The index used to look up type records is built once and shared by all
+//! threads.
+
+use pdb::FallibleIterator;
+use self_cell::self_cell;
+
+#[cfg(target_arch = "wasm32")]
+use instant::Instant;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt,
+    io::{self, Read, Seek},
+    rc::Rc,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, OnceLock, Weak,
+    },
+};
+
+use crate::{error::Result, pdb_file::PDBDataSource};
+
+/// Identifier given to each `PdbStreamCache`, used to index the streams opened
+/// by each thread
+static NEXT_PDB_STREAM_CACHE_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Streams opened by a thread, indexed by cache identifier, along with a weak
+/// reference used to detect when caches are dropped
+type ThreadPdbStreams = HashMap<u64, (Weak<()>, Rc<PdbStreams>)>;
+
+/// Views of a PDB file, indexed by the location of their pages
+type PdbViewMap = HashMap<Vec<(u64, usize)>, Weak<Vec<u8>>>;
+
+thread_local! {
+    static THREAD_PDB_STREAMS: RefCell<ThreadPdbStreams> = RefCell::new(HashMap::new());
+}
+
+/// Source of PDB data that can be shared between threads.
+/// Views of the same pages are only read once, and shared by all threads
+/// for as long as they're in use.
+#[derive(Clone)]
+pub(crate) struct SharedPdbSource {
+    data_source: Arc<Mutex<PDBDataSource>>,
+    /// Views handed out so far
+    views: Arc<Mutex<PdbViewMap>>,
+}
+
+impl SharedPdbSource {
+    fn new(data_source: PDBDataSource) -> Self {
+        Self {
+            data_source: Arc::new(Mutex::new(data_source)),
+            views: Default::default(),
+        }
+    }
+
+    fn read_slices(&self, slices: &[pdb::SourceSlice]) -> io::Result<Vec<u8>> {
+        let mut data_source = self.data_source.lock().expect("lock shouldn't be poisoned");
+        let mut bytes = vec![0; slices.iter().map(|slice| slice.size).sum()];
+        let mut output_offset = 0;
+        for slice in slices {
+            data_source.seek(io::SeekFrom::Start(slice.offset))?;
+            data_source.read_exact(&mut bytes[output_offset..output_offset + slice.size])?;
+            output_offset += slice.size;
+        }
+
+        Ok(bytes)
+    }
+}
+
+impl fmt::Debug for SharedPdbSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedPdbSource")
+            .field("data_source", &self.data_source)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'s> pdb::Source<'s> for SharedPdbSource {
+    fn view(&mut self, slices: &[pdb::SourceSlice]) -> io::Result<Box<dyn pdb::SourceView<'s>>> {
+        let view_key: Vec<(u64, usize)> = slices
+            .iter()
+            .map(|slice| (slice.offset, slice.size))
+            .collect();
+        let cached_view = self
+            .views
+            .lock()
+            .expect("lock shouldn't be poisoned")
+            .get(&view_key)
+            .and_then(Weak::upgrade);
+        if let Some(bytes) = cached_view {
+            return Ok(Box::new(SharedView(bytes)));
+        }
+
+        // Note: the lock isn't held while reading, views requested
+        // concurrently might thus be read more than once
+        let bytes = Arc::new(self.read_slices(slices)?);
+        let mut views = self.views.lock().expect("lock shouldn't be poisoned");
+        // Forget about views that aren't used anymore
+        views.retain(|_, view| view.strong_count() > 0);
+        views.insert(view_key, Arc::downgrade(&bytes));
+
+        Ok(Box::new(SharedView(bytes)))
+    }
+}
+
+/// View of the content of a `SharedPdbSource`
+struct SharedView(Arc<Vec<u8>>);
+
+impl fmt::Debug for SharedView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SharedView({} bytes)", self.0.len())
+    }
+}
+
+impl pdb::SourceView<'_> for SharedView {
+    fn as_slice(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+/// TPI stream opened from a `SharedPdbSource`
+struct SharedTypeInformation(pdb::TypeInformation<'static>);
+
+// SAFETY: `pdb::TypeInformation` is neither `Send` nor `Sync` only because it
+// holds its data as a `Box<dyn SourceView>`, and it's only ever accessed
+// through shared references. Views handed out by `SharedPdbSource` are
+// `SharedView`s, which are immutable and both `Send` and `Sync`.
+unsafe impl Send for SharedTypeInformation {}
+unsafe impl Sync for SharedTypeInformation {}
+
+type TypeRecordFinder<'t> = pdb::TypeFinder<'t>;
+
+self_cell!(
+    /// Index used to look up type records by type index, along with the TPI
+    /// stream it references
+    struct TypeFinderCell {
+        owner: SharedTypeInformation,
+
+        #[covariant]
+        dependent: TypeRecordFinder,
+    }
+);
+
+/// Index used to look up type records, built on first use and shared by all
+/// threads
+#[derive(Default)]
+struct SharedTypeFinder {
+    type_finder: OnceLock<TypeFinderCell>,
+    /// Held while building the index, so that it's only built once
+    build_lock: Mutex<()>,
+}
+
+/// Streams of a PDB file, opened by a single thread
+pub(crate) struct PdbStreams {
+    pdb: RefCell<pdb::PDB<'static, SharedPdbSource>>,
+    pub type_information: pdb::TypeInformation<'static>,
+    pub debug_information: pdb::DebugInformation<'static>,
+    pub global_symbols: pdb::SymbolTable<'static>,
+    type_finder: Arc<SharedTypeFinder>,
+}
+
+impl PdbStreams {
+    fn open(source: SharedPdbSource, type_finder: Arc<SharedTypeFinder>) -> Result<Self> {
+        let mut pdb = pdb::PDB::open(source)?;
+        let type_information = pdb.type_information()?;
+        let debug_information = pdb.debug_information()?;
+        let global_symbols = pdb.global_symbols()?;
+
+        Ok(Self {
+            pdb: RefCell::new(pdb),
+            type_information,
+            debug_information,
+            global_symbols,
+            type_finder,
+        })
+    }
+
+    /// Give access to the underlying `pdb::PDB`, to open other streams.
+    /// Note: `f` shouldn't access `self` (the `pdb::PDB` is borrowed mutably).
+    pub fn with_pdb<R>(
+        &self,
+        f: impl FnOnce(&mut pdb::PDB<'static, SharedPdbSource>) -> pdb::Result<R>,
+    ) -> Result<R> {
+        Ok(f(&mut self.pdb.borrow_mut())?)
+    }
+
+    /// Return the index used to look up type records by type index. The index
+    /// is built on first use (by any thread), which requires going through
+    /// the whole TPI stream once.
+    pub fn type_finder(&self) -> Result<&pdb::TypeFinder<'_>> {
+        let shared_type_finder = &self.type_finder;
+        if let Some(type_finder) = shared_type_finder.type_finder.get() {
+            return Ok(type_finder.borrow_dependent());
+        }
+
+        let _build_guard = shared_type_finder
+            .build_lock
+            .lock()
+            .expect("lock shouldn't be poisoned");
+        // Note: the index might have been built while waiting for the lock
+        if let Some(type_finder) = shared_type_finder.type_finder.get() {
+            return Ok(type_finder.borrow_dependent());
+        }
+
+        let type_finder_start = Instant::now();
+        let type_information = self.with_pdb(|pdb| pdb.type_information())?;
+        let type_finder = TypeFinderCell::try_new(
+            SharedTypeInformation(type_information),
+            |type_information| {
+                let type_information = &type_information.0;
+                let mut type_finder = type_information.finder();
+                let mut type_iter = type_information.iter();
+                while (type_iter.next()?).is_some() {
+                    type_finder.update(&type_iter);
+                }
+
+                Ok::<_, pdb::Error>(type_finder)
+            },
+        )?;
+        log::debug!(
+            "Type index building took {} ms",
+            type_finder_start.elapsed().as_millis()
+        );
+
+        Ok(shared_type_finder
+            .type_finder
+            .get_or_init(|| type_finder)
+            .borrow_dependent())
+    }
+}
+
+/// Streams of a PDB file, opened on demand by each thread accessing the file
+pub(crate) struct PdbStreamCache {
+    id: u64,
+    source: SharedPdbSource,
+    type_finder: Arc<SharedTypeFinder>,
+    /// Only used to let threads know when the cache has been dropped
+    alive: Arc<()>,
+}
+
+impl PdbStreamCache {
+    pub fn new(data_source: PDBDataSource) -> Self {
+        Self {
+            id: NEXT_PDB_STREAM_CACHE_ID.fetch_add(1, Ordering::Relaxed),
+            source: SharedPdbSource::new(data_source),
+            type_finder: Default::default(),
+            alive: Arc::new(()),
+        }
+    }
+
+    /// Return the streams opened by the current thread, opening them if
+    /// needed
+    pub fn get(&self) -> Result<Rc<PdbStreams>> {
+        THREAD_PDB_STREAMS.with(|thread_streams| {
+            let mut thread_streams = thread_streams.borrow_mut();
+            // Close streams of PDB files that have been dropped, so that their
+            // content is freed
+            thread_streams.retain(|_, (alive, _)| alive.strong_count() > 0);
+            if let Some((_, streams)) = thread_streams.get(&self.id) {
+                return Ok(streams.clone());
+            }
+
+            let streams = Rc::new(PdbStreams::open(
+                self.source.clone(),
+                self.type_finder.clone(),
+            )?);
+            thread_streams.insert(self.id, (Arc::downgrade(&self.alive), streams.clone()));
+
+            Ok(streams)
+        })
+    }
+}
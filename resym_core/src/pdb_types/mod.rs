@@ -460,6 +460,56 @@ pub fn type_size(type_finder: &pdb::TypeFinder, type_index: pdb::TypeIndex) -> R
     Ok(size)
 }
 
+/// Indicate if the given type is a pointer (or a reference), ignoring
+/// modifiers.
+pub fn is_pointer_type(type_finder: &pdb::TypeFinder, type_index: pdb::TypeIndex) -> Result<bool> {
+    let is_pointer = match type_finder.find(type_index)?.parse()? {
+        pdb::TypeData::Primitive(data) => data.indirection.is_some(),
+        pdb::TypeData::Pointer(_) => true,
+        pdb::TypeData::Modifier(data) => is_pointer_type(type_finder, data.underlying_type)?,
+        _ => false,
+    };
+
+    Ok(is_pointer)
+}
+
+/// Indicate if the given type is an enum, ignoring modifiers.
+pub fn is_enum_type(type_finder: &pdb::TypeFinder, type_index: pdb::TypeIndex) -> Result<bool> {
+    let is_enum = match type_finder.find(type_index)?.parse()? {
+        pdb::TypeData::Enumeration(_) => true,
+        pdb::TypeData::Modifier(data) => is_enum_type(type_finder, data.underlying_type)?,
+        _ => false,
+    };
+
+    Ok(is_enum)
+}
+
+/// Return the user-defined type (class, struct, union or enum) the given type
+/// refers to, looking through pointers, modifiers, arrays and bit-fields.
+pub fn underlying_udt_type_index(
+    type_finder: &pdb::TypeFinder,
+    type_index: pdb::TypeIndex,
+) -> Result<Option<pdb::TypeIndex>> {
+    let udt_type_index = match type_finder.find(type_index)?.parse()? {
+        pdb::TypeData::Class(_) | pdb::TypeData::Union(_) | pdb::TypeData::Enumeration(_) => {
+            Some(type_index)
+        }
+        pdb::TypeData::Pointer(data) => {
+            underlying_udt_type_index(type_finder, data.underlying_type)?
+        }
+        pdb::TypeData::Modifier(data) => {
+            underlying_udt_type_index(type_finder, data.underlying_type)?
+        }
+        pdb::TypeData::Array(data) => underlying_udt_type_index(type_finder, data.element_type)?,
+        pdb::TypeData::Bitfield(data) => {
+            underlying_udt_type_index(type_finder, data.underlying_type)?
+        }
+        _ => None,
+    };
+
+    Ok(udt_type_index)
+}
+
 /// Indicate if the given `type_name` is the name of an anonymous type.
 pub fn is_unnamed_type(type_name: &str) -> bool {
     type_name.contains("<anonymous-")
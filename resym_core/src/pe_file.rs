@@ -0,0 +1,394 @@
+use object::{
+    pe,
+    read::pe::{ExportTarget, ImageNtHeaders, ImageOptionalHeader, Import, PeFile32, PeFile64},
+    LittleEndian as LE, Object,
+};
+
+use std::{collections::HashMap, path::PathBuf};
+#[cfg(not(target_arch = "wasm32"))]
+use std::{fs, io::Read, path::Path};
+
+use crate::error::{Result, ResymCoreError};
+
+/// Maximum number of bytes read when extracting string literals
+const MAX_STRING_LENGTH: usize = 1024;
+
+/// CodeView debug information (i.e., `RSDS` record) referencing the PDB file
+/// that matches a PE image
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodeViewInfo {
+    /// GUID of the PDB, as stored in the image
+    pub guid: [u8; 16],
+    /// Age of the PDB
+    pub age: u32,
+    /// Path of the PDB, as stored in the image
+    pub pdb_path: String,
+}
+
+impl CodeViewInfo {
+    /// Return the name of the PDB file, without the path of the directory it
+    /// was generated in. Returns `None` if the name can't be safely joined to
+    /// a directory (e.g., `..` or names with a drive prefix).
+    pub fn pdb_file_name(&self) -> Option<&str> {
+        let pdb_file_name = self.pdb_path.rsplit(['\\', '/']).next()?;
+        let is_plain_file_name = !matches!(pdb_file_name, "" | "." | "..")
+            && !pdb_file_name.contains(['\\', '/', ':', '\0']);
+
+        is_plain_file_name.then_some(pdb_file_name)
+    }
+
+    /// Return the key identifying the PDB in symbol stores (i.e., its GUID
+    /// followed by its age, both in hexadecimal)
+    pub fn symbol_store_key(&self) -> String {
+        pdb_signature(&self.guid, self.age)
+    }
+}
+
+/// Format a PDB's GUID (in its on-disk layout) and age the way symbol stores
+/// do (i.e., GUID followed by the age, both in hexadecimal)
+pub(crate) fn pdb_signature(guid: &[u8; 16], age: u32) -> String {
+    format!(
+        "{:08X}{:04X}{:04X}{}{:X}",
+        u32::from_le_bytes([guid[0], guid[1], guid[2], guid[3]]),
+        u16::from_le_bytes([guid[4], guid[5]]),
+        u16::from_le_bytes([guid[6], guid[7]]),
+        guid[8..]
+            .iter()
+            .map(|byte| format!("{byte:02X}"))
+            .collect::<String>(),
+        age
+    )
+}
+
+/// Symbol exported by a PE image
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PeExport {
+    pub ordinal: u32,
+    /// Name of the export, if not exported by ordinal only
+    pub name: Option<String>,
+    /// Address of the export, `None` for forwarded exports
+    pub rva: Option<u32>,
+}
+
+/// Symbol imported by a PE image
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PeImport {
+    /// Name of the module the symbol is imported from (e.g., `KERNEL32.dll`)
+    pub module_name: String,
+    /// Name of the import, if not imported by ordinal
+    pub name: Option<String>,
+    /// Ordinal of the import, if imported by ordinal
+    pub ordinal: Option<u16>,
+    /// Address of the import's entry in the import address table
+    pub iat_rva: u32,
+}
+
+/// Section of a PE image, used to map RVAs to file offsets
+#[derive(Clone, Debug)]
+struct PeSection {
+    rva: u32,
+    virtual_size: u32,
+    raw_offset: u32,
+    raw_size: u32,
+    executable: bool,
+}
+
+/// PE image (i.e., EXE or DLL) loaded alongside a PDB file, used to retrieve
+/// information that isn't available in the PDB itself.
+pub struct PeFile {
+    pub file_path: PathBuf,
+    pub image_base: u64,
+    /// Size of pointers, in bytes
+    pub pointer_size: u32,
+    pub code_view_info: Option<CodeViewInfo>,
+    pub exports: Vec<PeExport>,
+    pub imports: Vec<PeImport>,
+    sections: Vec<PeSection>,
+    export_index_by_rva: HashMap<u32, usize>,
+    import_index_by_iat_rva: HashMap<u32, usize>,
+    data: Vec<u8>,
+}
+
+impl PeFile {
+    /// Create `PeFile` from an `std::path::Path`
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_from_file(pe_file_path: &Path) -> Result<Self> {
+        let pe_file_data = fs::read(pe_file_path)?;
+
+        Self::load_from_bytes(pe_file_path.to_owned(), pe_file_data)
+    }
+
+    /// Create `PeFile` from a `PathBuf` and a `Vec<u8>`
+    pub fn load_from_bytes(pe_file_path: PathBuf, pe_file_data: Vec<u8>) -> Result<Self> {
+        match object::read::pe::optional_header_magic(pe_file_data.as_slice())? {
+            pe::IMAGE_NT_OPTIONAL_HDR32_MAGIC => {
+                let pe_file = PeFile32::parse(pe_file_data.as_slice())?;
+                Self::from_parsed_image(pe_file_path, &pe_file)
+            }
+            _ => {
+                let pe_file = PeFile64::parse(pe_file_data.as_slice())?;
+                Self::from_parsed_image(pe_file_path, &pe_file)
+            }
+        }
+        .map(|mut pe_file| {
+            pe_file.data = pe_file_data;
+            pe_file
+        })
+    }
+
+    fn from_parsed_image<'data, Pe: ImageNtHeaders>(
+        file_path: PathBuf,
+        pe_file: &object::read::pe::PeFile<'data, Pe, &'data [u8]>,
+    ) -> Result<Self> {
+        let image_base = pe_file.nt_headers().optional_header().image_base();
+        let pointer_size = if pe_file.is_64() { 8 } else { 4 };
+
+        let code_view_info = pe_file.pdb_info()?.map(|code_view| CodeViewInfo {
+            guid: code_view.guid(),
+            age: code_view.age(),
+            pdb_path: String::from_utf8_lossy(code_view.path()).into_owned(),
+        });
+
+        let sections = pe_file
+            .section_table()
+            .iter()
+            .map(|section| PeSection {
+                rva: section.virtual_address.get(LE),
+                virtual_size: section.virtual_size.get(LE),
+                raw_offset: section.pointer_to_raw_data.get(LE),
+                raw_size: section.size_of_raw_data.get(LE),
+                executable: section.characteristics.get(LE) & pe::IMAGE_SCN_MEM_EXECUTE != 0,
+            })
+            .collect();
+
+        // Exported symbols
+        let mut exports = vec![];
+        if let Some(export_table) = pe_file.export_table()? {
+            for export in export_table.exports()? {
+                exports.push(PeExport {
+                    ordinal: export.ordinal,
+                    name: export
+                        .name
+                        .map(|name| String::from_utf8_lossy(name).into_owned()),
+                    rva: match export.target {
+                        ExportTarget::Address(rva) => Some(rva),
+                        _ => None,
+                    },
+                });
+            }
+        }
+
+        // Imported symbols
+        let mut imports = vec![];
+        if let Some(import_table) = pe_file.import_table()? {
+            let mut import_descriptors = import_table.descriptors()?;
+            while let Some(import_descriptor) = import_descriptors.next()? {
+                let module_name =
+                    String::from_utf8_lossy(import_table.name(import_descriptor.name.get(LE))?)
+                        .into_owned();
+                let iat_rva = import_descriptor.first_thunk.get(LE);
+                // Prefer the import lookup table, as the import address table
+                // might have been bound
+                let lookup_table_rva = match import_descriptor.original_first_thunk.get(LE) {
+                    0 => iat_rva,
+                    rva => rva,
+                };
+
+                let mut thunks = import_table.thunks(lookup_table_rva)?;
+                let mut thunk_index: u32 = 0;
+                while let Some(thunk) = thunks.next::<Pe>()? {
+                    let (name, ordinal) = match import_table.import::<Pe>(thunk)? {
+                        Import::Name(_, name) => {
+                            (Some(String::from_utf8_lossy(name).into_owned()), None)
+                        }
+                        Import::Ordinal(ordinal) => (None, Some(ordinal)),
+                    };
+                    let entry_rva = thunk_index
+                        .checked_mul(pointer_size)
+                        .and_then(|entry_offset| iat_rva.checked_add(entry_offset))
+                        .ok_or_else(|| {
+                            ResymCoreError::MalformedPeError(format!(
+                                "import address table of '{module_name}' is out of bounds"
+                            ))
+                        })?;
+                    imports.push(PeImport {
+                        module_name: module_name.clone(),
+                        name,
+                        ordinal,
+                        iat_rva: entry_rva,
+                    });
+                    thunk_index += 1;
+                }
+            }
+        }
+
+        let export_index_by_rva = exports
+            .iter()
+            .enumerate()
+            .filter_map(|(export_index, export)| Some((export.rva?, export_index)))
+            .collect();
+        let import_index_by_iat_rva = imports
+            .iter()
+            .enumerate()
+            .map(|(import_index, import)| (import.iat_rva, import_index))
+            .collect();
+
+        Ok(Self {
+            file_path,
+            image_base,
+            pointer_size,
+            code_view_info,
+            exports,
+            imports,
+            sections,
+            export_index_by_rva,
+            import_index_by_iat_rva,
+            // Note: set by the caller, to avoid copying the image's content
+            data: vec![],
+        })
+    }
+
+    /// Return the export located at the given address, if any
+    pub fn export_at_rva(&self, rva: u32) -> Option<&PeExport> {
+        self.export_index_by_rva
+            .get(&rva)
+            .and_then(|export_index| self.exports.get(*export_index))
+    }
+
+    /// Return the import whose import address table entry is located at the
+    /// given address, if any
+    pub fn import_at_iat_rva(&self, rva: u32) -> Option<&PeImport> {
+        self.import_index_by_iat_rva
+            .get(&rva)
+            .and_then(|import_index| self.imports.get(*import_index))
+    }
+
+    /// Check whether the given address is located in an executable section
+    pub fn is_executable_rva(&self, rva: u32) -> bool {
+        self.section_at_rva(rva)
+            .map(|section| section.executable)
+            .unwrap_or(false)
+    }
+
+    /// Read `size` bytes at the given address.
+    /// Returns `None` if the bytes aren't all backed by the image's content
+    /// (e.g., uninitialized data).
+    pub fn read_bytes_at_rva(&self, rva: u32, size: usize) -> Option<&[u8]> {
+        let section = self.section_at_rva(rva)?;
+        let offset_in_section = rva - section.rva;
+        let size = u32::try_from(size).ok()?;
+        if offset_in_section.checked_add(size)? > section.raw_size {
+            return None;
+        }
+
+        let file_offset = section.raw_offset.checked_add(offset_in_section)? as usize;
+        self.data
+            .get(file_offset..file_offset.checked_add(size as usize)?)
+    }
+
+    /// Read a pointer at the given address and convert it to an RVA
+    pub fn read_pointer_at_rva(&self, rva: u32) -> Option<u32> {
+        let pointer_bytes = self.read_bytes_at_rva(rva, self.pointer_size as usize)?;
+        let pointer_va = match *pointer_bytes {
+            [b0, b1, b2, b3] => u32::from_le_bytes([b0, b1, b2, b3]).into(),
+            [b0, b1, b2, b3, b4, b5, b6, b7] => {
+                u64::from_le_bytes([b0, b1, b2, b3, b4, b5, b6, b7])
+            }
+            _ => return None,
+        };
+
+        u32::try_from(pointer_va.checked_sub(self.image_base)?).ok()
+    }
+
+    /// Read a NUL-terminated string of `char_size`-byte characters at the
+    /// given address
+    pub fn read_string_at_rva(&self, rva: u32, char_size: usize) -> Option<String> {
+        let section = self.section_at_rva(rva)?;
+        let available_size = section
+            .rva
+            .checked_add(section.raw_size)?
+            .checked_sub(rva)? as usize;
+        let bytes = self.read_bytes_at_rva(
+            rva,
+            std::cmp::min(available_size, MAX_STRING_LENGTH * char_size),
+        )?;
+
+        match char_size {
+            1 => {
+                let string_length = bytes.iter().position(|c| *c == 0)?;
+                Some(String::from_utf8_lossy(&bytes[..string_length]).into_owned())
+            }
+            2 => {
+                let wide_chars: Vec<u16> = bytes
+                    .chunks_exact(2)
+                    .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                    .take_while(|c| *c != 0)
+                    .collect();
+                Some(String::from_utf16_lossy(&wide_chars))
+            }
+            _ => None,
+        }
+    }
+
+    /// Check that the PE image matches the PDB with the given GUID and age.
+    /// Note: a PDB matches an image if their GUIDs are equal and if the PDB's
+    /// age is equal to or higher than the image's.
+    pub fn check_matches_pdb(&self, pdb_guid: [u8; 16], pdb_age: u32) -> Result<()> {
+        let code_view_info = self.code_view_info.as_ref().ok_or_else(|| {
+            ResymCoreError::PeMismatchError("image has no CodeView debug information".to_string())
+        })?;
+
+        if code_view_info.guid != pdb_guid {
+            Err(ResymCoreError::PeMismatchError(format!(
+                "GUID mismatch (image references '{}' with GUID {})",
+                code_view_info.pdb_path,
+                format_guid(&code_view_info.guid)
+            )))
+        } else if code_view_info.age > pdb_age {
+            Err(ResymCoreError::PeMismatchError(format!(
+                "age mismatch (image: {}, PDB: {})",
+                code_view_info.age, pdb_age
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn section_at_rva(&self, rva: u32) -> Option<&PeSection> {
+        self.sections.iter().find(|section| {
+            rva >= section.rva
+                && rva - section.rva < std::cmp::max(section.virtual_size, section.raw_size)
+        })
+    }
+}
+
+/// Check whether the file at the given path looks like a PE image (i.e., starts
+/// with an `MZ` header)
+#[cfg(not(target_arch = "wasm32"))]
+pub fn is_pe_image_file(file_path: &Path) -> Result<bool> {
+    let mut magic = [0u8; 2];
+    match fs::File::open(file_path)?.read_exact(&mut magic) {
+        Ok(()) => Ok(&magic == b"MZ"),
+        Err(err) if err.kind() == std::io::ErrorKind::UnexpectedEof => Ok(false),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Format a GUID stored in its on-disk layout, the way Windows does
+/// (e.g., `{6B29FC40-CA47-1067-B31D-00DD010662DA}`)
+fn format_guid(guid: &[u8; 16]) -> String {
+    format!(
+        "{{{:08X}-{:04X}-{:04X}-{:02X}{:02X}-{:02X}{:02X}{:02X}{:02X}{:02X}{:02X}}}",
+        u32::from_le_bytes([guid[0], guid[1], guid[2], guid[3]]),
+        u16::from_le_bytes([guid[4], guid[5]]),
+        u16::from_le_bytes([guid[6], guid[7]]),
+        guid[8],
+        guid[9],
+        guid[10],
+        guid[11],
+        guid[12],
+        guid[13],
+        guid[14],
+        guid[15],
+    )
+}
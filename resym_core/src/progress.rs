@@ -0,0 +1,127 @@
+use std::sync::{
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    Arc,
+};
+
+use crate::error::{Result, ResymCoreError};
+
+/// Identifier given by the backend to each command it receives
+pub type RequestId = u64;
+
+/// Progress of a request, as reported to the frontend
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Progress {
+    /// The request is being processed. Contains a description of the current
+    /// step, as well as the number of items processed out of the total.
+    Running {
+        description: String,
+        done: usize,
+        total: usize,
+    },
+    /// The request has been processed (successfully or not) or cancelled
+    Done,
+}
+
+impl Progress {
+    /// Return the ratio of processed items, between 0.0 and 1.0
+    pub fn fraction(&self) -> f32 {
+        match self {
+            Progress::Running { done, total, .. } if *total > 0 => {
+                (*done as f32 / *total as f32).min(1.0)
+            }
+            Progress::Running { .. } => 0.0,
+            Progress::Done => 1.0,
+        }
+    }
+}
+
+/// Token shared between the frontend and the backend, used to cancel a request
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Number of distinct progress values reported for a given step. This avoids
+/// flooding the frontend with updates.
+const PROGRESS_RESOLUTION: usize = 100;
+/// Value used to indicate nothing has been reported yet
+const NOTHING_REPORTED: usize = usize::MAX;
+
+/// Handle given to long-running operations, used to report their progress and
+/// to check whether they've been cancelled.
+/// The default reporter doesn't report anything and can't be cancelled.
+#[derive(Clone)]
+pub struct ProgressReporter {
+    cancellation_token: CancellationToken,
+    callback: Option<Arc<dyn Fn(Progress) + Send + Sync>>,
+    last_reported_step: Arc<AtomicUsize>,
+}
+
+impl Default for ProgressReporter {
+    fn default() -> Self {
+        Self {
+            cancellation_token: Default::default(),
+            callback: None,
+            last_reported_step: Arc::new(AtomicUsize::new(NOTHING_REPORTED)),
+        }
+    }
+}
+
+impl ProgressReporter {
+    pub fn new(
+        cancellation_token: CancellationToken,
+        callback: impl Fn(Progress) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            cancellation_token,
+            callback: Some(Arc::new(callback)),
+            last_reported_step: Arc::new(AtomicUsize::new(NOTHING_REPORTED)),
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation_token.is_cancelled()
+    }
+
+    /// Return `CancelledError` if the request has been cancelled
+    pub fn check_cancelled(&self) -> Result<()> {
+        if self.is_cancelled() {
+            Err(ResymCoreError::CancelledError)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Report that `done` items have been processed out of `total`.
+    /// Updates are only forwarded when the progress changes noticeably.
+    pub fn report(&self, description: &str, done: usize, total: usize) {
+        let callback = match &self.callback {
+            Some(callback) => callback,
+            None => {
+                return;
+            }
+        };
+
+        let step = done.saturating_mul(PROGRESS_RESOLUTION) / total.max(1);
+        if self.last_reported_step.swap(step, Ordering::Relaxed) != step {
+            callback(Progress::Running {
+                description: description.to_string(),
+                done,
+                total,
+            });
+        }
+    }
+
+    /// Return `true` if progress has been reported through this reporter
+    pub fn has_reported(&self) -> bool {
+        self.last_reported_step.load(Ordering::Relaxed) != NOTHING_REPORTED
+    }
+}
@@ -61,3 +61,20 @@ macro_rules! find_any_if_available {
         $expression.par_iter().find_any($($x)*)
     };
 }
+
+/// Macro used to run a closure on rayon's thread pool, or in place if rayon
+/// isn't available
+#[macro_export]
+#[cfg(not(feature = "rayon"))]
+macro_rules! spawn_if_available {
+    ($closure:expr) => {
+        ($closure)()
+    };
+}
+#[macro_export]
+#[cfg(feature = "rayon")]
+macro_rules! spawn_if_available {
+    ($closure:expr) => {
+        rayon::spawn($closure)
+    };
+}
@@ -0,0 +1,996 @@
+#[cfg(target_arch = "wasm32")]
+use instant::Instant;
+#[cfg(feature = "rayon")]
+use rayon::{
+    iter::{IntoParallelRefIterator, ParallelIterator},
+    prelude::ParallelSliceMut,
+};
+
+use std::{
+    collections::HashMap,
+    ops::Range,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+#[cfg(not(target_arch = "wasm32"))]
+use std::{path::Path, time::Instant};
+
+use crate::{
+    class_hierarchy::ClassHierarchyTree,
+    diffing::{
+        diff_module_by_path, diff_symbol_by_name, diff_type_by_name, diff_type_list, Diff,
+        TypeDiffList, TypeDiffStatusCache,
+    },
+    error::{Result, ResymCoreError},
+    field_search::{filter_fields, FieldInfoList, FieldSearchQuery},
+    frontend::ReconstructedType,
+    inline_sites::InlineSiteList,
+    layout_search::{match_layouts, LayoutMatchList, LayoutQuery},
+    options::{FilterOptions, ReconstructionOptions, TypeGraphExportOptions},
+    par_iter_if_available, par_sort_by_if_available,
+    pdb_file::{
+        ModuleIndex, ModuleList, PdbFile, SymbolIndex, SymbolList, SymbolListView, TypeDetailsList,
+        TypeIndex, TypeList,
+    },
+    pdb_types::{self, include_headers_for_flavor, PrimitiveReconstructionFlavor},
+    pe_file::PeFile,
+    progress::ProgressReporter,
+    size_report::{compute_size_report, SizeReport, SizeReportGrouping},
+    type_graph::{
+        render_type_graph, TypeEdge, TypeGraphNode, TypeGraphQuery, TypeGraphQueryResult,
+    },
+    type_layout::TypeLayout,
+    PKG_VERSION,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::{index_cache::IndexCache, pe_file, symbol_store::SymbolPath};
+
+/// Synchronous interface to a loaded PDB file. This is the simplest way to use
+/// `resym_core` as a library, all methods block until their result is ready.
+///
+/// ```no_run
+/// use resym_core::{options::ReconstructionOptions, session::Session};
+///
+/// let session = Session::open(std::path::Path::new("example.pdb"))?;
+/// let options = ReconstructionOptions::new().reconstruct_dependencies(true);
+/// let (reconstructed_type, _) = session.reconstruct_type_by_name("MyStruct", &options)?;
+/// println!("{reconstructed_type}");
+/// # Ok::<(), resym_core::ResymCoreError>(())
+/// ```
+pub struct Session {
+    pdb_file: PdbFile,
+    /// Identifier of the session, used to tell apart the sessions this one is
+    /// compared with
+    id: u64,
+    /// Statuses of the types compared with another session, computed so far
+    type_diff_cache: Mutex<Option<(TypeDiffCacheKey, Arc<TypeDiffStatusCache>)>>,
+}
+
+/// Identifier given to each `Session`
+static NEXT_SESSION_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Session and reconstruction options that type diff statuses depend on
+#[derive(PartialEq)]
+struct TypeDiffCacheKey {
+    session_to_id: u64,
+    primitives_flavor: PrimitiveReconstructionFlavor,
+    print_access_specifiers: bool,
+    integers_as_hexadecimal: bool,
+}
+
+impl From<PdbFile> for Session {
+    fn from(pdb_file: PdbFile) -> Self {
+        Self {
+            pdb_file,
+            id: NEXT_SESSION_ID.fetch_add(1, Ordering::Relaxed),
+            type_diff_cache: Default::default(),
+        }
+    }
+}
+
+impl Session {
+    /// Open a PDB file given its path. PE images are also accepted, in which
+    /// case the matching PDB file is looked up in the symbol search path
+    /// defined by `_NT_SYMBOL_PATH`. Indices are only cached on disk if
+    /// `RESYM_INDEX_CACHE_DIR` is set.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn open(file_path: &Path) -> Result<Self> {
+        Self::open_with(
+            file_path,
+            &SymbolPath::from_env(),
+            IndexCache::from_env(),
+            &Default::default(),
+        )
+    }
+
+    /// Open a PDB file given its path, with an explicit symbol search path
+    /// and index cache
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn open_with(
+        file_path: &Path,
+        symbol_path: &SymbolPath,
+        index_cache: Option<IndexCache>,
+        progress: &ProgressReporter,
+    ) -> Result<Self> {
+        if !pe_file::is_pe_image_file(file_path)? {
+            return PdbFile::load_from_file_with_index_cache(file_path, index_cache, progress)
+                .map(Self::from);
+        }
+
+        let pe_file = PeFile::load_from_file(file_path)?;
+        let pdb_file_path = symbol_path.find_pdb_for_pe_with_progress(&pe_file, progress)?;
+        let pdb_file =
+            PdbFile::load_from_file_with_index_cache(&pdb_file_path, index_cache, progress)?;
+        pdb_file.load_pe_file(pe_file)?;
+
+        Ok(Self::from(pdb_file))
+    }
+
+    /// Open a PDB file given its name and content
+    pub fn open_from_bytes(pdb_file_name: String, pdb_file_data: Vec<u8>) -> Result<Self> {
+        PdbFile::load_from_bytes_as_vec(pdb_file_name, pdb_file_data, &Default::default())
+            .map(Self::from)
+    }
+
+    /// Access the underlying `PdbFile`, for lower-level operations
+    pub fn pdb_file(&self) -> &PdbFile {
+        &self.pdb_file
+    }
+
+    /// Load the PE image matching the PDB file, to enrich reconstructed
+    /// symbols
+    pub fn load_pe_file(&self, pe_file: PeFile) -> Result<()> {
+        self.pdb_file.load_pe_file(pe_file)
+    }
+
+    /// Retrieve the types whose name match the given filter, ordered by type
+    /// index
+    pub fn list_types(&self, search_filter: &str, options: &FilterOptions) -> TypeList {
+        self.filter_types(search_filter, options, true)
+    }
+
+    pub(crate) fn filter_types(
+        &self,
+        search_filter: &str,
+        options: &FilterOptions,
+        sort_by_index: bool,
+    ) -> TypeList {
+        let filter_start = Instant::now();
+
+        // Filter out std types if needed
+        let filtered_type_list = if options.ignore_std_types {
+            filter_std_types(&self.pdb_file.complete_type_list)
+        } else {
+            self.pdb_file.complete_type_list.clone()
+        };
+
+        // Filter types following the search filter
+        let mut filtered_type_list = if search_filter.is_empty() {
+            // No need to filter
+            filtered_type_list
+        } else if options.use_regex {
+            filter_types_regex(&filtered_type_list, search_filter, options.case_insensitive)
+        } else {
+            filter_types_regular(&filtered_type_list, search_filter, options.case_insensitive)
+        };
+        if sort_by_index {
+            // Order types by type index, so the order is deterministic
+            // (i.e., independent from DashMap's hash function)
+            par_sort_by_if_available!(filtered_type_list, |lhs, rhs| lhs.1.cmp(&rhs.1));
+        }
+
+        log::debug!(
+            "Type filtering took {} ms",
+            filter_start.elapsed().as_millis()
+        );
+
+        filtered_type_list
+    }
+
+    /// Retrieve the symbols whose name match the given filter
+    pub fn list_symbols(&self, search_filter: &str, options: &FilterOptions) -> SymbolList {
+        self.list_symbols_with_progress(search_filter, options, &Default::default())
+    }
+
+    pub fn list_symbols_with_progress(
+        &self,
+        search_filter: &str,
+        options: &FilterOptions,
+        progress: &ProgressReporter,
+    ) -> SymbolList {
+        let filter_start = Instant::now();
+
+        match self.pdb_file.symbol_list(progress) {
+            Err(_) => SymbolList::default(),
+            Ok(symbol_list) => {
+                // Filter out std types if needed
+                let filtered_symbol_list = if options.ignore_std_types {
+                    filter_std_symbols(&symbol_list)
+                } else {
+                    symbol_list
+                };
+
+                let filtered_symbol_list = if search_filter.is_empty() {
+                    // No need to filter
+                    filtered_symbol_list
+                } else if options.use_regex {
+                    filter_symbols_regex(
+                        &filtered_symbol_list,
+                        search_filter,
+                        options.case_insensitive,
+                    )
+                } else {
+                    filter_symbols_regular(
+                        &filtered_symbol_list,
+                        search_filter,
+                        options.case_insensitive,
+                    )
+                };
+
+                log::debug!(
+                    "Symbol filtering took {} ms",
+                    filter_start.elapsed().as_millis()
+                );
+
+                // Convert refs to symbol info into clones and return it
+                par_iter_if_available!(filtered_symbol_list)
+                    .map(|e| (e.0.clone(), e.1))
+                    .collect()
+            }
+        }
+    }
+
+    /// Retrieve the modules whose path match the given filter
+    pub fn list_modules(&self, search_filter: &str, options: &FilterOptions) -> Result<ModuleList> {
+        let filter_start = Instant::now();
+
+        let filtered_module_list = if search_filter.is_empty() {
+            // No need to filter
+            self.pdb_file.module_list()?
+        } else if options.use_regex {
+            filter_modules_regex(
+                &self.pdb_file.module_list()?,
+                search_filter,
+                options.case_insensitive,
+            )
+        } else {
+            filter_modules_regular(
+                &self.pdb_file.module_list()?,
+                search_filter,
+                options.case_insensitive,
+            )
+        };
+
+        log::debug!(
+            "Module filtering took {} ms",
+            filter_start.elapsed().as_millis()
+        );
+
+        Ok(filtered_module_list)
+    }
+
+    pub fn reconstruct_type_by_index(
+        &self,
+        type_index: TypeIndex,
+        options: &ReconstructionOptions,
+    ) -> Result<ReconstructedType> {
+        let (data, xrefs_from) = self.pdb_file.reconstruct_type_by_index(
+            type_index,
+            options.primitives_flavor,
+            options.reconstruct_dependencies,
+            options.print_access_specifiers,
+            options.integers_as_hexadecimal,
+            options.ignore_std_types,
+        )?;
+        if options.print_header {
+            let file_header = self.file_header(options.primitives_flavor, options.ignore_std_types);
+            Ok((format!("{file_header}{data}"), xrefs_from))
+        } else {
+            Ok((data, xrefs_from))
+        }
+    }
+
+    pub fn reconstruct_type_by_name(
+        &self,
+        type_name: &str,
+        options: &ReconstructionOptions,
+    ) -> Result<ReconstructedType> {
+        let (data, xrefs_from) = self.pdb_file.reconstruct_type_by_name(
+            type_name,
+            options.primitives_flavor,
+            options.reconstruct_dependencies,
+            options.print_access_specifiers,
+            options.integers_as_hexadecimal,
+            options.ignore_std_types,
+        )?;
+        if options.print_header {
+            let file_header = self.file_header(options.primitives_flavor, options.ignore_std_types);
+            Ok((format!("{file_header}{data}"), xrefs_from))
+        } else {
+            Ok((data, xrefs_from))
+        }
+    }
+
+    pub fn reconstruct_all_types(&self, options: &ReconstructionOptions) -> Result<String> {
+        self.reconstruct_all_types_with_progress(options, &Default::default())
+    }
+
+    pub fn reconstruct_all_types_with_progress(
+        &self,
+        options: &ReconstructionOptions,
+        progress: &ProgressReporter,
+    ) -> Result<String> {
+        let data = self.pdb_file.reconstruct_all_types(
+            options.primitives_flavor,
+            options.print_access_specifiers,
+            options.integers_as_hexadecimal,
+            options.ignore_std_types,
+            progress,
+        )?;
+        if options.print_header {
+            let file_header = self.file_header(options.primitives_flavor, options.ignore_std_types);
+            Ok(format!("{file_header}{data}"))
+        } else {
+            Ok(data)
+        }
+    }
+
+    pub fn reconstruct_symbol_by_index(
+        &self,
+        symbol_index: SymbolIndex,
+        options: &ReconstructionOptions,
+    ) -> Result<String> {
+        let data = self.pdb_file.reconstruct_symbol_by_index(
+            symbol_index,
+            options.primitives_flavor,
+            options.print_access_specifiers,
+        )?;
+        if options.print_header {
+            let file_header = self.file_header(options.primitives_flavor, false);
+            Ok(format!("{file_header}\n{data}"))
+        } else {
+            Ok(data)
+        }
+    }
+
+    pub fn reconstruct_symbol_by_name(
+        &self,
+        symbol_name: &str,
+        options: &ReconstructionOptions,
+    ) -> Result<String> {
+        let data = self.pdb_file.reconstruct_symbol_by_name(
+            symbol_name,
+            options.primitives_flavor,
+            options.print_access_specifiers,
+        )?;
+        if options.print_header {
+            let file_header = self.file_header(options.primitives_flavor, false);
+            Ok(format!("{file_header}\n{data}"))
+        } else {
+            Ok(data)
+        }
+    }
+
+    pub fn reconstruct_all_symbols(&self, options: &ReconstructionOptions) -> Result<String> {
+        self.reconstruct_all_symbols_with_progress(options, &Default::default())
+    }
+
+    pub fn reconstruct_all_symbols_with_progress(
+        &self,
+        options: &ReconstructionOptions,
+        progress: &ProgressReporter,
+    ) -> Result<String> {
+        let data = self.pdb_file.reconstruct_all_symbols(
+            options.primitives_flavor,
+            options.print_access_specifiers,
+            progress,
+        )?;
+        if options.print_header {
+            let file_header = self.file_header(options.primitives_flavor, false);
+            Ok(format!("{file_header}{data}"))
+        } else {
+            Ok(data)
+        }
+    }
+
+    pub fn reconstruct_module_by_index(
+        &self,
+        module_index: ModuleIndex,
+        options: &ReconstructionOptions,
+    ) -> Result<String> {
+        let data = self.pdb_file.reconstruct_module_by_index(
+            module_index,
+            options.primitives_flavor,
+            options.print_access_specifiers,
+        )?;
+        if options.print_header {
+            let file_header = self.file_header(options.primitives_flavor, false);
+            Ok(format!("{file_header}\n{data}"))
+        } else {
+            Ok(data)
+        }
+    }
+
+    /// Reconstruct the given type from this PDB file and from `to`, and diff
+    /// the results
+    pub fn diff_type_by_name(
+        &self,
+        to: &Session,
+        type_name: &str,
+        options: &ReconstructionOptions,
+    ) -> Result<Diff> {
+        diff_type_by_name(
+            &self.pdb_file,
+            &to.pdb_file,
+            type_name,
+            options.primitives_flavor,
+            options.print_header,
+            options.reconstruct_dependencies,
+            options.print_access_specifiers,
+            options.integers_as_hexadecimal,
+            options.ignore_std_types,
+        )
+    }
+
+    /// Retrieve the types whose name match the given filter in this PDB file
+    /// and in `to`, along with their status (i.e., added, removed, modified or
+    /// unchanged in `to`), ordered by name
+    pub fn diff_type_list(
+        &self,
+        to: &Session,
+        search_filter: &str,
+        filter_options: &FilterOptions,
+        options: &ReconstructionOptions,
+    ) -> Result<TypeDiffList> {
+        self.diff_type_list_with_progress(
+            to,
+            search_filter,
+            filter_options,
+            options,
+            &Default::default(),
+        )
+    }
+
+    pub fn diff_type_list_with_progress(
+        &self,
+        to: &Session,
+        search_filter: &str,
+        filter_options: &FilterOptions,
+        options: &ReconstructionOptions,
+        progress: &ProgressReporter,
+    ) -> Result<TypeDiffList> {
+        // Note: statuses are kept between calls, so that only types that
+        // haven't been compared yet are reconstructed when the filter changes
+        let status_cache = self.type_diff_status_cache(to, options);
+        diff_type_list(
+            &self.pdb_file,
+            &to.pdb_file,
+            &self.filter_types(search_filter, filter_options, false),
+            &to.filter_types(search_filter, filter_options, false),
+            options.primitives_flavor,
+            options.print_access_specifiers,
+            options.integers_as_hexadecimal,
+            &status_cache,
+            progress,
+        )
+    }
+
+    /// Return the cache of type diff statuses for the given session and
+    /// options. Statuses cached for other sessions or options are discarded.
+    fn type_diff_status_cache(
+        &self,
+        to: &Session,
+        options: &ReconstructionOptions,
+    ) -> Arc<TypeDiffStatusCache> {
+        let cache_key = TypeDiffCacheKey {
+            session_to_id: to.id,
+            primitives_flavor: options.primitives_flavor,
+            print_access_specifiers: options.print_access_specifiers,
+            integers_as_hexadecimal: options.integers_as_hexadecimal,
+        };
+        let mut type_diff_cache = self
+            .type_diff_cache
+            .lock()
+            .expect("lock shouldn't be poisoned");
+        match type_diff_cache.as_ref() {
+            Some((key, status_cache)) if *key == cache_key => status_cache.clone(),
+            _ => {
+                let status_cache = Arc::new(TypeDiffStatusCache::default());
+                *type_diff_cache = Some((cache_key, status_cache.clone()));
+                status_cache
+            }
+        }
+    }
+
+    /// Reconstruct the given symbol from this PDB file and from `to`, and diff
+    /// the results
+    pub fn diff_symbol_by_name(
+        &self,
+        to: &Session,
+        symbol_name: &str,
+        options: &ReconstructionOptions,
+    ) -> Result<Diff> {
+        diff_symbol_by_name(
+            &self.pdb_file,
+            &to.pdb_file,
+            symbol_name,
+            options.primitives_flavor,
+            options.print_header,
+            options.print_access_specifiers,
+        )
+    }
+
+    /// Reconstruct the given module from this PDB file and from `to`, and diff
+    /// the results
+    pub fn diff_module_by_path(
+        &self,
+        to: &Session,
+        module_path: &str,
+        options: &ReconstructionOptions,
+    ) -> Result<Diff> {
+        diff_module_by_path(
+            &self.pdb_file,
+            &to.pdb_file,
+            module_path,
+            options.primitives_flavor,
+            options.print_header,
+            options.print_access_specifiers,
+        )
+    }
+
+    /// Retrieve the types that reference the given type
+    pub fn list_type_xrefs(&self, type_index: TypeIndex) -> Result<TypeList> {
+        self.list_type_xrefs_with_progress(type_index, &Default::default())
+    }
+
+    pub fn list_type_xrefs_with_progress(
+        &self,
+        type_index: TypeIndex,
+        progress: &ProgressReporter,
+    ) -> Result<TypeList> {
+        let xref_start = Instant::now();
+        let xref_list = self.pdb_file.get_xrefs_for_type(type_index, progress)?;
+        log::debug!(
+            "Xref resolution took {} ms",
+            xref_start.elapsed().as_millis()
+        );
+
+        Ok(xref_list)
+    }
+
+    /// Retrieve the size and the definition location of the given types
+    pub fn type_details(&self, type_list: &TypeList) -> Result<TypeDetailsList> {
+        self.pdb_file.type_details(type_list)
+    }
+
+    /// Compute the memory layout of the given class, struct or union
+    pub fn type_layout(&self, type_index: TypeIndex) -> Result<TypeLayout> {
+        self.pdb_file.type_layout(type_index)
+    }
+
+    /// Compute a report of code and data sizes, aggregated following the given
+    /// criterion
+    pub fn size_report(&self, grouping: SizeReportGrouping) -> Result<SizeReport> {
+        let report_start = Instant::now();
+        let size_report = compute_size_report(&self.pdb_file, grouping)?;
+        log::debug!(
+            "Size report computation took {} ms",
+            report_start.elapsed().as_millis()
+        );
+
+        Ok(size_report)
+    }
+
+    /// Retrieve the locations at which the given function has been inlined,
+    /// followed by the functions that have been inlined into it
+    pub fn list_inline_sites(&self, function_name: &str) -> Result<InlineSiteList> {
+        let inline_sites_start = Instant::now();
+        let mut inline_site_list = self.pdb_file.get_call_sites_for_inlinee(function_name)?;
+        inline_site_list.extend(self.pdb_file.get_inlinees_for_function(function_name)?);
+        log::debug!(
+            "Inline site resolution took {} ms",
+            inline_sites_start.elapsed().as_millis()
+        );
+
+        Ok(inline_site_list)
+    }
+
+    /// Query the graph of dependencies between types
+    pub fn query_type_graph(&self, query: &TypeGraphQuery) -> Result<TypeGraphQueryResult> {
+        self.query_type_graph_with_progress(query, &Default::default())
+    }
+
+    pub fn query_type_graph_with_progress(
+        &self,
+        query: &TypeGraphQuery,
+        progress: &ProgressReporter,
+    ) -> Result<TypeGraphQueryResult> {
+        let query_start = Instant::now();
+        let type_graph = self.pdb_file.type_graph(progress)?;
+        let type_names: HashMap<TypeIndex, &str> = self
+            .pdb_file
+            .complete_type_list
+            .iter()
+            .map(|(type_name, type_index)| (*type_index, type_name.as_str()))
+            .collect();
+        let to_nodes = |edges: Vec<(TypeEdge, usize)>| -> Vec<TypeGraphNode> {
+            edges
+                .into_iter()
+                .map(|(edge, depth)| TypeGraphNode {
+                    type_name: type_names
+                        .get(&edge.type_index)
+                        .map(|type_name| type_name.to_string())
+                        .unwrap_or_default(),
+                    type_index: edge.type_index,
+                    depth,
+                    is_pointer: edge.is_pointer,
+                })
+                .collect()
+        };
+        let with_positions = |edges: Vec<TypeEdge>| -> Vec<(TypeEdge, usize)> {
+            edges
+                .into_iter()
+                .enumerate()
+                .map(|(position, edge)| (edge, position))
+                .collect()
+        };
+
+        let result = match query {
+            TypeGraphQuery::Dependencies {
+                type_name,
+                max_depth,
+            } => {
+                let type_index = self.find_complete_type(type_name)?;
+                vec![to_nodes(type_graph.dependencies(type_index, *max_depth))]
+            }
+            TypeGraphQuery::Dependents {
+                type_name,
+                max_depth,
+            } => {
+                let type_index = self.find_complete_type(type_name)?;
+                vec![to_nodes(type_graph.dependents(type_index, *max_depth))]
+            }
+            TypeGraphQuery::Path {
+                from_type_name,
+                to_type_name,
+                follow_pointers,
+            } => {
+                let from_type_index = self.find_complete_type(from_type_name)?;
+                let to_type_index = self.find_complete_type(to_type_name)?;
+                let path = type_graph
+                    .shortest_path(from_type_index, to_type_index, *follow_pointers)
+                    .unwrap_or_default();
+                vec![to_nodes(with_positions(path))]
+            }
+            TypeGraphQuery::Cycles { type_name } => {
+                let cycles = if let Some(type_name) = type_name {
+                    let type_index = self.find_complete_type(type_name)?;
+                    type_graph.cycle_through(type_index).into_iter().collect()
+                } else {
+                    type_graph.cycles()
+                };
+                cycles
+                    .into_iter()
+                    .map(|cycle| to_nodes(with_positions(cycle)))
+                    .collect()
+            }
+        };
+        log::debug!(
+            "Type graph query took {} ms",
+            query_start.elapsed().as_millis()
+        );
+
+        Ok(result)
+    }
+
+    /// Export the graph of the types needed by the given type (included)
+    pub fn export_type_graph(
+        &self,
+        type_name: &str,
+        options: &TypeGraphExportOptions,
+    ) -> Result<String> {
+        self.export_type_graph_with_progress(type_name, options, &Default::default())
+    }
+
+    pub fn export_type_graph_with_progress(
+        &self,
+        type_name: &str,
+        options: &TypeGraphExportOptions,
+        progress: &ProgressReporter,
+    ) -> Result<String> {
+        let type_graph = self.pdb_file.type_graph(progress)?;
+        let type_index = self.find_complete_type(type_name)?;
+        let type_names: HashMap<TypeIndex, &str> = self
+            .pdb_file
+            .complete_type_list
+            .iter()
+            .map(|(type_name, type_index)| (*type_index, type_name.as_str()))
+            .collect();
+        let type_name_of = |type_index: TypeIndex| -> &str {
+            type_names.get(&type_index).copied().unwrap_or_default()
+        };
+
+        let edges = type_graph.subgraph(type_index, options.max_depth, |type_index| {
+            !(options.ignore_std_types && type_name_of(type_index).starts_with("std::"))
+        });
+
+        Ok(render_type_graph(
+            type_index,
+            &edges,
+            options.format,
+            |type_index| type_name_of(type_index).to_string(),
+        ))
+    }
+
+    /// Retrieve the base classes of the given class and the classes that
+    /// derive from it, transitively
+    pub fn class_hierarchy(&self, type_name: &str) -> Result<ClassHierarchyTree> {
+        self.class_hierarchy_with_progress(type_name, &Default::default())
+    }
+
+    pub fn class_hierarchy_with_progress(
+        &self,
+        type_name: &str,
+        progress: &ProgressReporter,
+    ) -> Result<ClassHierarchyTree> {
+        let class_hierarchy = self.pdb_file.class_hierarchy(progress)?;
+        let type_index = self.find_complete_type(type_name)?;
+        let type_names: HashMap<TypeIndex, &str> = self
+            .pdb_file
+            .complete_type_list
+            .iter()
+            .map(|(type_name, type_index)| (*type_index, type_name.as_str()))
+            .collect();
+
+        Ok(class_hierarchy.tree(type_index, |type_index| {
+            type_names
+                .get(&type_index)
+                .map(|type_name| type_name.to_string())
+                .unwrap_or_default()
+        }))
+    }
+
+    /// Search the fields of all complete types by name, type and/or offset
+    pub fn find_fields(
+        &self,
+        query: &FieldSearchQuery,
+        options: &FilterOptions,
+    ) -> Result<FieldInfoList> {
+        self.find_fields_with_progress(query, options, &Default::default())
+    }
+
+    pub fn find_fields_with_progress(
+        &self,
+        query: &FieldSearchQuery,
+        options: &FilterOptions,
+        progress: &ProgressReporter,
+    ) -> Result<FieldInfoList> {
+        let field_list = self.pdb_file.field_list(progress)?;
+
+        Ok(filter_fields(field_list, query, options))
+    }
+
+    /// Search complete types by layout (size and fields at given offsets),
+    /// ranked by the number of constraints they satisfy
+    pub fn match_layout(
+        &self,
+        query: &LayoutQuery,
+        max_results: Option<usize>,
+    ) -> Result<LayoutMatchList> {
+        self.match_layout_with_progress(query, max_results, &Default::default())
+    }
+
+    pub fn match_layout_with_progress(
+        &self,
+        query: &LayoutQuery,
+        max_results: Option<usize>,
+        progress: &ProgressReporter,
+    ) -> Result<LayoutMatchList> {
+        let field_list = self.pdb_file.field_list(progress)?;
+        // Note: fields are grouped by containing type in the field list
+        let mut field_ranges: HashMap<TypeIndex, Range<usize>> = HashMap::new();
+        for (field_position, field) in field_list.iter().enumerate() {
+            field_ranges
+                .entry(field.type_index)
+                .or_insert(field_position..field_position)
+                .end = field_position + 1;
+        }
+
+        let streams = self.pdb_file.streams()?;
+        let type_finder = streams.type_finder()?;
+        let type_count = self.pdb_file.complete_type_list.len();
+        let mut type_layouts = Vec::with_capacity(type_count);
+        for (type_position, (type_name, type_index)) in
+            self.pdb_file.complete_type_list.iter().enumerate()
+        {
+            progress.check_cancelled()?;
+            progress.report("Matching layouts", type_position, type_count);
+
+            // Note: types whose size can't be computed are skipped, instead of
+            // failing the whole search
+            let size = match pdb_types::type_size(type_finder, (*type_index).into()) {
+                Ok(size) => size,
+                Err(err) => {
+                    log::debug!("Skipping '{type_name}' when matching layouts: {err}");
+                    continue;
+                }
+            };
+            let fields = field_ranges
+                .get(type_index)
+                .map(|field_range| &field_list[field_range.clone()])
+                .unwrap_or_default();
+            type_layouts.push((type_name.as_str(), *type_index, size as u64, fields));
+        }
+
+        match_layouts(type_layouts, query, max_results)
+    }
+
+    /// Find the complete type with the given name (the first one declared if
+    /// there are several)
+    fn find_complete_type(&self, type_name: &str) -> Result<TypeIndex> {
+        self.pdb_file
+            .complete_type_list
+            .iter()
+            .filter(|(name, _)| name == type_name)
+            .map(|(_, type_index)| *type_index)
+            .min()
+            .ok_or_else(|| ResymCoreError::TypeNameNotFoundError(type_name.to_string()))
+    }
+
+    fn file_header(
+        &self,
+        primitives_flavor: PrimitiveReconstructionFlavor,
+        ignore_std_types: bool,
+    ) -> String {
+        format!(
+            concat!(
+                "//\n",
+                "// Information extracted with resym v{}\n",
+                "//\n",
+                "// PDB file: {}\n",
+                "// Image architecture: {}\n",
+                "//\n",
+                "\n{}"
+            ),
+            PKG_VERSION,
+            self.pdb_file.file_path.display(),
+            self.pdb_file.machine_type,
+            include_headers_for_flavor(primitives_flavor, ignore_std_types)
+        )
+    }
+}
+
+/// Filter type list with a regular expression
+fn filter_types_regex(
+    type_list: &[(String, u32)],
+    search_filter: &str,
+    case_insensitive_search: bool,
+) -> TypeList {
+    match regex::RegexBuilder::new(search_filter)
+        .case_insensitive(case_insensitive_search)
+        .build()
+    {
+        // In case of error, return an empty result
+        Err(_) => vec![],
+        Ok(regex) => par_iter_if_available!(type_list)
+            .filter(|r| regex.find(&r.0).is_some())
+            .cloned()
+            .collect(),
+    }
+}
+
+/// Filter type list with a plain (sub-)string
+fn filter_types_regular(
+    type_list: &[(String, u32)],
+    search_filter: &str,
+    case_insensitive_search: bool,
+) -> TypeList {
+    if case_insensitive_search {
+        let search_filter = search_filter.to_lowercase();
+        par_iter_if_available!(type_list)
+            .filter(|r| r.0.to_lowercase().contains(&search_filter))
+            .cloned()
+            .collect()
+    } else {
+        par_iter_if_available!(type_list)
+            .filter(|r| r.0.contains(search_filter))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Filter type list to remove types in the `std` namespace
+fn filter_std_types(type_list: &[(String, TypeIndex)]) -> TypeList {
+    par_iter_if_available!(type_list)
+        .filter(|r| !r.0.starts_with("std::"))
+        .cloned()
+        .collect()
+}
+
+/// Filter symbol list to remove types in the `std` namespace
+fn filter_std_symbols<'s>(symbol_list: &'s [&(String, SymbolIndex)]) -> SymbolListView<'s> {
+    par_iter_if_available!(symbol_list)
+        .filter(|r| !r.0.starts_with("std::"))
+        .cloned()
+        .collect()
+}
+
+/// Filter type list with a regular expression
+fn filter_symbols_regex<'s>(
+    symbol_list: &'s [&(String, SymbolIndex)],
+    search_filter: &str,
+    case_insensitive_search: bool,
+) -> SymbolListView<'s> {
+    match regex::RegexBuilder::new(search_filter)
+        .case_insensitive(case_insensitive_search)
+        .build()
+    {
+        // In case of error, return an empty result
+        Err(_) => vec![],
+        Ok(regex) => par_iter_if_available!(symbol_list)
+            .filter(|r| regex.find(&r.0).is_some())
+            .cloned()
+            .collect(),
+    }
+}
+
+/// Filter type list with a plain (sub-)string
+fn filter_symbols_regular<'s>(
+    symbol_list: &'s [&(String, SymbolIndex)],
+    search_filter: &str,
+    case_insensitive_search: bool,
+) -> SymbolListView<'s> {
+    if case_insensitive_search {
+        let search_filter = search_filter.to_lowercase();
+        par_iter_if_available!(symbol_list)
+            .filter(|r| r.0.to_lowercase().contains(&search_filter))
+            .cloned()
+            .collect()
+    } else {
+        par_iter_if_available!(symbol_list)
+            .filter(|r| r.0.contains(search_filter))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Filter module list with a regular expression
+fn filter_modules_regex(
+    module_list: &[(String, usize)],
+    search_filter: &str,
+    case_insensitive_search: bool,
+) -> Vec<(String, usize)> {
+    match regex::RegexBuilder::new(search_filter)
+        .case_insensitive(case_insensitive_search)
+        .build()
+    {
+        // In case of error, return an empty result
+        Err(_) => vec![],
+        Ok(regex) => par_iter_if_available!(module_list)
+            .filter(|r| regex.find(&r.0).is_some())
+            .cloned()
+            .collect(),
+    }
+}
+
+/// Filter module list with a plain (sub-)string
+fn filter_modules_regular(
+    module_list: &[(String, usize)],
+    search_filter: &str,
+    case_insensitive_search: bool,
+) -> Vec<(String, usize)> {
+    if case_insensitive_search {
+        let search_filter = search_filter.to_lowercase();
+        par_iter_if_available!(module_list)
+            .filter(|r| r.0.to_lowercase().contains(&search_filter))
+            .cloned()
+            .collect()
+    } else {
+        par_iter_if_available!(module_list)
+            .filter(|r| r.0.contains(search_filter))
+            .cloned()
+            .collect()
+    }
+}
@@ -0,0 +1,200 @@
+use std::{collections::BTreeMap, str::FromStr};
+
+use crate::{
+    error::{Result, ResymCoreError},
+    pdb_file::{ModuleIndex, PdbFile},
+};
+
+/// Name used for entries which couldn't be attributed to a particular group
+const UNATTRIBUTED_GROUP_NAME: &str = "(none)";
+
+/// Criterion used to aggregate symbol sizes in a size report
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SizeReportGrouping {
+    /// Group symbols by module (i.e., object file)
+    Module,
+    /// Group symbols by the directory of the source file they've been compiled from
+    SourceDirectory,
+    /// Group symbols by their enclosing namespace or class
+    Namespace,
+    /// Group symbols by template (e.g., all `std::vector<T>` instantiations together)
+    TemplateFamily,
+}
+
+impl FromStr for SizeReportGrouping {
+    type Err = ResymCoreError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "module" | "mod" => Ok(SizeReportGrouping::Module),
+            "source-directory" | "source-dir" | "dir" => Ok(SizeReportGrouping::SourceDirectory),
+            "namespace" | "class" | "ns" => Ok(SizeReportGrouping::Namespace),
+            "template-family" | "template" => Ok(SizeReportGrouping::TemplateFamily),
+            _ => Err(ResymCoreError::ParseSizeReportGroupingError(s.to_owned())),
+        }
+    }
+}
+
+/// Size information gathered for a single code or data symbol
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SymbolSize {
+    /// Name of the symbol
+    pub name: String,
+    /// Index of the module the symbol has been attributed to, if any
+    pub module_index: Option<ModuleIndex>,
+    /// Path of the source file the symbol has been compiled from, if known
+    pub source_file: Option<String>,
+    /// Number of bytes of code (i.e., `procedure.len`)
+    pub code_size: u64,
+    /// Number of bytes of data (i.e., size of the symbol's type)
+    pub data_size: u64,
+}
+
+/// Aggregated sizes for a group of symbols
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SizeReportEntry {
+    /// Name of the group
+    pub name: String,
+    /// Number of symbols in the group
+    pub symbol_count: usize,
+    /// Bytes of code attributed to the group
+    pub code_size: u64,
+    /// Bytes of data attributed to the group
+    pub data_size: u64,
+    /// Bytes contributed to the image's sections by the group, according to
+    /// the linker. Only available when grouping by module.
+    pub section_contribution_size: Option<u64>,
+}
+
+impl SizeReportEntry {
+    pub fn total_size(&self) -> u64 {
+        self.code_size + self.data_size
+    }
+}
+
+pub type SizeReport = Vec<SizeReportEntry>;
+
+/// Compute a size report for the given PDB file, aggregating code and data
+/// bytes following the given `grouping` criterion.
+/// Entries are ordered by name.
+pub fn compute_size_report(pdb_file: &PdbFile, grouping: SizeReportGrouping) -> Result<SizeReport> {
+    let symbol_sizes = pdb_file.symbol_sizes()?;
+    let module_list = pdb_file.module_list()?;
+
+    let mut groups: BTreeMap<String, SizeReportEntry> = BTreeMap::new();
+    for symbol_size in symbol_sizes {
+        let group_name = match grouping {
+            SizeReportGrouping::Module => symbol_size
+                .module_index
+                .and_then(|module_index| module_list.get(module_index))
+                .map(|(module_path, _)| module_path.clone()),
+            SizeReportGrouping::SourceDirectory => symbol_size
+                .source_file
+                .as_deref()
+                .and_then(source_directory),
+            SizeReportGrouping::Namespace => symbol_namespace(&symbol_size.name),
+            SizeReportGrouping::TemplateFamily => symbol_template_family(&symbol_size.name),
+        }
+        .unwrap_or_else(|| UNATTRIBUTED_GROUP_NAME.to_string());
+
+        let entry = groups
+            .entry(group_name.clone())
+            .or_insert_with(|| SizeReportEntry {
+                name: group_name,
+                ..Default::default()
+            });
+        entry.symbol_count += 1;
+        entry.code_size += symbol_size.code_size;
+        entry.data_size += symbol_size.data_size;
+    }
+
+    // Section contributions can only be attributed to modules
+    if grouping == SizeReportGrouping::Module {
+        for (module_index, contribution_size) in pdb_file.module_section_contributions()? {
+            if let Some((module_path, _)) = module_list.get(module_index) {
+                let entry = groups
+                    .entry(module_path.clone())
+                    .or_insert_with(|| SizeReportEntry {
+                        name: module_path.clone(),
+                        ..Default::default()
+                    });
+                *entry.section_contribution_size.get_or_insert(0) += contribution_size;
+            }
+        }
+    }
+
+    Ok(groups.into_values().collect())
+}
+
+/// Return the directory part of a source file path (Windows or POSIX style)
+fn source_directory(source_file: &str) -> Option<String> {
+    source_file
+        .rfind(['\\', '/'])
+        .map(|separator_pos| source_file[..separator_pos].to_string())
+}
+
+/// Split a qualified C++ name into its components, ignoring separators
+/// that appear inside of template argument lists.
+fn split_qualified_name(name: &str) -> Vec<&str> {
+    let mut components = vec![];
+    let mut depth = 0_i32;
+    let mut component_start = 0;
+    let bytes = name.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            // Brackets that are part of an operator's name (e.g., `operator<<`)
+            // aren't template argument delimiters
+            b'<' | b'>' if is_operator_name(&name[component_start..i]) => {}
+            b'<' | b'(' => depth += 1,
+            b'>' | b')' => depth -= 1,
+            b':' if depth == 0 && bytes.get(i + 1) == Some(&b':') => {
+                components.push(&name[component_start..i]);
+                component_start = i + 2;
+                i += 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    components.push(&name[component_start..]);
+
+    components
+}
+
+/// Check whether the given (partial) name component is an operator's name
+fn is_operator_name(name_component: &str) -> bool {
+    name_component
+        .trim_end_matches(['<', '>', '-', '='])
+        .ends_with("operator")
+}
+
+/// Return the namespace or class a symbol is declared in, if any
+fn symbol_namespace(symbol_name: &str) -> Option<String> {
+    let components = split_qualified_name(symbol_name);
+    if components.len() > 1 {
+        Some(components[..components.len() - 1].join("::"))
+    } else {
+        None
+    }
+}
+
+/// Return the template family of a symbol (i.e., the qualified name of the
+/// outermost template it belongs to, with template arguments stripped), if any
+fn symbol_template_family(symbol_name: &str) -> Option<String> {
+    let components = split_qualified_name(symbol_name);
+    components
+        .iter()
+        .position(|component| component.contains('<') && !is_operator_name(component))
+        .map(|template_component_pos| {
+            let mut family_components: Vec<&str> = components[..template_component_pos].to_vec();
+            let template_component = components[template_component_pos];
+            let template_name = template_component
+                .find('<')
+                .map(|pos| &template_component[..pos])
+                .unwrap_or(template_component);
+            family_components.push(template_name);
+
+            format!("{}<>", family_components.join("::"))
+        })
+}
@@ -0,0 +1,525 @@
+#[cfg(feature = "http")]
+use std::time::Duration;
+use std::{
+    fs,
+    io::{Cursor, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+#[cfg(feature = "http")]
+use crossbeam_channel::RecvTimeoutError;
+
+use crate::{
+    error::{Result, ResymCoreError},
+    pe_file::{CodeViewInfo, PeFile},
+    progress::ProgressReporter,
+};
+
+/// Environment variable used to configure the default symbol search path
+pub const SYMBOL_PATH_ENV_VAR: &str = "_NT_SYMBOL_PATH";
+/// Interval at which pending downloads check whether they've been cancelled
+#[cfg(feature = "http")]
+const CANCELLATION_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Element of a symbol search path (i.e., what's between two `;`)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SymbolPathElement {
+    /// Local directory (or network share), searched both as a flat directory
+    /// and as a symbol store
+    Directory(PathBuf),
+    /// Downstream store in which files retrieved by the symbol servers that
+    /// follow get cached (i.e., `cache*<dir>`)
+    Cache(PathBuf),
+    /// Symbol server (i.e., `srv*[<downstream store>*]<upstream store>`).
+    /// The upstream store is either a URL or a local symbol store.
+    SymbolServer {
+        downstream_stores: Vec<PathBuf>,
+        upstream_store: String,
+    },
+}
+
+/// `_NT_SYMBOL_PATH`-style search path, used to locate the PDB file that
+/// matches a PE image
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SymbolPath {
+    pub elements: Vec<SymbolPathElement>,
+}
+
+impl FromStr for SymbolPath {
+    type Err = ResymCoreError;
+
+    fn from_str(symbol_path: &str) -> Result<Self> {
+        let mut elements = vec![];
+        for element in symbol_path.split(';').map(str::trim) {
+            if element.is_empty() {
+                continue;
+            }
+
+            let mut parts = element.split('*');
+            match parts.next().map(str::to_lowercase).as_deref() {
+                Some("cache") => {
+                    let cache_dir = parts.next().unwrap_or_default();
+                    elements.push(SymbolPathElement::Cache(if cache_dir.is_empty() {
+                        default_downstream_store()
+                    } else {
+                        PathBuf::from(cache_dir)
+                    }));
+                }
+                Some(prefix @ ("srv" | "symsrv")) => {
+                    if prefix == "symsrv" {
+                        // Skip the name of the symbol server DLL
+                        parts.next();
+                    }
+                    let mut stores: Vec<&str> = parts.collect();
+                    let upstream_store = match stores.pop() {
+                        Some(upstream_store) if !upstream_store.is_empty() => upstream_store,
+                        _ => {
+                            return Err(ResymCoreError::InvalidParameterError(format!(
+                                "symbol server without an upstream store: '{element}'"
+                            )));
+                        }
+                    };
+                    elements.push(SymbolPathElement::SymbolServer {
+                        downstream_stores: stores
+                            .into_iter()
+                            .map(|store| {
+                                if store.is_empty() {
+                                    default_downstream_store()
+                                } else {
+                                    PathBuf::from(store)
+                                }
+                            })
+                            .collect(),
+                        upstream_store: upstream_store.to_string(),
+                    });
+                }
+                _ => {
+                    if element.contains('*') {
+                        return Err(ResymCoreError::InvalidParameterError(format!(
+                            "unsupported symbol path element: '{element}'"
+                        )));
+                    }
+                    elements.push(SymbolPathElement::Directory(PathBuf::from(element)));
+                }
+            }
+        }
+
+        Ok(Self { elements })
+    }
+}
+
+impl SymbolPath {
+    /// Create `SymbolPath` from the `_NT_SYMBOL_PATH` environment variable.
+    /// Returns an empty search path if the variable isn't set or is invalid.
+    pub fn from_env() -> Self {
+        match std::env::var(SYMBOL_PATH_ENV_VAR) {
+            Err(_) => Self::default(),
+            Ok(symbol_path) => symbol_path.parse().unwrap_or_else(|err| {
+                log::warn!("Ignoring invalid {SYMBOL_PATH_ENV_VAR}: {err}");
+                Self::default()
+            }),
+        }
+    }
+
+    /// Locate the PDB file that matches the given PE image.
+    /// The path stored in the image and the image's directory are searched
+    /// first, followed by the symbol search path.
+    pub fn find_pdb_for_pe(&self, pe_file: &PeFile) -> Result<PathBuf> {
+        self.find_pdb_for_pe_with_progress(pe_file, &Default::default())
+    }
+
+    pub fn find_pdb_for_pe_with_progress(
+        &self,
+        pe_file: &PeFile,
+        progress: &ProgressReporter,
+    ) -> Result<PathBuf> {
+        let code_view_info = pe_file.code_view_info.as_ref().ok_or_else(|| {
+            ResymCoreError::PdbNotFoundError(format!(
+                "'{}' has no CodeView debug information",
+                pe_file.file_path.display()
+            ))
+        })?;
+
+        let mut candidate_paths = vec![PathBuf::from(&code_view_info.pdb_path)];
+        if let (Some(image_dir), Some(pdb_name)) =
+            (pe_file.file_path.parent(), code_view_info.pdb_file_name())
+        {
+            candidate_paths.push(image_dir.join(pdb_name));
+        }
+        for candidate_path in candidate_paths {
+            if is_matching_pdb(&candidate_path, code_view_info) {
+                return Ok(candidate_path);
+            }
+        }
+
+        self.find_pdb_with_progress(code_view_info, progress)
+    }
+
+    /// Locate the PDB file referenced by the given CodeView debug information
+    /// in the symbol search path. PDB files retrieved from symbol servers are
+    /// stored in downstream stores and decompressed if needed.
+    pub fn find_pdb(&self, code_view_info: &CodeViewInfo) -> Result<PathBuf> {
+        self.find_pdb_with_progress(code_view_info, &Default::default())
+    }
+
+    pub fn find_pdb_with_progress(
+        &self,
+        code_view_info: &CodeViewInfo,
+        progress: &ProgressReporter,
+    ) -> Result<PathBuf> {
+        let pdb_name = code_view_info.pdb_file_name().ok_or_else(|| {
+            ResymCoreError::InvalidParameterError(format!(
+                "invalid PDB file name: '{}'",
+                code_view_info.pdb_path
+            ))
+        })?;
+        let store_key = code_view_info.symbol_store_key();
+
+        let mut cache_stores: Vec<PathBuf> = vec![];
+        for (element_index, element) in self.elements.iter().enumerate() {
+            progress.check_cancelled()?;
+            progress.report("Searching symbol path", element_index, self.elements.len());
+
+            let search_result = match element {
+                SymbolPathElement::Directory(directory) => {
+                    let pdb_path = directory.join(pdb_name);
+                    if is_matching_pdb(&pdb_path, code_view_info) {
+                        return Ok(pdb_path);
+                    }
+                    let expansion_store = cache_stores
+                        .first()
+                        .cloned()
+                        .unwrap_or_else(default_downstream_store);
+                    find_matching_in_local_store(
+                        directory,
+                        &expansion_store,
+                        pdb_name,
+                        code_view_info,
+                    )
+                }
+                SymbolPathElement::Cache(cache_store) => {
+                    cache_stores.push(cache_store.clone());
+                    find_matching_in_local_store(cache_store, cache_store, pdb_name, code_view_info)
+                }
+                SymbolPathElement::SymbolServer {
+                    downstream_stores,
+                    upstream_store,
+                } => {
+                    let downstream_stores = if !downstream_stores.is_empty() {
+                        downstream_stores.clone()
+                    } else if !cache_stores.is_empty() {
+                        cache_stores.clone()
+                    } else {
+                        vec![default_downstream_store()]
+                    };
+                    find_in_symbol_server(
+                        &downstream_stores,
+                        upstream_store,
+                        pdb_name,
+                        code_view_info,
+                        progress,
+                    )
+                }
+            };
+
+            match search_result {
+                Ok(Some(pdb_path)) => {
+                    log::info!("'{}' found at '{}'", pdb_name, pdb_path.display());
+                    return Ok(pdb_path);
+                }
+                Ok(None) => {}
+                Err(ResymCoreError::CancelledError) => return Err(ResymCoreError::CancelledError),
+                Err(err) => log::warn!("Failed to search {:?}: {}", element, err),
+            }
+        }
+
+        Err(ResymCoreError::PdbNotFoundError(format!(
+            "'{pdb_name}' ({store_key}) isn't in the symbol search path"
+        )))
+    }
+}
+
+/// Return the directory used to cache files retrieved from symbol servers,
+/// when the symbol search path doesn't specify one
+pub fn default_downstream_store() -> PathBuf {
+    std::env::temp_dir().join("resym").join("symbols")
+}
+
+fn find_in_symbol_server(
+    downstream_stores: &[PathBuf],
+    upstream_store: &str,
+    pdb_name: &str,
+    code_view_info: &CodeViewInfo,
+    progress: &ProgressReporter,
+) -> Result<Option<PathBuf>> {
+    // Look into downstream stores first
+    for downstream_store in downstream_stores {
+        if let Some(pdb_path) = find_matching_in_local_store(
+            downstream_store,
+            downstream_store,
+            pdb_name,
+            code_view_info,
+        )? {
+            return Ok(Some(pdb_path));
+        }
+    }
+
+    // Retrieve the file from the upstream store and populate downstream stores
+    let store_key = code_view_info.symbol_store_key();
+    let pdb_data = match read_from_upstream_store(upstream_store, pdb_name, &store_key, progress)? {
+        None => return Ok(None),
+        Some((pdb_data, false)) => pdb_data,
+        Some((compressed_pdb_data, true)) => expand_compressed_file(&compressed_pdb_data)?,
+    };
+    if !is_matching_pdb_data(&pdb_data, code_view_info) {
+        log::warn!("'{pdb_name}' retrieved from '{upstream_store}' doesn't match, ignoring it");
+        return Ok(None);
+    }
+    let mut pdb_path = None;
+    for downstream_store in downstream_stores {
+        let stored_pdb_path =
+            write_to_local_store(downstream_store, pdb_name, &store_key, &pdb_data)?;
+        pdb_path.get_or_insert(stored_pdb_path);
+    }
+
+    Ok(pdb_path)
+}
+
+/// Look for a PDB file matching the given CodeView debug information in a
+/// local symbol store. Compressed files are decompressed into
+/// `expansion_store`, once they've been checked.
+fn find_matching_in_local_store(
+    store: &Path,
+    expansion_store: &Path,
+    pdb_name: &str,
+    code_view_info: &CodeViewInfo,
+) -> Result<Option<PathBuf>> {
+    let store_key = code_view_info.symbol_store_key();
+    let pdb_path = match find_in_local_store(store, pdb_name, &store_key) {
+        None => return Ok(None),
+        Some(pdb_path) => pdb_path,
+    };
+
+    if !is_compressed_file(&pdb_path) {
+        if is_matching_pdb(&pdb_path, code_view_info) {
+            return Ok(Some(pdb_path));
+        }
+        log::warn!("'{}' doesn't match, ignoring it", pdb_path.display());
+        return Ok(None);
+    }
+
+    log::debug!("Decompressing '{}' ...", pdb_path.display());
+    let pdb_data = expand_compressed_file(&fs::read(&pdb_path)?)?;
+    if !is_matching_pdb_data(&pdb_data, code_view_info) {
+        log::warn!("'{}' doesn't match, ignoring it", pdb_path.display());
+        return Ok(None);
+    }
+
+    write_to_local_store(expansion_store, pdb_name, &store_key, &pdb_data).map(Some)
+}
+
+/// Look for a file in a local symbol store. Returns the path of the file,
+/// which might be compressed (i.e., `.pd_` files).
+fn find_in_local_store(store: &Path, file_name: &str, store_key: &str) -> Option<PathBuf> {
+    let mut store_dirs = vec![store.join(file_name).join(store_key)];
+    // Two-tier stores use the first two characters of file names as a prefix
+    if store.join("index2.txt").is_file() {
+        let prefix: String = file_name.chars().take(2).collect();
+        store_dirs.insert(0, store.join(prefix).join(file_name).join(store_key));
+    }
+
+    for store_dir in store_dirs {
+        for candidate_name in [file_name.to_string(), compressed_file_name(file_name)] {
+            let candidate_path = store_dir.join(candidate_name);
+            log::debug!("Looking for '{}' ...", candidate_path.display());
+            if candidate_path.is_file() {
+                return Some(candidate_path);
+            }
+        }
+
+        // Files can also be referenced through pointer files
+        if let Ok(file_pointer) = fs::read_to_string(store_dir.join("file.ptr")) {
+            if let Some(pointed_path) = file_pointer.trim().strip_prefix("PATH:") {
+                let pointed_path = PathBuf::from(pointed_path);
+                if pointed_path.is_file() {
+                    return Some(pointed_path);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Retrieve a file from an upstream store, which can be either a URL or a
+/// local symbol store. Returns the file's content and whether it's compressed.
+fn read_from_upstream_store(
+    upstream_store: &str,
+    file_name: &str,
+    store_key: &str,
+    progress: &ProgressReporter,
+) -> Result<Option<(Vec<u8>, bool)>> {
+    let lowercase_store = upstream_store.to_lowercase();
+    if lowercase_store.starts_with("http://") || lowercase_store.starts_with("https://") {
+        return fetch_from_http_store(upstream_store, file_name, store_key, progress);
+    }
+
+    match find_in_local_store(Path::new(upstream_store), file_name, store_key) {
+        None => Ok(None),
+        Some(file_path) => {
+            let is_compressed = is_compressed_file(&file_path);
+            Ok(Some((fs::read(file_path)?, is_compressed)))
+        }
+    }
+}
+
+/// Download a file from an HTTP symbol server. Downloads run in the
+/// background so that the request can be cancelled while waiting for them.
+#[cfg(feature = "http")]
+fn fetch_from_http_store(
+    store_url: &str,
+    file_name: &str,
+    store_key: &str,
+    progress: &ProgressReporter,
+) -> Result<Option<(Vec<u8>, bool)>> {
+    for (candidate_name, is_compressed) in [
+        (file_name.to_string(), false),
+        (compressed_file_name(file_name), true),
+    ] {
+        let url = format!(
+            "{}/{}/{}/{}",
+            store_url.trim_end_matches('/'),
+            file_name,
+            store_key,
+            candidate_name
+        );
+        log::debug!("Fetching '{url}' ...");
+        let (tx_response, rx_response) = crossbeam_channel::bounded(1);
+        ehttp::fetch(ehttp::Request::get(&url), move |result| {
+            // Note: the receiver is gone if the request has been cancelled
+            let _ = tx_response.send(result);
+        });
+        let response = loop {
+            match rx_response.recv_timeout(CANCELLATION_POLL_INTERVAL) {
+                Ok(result) => break result.map_err(ResymCoreError::EHttpError)?,
+                Err(RecvTimeoutError::Timeout) => progress.check_cancelled()?,
+                Err(RecvTimeoutError::Disconnected) => {
+                    return Err(ResymCoreError::EHttpError(format!(
+                        "'{url}' didn't return a response"
+                    )));
+                }
+            }
+        };
+        if response.ok {
+            return Ok(Some((response.bytes, is_compressed)));
+        } else if response.status != 404 {
+            return Err(ResymCoreError::EHttpError(format!(
+                "'{}' returned {} {}",
+                url, response.status, response.status_text
+            )));
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(not(feature = "http"))]
+fn fetch_from_http_store(
+    store_url: &str,
+    _file_name: &str,
+    _store_key: &str,
+    _progress: &ProgressReporter,
+) -> Result<Option<(Vec<u8>, bool)>> {
+    Err(ResymCoreError::NotImplementedError(format!(
+        "HTTP symbol servers aren't supported in this build ('{store_url}')"
+    )))
+}
+
+fn write_to_local_store(
+    store: &Path,
+    file_name: &str,
+    store_key: &str,
+    file_data: &[u8],
+) -> Result<PathBuf> {
+    let store_dir = store.join(file_name).join(store_key);
+    fs::create_dir_all(&store_dir)?;
+    let file_path = store_dir.join(file_name);
+    fs::write(&file_path, file_data)?;
+
+    Ok(file_path)
+}
+
+/// Extract the first file of a CAB archive (i.e., `.pd_` files)
+fn expand_compressed_file(compressed_data: &[u8]) -> Result<Vec<u8>> {
+    let mut cabinet = cab::Cabinet::new(Cursor::new(compressed_data))?;
+    let file_name = cabinet
+        .folder_entries()
+        .flat_map(|folder| folder.file_entries())
+        .map(|file| file.name().to_string())
+        .next()
+        .ok_or_else(|| {
+            ResymCoreError::InvalidParameterError("compressed file is empty".to_string())
+        })?;
+
+    let mut file_data = vec![];
+    cabinet.read_file(&file_name)?.read_to_end(&mut file_data)?;
+
+    Ok(file_data)
+}
+
+/// Return the name of the compressed version of a file (e.g., `ntdll.pd_`)
+fn compressed_file_name(file_name: &str) -> String {
+    let mut compressed_name = file_name.to_string();
+    compressed_name.pop();
+    compressed_name.push('_');
+
+    compressed_name
+}
+
+fn is_compressed_file(file_path: &Path) -> bool {
+    file_path
+        .extension()
+        .map(|extension| extension.to_string_lossy().ends_with('_'))
+        .unwrap_or(false)
+}
+
+/// Check whether the PDB file at the given path matches the given CodeView
+/// debug information
+fn is_matching_pdb(pdb_path: &Path, code_view_info: &CodeViewInfo) -> bool {
+    if !pdb_path.is_file() {
+        return false;
+    }
+    log::debug!("Checking '{}' ...", pdb_path.display());
+    match fs::File::open(pdb_path) {
+        Ok(pdb_file) => is_matching_pdb_source(pdb_file, code_view_info),
+        Err(_) => false,
+    }
+}
+
+/// Check whether the given PDB file content matches the given CodeView debug
+/// information
+fn is_matching_pdb_data(pdb_data: &[u8], code_view_info: &CodeViewInfo) -> bool {
+    is_matching_pdb_source(Cursor::new(pdb_data), code_view_info)
+}
+
+fn is_matching_pdb_source<'s>(
+    pdb_source: impl pdb::Source<'s> + 's,
+    code_view_info: &CodeViewInfo,
+) -> bool {
+    let read_guid_and_age = || -> Result<([u8; 16], u32)> {
+        let mut pdb = pdb::PDB::open(pdb_source)?;
+        let pdb_information = pdb.pdb_information()?;
+        let pdb_age = pdb
+            .debug_information()?
+            .age()
+            .unwrap_or(pdb_information.age);
+
+        Ok((pdb_information.guid.to_bytes_le(), pdb_age))
+    };
+
+    match read_guid_and_age() {
+        Ok((pdb_guid, pdb_age)) => pdb_guid == code_view_info.guid && pdb_age >= code_view_info.age,
+        Err(_) => false,
+    }
+}
@@ -0,0 +1,53 @@
+use std::ops::Range;
+
+use crate::{
+    error::{Result, ResymCoreError},
+    options::FilterOptions,
+};
+
+/// Find all the occurrences of `query` in `text`.
+/// Matches are returned as sorted, non-overlapping byte ranges.
+pub fn find_text_matches(
+    text: &str,
+    query: &str,
+    options: &FilterOptions,
+) -> Result<Vec<Range<usize>>> {
+    if query.is_empty() {
+        return Ok(vec![]);
+    }
+
+    // Note: plain queries are escaped and matched as regular expressions too,
+    // as lowercasing the text could change the offsets of the matches
+    let pattern = if options.use_regex {
+        query.to_string()
+    } else {
+        regex::escape(query)
+    };
+    let regex = regex::RegexBuilder::new(&pattern)
+        .case_insensitive(options.case_insensitive)
+        .build()
+        .map_err(|err| ResymCoreError::InvalidParameterError(err.to_string()))?;
+
+    Ok(regex
+        .find_iter(text)
+        // Ignore empty matches, which cannot be highlighted
+        .filter(|text_match| !text_match.is_empty())
+        .map(|text_match| text_match.range())
+        .collect())
+}
+
+/// Return the byte offset at which the given line (starting at 1) starts, if
+/// the text contains that many lines
+pub fn line_start_offset(text: &str, line_number: usize) -> Option<usize> {
+    if line_number == 0 {
+        return None;
+    }
+    if line_number == 1 {
+        return Some(0);
+    }
+
+    text.match_indices('\n')
+        .nth(line_number - 2)
+        .map(|(offset, _)| offset + 1)
+        .filter(|offset| *offset < text.len())
+}
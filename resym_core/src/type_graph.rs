@@ -0,0 +1,524 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    fmt::Write,
+    str::FromStr,
+};
+
+use crate::{error::ResymCoreError, pdb_file::TypeIndex};
+
+/// Reference from a type to another type
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TypeEdge {
+    pub type_index: TypeIndex,
+    /// `true` if the type is only referenced through pointers (or references)
+    pub is_pointer: bool,
+    /// `true` if the type is a base class of the referencing type
+    pub is_base_class: bool,
+}
+
+/// Query on the type dependency graph. Types are designated by name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TypeGraphQuery {
+    /// Types needed by the given type, transitively, up to `max_depth` levels
+    Dependencies {
+        type_name: String,
+        max_depth: Option<usize>,
+    },
+    /// Types that need the given type, transitively, up to `max_depth` levels
+    Dependents {
+        type_name: String,
+        max_depth: Option<usize>,
+    },
+    /// Shortest chain of types leading from a type to another. Only types
+    /// contained by value are considered, unless `follow_pointers` is set.
+    Path {
+        from_type_name: String,
+        to_type_name: String,
+        follow_pointers: bool,
+    },
+    /// Cycles going through the given type, or all cycles if no type is given
+    Cycles { type_name: Option<String> },
+}
+
+/// Type returned by type graph queries
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypeGraphNode {
+    pub type_name: String,
+    pub type_index: TypeIndex,
+    /// Distance to the queried type, or position in paths and cycles
+    pub depth: usize,
+    /// `true` if the type has been reached through a pointer
+    pub is_pointer: bool,
+}
+
+/// Result of a type graph query: a single list of types for dependencies,
+/// dependents and paths (empty if no path exists), one list per cycle
+/// otherwise
+pub type TypeGraphQueryResult = Vec<Vec<TypeGraphNode>>;
+
+/// Graph of the dependencies between complete types (i.e., which types are
+/// needed to reconstruct a given type)
+#[derive(Debug, Default)]
+pub struct TypeGraph {
+    dependencies: BTreeMap<TypeIndex, Vec<TypeEdge>>,
+    dependents: BTreeMap<TypeIndex, Vec<TypeEdge>>,
+}
+
+impl TypeGraph {
+    /// Create a graph from a list of `(from, to, is_pointer)` edges
+    pub fn from_edges(edges: impl IntoIterator<Item = (TypeIndex, TypeIndex, bool)>) -> Self {
+        let mut graph = Self::default();
+        for (from, to, is_pointer) in edges {
+            add_edge(&mut graph.dependencies, from, to, is_pointer);
+            add_edge(&mut graph.dependents, to, from, is_pointer);
+        }
+        // Sort edges so that traversals are deterministic
+        for edges in graph
+            .dependencies
+            .values_mut()
+            .chain(graph.dependents.values_mut())
+        {
+            edges.sort_unstable_by_key(|edge| edge.type_index);
+        }
+
+        graph
+    }
+
+    /// Flag the given `(derived, base)` edges as inheritance edges, adding
+    /// them if needed
+    pub fn with_base_classes(
+        mut self,
+        base_classes: impl IntoIterator<Item = (TypeIndex, TypeIndex)>,
+    ) -> Self {
+        for (derived, base) in base_classes {
+            for (adjacency_map, from, to) in [
+                (&mut self.dependencies, derived, base),
+                (&mut self.dependents, base, derived),
+            ] {
+                add_edge(adjacency_map, from, to, false);
+                if let Some(edge) = adjacency_map
+                    .get_mut(&from)
+                    .and_then(|edges| edges.iter_mut().find(|edge| edge.type_index == to))
+                {
+                    edge.is_base_class = true;
+                }
+                if let Some(edges) = adjacency_map.get_mut(&from) {
+                    edges.sort_unstable_by_key(|edge| edge.type_index);
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Types directly needed by the given type
+    pub fn direct_dependencies(&self, type_index: TypeIndex) -> &[TypeEdge] {
+        self.dependencies
+            .get(&type_index)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Types that directly need the given type
+    pub fn direct_dependents(&self, type_index: TypeIndex) -> &[TypeEdge] {
+        self.dependents
+            .get(&type_index)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Types needed by the given type, transitively, along with their
+    /// distance to it. Stops after `max_depth` levels if specified.
+    pub fn dependencies(
+        &self,
+        type_index: TypeIndex,
+        max_depth: Option<usize>,
+    ) -> Vec<(TypeEdge, usize)> {
+        breadth_first_search(&self.dependencies, type_index, max_depth)
+    }
+
+    /// Types that need the given type, transitively, along with their
+    /// distance to it. Stops after `max_depth` levels if specified.
+    pub fn dependents(
+        &self,
+        type_index: TypeIndex,
+        max_depth: Option<usize>,
+    ) -> Vec<(TypeEdge, usize)> {
+        breadth_first_search(&self.dependents, type_index, max_depth)
+    }
+
+    /// Shortest chain of dependencies leading from `from` to `to`, both
+    /// included. Only types contained by value are considered, unless
+    /// `follow_pointers` is set.
+    pub fn shortest_path(
+        &self,
+        from: TypeIndex,
+        to: TypeIndex,
+        follow_pointers: bool,
+    ) -> Option<Vec<TypeEdge>> {
+        let start = TypeEdge {
+            type_index: from,
+            is_pointer: false,
+            is_base_class: false,
+        };
+        if from == to {
+            return Some(vec![start]);
+        }
+
+        // Map visited types to the edge they've been reached through
+        let mut predecessors: HashMap<TypeIndex, (TypeIndex, TypeEdge)> = HashMap::new();
+        let mut types_to_visit = VecDeque::from([from]);
+        while let Some(current_type_index) = types_to_visit.pop_front() {
+            for edge in self.direct_dependencies(current_type_index) {
+                if (edge.is_pointer && !follow_pointers)
+                    || edge.type_index == from
+                    || predecessors.contains_key(&edge.type_index)
+                {
+                    continue;
+                }
+                predecessors.insert(edge.type_index, (current_type_index, *edge));
+                if edge.type_index == to {
+                    return Some(rebuild_path(&predecessors, start, to));
+                }
+                types_to_visit.push_back(edge.type_index);
+            }
+        }
+
+        None
+    }
+
+    /// Edges between the types needed by the given type (included), up to
+    /// `max_depth` levels. Types for which `filter` returns `false` are left
+    /// out, along with the types only reachable through them.
+    pub fn subgraph(
+        &self,
+        type_index: TypeIndex,
+        max_depth: Option<usize>,
+        filter: impl Fn(TypeIndex) -> bool,
+    ) -> Vec<(TypeIndex, TypeEdge)> {
+        let mut edges = vec![];
+        let mut visited_types = HashSet::from([type_index]);
+        let mut types_to_visit = VecDeque::from([(type_index, 0)]);
+        while let Some((current_type_index, depth)) = types_to_visit.pop_front() {
+            if max_depth.is_some_and(|max_depth| depth >= max_depth) {
+                continue;
+            }
+            for edge in self.direct_dependencies(current_type_index) {
+                if !filter(edge.type_index) {
+                    continue;
+                }
+                edges.push((current_type_index, *edge));
+                if visited_types.insert(edge.type_index) {
+                    types_to_visit.push_back((edge.type_index, depth + 1));
+                }
+            }
+        }
+
+        edges
+    }
+
+    /// Groups of types that depend on each other (i.e., strongly connected
+    /// components), each one given as a cycle starting and ending with its
+    /// smallest type index. Note: cycles necessarily go through pointers.
+    pub fn cycles(&self) -> Vec<Vec<TypeEdge>> {
+        strongly_connected_components(&self.dependencies)
+            .into_iter()
+            .filter_map(|component| {
+                let first_type_index = *component.iter().min()?;
+                self.cycle_through(first_type_index)
+            })
+            .collect()
+    }
+
+    /// Shortest cycle going through the given type, if any
+    pub fn cycle_through(&self, type_index: TypeIndex) -> Option<Vec<TypeEdge>> {
+        // Look for the shortest path from one of the type's dependencies back
+        // to the type
+        let mut best_cycle: Option<Vec<TypeEdge>> = None;
+        for edge in self.direct_dependencies(type_index) {
+            let path = if edge.type_index == type_index {
+                Some(vec![])
+            } else {
+                self.shortest_path(edge.type_index, type_index, true)
+            };
+            if let Some(path) = path {
+                let is_shorter = match &best_cycle {
+                    Some(best_cycle) => path.len() + 1 < best_cycle.len(),
+                    None => true,
+                };
+                if is_shorter {
+                    let mut cycle = vec![
+                        TypeEdge {
+                            type_index,
+                            is_pointer: false,
+                            is_base_class: false,
+                        },
+                        *edge,
+                    ];
+                    cycle.extend(path.into_iter().skip(1));
+                    best_cycle = Some(cycle);
+                }
+            }
+        }
+
+        best_cycle
+    }
+}
+
+fn add_edge(
+    adjacency_map: &mut BTreeMap<TypeIndex, Vec<TypeEdge>>,
+    from: TypeIndex,
+    to: TypeIndex,
+    is_pointer: bool,
+) {
+    let edges = adjacency_map.entry(from).or_default();
+    match edges.iter_mut().find(|edge| edge.type_index == to) {
+        // Types referenced both by value and through pointers are considered
+        // contained by value
+        Some(edge) => edge.is_pointer &= is_pointer,
+        None => edges.push(TypeEdge {
+            type_index: to,
+            is_pointer,
+            is_base_class: false,
+        }),
+    }
+}
+
+fn breadth_first_search(
+    adjacency_map: &BTreeMap<TypeIndex, Vec<TypeEdge>>,
+    type_index: TypeIndex,
+    max_depth: Option<usize>,
+) -> Vec<(TypeEdge, usize)> {
+    let mut result = vec![];
+    let mut visited_types = HashSet::from([type_index]);
+    let mut types_to_visit = VecDeque::from([(type_index, 0)]);
+    while let Some((current_type_index, depth)) = types_to_visit.pop_front() {
+        if max_depth.is_some_and(|max_depth| depth >= max_depth) {
+            continue;
+        }
+        if let Some(edges) = adjacency_map.get(&current_type_index) {
+            for edge in edges {
+                if visited_types.insert(edge.type_index) {
+                    result.push((*edge, depth + 1));
+                    types_to_visit.push_back((edge.type_index, depth + 1));
+                }
+            }
+        }
+    }
+
+    result
+}
+
+fn rebuild_path(
+    predecessors: &HashMap<TypeIndex, (TypeIndex, TypeEdge)>,
+    start: TypeEdge,
+    end: TypeIndex,
+) -> Vec<TypeEdge> {
+    let mut path = vec![];
+    let mut current_type_index = end;
+    while let Some((predecessor, edge)) = predecessors.get(&current_type_index) {
+        path.push(*edge);
+        current_type_index = *predecessor;
+    }
+    path.push(start);
+    path.reverse();
+
+    path
+}
+
+/// Tarjan's algorithm (iterative version, as type graphs can be very deep).
+/// Only returns components made of several types, or of a single type that
+/// depends on itself.
+fn strongly_connected_components(
+    adjacency_map: &BTreeMap<TypeIndex, Vec<TypeEdge>>,
+) -> Vec<Vec<TypeIndex>> {
+    #[derive(Clone, Copy)]
+    struct NodeState {
+        index: usize,
+        low_link: usize,
+        on_stack: bool,
+    }
+
+    let mut components = vec![];
+    let mut node_states: HashMap<TypeIndex, NodeState> = HashMap::new();
+    let mut stack: Vec<TypeIndex> = vec![];
+    let mut next_index = 0;
+    for &root in adjacency_map.keys() {
+        if node_states.contains_key(&root) {
+            continue;
+        }
+
+        // Each frame contains a node and the position of the next edge to visit
+        let mut call_stack: Vec<(TypeIndex, usize)> = vec![(root, 0)];
+        while let Some((node, edge_position)) = call_stack.pop() {
+            if edge_position == 0 {
+                node_states.insert(
+                    node,
+                    NodeState {
+                        index: next_index,
+                        low_link: next_index,
+                        on_stack: true,
+                    },
+                );
+                next_index += 1;
+                stack.push(node);
+            }
+
+            let edges = adjacency_map
+                .get(&node)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            if let Some(edge) = edges.get(edge_position) {
+                call_stack.push((node, edge_position + 1));
+                match node_states.get(&edge.type_index) {
+                    None => call_stack.push((edge.type_index, 0)),
+                    Some(successor_state) if successor_state.on_stack => {
+                        let successor_index = successor_state.index;
+                        let node_state = node_states.get_mut(&node).expect("node must be known");
+                        node_state.low_link = node_state.low_link.min(successor_index);
+                    }
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            // All edges have been visited
+            let node_state = node_states[&node];
+            if let Some((parent, _)) = call_stack.last() {
+                let parent_state = node_states.get_mut(parent).expect("node must be known");
+                parent_state.low_link = parent_state.low_link.min(node_state.low_link);
+            }
+            if node_state.low_link == node_state.index {
+                let mut component = vec![];
+                while let Some(member) = stack.pop() {
+                    if let Some(member_state) = node_states.get_mut(&member) {
+                        member_state.on_stack = false;
+                    }
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                let is_cycle =
+                    component.len() > 1 || edges.iter().any(|edge| edge.type_index == node);
+                if is_cycle {
+                    component.sort_unstable();
+                    components.push(component);
+                }
+            }
+        }
+    }
+    components.sort_unstable();
+
+    components
+}
+
+/// Format used to export type graphs
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TypeGraphFormat {
+    /// Graphviz's DOT language
+    #[default]
+    Dot,
+    /// Mermaid flowchart
+    Mermaid,
+}
+
+impl FromStr for TypeGraphFormat {
+    type Err = ResymCoreError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "dot" | "graphviz" => Ok(TypeGraphFormat::Dot),
+            "mermaid" => Ok(TypeGraphFormat::Mermaid),
+            _ => Err(ResymCoreError::ParseTypeGraphFormatError(s.to_owned())),
+        }
+    }
+}
+
+/// Render a graph given its root type and edges (see `TypeGraph::subgraph`).
+/// Types contained by value are linked with plain arrows, types referenced
+/// through pointers with dashed arrows and base classes with bold arrows.
+pub fn render_type_graph(
+    root_type_index: TypeIndex,
+    edges: &[(TypeIndex, TypeEdge)],
+    format: TypeGraphFormat,
+    type_name: impl Fn(TypeIndex) -> String,
+) -> String {
+    // List types in order of appearance, starting with the root
+    let mut type_indices = vec![root_type_index];
+    let mut known_types = HashSet::from([root_type_index]);
+    for (from, edge) in edges {
+        for type_index in [*from, edge.type_index] {
+            if known_types.insert(type_index) {
+                type_indices.push(type_index);
+            }
+        }
+    }
+
+    let mut output = String::new();
+    match format {
+        TypeGraphFormat::Dot => {
+            let _ = writeln!(
+                output,
+                "digraph \"{}\" {{",
+                escape_dot_string(&type_name(root_type_index))
+            );
+            let _ = writeln!(output, "    node [shape=box];");
+            for type_index in type_indices {
+                let style = if type_index == root_type_index {
+                    ", style=bold"
+                } else {
+                    ""
+                };
+                let _ = writeln!(
+                    output,
+                    "    t{type_index} [label=\"{}\"{style}];",
+                    escape_dot_string(&type_name(type_index))
+                );
+            }
+            for (from, edge) in edges {
+                let style = if edge.is_base_class {
+                    " [arrowhead=empty, style=bold]"
+                } else if edge.is_pointer {
+                    " [style=dashed]"
+                } else {
+                    ""
+                };
+                let _ = writeln!(output, "    t{from} -> t{}{style};", edge.type_index);
+            }
+            output.push_str("}\n");
+        }
+        TypeGraphFormat::Mermaid => {
+            output.push_str("flowchart LR\n");
+            for type_index in type_indices {
+                let _ = writeln!(
+                    output,
+                    "    t{type_index}[\"{}\"]",
+                    escape_mermaid_string(&type_name(type_index))
+                );
+            }
+            for (from, edge) in edges {
+                let arrow = if edge.is_base_class {
+                    "==>"
+                } else if edge.is_pointer {
+                    "-.->"
+                } else {
+                    "-->"
+                };
+                let _ = writeln!(output, "    t{from} {arrow} t{}", edge.type_index);
+            }
+        }
+    }
+
+    output
+}
+
+fn escape_dot_string(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_mermaid_string(s: &str) -> String {
+    s.replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
+}
@@ -0,0 +1,126 @@
+use std::ops::Range;
+
+use crate::pdb_file::TypeIndex;
+
+/// Kind of item occupying (part of) a type's memory
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LayoutEntryKind {
+    /// Pointer to the virtual function table
+    VirtualTablePointer,
+    BaseClass,
+    Field,
+}
+
+/// Item occupying (part of) a type's memory
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LayoutEntry {
+    pub kind: LayoutEntryKind,
+    pub name: String,
+    /// Name of the entry's type (e.g., `char[16]`)
+    pub type_name: String,
+    /// User-defined type to navigate to from this entry (e.g., the pointee
+    /// type of pointers or the element type of arrays), if any
+    pub linked_type: Option<(String, TypeIndex)>,
+    /// Offset of the entry in bytes, within the containing type
+    pub offset: u64,
+    /// Size of the entry in bytes (i.e., the size of the allocation unit for
+    /// bit-field members)
+    pub size: u64,
+    /// Offset and size in bits, within the allocation unit. Present only for
+    /// bit-field members.
+    pub bitfield_info: Option<(u8, u8)>,
+    /// Row in which the entry should be displayed, so that overlapping entries
+    /// (e.g., members of unions) are stacked instead of drawn on top of each
+    /// other
+    pub lane: usize,
+}
+
+impl LayoutEntry {
+    /// Range of bits occupied by the entry, within the containing type
+    pub fn bit_range(&self) -> Range<u64> {
+        match self.bitfield_info {
+            Some((bit_offset, bit_size)) => {
+                let start = self.offset * 8 + u64::from(bit_offset);
+                start..start + u64::from(bit_size)
+            }
+            None => self.offset * 8..(self.offset + self.size) * 8,
+        }
+    }
+}
+
+/// Memory layout of a class, struct or union
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypeLayout {
+    pub type_name: String,
+    pub type_index: TypeIndex,
+    pub size: u64,
+    pub is_union: bool,
+    /// Entries in declaration order
+    pub entries: Vec<LayoutEntry>,
+    /// Number of rows needed to display all entries
+    pub lane_count: usize,
+    /// Byte ranges that aren't occupied by any entry
+    pub padding: Vec<Range<u64>>,
+}
+
+impl TypeLayout {
+    /// Create a layout from entries given in declaration order, assigning them
+    /// rows and locating padding holes
+    pub fn new(
+        type_name: String,
+        type_index: TypeIndex,
+        size: u64,
+        is_union: bool,
+        mut entries: Vec<LayoutEntry>,
+    ) -> Self {
+        // Put each entry in the first row in which it doesn't overlap with any
+        // other entry. Note: bit ranges are compared, so that bit-field members
+        // sharing an allocation unit end up in the same row.
+        let mut lanes: Vec<Vec<Range<u64>>> = vec![];
+        for entry in &mut entries {
+            let bit_range = entry.bit_range();
+            let overlaps = |lane: &Vec<Range<u64>>| {
+                lane.iter().any(|other_range| {
+                    other_range.start < bit_range.end && bit_range.start < other_range.end
+                })
+            };
+            entry.lane = match lanes.iter().position(|lane| !overlaps(lane)) {
+                Some(lane_index) => lane_index,
+                None => {
+                    lanes.push(vec![]);
+                    lanes.len() - 1
+                }
+            };
+            lanes[entry.lane].push(bit_range);
+        }
+
+        // Look for bytes that aren't covered by any entry
+        let mut occupied_ranges: Vec<Range<u64>> = entries
+            .iter()
+            .map(|entry| entry.offset..entry.offset + entry.size)
+            .collect();
+        occupied_ranges.sort_by_key(|range| range.start);
+        let mut padding = vec![];
+        let mut padding_start = 0;
+        for occupied_range in occupied_ranges {
+            if occupied_range.start > padding_start {
+                padding.push(padding_start..occupied_range.start.min(size));
+            }
+            padding_start = padding_start.max(occupied_range.end);
+        }
+        if padding_start < size {
+            padding.push(padding_start..size);
+        }
+        padding.retain(|range| !range.is_empty());
+
+        Self {
+            type_name,
+            type_index,
+            size,
+            is_union,
+            entries,
+            lane_count: lanes.len(),
+            padding,
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use std::path::Path;
+
+use resym_core::{
+    annotations::{AnnotationStore, Annotations, Bookmark},
+    options::ReconstructionOptions,
+    pdb_file::PdbFile,
+    session::Session,
+};
+use tempdir::TempDir;
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+const TEST_TYPE_NAME: &str = "resym_test::StructTest";
+
+fn test_annotations() -> Annotations {
+    let mut annotations = Annotations::default();
+    annotations.add_bookmark(Bookmark::Type {
+        type_name: TEST_TYPE_NAME.to_string(),
+        type_index: 0x1003,
+    });
+    annotations.add_bookmark(Bookmark::Symbol {
+        symbol_name: "main".to_string(),
+        symbol_index: Some((2, 0x4)),
+    });
+    annotations.add_bookmark(Bookmark::Module {
+        module_path: "test.obj".to_string(),
+        module_index: 2,
+    });
+    annotations.set_note(TEST_TYPE_NAME, None, "Main test structure");
+    annotations.set_note(TEST_TYPE_NAME, Some("u2"), "First line\n\tSecond line \\o/");
+
+    annotations
+}
+
+#[test]
+fn test_annotations_serialization() {
+    let annotations = test_annotations();
+    let serialized_annotations = annotations.to_string();
+    assert_eq!(
+        Annotations::parse(&serialized_annotations).expect("parse annotations"),
+        annotations
+    );
+
+    assert!(Annotations::parse("bookmark\tfoo").is_err());
+    assert!(Annotations::parse("type\tnot_an_index\tfoo").is_err());
+    assert!(Annotations::parse("note\tfoo\t\tinvalid \\escape").is_err());
+}
+
+#[test]
+fn test_annotations_edition() {
+    let mut annotations = test_annotations();
+
+    // Bookmarks are identified by kind and name
+    let type_bookmark = Bookmark::Type {
+        type_name: TEST_TYPE_NAME.to_string(),
+        type_index: 0x1234,
+    };
+    assert!(annotations.is_bookmarked(&type_bookmark));
+    annotations.add_bookmark(type_bookmark.clone());
+    assert_eq!(annotations.bookmarks().len(), 3);
+    assert_eq!(annotations.bookmarks()[0], type_bookmark);
+    annotations.remove_bookmark(&type_bookmark);
+    assert!(!annotations.is_bookmarked(&type_bookmark));
+
+    // Blank notes remove existing notes
+    assert_eq!(
+        annotations.note(TEST_TYPE_NAME, None),
+        Some("Main test structure")
+    );
+    annotations.set_note(TEST_TYPE_NAME, None, " \n");
+    assert_eq!(annotations.note(TEST_TYPE_NAME, None), None);
+    assert_eq!(annotations.notes().count(), 1);
+}
+
+#[test]
+fn test_annotations_rendering() {
+    let session = Session::from(
+        PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb"),
+    );
+    let (reconstructed_type, _) = session
+        .reconstruct_type_by_name(TEST_TYPE_NAME, &ReconstructionOptions::new())
+        .expect("type reconstruction");
+
+    let annotated_type = test_annotations().annotate(&reconstructed_type);
+    let annotated_lines: Vec<&str> = annotated_type.lines().collect();
+    let type_line = annotated_lines
+        .iter()
+        .position(|line| line.starts_with("struct resym_test::StructTest "))
+        .expect("type definition");
+    assert_eq!(annotated_lines[type_line - 1], "// Main test structure");
+    let field_line = annotated_lines
+        .iter()
+        .position(|line| line.ends_with(" u2;"))
+        .expect("field definition");
+    assert_eq!(
+        annotated_lines[field_line - 2..field_line],
+        ["  // First line", "  // \tSecond line \\o/"]
+    );
+    // Other lines are left untouched
+    assert_eq!(
+        annotated_lines.len(),
+        reconstructed_type.lines().count() + 3
+    );
+}
+
+#[test]
+fn test_annotations_field_name_parsing() {
+    const FIELDS: &[(&str, &str)] = &[
+        (
+            "templated_field",
+            "/* 0x0000 */ public: Foo<Bar[3], std::function<void (int32_t)> > templated_field;",
+        ),
+        (
+            "callback",
+            "/* 0x0010 */ public: void (* callback)(Foo<Bar[3]>*, void (**)(void*));",
+        ),
+        (
+            "callbacks",
+            "/* 0x0018 */ public: int32_t (* callbacks[2])(uint32_t);",
+        ),
+        (
+            "bit_field",
+            "/* 0x0028 */ public: uint32_t bit_field : 3; /* BitPos=0 */",
+        ),
+    ];
+    let mut reconstructed_type = "struct Foo { /* Size=0x30 */\n".to_string();
+    let mut annotations = Annotations::default();
+    for (field_name, field_line) in FIELDS {
+        reconstructed_type.push_str(&format!("  {field_line}\n"));
+        annotations.set_note("Foo", Some(field_name), field_name);
+    }
+    reconstructed_type.push_str("};\n");
+
+    let annotated_type = annotations.annotate(&reconstructed_type);
+    let annotated_lines: Vec<&str> = annotated_type.lines().collect();
+    for (field_name, field_line) in FIELDS {
+        let field_line_index = annotated_lines
+            .iter()
+            .position(|line| line.trim() == *field_line)
+            .expect("field definition");
+        assert_eq!(
+            annotated_lines[field_line_index - 1],
+            format!("  // {field_name}")
+        );
+    }
+}
+
+#[test]
+fn test_annotation_store() {
+    let annotation_dir =
+        TempDir::new("resym_annotations").expect("failed to create temporary directory");
+    let annotation_store = AnnotationStore::new(annotation_dir.path().join("annotations"));
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    let (pdb_guid, pdb_age) = pdb_file.guid_and_age().expect("PDB GUID and age");
+
+    // PDB files without annotations have empty annotations
+    assert!(annotation_store
+        .load(&pdb_guid, pdb_age)
+        .expect("load annotations")
+        .is_empty());
+
+    let annotations = test_annotations();
+    annotation_store
+        .store(&pdb_guid, pdb_age, &annotations)
+        .expect("store annotations");
+    assert!(annotation_store
+        .annotation_file_path(&pdb_guid, pdb_age)
+        .exists());
+    assert_eq!(
+        annotation_store
+            .load(&pdb_guid, pdb_age)
+            .expect("load annotations"),
+        annotations
+    );
+
+    // Storing empty annotations removes the annotation file
+    annotation_store
+        .store(&pdb_guid, pdb_age, &Annotations::default())
+        .expect("store annotations");
+    assert!(!annotation_store
+        .annotation_file_path(&pdb_guid, pdb_age)
+        .exists());
+}
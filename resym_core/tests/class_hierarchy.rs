@@ -0,0 +1,31 @@
+use resym_core::class_hierarchy::{ClassHierarchy, ClassHierarchyNode};
+
+fn names(nodes: &[ClassHierarchyNode]) -> Vec<String> {
+    nodes.iter().map(|node| node.type_name.clone()).collect()
+}
+
+#[test]
+fn test_class_hierarchy_diamond() {
+    // 4 derives from 2 and 3, which both virtually derive from 1
+    let class_hierarchy =
+        ClassHierarchy::from_edges([(2, 1, true), (3, 1, true), (4, 2, false), (4, 3, false)]);
+    assert_eq!(class_hierarchy.base_classes(4).len(), 2);
+    assert_eq!(class_hierarchy.derived_classes(1).len(), 2);
+    assert_eq!(class_hierarchy.edges().count(), 4);
+
+    let tree = class_hierarchy.tree(4, |type_index| format!("C{type_index}"));
+    assert_eq!(tree.type_name, "C4");
+    assert!(tree.derived_classes.is_empty());
+    assert_eq!(names(&tree.base_classes), ["C2", "C3"]);
+    assert!(!tree.base_classes[0].is_virtual);
+    // Shared virtual base classes appear under each path
+    for base_class in &tree.base_classes {
+        assert_eq!(names(&base_class.children), ["C1"]);
+        assert!(base_class.children[0].is_virtual);
+    }
+
+    let tree = class_hierarchy.tree(1, |type_index| format!("C{type_index}"));
+    assert!(tree.base_classes.is_empty());
+    assert_eq!(names(&tree.derived_classes), ["C2", "C3"]);
+    assert_eq!(names(&tree.derived_classes[1].children), ["C4"]);
+}
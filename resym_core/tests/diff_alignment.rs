@@ -0,0 +1,42 @@
+use resym_core::diffing::{inline_changes, side_by_side_rows, DiffChange};
+
+#[test]
+fn test_side_by_side_rows() {
+    let metadata = [
+        ((Some(0), Some(0)), DiffChange::Equal),
+        ((Some(1), None), DiffChange::Delete),
+        ((Some(2), None), DiffChange::Delete),
+        ((None, Some(1)), DiffChange::Insert),
+        ((Some(3), Some(2)), DiffChange::Equal),
+        ((None, Some(3)), DiffChange::Insert),
+    ];
+
+    // Deleted lines are aligned with the lines inserted in their place
+    let rows = side_by_side_rows(&metadata)
+        .into_iter()
+        .map(|row| (row.old_line, row.new_line))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        rows,
+        [
+            (Some(0), Some(0)),
+            (Some(1), Some(3)),
+            (Some(2), None),
+            (Some(4), Some(4)),
+            (None, Some(5)),
+        ]
+    );
+    assert!(side_by_side_rows(&[]).is_empty());
+}
+
+#[test]
+#[allow(clippy::single_range_in_vec_init)]
+fn test_inline_changes() {
+    let (old_ranges, new_ranges) = inline_changes("  uint32_t field1;", "  uint64_t field1;");
+    assert_eq!(old_ranges, [6..8]);
+    assert_eq!(new_ranges, [6..8]);
+
+    let (old_ranges, new_ranges) = inline_changes("int a;", "int abc;");
+    assert!(old_ranges.is_empty());
+    assert_eq!(new_ranges, [5..7]);
+}
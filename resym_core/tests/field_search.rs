@@ -0,0 +1,115 @@
+use std::path::Path;
+
+use resym_core::{
+    field_search::{filter_fields, parse_field_offset, FieldInfo, FieldSearchQuery},
+    options::FilterOptions,
+    pdb_file::PdbFile,
+};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+
+fn query(
+    field_name_filter: &str,
+    field_type_filter: &str,
+    offset: Option<u64>,
+) -> FieldSearchQuery {
+    FieldSearchQuery {
+        field_name_filter: field_name_filter.to_string(),
+        field_type_filter: field_type_filter.to_string(),
+        offset,
+    }
+}
+
+fn format_fields(field_list: &[FieldInfo]) -> String {
+    field_list.iter().fold(String::new(), |acc, field| {
+        format!(
+            "{acc}\n/* 0x{:04x} */ {}::{}: {}{}",
+            field.offset,
+            field.type_name,
+            field.field_name,
+            field.field_type_name,
+            if field.is_pointer { " (pointer)" } else { "" }
+        )
+    })
+}
+
+#[test]
+fn test_field_search() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    let field_list = pdb_file
+        .field_list(&Default::default())
+        .unwrap_or_else(|err| panic!("field listing failed: {err}"));
+
+    // Results are sorted by type name, then by offset
+    let options = FilterOptions::new().use_regex(true);
+    insta::assert_snapshot!(
+        "field_search_by_name",
+        format_fields(&filter_fields(
+            field_list,
+            &query("^u\\d$", "", None),
+            &options
+        ))
+    );
+    let options = FilterOptions::new().ignore_std_types(true);
+    insta::assert_snapshot!(
+        "field_search_by_type_and_offset",
+        format_fields(&filter_fields(
+            field_list,
+            &query("", "*", Some(0x8)),
+            &options
+        ))
+    );
+}
+
+#[test]
+fn test_field_search_filters() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    let field_list = pdb_file
+        .field_list(&Default::default())
+        .unwrap_or_else(|err| panic!("field listing failed: {err}"));
+    let field_names = |field_list: Vec<FieldInfo>| {
+        field_list
+            .into_iter()
+            .map(|field| format!("{}::{}", field.type_name, field.field_name))
+            .collect::<Vec<_>>()
+    };
+
+    let options = FilterOptions::new().case_insensitive(true);
+    assert_eq!(
+        field_names(filter_fields(
+            field_list,
+            &query("", "_list_entry", Some(0x20)),
+            &options
+        )),
+        [
+            "_PEB_LDR_DATA::InMemoryOrderModuleList",
+            "resym_test::NestedStructUnionRegression1::KCBListEntry"
+        ]
+    );
+    // Fields of types from the standard library can be ignored
+    let is_std_field = |field: &FieldInfo| field.type_name.starts_with("std::");
+    let options = FilterOptions::new();
+    assert!(filter_fields(field_list, &query("_My", "", None), &options)
+        .iter()
+        .any(is_std_field));
+    let options = options.ignore_std_types(true);
+    assert!(
+        !filter_fields(field_list, &query("_My", "", None), &options)
+            .iter()
+            .any(is_std_field)
+    );
+    // Case-sensitive regular expressions
+    let options = FilterOptions::new().use_regex(true);
+    assert!(filter_fields(field_list, &query("^flink$", "", None), &options).is_empty());
+    // Invalid regular expressions match nothing
+    assert!(filter_fields(field_list, &query("[", "", None), &options).is_empty());
+}
+
+#[test]
+fn test_field_offset_parsing() {
+    assert_eq!(parse_field_offset("16").ok(), Some(16));
+    assert_eq!(parse_field_offset("0x10").ok(), Some(16));
+    assert_eq!(parse_field_offset(" 0X1f ").ok(), Some(31));
+    assert!(parse_field_offset("0xg").is_err());
+    assert!(parse_field_offset("").is_err());
+}
@@ -0,0 +1,177 @@
+use std::{fs, path::Path};
+
+use resym_core::{
+    index_cache::IndexCache,
+    pdb_file::PdbFile,
+    progress::{CancellationToken, ProgressReporter},
+};
+use tempdir::TempDir;
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+
+/// Return the paths of the entries present in the given cache
+fn cache_entries(index_cache: &IndexCache) -> Vec<std::path::PathBuf> {
+    match fs::read_dir(index_cache.cache_dir()) {
+        Ok(entries) => entries
+            .map(|entry| entry.expect("failed to read cache entry").path())
+            .collect(),
+        Err(_) => vec![],
+    }
+}
+
+#[test]
+fn test_index_cache_creation_and_reuse() {
+    let cache_dir =
+        TempDir::new("resym_index_cache").expect("failed to create temporary directory");
+    let index_cache = IndexCache::new(cache_dir.path().join("cache"));
+
+    let pdb_file = PdbFile::load_from_file_with_index_cache(
+        Path::new(TEST_PDB_FILE_PATH),
+        Some(index_cache.clone()),
+        &Default::default(),
+    )
+    .expect("load test.pdb");
+    let cache_entries_after_first_load = cache_entries(&index_cache);
+    assert_eq!(cache_entries_after_first_load.len(), 1);
+
+    // Indices loaded from the cache must match the ones computed from the PDB
+    let cached_pdb_file = PdbFile::load_from_file_with_index_cache(
+        Path::new(TEST_PDB_FILE_PATH),
+        Some(index_cache.clone()),
+        &Default::default(),
+    )
+    .expect("load test.pdb");
+    assert_eq!(
+        cached_pdb_file.complete_type_list,
+        pdb_file.complete_type_list
+    );
+    assert_eq!(
+        cached_pdb_file.forwarder_to_complete_type.len(),
+        pdb_file.forwarder_to_complete_type.len()
+    );
+    for entry in pdb_file.forwarder_to_complete_type.iter() {
+        assert_eq!(
+            cached_pdb_file
+                .forwarder_to_complete_type
+                .get(entry.key())
+                .map(|complete_type| *complete_type),
+            Some(*entry.value())
+        );
+    }
+    assert_eq!(cache_entries(&index_cache), cache_entries_after_first_load);
+}
+
+#[test]
+fn test_index_cache_lazy_indices() {
+    let cache_dir =
+        TempDir::new("resym_index_cache").expect("failed to create temporary directory");
+    let index_cache = IndexCache::new(cache_dir.path().to_path_buf());
+
+    // Compute the symbol list and the xref map, which should get cached too
+    let pdb_file = PdbFile::load_from_file_with_index_cache(
+        Path::new(TEST_PDB_FILE_PATH),
+        Some(index_cache.clone()),
+        &Default::default(),
+    )
+    .expect("load test.pdb");
+    let symbol_list: Vec<_> = pdb_file
+        .symbol_list(&Default::default())
+        .expect("symbol list computation failed")
+        .into_iter()
+        .cloned()
+        .collect();
+    let (_, type_index) = pdb_file.complete_type_list[0];
+    let xrefs = pdb_file
+        .get_xrefs_for_type(type_index, &Default::default())
+        .expect("xref computation failed");
+
+    let cached_pdb_file = PdbFile::load_from_file_with_index_cache(
+        Path::new(TEST_PDB_FILE_PATH),
+        Some(index_cache),
+        &Default::default(),
+    )
+    .expect("load test.pdb");
+    assert!(!cached_pdb_file
+        .xref_to_map
+        .read()
+        .expect("lock shouldn't be poisoned")
+        .is_empty());
+
+    // Cached indices don't need to be computed, so lookups succeed even when
+    // cancelled
+    let cancellation_token = CancellationToken::default();
+    cancellation_token.cancel();
+    let cancelled_progress = ProgressReporter::new(cancellation_token, |_| {});
+    assert_eq!(
+        cached_pdb_file
+            .get_xrefs_for_type(type_index, &cancelled_progress)
+            .expect("xref lookup failed"),
+        xrefs
+    );
+    let cached_symbol_list: Vec<_> = cached_pdb_file
+        .symbol_list(&cancelled_progress)
+        .expect("symbol list lookup failed")
+        .into_iter()
+        .cloned()
+        .collect();
+    assert_eq!(cached_symbol_list, symbol_list);
+}
+
+#[test]
+fn test_index_cache_invalidation() {
+    let cache_dir =
+        TempDir::new("resym_index_cache").expect("failed to create temporary directory");
+    let index_cache = IndexCache::new(cache_dir.path().to_path_buf());
+
+    let pdb_file = PdbFile::load_from_file_with_index_cache(
+        Path::new(TEST_PDB_FILE_PATH),
+        Some(index_cache.clone()),
+        &Default::default(),
+    )
+    .expect("load test.pdb");
+    let cache_entry_paths = cache_entries(&index_cache);
+    assert_eq!(cache_entry_paths.len(), 1);
+
+    // Corrupted entries must be ignored and replaced
+    fs::write(&cache_entry_paths[0], b"corrupted").expect("failed to corrupt cache entry");
+    let reloaded_pdb_file = PdbFile::load_from_file_with_index_cache(
+        Path::new(TEST_PDB_FILE_PATH),
+        Some(index_cache.clone()),
+        &Default::default(),
+    )
+    .expect("load test.pdb");
+    assert_eq!(
+        reloaded_pdb_file.complete_type_list,
+        pdb_file.complete_type_list
+    );
+    assert_ne!(
+        fs::read(&cache_entry_paths[0]).expect("failed to read cache entry"),
+        b"corrupted"
+    );
+
+    // Truncated entries too
+    let cache_entry_data = fs::read(&cache_entry_paths[0]).expect("failed to read cache entry");
+    fs::write(
+        &cache_entry_paths[0],
+        &cache_entry_data[..cache_entry_data.len() / 2],
+    )
+    .expect("failed to truncate cache entry");
+    let reloaded_pdb_file = PdbFile::load_from_file_with_index_cache(
+        Path::new(TEST_PDB_FILE_PATH),
+        Some(index_cache.clone()),
+        &Default::default(),
+    )
+    .expect("load test.pdb");
+    assert_eq!(
+        reloaded_pdb_file.complete_type_list,
+        pdb_file.complete_type_list
+    );
+    assert_eq!(
+        fs::read(&cache_entry_paths[0]).expect("failed to read cache entry"),
+        cache_entry_data
+    );
+
+    assert_eq!(index_cache.clear().expect("failed to clear cache"), 1);
+    assert!(cache_entries(&index_cache).is_empty());
+    assert_eq!(index_cache.clear().expect("failed to clear cache"), 0);
+}
@@ -0,0 +1,80 @@
+use std::path::Path;
+
+use resym_core::pdb_file::PdbFile;
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+const TEST_INLINE_SITES_PDB_FILE_PATH: &str = "tests/data/test_inline_sites.pdb";
+
+#[test]
+fn test_inline_site_listing() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+
+    // Note: the test PDB comes from a debug build, nothing gets inlined
+    let inline_sites = pdb_file
+        .inline_sites()
+        .unwrap_or_else(|err| panic!("inline site listing failed: {err}"));
+    assert!(inline_sites.is_empty());
+
+    let call_sites = pdb_file
+        .get_call_sites_for_inlinee("resym_test::UnionTest::UnionTest")
+        .unwrap_or_else(|err| panic!("call site listing failed: {err}"));
+    assert!(call_sites.is_empty());
+    let inlinees = pdb_file
+        .get_inlinees_for_function("main")
+        .unwrap_or_else(|err| panic!("inlinee listing failed: {err}"));
+    assert!(inlinees.is_empty());
+}
+
+#[test]
+fn test_inline_site_listing_optimized() {
+    const MAIN_SYMBOL_INDEX: (usize, u32) = (0, 4);
+    const COMPUTE_SYMBOL_INDEX: (usize, u32) = (0, 112);
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_INLINE_SITES_PDB_FILE_PATH))
+        .expect("load test_inline_sites.pdb");
+
+    // Note: the inline site located outside of any procedure is ignored
+    let inline_sites = pdb_file
+        .inline_sites()
+        .unwrap_or_else(|err| panic!("inline site listing failed: {err}"));
+    let inline_sites = inline_sites
+        .iter()
+        .map(|inline_site| {
+            (
+                inline_site.inlinee_name.as_str(),
+                inline_site.caller_name.as_str(),
+                inline_site.caller_index,
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        inline_sites,
+        [
+            ("resym_test::Square", "main", MAIN_SYMBOL_INDEX),
+            // Nested inline site
+            ("Clamp", "main", MAIN_SYMBOL_INDEX),
+            // Inline site located in a block
+            ("resym_test::Square", "Compute", COMPUTE_SYMBOL_INDEX),
+        ]
+    );
+
+    let call_sites = pdb_file
+        .get_call_sites_for_inlinee("resym_test::Square")
+        .unwrap_or_else(|err| panic!("call site listing failed: {err}"));
+    assert_eq!(
+        call_sites
+            .iter()
+            .map(|inline_site| inline_site.caller_name.as_str())
+            .collect::<Vec<_>>(),
+        ["main", "Compute"]
+    );
+    let inlinees = pdb_file
+        .get_inlinees_for_function("main")
+        .unwrap_or_else(|err| panic!("inlinee listing failed: {err}"));
+    assert_eq!(
+        inlinees
+            .iter()
+            .map(|inline_site| inline_site.inlinee_name.as_str())
+            .collect::<Vec<_>>(),
+        ["resym_test::Square", "Clamp"]
+    );
+}
@@ -0,0 +1,68 @@
+use std::path::Path;
+
+use resym_core::{
+    layout_search::{FieldConstraint, FieldTypeConstraint, LayoutQuery},
+    pdb_file::PdbFile,
+    session::Session,
+};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+
+#[test]
+fn test_field_constraint_parsing() {
+    let constraint = |offset, field_type| FieldConstraint { offset, field_type };
+    assert_eq!(
+        "0x8:ptr".parse::<FieldConstraint>().ok(),
+        Some(constraint(8, FieldTypeConstraint::Pointer))
+    );
+    assert_eq!(
+        "16:uint32_t".parse::<FieldConstraint>().ok(),
+        Some(constraint(
+            16,
+            FieldTypeConstraint::TypeName("uint32_t".to_string())
+        ))
+    );
+    assert_eq!(
+        "0x18".parse::<FieldConstraint>().ok(),
+        Some(constraint(0x18, FieldTypeConstraint::Any))
+    );
+    assert!("ptr".parse::<FieldConstraint>().is_err());
+    assert!(":uint32_t".parse::<FieldConstraint>().is_err());
+}
+
+#[test]
+fn test_layout_matching() {
+    let session = Session::from(
+        PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb"),
+    );
+    let query = LayoutQuery {
+        size: Some(0x30),
+        fields: vec![
+            "0x8:ptr".parse().expect("valid constraint"),
+            "0x10:UINT64_T".parse().expect("valid constraint"),
+        ],
+    };
+
+    // Types are ranked by number of matched constraints, then by size
+    let layout_matches = session
+        .match_layout(&query, Some(10))
+        .expect("layout matching failed");
+    assert!(layout_matches
+        .iter()
+        .all(|layout_match| layout_match.constraint_count == 3));
+    let snapshot_data = layout_matches
+        .iter()
+        .fold(String::new(), |acc, layout_match| {
+            format!(
+                "{acc}\n{} (size=0x{:x}): {}/{}",
+                layout_match.type_name,
+                layout_match.size,
+                layout_match.matched_constraint_count,
+                layout_match.constraint_count
+            )
+        });
+    insta::assert_snapshot!("layout_matching", snapshot_data);
+
+    // Empty queries are rejected
+    assert!(session.match_layout(&LayoutQuery::default(), None).is_err());
+}
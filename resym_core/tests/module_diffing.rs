@@ -12,14 +12,14 @@ const TEST_MODULE_PATH: &str = "d:\\a01\\_work\\43\\s\\Intermediate\\vctools\\ms
 
 #[test]
 fn test_module_diffing_by_path() {
-    let mut pdb_file_from = PdbFile::load_from_file(Path::new(TEST_PDB_FROM_FILE_PATH))
+    let pdb_file_from = PdbFile::load_from_file(Path::new(TEST_PDB_FROM_FILE_PATH))
         .expect("load test_diff_from.pdb");
-    let mut pdb_file_to =
+    let pdb_file_to =
         PdbFile::load_from_file(Path::new(TEST_PDB_TO_FILE_PATH)).expect("load test_diff_to.pdb");
 
     let module_diff = diff_module_by_path(
-        &mut pdb_file_from,
-        &mut pdb_file_to,
+        &pdb_file_from,
+        &pdb_file_to,
         TEST_MODULE_PATH,
         PrimitiveReconstructionFlavor::Portable,
         true,
@@ -0,0 +1,378 @@
+use std::path::{Path, PathBuf};
+
+use resym_core::{pdb_file::PdbFile, pdb_types::PrimitiveReconstructionFlavor, pe_file::PeFile};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+
+const IMAGE_BASE: u64 = 0x1_8000_0000;
+const IMAGE_DIRECTORY_ENTRY_EXPORT: usize = 0;
+const IMAGE_DIRECTORY_ENTRY_IMPORT: usize = 1;
+const IMAGE_DIRECTORY_ENTRY_DEBUG: usize = 6;
+const IMAGE_SCN_CODE: u32 = 0x6000_0020;
+const IMAGE_SCN_READ_ONLY_DATA: u32 = 0x4000_0040;
+
+/// Section of a test PE image
+struct TestSection {
+    name: &'static [u8],
+    rva: u32,
+    raw_size: u32,
+    characteristics: u32,
+}
+
+/// 64-bit PE image under construction. Sections' content is laid out right
+/// after the headers, in order.
+struct TestPeImage {
+    sections: Vec<TestSection>,
+    image: Vec<u8>,
+}
+
+impl TestPeImage {
+    const HEADERS_SIZE: u32 = 0x400;
+    const OPTIONAL_HEADER_OFFSET: usize = 0x58;
+
+    fn new(sections: Vec<TestSection>) -> Self {
+        let image_size =
+            Self::HEADERS_SIZE + sections.iter().map(|section| section.raw_size).sum::<u32>();
+        let mut pe_image = Self {
+            sections,
+            image: vec![0u8; image_size as usize],
+        };
+
+        // DOS header
+        pe_image.write(0x00, b"MZ");
+        pe_image.write(0x3c, &0x40u32.to_le_bytes());
+        // NT signature and file header
+        pe_image.write(0x40, b"PE\0\0");
+        pe_image.write(0x44, &0x8664u16.to_le_bytes()); // Machine: AMD64
+        let section_count = pe_image.sections.len() as u16;
+        pe_image.write(0x46, &section_count.to_le_bytes()); // NumberOfSections
+        pe_image.write(0x54, &240u16.to_le_bytes()); // SizeOfOptionalHeader
+        pe_image.write(0x56, &0x2022u16.to_le_bytes()); // Characteristics
+
+        // Optional header
+        let optional_header = Self::OPTIONAL_HEADER_OFFSET;
+        let size_of_image = pe_image
+            .sections
+            .iter()
+            .map(|section| section.rva + section.raw_size)
+            .max()
+            .unwrap_or(Self::HEADERS_SIZE);
+        // Aligned on `SectionAlignment`
+        let size_of_image = (size_of_image + 0xfff) & !0xfff;
+        pe_image.write(optional_header, &0x20bu16.to_le_bytes()); // Magic: PE32+
+        pe_image.write(optional_header + 24, &IMAGE_BASE.to_le_bytes()); // ImageBase
+        pe_image.write(optional_header + 32, &0x1000u32.to_le_bytes()); // SectionAlignment
+        pe_image.write(optional_header + 36, &0x200u32.to_le_bytes()); // FileAlignment
+        pe_image.write(optional_header + 56, &size_of_image.to_le_bytes()); // SizeOfImage
+        pe_image.write(optional_header + 60, &Self::HEADERS_SIZE.to_le_bytes()); // SizeOfHeaders
+        pe_image.write(optional_header + 68, &3u16.to_le_bytes()); // Subsystem
+        pe_image.write(optional_header + 108, &16u32.to_le_bytes()); // NumberOfRvaAndSizes
+
+        // Section headers
+        let mut raw_offset = Self::HEADERS_SIZE;
+        for section_index in 0..pe_image.sections.len() {
+            let section_header = optional_header + 240 + section_index * 40;
+            let (name, rva, raw_size, characteristics) = {
+                let section = &pe_image.sections[section_index];
+                (
+                    section.name,
+                    section.rva,
+                    section.raw_size,
+                    section.characteristics,
+                )
+            };
+            pe_image.write(section_header, name);
+            pe_image.write(section_header + 8, &raw_size.to_le_bytes()); // VirtualSize
+            pe_image.write(section_header + 12, &rva.to_le_bytes());
+            pe_image.write(section_header + 16, &raw_size.to_le_bytes()); // SizeOfRawData
+            pe_image.write(section_header + 20, &raw_offset.to_le_bytes());
+            pe_image.write(section_header + 36, &characteristics.to_le_bytes());
+            raw_offset += raw_size;
+        }
+
+        pe_image
+    }
+
+    fn write(&mut self, offset: usize, bytes: &[u8]) {
+        self.image[offset..offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    /// Return the file offset of the given address
+    fn file_offset(&self, rva: u32) -> u32 {
+        let mut raw_offset = Self::HEADERS_SIZE;
+        for section in &self.sections {
+            if rva >= section.rva && rva < section.rva + section.raw_size {
+                return raw_offset + (rva - section.rva);
+            }
+            raw_offset += section.raw_size;
+        }
+
+        panic!("RVA 0x{rva:x} isn't located in any section");
+    }
+
+    fn write_at_rva(&mut self, rva: u32, bytes: &[u8]) {
+        let file_offset = self.file_offset(rva) as usize;
+        self.write(file_offset, bytes);
+    }
+
+    fn set_data_directory(&mut self, directory_index: usize, rva: u32, size: u32) {
+        let data_directory = Self::OPTIONAL_HEADER_OFFSET + 112 + directory_index * 8;
+        self.write(data_directory, &rva.to_le_bytes());
+        self.write(data_directory + 4, &size.to_le_bytes());
+    }
+
+    /// Add a CodeView debug directory entry that references a PDB with the
+    /// given GUID and age
+    fn add_code_view_info(&mut self, rva: u32, pdb_guid: [u8; 16], pdb_age: u32) {
+        const CODE_VIEW_OFFSET: u32 = 0x20;
+
+        self.set_data_directory(IMAGE_DIRECTORY_ENTRY_DEBUG, rva, 28);
+        // Debug directory entry
+        let code_view_rva = rva + CODE_VIEW_OFFSET;
+        self.write_at_rva(rva + 12, &2u32.to_le_bytes()); // Type: CodeView
+        self.write_at_rva(rva + 16, &(4 + 16 + 4 + 9u32).to_le_bytes());
+        self.write_at_rva(rva + 20, &code_view_rva.to_le_bytes());
+        let code_view_file_offset = self.file_offset(code_view_rva);
+        self.write_at_rva(rva + 24, &code_view_file_offset.to_le_bytes());
+        // CodeView record
+        self.write_at_rva(code_view_rva, b"RSDS");
+        self.write_at_rva(code_view_rva + 4, &pdb_guid);
+        self.write_at_rva(code_view_rva + 20, &pdb_age.to_le_bytes());
+        self.write_at_rva(code_view_rva + 24, b"test.pdb\0");
+    }
+
+    /// Add an export table containing a single named export
+    fn add_export(&mut self, rva: u32, ordinal: u32, export_name: &[u8], export_rva: u32) {
+        const EXPORT_TABLE_SIZE: u32 = 0x100;
+
+        self.set_data_directory(IMAGE_DIRECTORY_ENTRY_EXPORT, rva, EXPORT_TABLE_SIZE);
+        let functions_rva = rva + 0x40;
+        let names_rva = rva + 0x48;
+        let name_ordinals_rva = rva + 0x50;
+        let module_name_rva = rva + 0x60;
+        let export_name_rva = rva + 0x80;
+        self.write_at_rva(rva + 12, &module_name_rva.to_le_bytes()); // Name
+        self.write_at_rva(rva + 16, &ordinal.to_le_bytes()); // Base
+        self.write_at_rva(rva + 20, &1u32.to_le_bytes()); // NumberOfFunctions
+        self.write_at_rva(rva + 24, &1u32.to_le_bytes()); // NumberOfNames
+        self.write_at_rva(rva + 28, &functions_rva.to_le_bytes());
+        self.write_at_rva(rva + 32, &names_rva.to_le_bytes());
+        self.write_at_rva(rva + 36, &name_ordinals_rva.to_le_bytes());
+        self.write_at_rva(functions_rva, &export_rva.to_le_bytes());
+        self.write_at_rva(names_rva, &export_name_rva.to_le_bytes());
+        self.write_at_rva(name_ordinals_rva, &0u16.to_le_bytes());
+        self.write_at_rva(module_name_rva, b"test.exe\0");
+        self.write_at_rva(export_name_rva, export_name);
+    }
+
+    /// Add an import table containing a single symbol imported by name,
+    /// whose import address table entry is located at `iat_rva`
+    fn add_import(&mut self, rva: u32, module_name: &[u8], import_name: &[u8], iat_rva: u32) {
+        // Import descriptors (the second one terminates the list)
+        self.set_data_directory(IMAGE_DIRECTORY_ENTRY_IMPORT, rva, 2 * 20);
+        let lookup_table_rva = rva + 0x40;
+        let hint_name_rva = rva + 0x80;
+        let module_name_rva = rva + 0xc0;
+        self.write_at_rva(rva, &lookup_table_rva.to_le_bytes()); // OriginalFirstThunk
+        self.write_at_rva(rva + 12, &module_name_rva.to_le_bytes()); // Name
+        self.write_at_rva(rva + 16, &iat_rva.to_le_bytes()); // FirstThunk
+                                                             // Import lookup table and (unbound) import address table
+        self.write_at_rva(lookup_table_rva, &u64::from(hint_name_rva).to_le_bytes());
+        self.write_at_rva(iat_rva, &u64::from(hint_name_rva).to_le_bytes());
+        // Hint and name
+        self.write_at_rva(hint_name_rva + 2, import_name);
+        self.write_at_rva(module_name_rva, module_name);
+    }
+}
+
+/// Build a minimal 64-bit PE image, with a single `.rdata` section containing
+/// a CodeView debug directory entry that references a PDB with the given GUID
+/// and age.
+fn build_test_pe_image(pdb_guid: [u8; 16], pdb_age: u32) -> Vec<u8> {
+    const SECTION_RVA: u32 = 0x1000;
+
+    let mut pe_image = TestPeImage::new(vec![TestSection {
+        name: b".rdata\0\0",
+        rva: SECTION_RVA,
+        raw_size: 0x200,
+        characteristics: IMAGE_SCN_READ_ONLY_DATA,
+    }]);
+    pe_image.add_code_view_info(SECTION_RVA, pdb_guid, pdb_age);
+
+    pe_image.image
+}
+
+/// Build a 64-bit PE image whose sections match the ones of `test.pdb`, with
+/// content located at the address of some of the PDB's symbols:
+/// - `WideCharToMultiByte` is exported with ordinal 7
+/// - `__imp_WideCharToMultiByte` is the IAT entry of an import
+/// - `??_C@_0M@HLOHPNFA@RegCloseKey@` is a string literal
+/// - `??_7PureVirtualClassSpecialized@resym_test@@6B@` is a vtable with two
+///   entries
+fn build_test_pe_image_with_symbols(pdb_guid: [u8; 16], pdb_age: u32) -> Vec<u8> {
+    const TEXT_RVA: u32 = 0x11000;
+    const RDATA_RVA: u32 = 0x1a000;
+    const IDATA_RVA: u32 = 0x22000;
+
+    let mut pe_image = TestPeImage::new(vec![
+        TestSection {
+            name: b".text\0\0\0",
+            rva: TEXT_RVA,
+            raw_size: 0x8800,
+            characteristics: IMAGE_SCN_CODE,
+        },
+        TestSection {
+            name: b".rdata\0\0",
+            rva: RDATA_RVA,
+            raw_size: 0x3e00,
+            characteristics: IMAGE_SCN_READ_ONLY_DATA,
+        },
+        TestSection {
+            name: b".idata\0\0",
+            rva: IDATA_RVA,
+            raw_size: 0x1000,
+            characteristics: IMAGE_SCN_READ_ONLY_DATA,
+        },
+    ]);
+    // Note: these are located in parts of the sections that aren't used by
+    // the PDB's symbols
+    pe_image.add_code_view_info(RDATA_RVA + 0x3c00, pdb_guid, pdb_age);
+    pe_image.add_export(RDATA_RVA + 0x3d00, 7, b"WideCharToMultiByte\0", 0x161a6);
+    pe_image.add_import(
+        IDATA_RVA + 0x800,
+        b"KERNEL32.dll\0",
+        b"WideCharToMultiByte\0",
+        0x22020,
+    );
+    // String literal
+    pe_image.write_at_rva(0x1c020, b"RegCloseKey\0");
+    // Virtual function table, terminated by a null pointer
+    pe_image.write_at_rva(0x1b6a8, &(IMAGE_BASE + 0x11c00).to_le_bytes());
+    pe_image.write_at_rva(0x1b6b0, &(IMAGE_BASE + 0x11c40).to_le_bytes());
+
+    pe_image.image
+}
+
+#[test]
+fn test_pe_loading_invalid_image() {
+    // PDB files aren't valid PE images
+    assert!(PeFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).is_err());
+}
+
+#[test]
+fn test_pe_loading_matching_image() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    let (pdb_guid, pdb_age) = pdb_file
+        .guid_and_age()
+        .unwrap_or_else(|err| panic!("failed to read the PDB's GUID: {err}"));
+
+    let pe_file = PeFile::load_from_bytes(
+        PathBuf::from("test.exe"),
+        build_test_pe_image(pdb_guid, pdb_age),
+    )
+    .unwrap_or_else(|err| panic!("PE loading failed: {err}"));
+    assert_eq!(pe_file.image_base, 0x1_8000_0000);
+    assert_eq!(pe_file.pointer_size, 8);
+    let code_view_info = pe_file
+        .code_view_info
+        .as_ref()
+        .expect("CodeView information should be present");
+    assert_eq!(code_view_info.guid, pdb_guid);
+    assert_eq!(code_view_info.age, pdb_age);
+    assert_eq!(code_view_info.pdb_path, "test.pdb");
+
+    pdb_file
+        .load_pe_file(pe_file)
+        .unwrap_or_else(|err| panic!("matching PE rejected: {err}"));
+    assert!(pdb_file.pe_file().is_some());
+}
+
+#[test]
+fn test_pe_loading_mismatching_image() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    let (pdb_guid, pdb_age) = pdb_file
+        .guid_and_age()
+        .unwrap_or_else(|err| panic!("failed to read the PDB's GUID: {err}"));
+
+    // Different GUID
+    let pe_file = PeFile::load_from_bytes(
+        PathBuf::from("test.exe"),
+        build_test_pe_image([0; 16], pdb_age),
+    )
+    .unwrap_or_else(|err| panic!("PE loading failed: {err}"));
+    assert!(pdb_file.load_pe_file(pe_file).is_err());
+
+    // Image more recent than the PDB
+    let pe_file = PeFile::load_from_bytes(
+        PathBuf::from("test.exe"),
+        build_test_pe_image(pdb_guid, pdb_age + 1),
+    )
+    .unwrap_or_else(|err| panic!("PE loading failed: {err}"));
+    assert!(pdb_file.load_pe_file(pe_file).is_err());
+    assert!(pdb_file.pe_file().is_none());
+}
+
+#[test]
+fn test_pe_loading_symbol_enrichment() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    let (pdb_guid, pdb_age) = pdb_file
+        .guid_and_age()
+        .unwrap_or_else(|err| panic!("failed to read the PDB's GUID: {err}"));
+
+    let pe_file = PeFile::load_from_bytes(
+        PathBuf::from("test.exe"),
+        build_test_pe_image_with_symbols(pdb_guid, pdb_age),
+    )
+    .unwrap_or_else(|err| panic!("PE loading failed: {err}"));
+    assert_eq!(pe_file.exports.len(), 1);
+    assert_eq!(pe_file.exports[0].ordinal, 7);
+    assert_eq!(
+        pe_file.exports[0].name.as_deref(),
+        Some("WideCharToMultiByte")
+    );
+    assert_eq!(pe_file.imports.len(), 1);
+    assert_eq!(pe_file.imports[0].module_name, "KERNEL32.dll");
+    assert_eq!(
+        pe_file.imports[0].name.as_deref(),
+        Some("WideCharToMultiByte")
+    );
+    assert_eq!(pe_file.imports[0].iat_rva, 0x22020);
+    pdb_file
+        .load_pe_file(pe_file)
+        .unwrap_or_else(|err| panic!("matching PE rejected: {err}"));
+
+    let reconstruct_symbol = |symbol_name: &str| {
+        pdb_file
+            .reconstruct_symbol_by_name(symbol_name, PrimitiveReconstructionFlavor::Portable, false)
+            .unwrap_or_else(|err| panic!("symbol reconstruction failed: {err}"))
+    };
+    // Export ordinals
+    assert_eq!(
+        reconstruct_symbol("WideCharToMultiByte"),
+        "void WideCharToMultiByte(); // RVA=0x161a6 Export=#7 (no type information)"
+    );
+    // Import names
+    assert_eq!(
+        reconstruct_symbol("__imp_WideCharToMultiByte"),
+        "char __imp_WideCharToMultiByte; // RVA=0x22020 Import=KERNEL32.dll!WideCharToMultiByte (no type information)"
+    );
+    // Names of string literals and vtables are filtered out of the symbol
+    // list, so these are looked up by index (in the global symbol stream)
+    let reconstruct_global_symbol = |symbol_offset: u32| {
+        pdb_file
+            .reconstruct_symbol_by_index(
+                (usize::MAX, symbol_offset),
+                PrimitiveReconstructionFlavor::Portable,
+                false,
+            )
+            .unwrap_or_else(|err| panic!("symbol reconstruction failed: {err}"))
+    };
+    // String literals (`??_C@_0M@HLOHPNFA@RegCloseKey@`)
+    assert!(reconstruct_global_symbol(0xfd94).contains("; // RVA=0x1c020 Value=\"RegCloseKey\" "));
+    // Virtual function tables (`??_7PureVirtualClassSpecialized@resym_test@@6B@`)
+    assert!(reconstruct_global_symbol(0x10030).contains(concat!(
+        "; // RVA=0x1b6a8\n",
+        "//   [0] RVA=0x11c00 resym_test::InterfaceImplClass::InterfaceVirtual\n",
+        "//   [1] RVA=0x11c40 resym_test::SpecializedInterfaceImplClass::InterfaceVirtual "
+    )));
+}
@@ -0,0 +1,75 @@
+use std::sync::{Arc, Mutex};
+
+use resym_core::{
+    pdb_file::PdbFile,
+    pdb_types::PrimitiveReconstructionFlavor,
+    progress::{CancellationToken, Progress, ProgressReporter},
+    ResymCoreError,
+};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+
+#[test]
+fn test_progress_reporting() {
+    let pdb_data = std::fs::read(TEST_PDB_FILE_PATH).expect("failed to read test.pdb");
+    let reported_progress = Arc::new(Mutex::new(vec![]));
+    let progress = {
+        let reported_progress = reported_progress.clone();
+        ProgressReporter::new(CancellationToken::default(), move |progress| {
+            reported_progress
+                .lock()
+                .expect("lock shouldn't be poisoned")
+                .push(progress)
+        })
+    };
+
+    let pdb_file = PdbFile::load_from_bytes_as_vec("test.pdb".to_string(), pdb_data, &progress)
+        .expect("load test.pdb");
+    pdb_file
+        .symbol_list(&progress)
+        .expect("symbol listing failed");
+    assert!(progress.has_reported());
+
+    let reported_progress = reported_progress
+        .lock()
+        .expect("lock shouldn't be poisoned");
+    assert!(reported_progress.iter().any(|progress| matches!(
+        progress,
+        Progress::Running { description, .. } if description == "Loading types"
+    )));
+    assert!(reported_progress.iter().any(|progress| matches!(
+        progress,
+        Progress::Running { description, .. } if description == "Listing symbols"
+    )));
+    assert!(reported_progress
+        .iter()
+        .all(|progress| (0.0..=1.0).contains(&progress.fraction())));
+}
+
+#[test]
+fn test_cancellation() {
+    let pdb_data = std::fs::read(TEST_PDB_FILE_PATH).expect("failed to read test.pdb");
+    let cancellation_token = CancellationToken::default();
+    let progress = ProgressReporter::new(cancellation_token.clone(), |_| {});
+
+    let pdb_file =
+        PdbFile::load_from_bytes_as_vec("test.pdb".to_string(), pdb_data.clone(), &progress)
+            .expect("load test.pdb");
+
+    // Cancelled requests must stop with a `CancelledError`
+    cancellation_token.cancel();
+    assert!(matches!(
+        pdb_file.reconstruct_all_types(
+            PrimitiveReconstructionFlavor::Portable,
+            true,
+            false,
+            false,
+            &progress
+        ),
+        Err(ResymCoreError::CancelledError)
+    ));
+    assert!(matches!(
+        PdbFile::load_from_bytes_as_vec("test.pdb".to_string(), pdb_data, &progress),
+        Err(ResymCoreError::CancelledError)
+    ));
+}
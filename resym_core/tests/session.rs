@@ -0,0 +1,257 @@
+use std::path::Path;
+
+use resym_core::{
+    field_search::FieldSearchQuery,
+    layout_search::LayoutQuery,
+    options::{FilterOptions, ReconstructionOptions},
+    pdb_file::PdbFile,
+    pdb_types::PrimitiveReconstructionFlavor,
+    session::Session,
+    type_graph::TypeGraphQuery,
+    type_layout::LayoutEntryKind,
+};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+const TEST_TYPE_NAME: &str = "resym_test::StructTest";
+
+fn open_test_session() -> Session {
+    Session::from(PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb"))
+}
+
+#[test]
+fn test_session_type_reconstruction() {
+    let session = open_test_session();
+    let options = ReconstructionOptions::new()
+        .primitives_flavor(PrimitiveReconstructionFlavor::Microsoft)
+        .print_access_specifiers(true);
+
+    // Results must match the ones of the lower-level API
+    let (reconstructed_type, _) = session
+        .reconstruct_type_by_name(TEST_TYPE_NAME, &options)
+        .expect("type reconstruction failed");
+    let (expected_type, _) = session
+        .pdb_file()
+        .reconstruct_type_by_name(
+            TEST_TYPE_NAME,
+            PrimitiveReconstructionFlavor::Microsoft,
+            false,
+            true,
+            true,
+            false,
+        )
+        .expect("type reconstruction failed");
+    assert_eq!(reconstructed_type, expected_type);
+
+    // Headers are prepended on demand
+    let (reconstructed_type_with_header, _) = session
+        .reconstruct_type_by_name(TEST_TYPE_NAME, &options.print_header(true))
+        .expect("type reconstruction failed");
+    assert!(reconstructed_type_with_header.starts_with("//\n// Information extracted with resym"));
+    assert!(reconstructed_type_with_header.ends_with(&reconstructed_type));
+}
+
+#[test]
+fn test_session_type_listing() {
+    let session = open_test_session();
+
+    let type_list = session.list_types("structtest", &FilterOptions::new().case_insensitive(true));
+    assert!(type_list.iter().any(|(name, _)| name == TEST_TYPE_NAME));
+    assert!(type_list.windows(2).all(|types| types[0].1 <= types[1].1));
+
+    let type_list = session.list_types("structtest", &FilterOptions::new());
+    assert!(type_list.is_empty());
+
+    let type_list = session.list_types(
+        "^resym_test::StructTest$",
+        &FilterOptions::new().use_regex(true),
+    );
+    assert_eq!(type_list.len(), 1);
+    let (_, type_index) = type_list[0];
+    assert!(session
+        .reconstruct_type_by_index(type_index, &ReconstructionOptions::new())
+        .is_ok());
+}
+
+#[test]
+fn test_session_type_graph_queries() {
+    let session = open_test_session();
+
+    let query_result = session
+        .query_type_graph(&TypeGraphQuery::Path {
+            from_type_name: "resym_test::SpecializedInterfaceImplClass".to_string(),
+            to_type_name: "resym_test::PureVirtualClass".to_string(),
+            follow_pointers: false,
+        })
+        .expect("type graph query failed");
+    let path = query_result
+        .iter()
+        .flatten()
+        .map(|node| node.type_name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        path,
+        [
+            "resym_test::SpecializedInterfaceImplClass",
+            "resym_test::PureVirtualClassSpecialized",
+            "resym_test::PureVirtualClass"
+        ]
+    );
+
+    // Dependents are the reverse of dependencies
+    let query_result = session
+        .query_type_graph(&TypeGraphQuery::Dependents {
+            type_name: "resym_test::PureVirtualClass".to_string(),
+            max_depth: Some(1),
+        })
+        .expect("type graph query failed");
+    assert!(query_result.iter().flatten().all(|node| node.depth == 1));
+    assert!(query_result
+        .iter()
+        .flatten()
+        .any(|node| node.type_name == "resym_test::InterfaceImplClass"));
+
+    assert!(session
+        .query_type_graph(&TypeGraphQuery::Dependencies {
+            type_name: "resym_test::DoesNotExist".to_string(),
+            max_depth: None,
+        })
+        .is_err());
+}
+
+#[test]
+fn test_session_class_hierarchy() {
+    let session = open_test_session();
+
+    let class_hierarchy = session
+        .class_hierarchy("resym_test::PureVirtualClass")
+        .expect("class hierarchy retrieval failed");
+    assert!(class_hierarchy.base_classes.is_empty());
+    let derived_class_names = class_hierarchy
+        .derived_classes
+        .iter()
+        .map(|node| node.type_name.as_str())
+        .collect::<Vec<_>>();
+    assert!(derived_class_names.contains(&"resym_test::InterfaceImplClass"));
+    let specialized_class = class_hierarchy
+        .derived_classes
+        .iter()
+        .find(|node| node.type_name == "resym_test::PureVirtualClassSpecialized")
+        .expect("derived class not found");
+    assert_eq!(specialized_class.children.len(), 1);
+    assert_eq!(
+        specialized_class.children[0].type_name,
+        "resym_test::SpecializedInterfaceImplClass"
+    );
+}
+
+#[test]
+fn test_session_field_search() {
+    let session = open_test_session();
+    let query = FieldSearchQuery {
+        field_name_filter: "^u\\d$".to_string(),
+        field_type_filter: String::default(),
+        offset: Some(0x10),
+    };
+
+    let field_list = session
+        .find_fields(&query, &FilterOptions::new().use_regex(true))
+        .expect("field search failed");
+    let field = field_list
+        .iter()
+        .find(|field| field.type_name == TEST_TYPE_NAME)
+        .expect("field not found");
+    assert_eq!(field.field_name, "u4");
+    assert_eq!(field.field_type_name, "uint64_t");
+    assert_eq!(field.offset, 0x10);
+
+    // Static members aren't fields
+    let query = FieldSearchQuery {
+        field_name_filter: "su5".to_string(),
+        ..Default::default()
+    };
+    let field_list = session
+        .find_fields(&query, &FilterOptions::new())
+        .expect("field search failed");
+    assert!(field_list
+        .iter()
+        .all(|field| field.type_name != TEST_TYPE_NAME));
+}
+
+#[test]
+fn test_session_layout_matching() {
+    let session = open_test_session();
+    let query = LayoutQuery {
+        size: Some(0x18),
+        fields: vec![
+            "0xc:uint32_t".parse().expect("valid constraint"),
+            "0x10:uint64_t".parse().expect("valid constraint"),
+        ],
+    };
+
+    let layout_matches = session
+        .match_layout(&query, Some(10))
+        .expect("layout matching failed");
+    assert!(layout_matches.len() <= 10);
+    let layout_match = layout_matches
+        .iter()
+        .find(|layout_match| layout_match.type_name == TEST_TYPE_NAME)
+        .expect("type not found");
+    assert_eq!(layout_match.size, 0x18);
+    assert_eq!(layout_match.matched_constraint_count, 3);
+    assert_eq!(layout_matches[0].matched_constraint_count, 3);
+}
+
+#[test]
+fn test_session_type_details() {
+    let session = open_test_session();
+    let type_list = session.list_types(TEST_TYPE_NAME, &FilterOptions::new());
+    let type_details = session
+        .type_details(&type_list)
+        .expect("type details retrieval failed");
+    let struct_details = type_details
+        .iter()
+        .find(|type_details| type_details.type_name == TEST_TYPE_NAME)
+        .expect("type not found");
+    assert_eq!(struct_details.size, Some(0x18));
+    let (source_file, source_line) = struct_details
+        .definition_location
+        .as_ref()
+        .expect("definition location not found");
+    assert!(source_file.ends_with("symbol_zoo.cpp"));
+    assert_eq!(*source_line, 130);
+}
+
+#[test]
+#[allow(clippy::single_range_in_vec_init)]
+fn test_session_type_layout() {
+    let session = open_test_session();
+    let type_list = session.list_types(TEST_TYPE_NAME, &FilterOptions::new());
+    let (_, type_index) = type_list
+        .iter()
+        .find(|(type_name, _)| type_name == TEST_TYPE_NAME)
+        .expect("type not found");
+
+    let type_layout = session
+        .type_layout(*type_index)
+        .expect("type layout computation failed");
+    assert_eq!(type_layout.type_name, TEST_TYPE_NAME);
+    assert_eq!(type_layout.size, 0x18);
+    assert!(!type_layout.is_union);
+    let entries = type_layout
+        .entries
+        .iter()
+        .map(|entry| (entry.kind, entry.name.as_str(), entry.offset, entry.size))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        entries,
+        [
+            (LayoutEntryKind::VirtualTablePointer, "vfptr", 0x0, 8),
+            (LayoutEntryKind::Field, "u1", 0x8, 1),
+            (LayoutEntryKind::Field, "u2", 0xa, 2),
+            (LayoutEntryKind::Field, "u3", 0xc, 4),
+            (LayoutEntryKind::Field, "u4", 0x10, 8),
+        ]
+    );
+    assert_eq!(type_layout.padding, [0x9..0xa]);
+    assert_eq!(type_layout.lane_count, 1);
+}
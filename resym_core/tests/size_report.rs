@@ -0,0 +1,53 @@
+use std::path::Path;
+
+use resym_core::{
+    pdb_file::PdbFile,
+    size_report::{compute_size_report, SizeReportGrouping},
+};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+
+#[test]
+fn test_size_report_by_module() {
+    test_size_report_internal("size_report_by_module", SizeReportGrouping::Module);
+}
+
+#[test]
+fn test_size_report_by_source_directory() {
+    test_size_report_internal(
+        "size_report_by_source_directory",
+        SizeReportGrouping::SourceDirectory,
+    );
+}
+
+#[test]
+fn test_size_report_by_namespace() {
+    test_size_report_internal("size_report_by_namespace", SizeReportGrouping::Namespace);
+}
+
+#[test]
+fn test_size_report_by_template_family() {
+    test_size_report_internal(
+        "size_report_by_template_family",
+        SizeReportGrouping::TemplateFamily,
+    );
+}
+
+fn test_size_report_internal(snapshot_name: &str, grouping: SizeReportGrouping) {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+
+    let size_report = compute_size_report(&pdb_file, grouping)
+        .unwrap_or_else(|err| panic!("size report computation failed: {err}"));
+
+    let snapshot_data = size_report.into_iter().fold(String::new(), |acc, entry| {
+        format!(
+            "{acc}\n{} {} {} {:?} {}",
+            entry.code_size,
+            entry.data_size,
+            entry.symbol_count,
+            entry.section_contribution_size,
+            entry.name
+        )
+    });
+    insta::assert_snapshot!(snapshot_name, snapshot_data);
+}
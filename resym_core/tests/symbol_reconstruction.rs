@@ -0,0 +1,95 @@
+use std::path::Path;
+
+use resym_core::{pdb_file::PdbFile, pdb_types::PrimitiveReconstructionFlavor};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+const TEST_SYMBOLS_PDB_FILE_PATH: &str = "tests/data/test_symbols.pdb";
+const TEST_SYMBOL_NAMES: &[&str] = &[
+    // S_CONSTANT
+    "PDBIntv80",
+    "std::integral_constant<bool,1>::value",
+    // S_TRAMPOLINE
+    "__trampoline_2_5",
+];
+
+#[test]
+fn test_symbol_reconstruction() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+
+    {
+        let symbol_list = pdb_file
+            .symbol_list(&Default::default())
+            .unwrap_or_else(|err| panic!("symbol listing failed: {err}"));
+        for symbol_name in TEST_SYMBOL_NAMES {
+            assert!(
+                symbol_list.iter().any(|(name, _)| name == symbol_name),
+                "'{symbol_name}' isn't listed"
+            );
+        }
+    }
+
+    let snapshot_name = "symbol_reconstruction";
+    let snapshot_data = TEST_SYMBOL_NAMES
+        .iter()
+        .fold(String::new(), |acc, symbol_name| {
+            let reconstructed_symbol = pdb_file
+                .reconstruct_symbol_by_name(
+                    symbol_name,
+                    PrimitiveReconstructionFlavor::Portable,
+                    false,
+                )
+                .unwrap_or_else(|err| panic!("symbol reconstruction failed: {err}"));
+            format!("{acc}\n{reconstructed_symbol}")
+        });
+    insta::assert_snapshot!(snapshot_name, snapshot_data);
+}
+
+#[test]
+fn test_thunk_reconstruction() {
+    const KERNEL32_IMPORT_MODULE_INDEX: usize = 49;
+    const WIDE_CHAR_TO_MULTI_BYTE_THUNK_INDEX: u32 = 76;
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+
+    // Note: thunks share their name with the matching public symbol, which
+    // takes precedence when looking symbols up by name
+    let reconstructed_symbol = pdb_file
+        .reconstruct_symbol_by_index(
+            (
+                KERNEL32_IMPORT_MODULE_INDEX,
+                WIDE_CHAR_TO_MULTI_BYTE_THUNK_INDEX,
+            ),
+            PrimitiveReconstructionFlavor::Portable,
+            false,
+        )
+        .unwrap_or_else(|err| panic!("symbol reconstruction failed: {err}"));
+    assert_eq!(
+        reconstructed_symbol,
+        "void WideCharToMultiByte(); // RVA=0x161a6 CodeSize=0x6 (thunk)"
+    );
+}
+
+#[test]
+fn test_thread_local_reconstruction() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_SYMBOLS_PDB_FILE_PATH))
+        .expect("load test_symbols.pdb");
+
+    for (symbol_name, expected_symbol) in [
+        // S_GTHREAD32
+        (
+            "tls_counter",
+            "thread_local int32_t tls_counter; // TLS offset=0x0",
+        ),
+        // S_LTHREAD32
+        (
+            "tls_flags",
+            "static thread_local const uint32_t tls_flags; // TLS offset=0x8",
+        ),
+        // S_CONSTANT
+        ("kTlsEnabled", "constexpr bool kTlsEnabled = false;"),
+    ] {
+        let reconstructed_symbol = pdb_file
+            .reconstruct_symbol_by_name(symbol_name, PrimitiveReconstructionFlavor::Portable, false)
+            .unwrap_or_else(|err| panic!("symbol reconstruction failed: {err}"));
+        assert_eq!(reconstructed_symbol, expected_symbol);
+    }
+}
@@ -0,0 +1,304 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::{Cursor, Read, Write},
+    net::TcpListener,
+    path::{Path, PathBuf},
+    thread,
+};
+
+use resym_core::{
+    pdb_file::PdbFile,
+    pe_file::CodeViewInfo,
+    progress::{CancellationToken, ProgressReporter},
+    symbol_store::{SymbolPath, SymbolPathElement},
+};
+use tempdir::TempDir;
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+
+/// Return the CodeView debug information an image built alongside the test
+/// PDB would contain
+fn test_pdb_code_view_info() -> CodeViewInfo {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    let (guid, age) = pdb_file
+        .guid_and_age()
+        .unwrap_or_else(|err| panic!("failed to read the PDB's GUID: {err}"));
+
+    CodeViewInfo {
+        guid,
+        age,
+        pdb_path: "C:\\build\\x64\\Release\\test.pdb".to_string(),
+    }
+}
+
+/// Start a minimal HTTP server standing in for a symbol server. It serves the
+/// given files and answers 404 to any other request.
+/// Returns the URL of the symbol store.
+fn start_symbol_server(files: HashMap<String, Vec<u8>>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("failed to bind the symbol server");
+    let server_address = listener.local_addr().expect("failed to get the address");
+    thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            // Read the request's header
+            let mut request = vec![];
+            let mut buffer = [0u8; 1024];
+            while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+                match stream.read(&mut buffer) {
+                    Ok(0) | Err(_) => break,
+                    Ok(read_size) => request.extend_from_slice(&buffer[..read_size]),
+                }
+            }
+
+            let request = String::from_utf8_lossy(&request);
+            let request_path = request.split_whitespace().nth(1).unwrap_or_default();
+            let response_body = files.get(request_path);
+            let response_header = match response_body {
+                Some(body) => format!(
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    body.len()
+                ),
+                None => "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                    .to_string(),
+            };
+            let _ = stream.write_all(response_header.as_bytes());
+            if let Some(body) = response_body {
+                let _ = stream.write_all(body);
+            }
+        }
+    });
+
+    format!("http://{server_address}/symbols")
+}
+
+/// Compress the given file into a CAB archive, like `.pd_` files
+fn compress_file(file_name: &str, file_data: &[u8]) -> Vec<u8> {
+    let mut cabinet_builder = cab::CabinetBuilder::new();
+    cabinet_builder
+        .add_folder(cab::CompressionType::MsZip)
+        .add_file(file_name);
+    let mut cabinet_writer = cabinet_builder
+        .build(Cursor::new(vec![]))
+        .expect("failed to create cabinet");
+    while let Some(mut file_writer) = cabinet_writer.next_file().expect("cabinet error") {
+        file_writer.write_all(file_data).expect("cabinet error");
+    }
+
+    cabinet_writer
+        .finish()
+        .expect("failed to write cabinet")
+        .into_inner()
+}
+
+#[test]
+fn test_symbol_path_parsing() {
+    let symbol_path: SymbolPath =
+        "C:\\symbols; cache*C:\\cache;srv*C:\\downstream*https://msdl.microsoft.com/download/symbols;symsrv*symsrv.dll*\\\\server\\symbols;SRV**http://localhost/"
+            .parse()
+            .unwrap_or_else(|err| panic!("parsing failed: {err}"));
+    assert_eq!(symbol_path.elements.len(), 5);
+    assert_eq!(
+        symbol_path.elements[0],
+        SymbolPathElement::Directory(PathBuf::from("C:\\symbols"))
+    );
+    assert_eq!(
+        symbol_path.elements[1],
+        SymbolPathElement::Cache(PathBuf::from("C:\\cache"))
+    );
+    assert_eq!(
+        symbol_path.elements[2],
+        SymbolPathElement::SymbolServer {
+            downstream_stores: vec![PathBuf::from("C:\\downstream")],
+            upstream_store: "https://msdl.microsoft.com/download/symbols".to_string(),
+        }
+    );
+    assert_eq!(
+        symbol_path.elements[3],
+        SymbolPathElement::SymbolServer {
+            downstream_stores: vec![],
+            upstream_store: "\\\\server\\symbols".to_string(),
+        }
+    );
+    assert_eq!(
+        symbol_path.elements[4],
+        SymbolPathElement::SymbolServer {
+            downstream_stores: vec![resym_core::symbol_store::default_downstream_store()],
+            upstream_store: "http://localhost/".to_string(),
+        }
+    );
+
+    assert!("srv*".parse::<SymbolPath>().is_err());
+    assert!("unknown*C:\\symbols".parse::<SymbolPath>().is_err());
+}
+
+#[test]
+fn test_symbol_store_key() {
+    let code_view_info = CodeViewInfo {
+        guid: [
+            0x40, 0xfc, 0x29, 0x6b, 0x47, 0xca, 0x67, 0x10, 0xb3, 0x1d, 0x00, 0xdd, 0x01, 0x06,
+            0x62, 0xda,
+        ],
+        age: 42,
+        pdb_path: "D:\\a\\b\\ntdll.pdb".to_string(),
+    };
+    assert_eq!(code_view_info.pdb_file_name(), Some("ntdll.pdb"));
+    assert_eq!(
+        code_view_info.symbol_store_key(),
+        "6B29FC40CA471067B31D00DD010662DA2A"
+    );
+}
+
+#[test]
+fn test_pdb_file_name_rejects_unsafe_names() {
+    for pdb_path in [
+        "",
+        "D:\\a\\",
+        "..",
+        "a/.",
+        "C:ntdll.pdb",
+        "D:\\a\\ntdll.pdb:stream",
+    ] {
+        let code_view_info = CodeViewInfo {
+            guid: [0; 16],
+            age: 1,
+            pdb_path: pdb_path.to_string(),
+        };
+        assert_eq!(code_view_info.pdb_file_name(), None, "{pdb_path}");
+    }
+}
+
+#[test]
+fn test_pdb_lookup_in_directory() {
+    let code_view_info = test_pdb_code_view_info();
+    let symbol_dir = TempDir::new("resym_symbols").expect("failed to create temporary directory");
+    let pdb_path = symbol_dir.path().join("test.pdb");
+    fs::copy(TEST_PDB_FILE_PATH, &pdb_path).expect("failed to copy test.pdb");
+
+    let symbol_path: SymbolPath = symbol_dir.path().display().to_string().parse().unwrap();
+    assert_eq!(
+        symbol_path
+            .find_pdb(&code_view_info)
+            .unwrap_or_else(|err| panic!("lookup failed: {err}")),
+        pdb_path
+    );
+
+    // PDBs whose GUID doesn't match must be ignored
+    let mismatching_code_view_info = CodeViewInfo {
+        guid: [0; 16],
+        ..code_view_info
+    };
+    assert!(symbol_path.find_pdb(&mismatching_code_view_info).is_err());
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_pdb_download_from_symbol_server() {
+    let code_view_info = test_pdb_code_view_info();
+    let pdb_data = fs::read(TEST_PDB_FILE_PATH).expect("failed to read test.pdb");
+    let store_url = start_symbol_server(HashMap::from([(
+        format!(
+            "/symbols/test.pdb/{}/test.pdb",
+            code_view_info.symbol_store_key()
+        ),
+        pdb_data.clone(),
+    )]));
+
+    let downstream_store =
+        TempDir::new("resym_symbols").expect("failed to create temporary directory");
+    let symbol_path: SymbolPath =
+        format!("srv*{}*{}", downstream_store.path().display(), store_url)
+            .parse()
+            .unwrap();
+    let pdb_path = symbol_path
+        .find_pdb(&code_view_info)
+        .unwrap_or_else(|err| panic!("lookup failed: {err}"));
+    assert_eq!(
+        pdb_path,
+        downstream_store
+            .path()
+            .join("test.pdb")
+            .join(code_view_info.symbol_store_key())
+            .join("test.pdb")
+    );
+    assert_eq!(fs::read(&pdb_path).unwrap(), pdb_data);
+
+    // The PDB should now be retrieved from the downstream store
+    let symbol_path: SymbolPath = format!(
+        "srv*{}*http://127.0.0.1:1/symbols",
+        downstream_store.path().display()
+    )
+    .parse()
+    .unwrap();
+    assert_eq!(
+        symbol_path
+            .find_pdb(&code_view_info)
+            .unwrap_or_else(|err| panic!("lookup failed: {err}")),
+        pdb_path
+    );
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_mismatching_pdb_from_symbol_server_is_ignored() {
+    // The symbol server returns a PDB that doesn't match the requested one
+    let code_view_info = CodeViewInfo {
+        guid: [0; 16],
+        ..test_pdb_code_view_info()
+    };
+    let store_url = start_symbol_server(HashMap::from([(
+        format!(
+            "/symbols/test.pdb/{}/test.pdb",
+            code_view_info.symbol_store_key()
+        ),
+        fs::read(TEST_PDB_FILE_PATH).expect("failed to read test.pdb"),
+    )]));
+
+    let downstream_store =
+        TempDir::new("resym_symbols").expect("failed to create temporary directory");
+    let symbol_path: SymbolPath =
+        format!("srv*{}*{}", downstream_store.path().display(), store_url)
+            .parse()
+            .unwrap();
+    assert!(symbol_path.find_pdb(&code_view_info).is_err());
+    // Mismatching PDBs must not be stored in downstream stores
+    assert!(!downstream_store.path().join("test.pdb").exists());
+}
+
+#[test]
+fn test_cancelled_pdb_lookup() {
+    let cancellation_token = CancellationToken::default();
+    cancellation_token.cancel();
+    let progress = ProgressReporter::new(cancellation_token, |_| {});
+
+    let symbol_path: SymbolPath = "srv*http://127.0.0.1:1/symbols".parse().unwrap();
+    assert!(matches!(
+        symbol_path.find_pdb_with_progress(&test_pdb_code_view_info(), &progress),
+        Err(resym_core::ResymCoreError::CancelledError)
+    ));
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_compressed_pdb_download_from_symbol_server() {
+    let code_view_info = test_pdb_code_view_info();
+    let pdb_data = fs::read(TEST_PDB_FILE_PATH).expect("failed to read test.pdb");
+    let store_url = start_symbol_server(HashMap::from([(
+        format!(
+            "/symbols/test.pdb/{}/test.pd_",
+            code_view_info.symbol_store_key()
+        ),
+        compress_file("test.pdb", &pdb_data),
+    )]));
+
+    // Files retrieved by the symbol server get cached in the `cache*` store
+    let cache_store = TempDir::new("resym_symbols").expect("failed to create temporary directory");
+    let symbol_path: SymbolPath =
+        format!("cache*{};srv*{}", cache_store.path().display(), store_url)
+            .parse()
+            .unwrap();
+    let pdb_path = symbol_path
+        .find_pdb(&code_view_info)
+        .unwrap_or_else(|err| panic!("lookup failed: {err}"));
+    assert!(pdb_path.starts_with(cache_store.path()));
+    assert_eq!(fs::read(&pdb_path).unwrap(), pdb_data);
+}
@@ -0,0 +1,46 @@
+use resym_core::{
+    options::FilterOptions,
+    text_search::{find_text_matches, line_start_offset},
+};
+
+const TEXT: &str = "struct Foo {\n  int foo;\n  FOO* next;\n};\n";
+
+#[test]
+fn test_text_search_plain() {
+    let matches = find_text_matches(TEXT, "foo", &FilterOptions::new()).expect("search failed");
+    assert_eq!(matches, vec![19..22]);
+
+    let matches = find_text_matches(TEXT, "foo", &FilterOptions::new().case_insensitive(true))
+        .expect("search failed");
+    assert_eq!(matches, vec![7..10, 19..22, 26..29]);
+
+    // Special characters are matched literally
+    let matches = find_text_matches(TEXT, "FOO*", &FilterOptions::new()).expect("search failed");
+    assert_eq!(matches, vec![26..30]);
+
+    // Empty queries don't match anything
+    let matches = find_text_matches(TEXT, "", &FilterOptions::new()).expect("search failed");
+    assert!(matches.is_empty());
+}
+
+#[test]
+fn test_text_search_regex() {
+    let options = FilterOptions::new().use_regex(true);
+    let matches = find_text_matches(TEXT, r"\bint \w+", &options).expect("search failed");
+    assert_eq!(matches, vec![15..22]);
+
+    // Empty matches are ignored
+    let matches = find_text_matches(TEXT, "z*", &options).expect("search failed");
+    assert!(matches.is_empty());
+
+    assert!(find_text_matches(TEXT, "(", &options).is_err());
+}
+
+#[test]
+fn test_line_start_offset() {
+    assert_eq!(line_start_offset(TEXT, 0), None);
+    assert_eq!(line_start_offset(TEXT, 1), Some(0));
+    assert_eq!(line_start_offset(TEXT, 2), Some(13));
+    assert_eq!(line_start_offset(TEXT, 4), Some(37));
+    assert_eq!(line_start_offset(TEXT, 5), None);
+}
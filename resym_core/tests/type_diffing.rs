@@ -1,7 +1,11 @@
 use std::path::Path;
 
 use resym_core::{
-    diffing::diff_type_by_name, pdb_file::PdbFile, pdb_types::PrimitiveReconstructionFlavor,
+    diffing::{diff_type_by_name, TypeDiffStatus},
+    options::{FilterOptions, ReconstructionOptions},
+    pdb_file::PdbFile,
+    pdb_types::PrimitiveReconstructionFlavor,
+    session::Session,
 };
 
 const TEST_PDB_FROM_FILE_PATH: &str = "tests/data/test_diff_from.pdb";
@@ -58,3 +62,72 @@ fn test_struct_diffing_inexistent_type() {
     )
     .is_err());
 }
+
+#[test]
+fn test_type_list_diffing() {
+    let session_from = Session::from(
+        PdbFile::load_from_file(Path::new(TEST_PDB_FROM_FILE_PATH))
+            .expect("load test_diff_from.pdb"),
+    );
+    let session_to = Session::from(
+        PdbFile::load_from_file(Path::new(TEST_PDB_TO_FILE_PATH)).expect("load test_diff_to.pdb"),
+    );
+
+    let type_diff_list = session_from
+        .diff_type_list(
+            &session_to,
+            "Struct",
+            &FilterOptions::new(),
+            &ReconstructionOptions::new(),
+        )
+        .expect("type list diffing");
+    let status_of = |type_name: &str| {
+        type_diff_list
+            .iter()
+            .find(|(name, _)| name == type_name)
+            .map(|(_, status)| *status)
+    };
+    assert_eq!(status_of("NewStruct"), Some(TypeDiffStatus::Added));
+    assert_eq!(status_of("RemovedStruct"), Some(TypeDiffStatus::Removed));
+    assert_eq!(status_of("UserStructAdd"), Some(TypeDiffStatus::Modified));
+    assert_eq!(
+        status_of("UserStructAddAndReplace"),
+        Some(TypeDiffStatus::Modified)
+    );
+    assert_eq!(
+        status_of("UserStructRemove"),
+        Some(TypeDiffStatus::Modified)
+    );
+    // Types are ordered by name
+    assert!(type_diff_list
+        .windows(2)
+        .all(|types| types[0].0 < types[1].0));
+
+    // Types present in both PDBs with the same layout are unchanged
+    let type_diff_list = session_from
+        .diff_type_list(
+            &session_to,
+            "",
+            &FilterOptions::new(),
+            &ReconstructionOptions::new(),
+        )
+        .expect("type list diffing");
+    assert!(type_diff_list
+        .iter()
+        .any(|(_, status)| *status == TypeDiffStatus::Unchanged));
+
+    // Statuses computed for previous filters give the same results
+    let filtered_type_diff_list = session_from
+        .diff_type_list(
+            &session_to,
+            "Struct",
+            &FilterOptions::new(),
+            &ReconstructionOptions::new(),
+        )
+        .expect("type list diffing");
+    let expected_type_diff_list: Vec<_> = type_diff_list
+        .into_iter()
+        .filter(|(type_name, _)| type_name.contains("Struct"))
+        .collect();
+    assert_eq!(filtered_type_diff_list, expected_type_diff_list);
+}
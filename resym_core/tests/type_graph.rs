@@ -0,0 +1,102 @@
+use resym_core::type_graph::{render_type_graph, TypeGraph, TypeGraphFormat};
+
+fn test_graph() -> TypeGraph {
+    // 1 -> 2 -> 3 -*> 1, 2 -> 4, 4 -*> 4, 5 -> 3
+    TypeGraph::from_edges([
+        (1, 2, false),
+        (2, 3, false),
+        (3, 1, true),
+        (2, 4, false),
+        (4, 4, true),
+        (5, 3, false),
+    ])
+}
+
+#[test]
+fn test_type_graph_dependencies_and_dependents() {
+    let graph = test_graph();
+    let dependencies: Vec<_> = graph
+        .dependencies(1, None)
+        .into_iter()
+        .map(|(edge, depth)| (edge.type_index, depth))
+        .collect();
+    assert_eq!(dependencies, vec![(2, 1), (3, 2), (4, 2)]);
+    assert_eq!(graph.dependencies(1, Some(1)).len(), 1);
+
+    let dependents: Vec<_> = graph
+        .dependents(3, None)
+        .into_iter()
+        .map(|(edge, depth)| (edge.type_index, depth))
+        .collect();
+    assert_eq!(dependents, vec![(2, 1), (5, 1), (1, 2)]);
+}
+
+#[test]
+fn test_type_graph_shortest_paths() {
+    let graph = test_graph();
+    let path: Vec<_> = graph
+        .shortest_path(5, 2, true)
+        .expect("path should exist")
+        .into_iter()
+        .map(|edge| (edge.type_index, edge.is_pointer))
+        .collect();
+    assert_eq!(path, vec![(5, false), (3, false), (1, true), (2, false)]);
+    // Pointers aren't followed by default
+    assert!(graph.shortest_path(5, 2, false).is_none());
+}
+
+#[test]
+fn test_type_graph_cycles() {
+    let graph = test_graph();
+    let cycles: Vec<Vec<_>> = graph
+        .cycles()
+        .into_iter()
+        .map(|cycle| cycle.into_iter().map(|edge| edge.type_index).collect())
+        .collect();
+    assert_eq!(cycles, vec![vec![1, 2, 3, 1], vec![4, 4]]);
+    assert!(graph.cycle_through(5).is_none());
+}
+
+#[test]
+fn test_type_graph_export() {
+    // 6 derives from 2
+    let graph = test_graph().with_base_classes([(6, 2)]);
+    let edges = graph.subgraph(6, Some(2), |type_index| type_index != 4);
+    let edges_summary: Vec<_> = edges
+        .iter()
+        .map(|(from, edge)| (*from, edge.type_index, edge.is_base_class))
+        .collect();
+    assert_eq!(edges_summary, vec![(6, 2, true), (2, 3, false)]);
+
+    let dot_graph = render_type_graph(6, &edges, TypeGraphFormat::Dot, |type_index| {
+        format!("Type<{type_index}>")
+    });
+    assert_eq!(
+        dot_graph,
+        concat!(
+            "digraph \"Type<6>\" {\n",
+            "    node [shape=box];\n",
+            "    t6 [label=\"Type<6>\", style=bold];\n",
+            "    t2 [label=\"Type<2>\"];\n",
+            "    t3 [label=\"Type<3>\"];\n",
+            "    t6 -> t2 [arrowhead=empty, style=bold];\n",
+            "    t2 -> t3;\n",
+            "}\n",
+        )
+    );
+
+    let mermaid_graph = render_type_graph(6, &edges, TypeGraphFormat::Mermaid, |type_index| {
+        format!("Type<{type_index}>")
+    });
+    assert_eq!(
+        mermaid_graph,
+        concat!(
+            "flowchart LR\n",
+            "    t6[\"Type#lt;6#gt;\"]\n",
+            "    t2[\"Type#lt;2#gt;\"]\n",
+            "    t3[\"Type#lt;3#gt;\"]\n",
+            "    t6 ==> t2\n",
+            "    t2 --> t3\n",
+        )
+    );
+}
@@ -0,0 +1,87 @@
+use std::path::Path;
+
+use resym_core::{pdb_file::PdbFile, type_layout::TypeLayout};
+
+const TEST_PDB_FILE_PATH: &str = "tests/data/test.pdb";
+const TEST_TYPE_NAMES: &[&str] = &[
+    // Bit-fields sharing allocation units
+    "resym_test::BitFieldsTest1",
+    // Padding between members
+    "resym_test::BitFieldsTest7",
+    // Overlapping members
+    "resym_test::UnionTest",
+    // Anonymous unions nested in a struct
+    "resym_test::StructUnnamedUdtTest2",
+];
+
+fn format_type_layout(type_layout: &TypeLayout) -> String {
+    let mut output = format!(
+        "{} {} (size=0x{:x}, lanes={})\n",
+        if type_layout.is_union {
+            "union"
+        } else {
+            "struct"
+        },
+        type_layout.type_name,
+        type_layout.size,
+        type_layout.lane_count
+    );
+    for entry in &type_layout.entries {
+        let bitfield_info = entry
+            .bitfield_info
+            .map(|(bit_offset, bit_size)| format!(" bits={bit_offset}:{bit_size}"))
+            .unwrap_or_default();
+        output += &format!(
+            "  /* 0x{:04x} */ [lane {}] {:?} {} {} (size=0x{:x}{})\n",
+            entry.offset,
+            entry.lane,
+            entry.kind,
+            entry.type_name,
+            entry.name,
+            entry.size,
+            bitfield_info
+        );
+    }
+    for padding in &type_layout.padding {
+        output += &format!(
+            "  /* 0x{:04x} */ padding (size=0x{:x})\n",
+            padding.start,
+            padding.end - padding.start
+        );
+    }
+
+    output
+}
+
+#[test]
+fn test_type_layout() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+
+    let snapshot_data = TEST_TYPE_NAMES
+        .iter()
+        .fold(String::new(), |acc, type_name| {
+            let (_, type_index) = pdb_file
+                .complete_type_list
+                .iter()
+                .find(|(name, _)| name == type_name)
+                .unwrap_or_else(|| panic!("'{type_name}' not found"));
+            let type_layout = pdb_file
+                .type_layout(*type_index)
+                .unwrap_or_else(|err| panic!("type layout computation failed: {err}"));
+            format!("{acc}\n{}", format_type_layout(&type_layout))
+        });
+    insta::assert_snapshot!("type_layout", snapshot_data);
+}
+
+#[test]
+fn test_type_layout_of_non_udt() {
+    let pdb_file = PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    let (_, type_index) = pdb_file
+        .complete_type_list
+        .iter()
+        .find(|(name, _)| name == "resym_test::EnumTest1")
+        .expect("type not found");
+
+    // Only classes, structs and unions have a layout
+    assert!(pdb_file.type_layout(*type_index).is_err());
+}
@@ -78,6 +78,40 @@ fn test_type_reconstruction_msvc_access_specifiers() {
     );
 }
 
+#[test]
+fn test_type_reconstruction_data_sources() {
+    // Memory-mapped file
+    let mapped_pdb_file =
+        PdbFile::load_from_file(Path::new(TEST_PDB_FILE_PATH)).expect("load test.pdb");
+    // In-memory buffer
+    let pdb_data = std::fs::read(TEST_PDB_FILE_PATH).expect("read test.pdb");
+    let buffered_pdb_file =
+        PdbFile::load_from_bytes_as_vec("test.pdb".to_string(), pdb_data, &Default::default())
+            .expect("load test.pdb");
+
+    for test_case_type_name in TEST_CASES {
+        let reconstruct_type = |pdb_file: &PdbFile| {
+            pdb_file
+                .reconstruct_type_by_name(
+                    test_case_type_name,
+                    PrimitiveReconstructionFlavor::Portable,
+                    false,
+                    true,
+                    false,
+                    false,
+                )
+                .unwrap_or_else(|_| panic!("reconstruct type: {test_case_type_name}"))
+                .0
+        };
+
+        // Reconstructions reuse the type index built on first use and must be
+        // identical regardless of the data source
+        let reconstructed_type = reconstruct_type(&mapped_pdb_file);
+        assert_eq!(reconstructed_type, reconstruct_type(&mapped_pdb_file));
+        assert_eq!(reconstructed_type, reconstruct_type(&buffered_pdb_file));
+    }
+}
+
 fn test_type_reconstruction_internal(
     test_name: &str,
     primitives_flavor: PrimitiveReconstructionFlavor,
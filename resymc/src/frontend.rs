@@ -14,6 +14,11 @@ pub struct CLIFrontendController {
 impl FrontendController for CLIFrontendController {
     /// Used by the backend to send us commands and trigger a UI update
     fn send_command(&self, command: FrontendCommand) -> Result<()> {
+        // Note: progress isn't displayed by the CLI
+        if let FrontendCommand::Progress(..) = command {
+            return Ok(());
+        }
+
         self.tx_ui
             .send(command)
             .map_err(|err| ResymCoreError::CrossbeamError(err.to_string()))
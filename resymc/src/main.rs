@@ -4,7 +4,10 @@ mod resymc_options;
 mod syntax_highlighting;
 
 use anyhow::Result;
-use resym_core::pdb_types::PrimitiveReconstructionFlavor;
+use resym_core::{
+    field_search::FieldSearchQuery, layout_search::LayoutQuery, options::FilterOptions,
+    pdb_types::PrimitiveReconstructionFlavor, type_graph::TypeGraphQuery,
+};
 use structopt::StructOpt;
 
 use crate::resymc_app::ResymcApp;
@@ -45,6 +48,7 @@ fn main() -> Result<()> {
             integers_as_hexadecimal,
             ignore_std_types,
             highlight_syntax,
+            notes_file_path,
         } => app.dump_types_command(
             pdb_path,
             Some(type_name),
@@ -55,6 +59,7 @@ fn main() -> Result<()> {
             integers_as_hexadecimal,
             ignore_std_types,
             highlight_syntax,
+            notes_file_path,
             output_file_path,
         ),
         ResymcOptions::DumpAll {
@@ -66,6 +71,7 @@ fn main() -> Result<()> {
             integers_as_hexadecimal,
             ignore_std_types,
             highlight_syntax,
+            notes_file_path,
         } => app.dump_types_command(
             pdb_path,
             None,
@@ -76,6 +82,7 @@ fn main() -> Result<()> {
             integers_as_hexadecimal,
             ignore_std_types,
             highlight_syntax,
+            notes_file_path,
             output_file_path,
         ),
         ResymcOptions::Diff {
@@ -175,8 +182,10 @@ fn main() -> Result<()> {
             print_header,
             print_access_specifiers,
             highlight_syntax,
+            pe_path,
         } => app.dump_symbol_command(
             pdb_path,
+            pe_path,
             Some(symbol_name),
             primitive_types_flavor.unwrap_or(DEFAULT_PRIMITIVE_FLAVOR),
             print_header,
@@ -191,8 +200,10 @@ fn main() -> Result<()> {
             print_header,
             print_access_specifiers,
             highlight_syntax,
+            pe_path,
         } => app.dump_symbol_command(
             pdb_path,
+            pe_path,
             None,
             primitive_types_flavor.unwrap_or(DEFAULT_PRIMITIVE_FLAVOR),
             print_header,
@@ -219,5 +230,127 @@ fn main() -> Result<()> {
             highlight_syntax,
             output_file_path,
         ),
+        ResymcOptions::SizeReport {
+            pdb_path,
+            output_file_path,
+            group_by,
+            sort_by,
+            output_format,
+        } => app.size_report_command(pdb_path, group_by, sort_by, output_format, output_file_path),
+        ResymcOptions::Inlines {
+            pdb_path,
+            function_name,
+            output_file_path,
+            inlinees,
+        } => app.inlines_command(pdb_path, function_name, inlinees, output_file_path),
+        ResymcOptions::Dependencies {
+            pdb_path,
+            type_name,
+            output_file_path,
+            max_depth,
+        } => app.type_graph_command(
+            pdb_path,
+            TypeGraphQuery::Dependencies {
+                type_name,
+                max_depth,
+            },
+            output_file_path,
+        ),
+        ResymcOptions::Dependents {
+            pdb_path,
+            type_name,
+            output_file_path,
+            max_depth,
+        } => app.type_graph_command(
+            pdb_path,
+            TypeGraphQuery::Dependents {
+                type_name,
+                max_depth,
+            },
+            output_file_path,
+        ),
+        ResymcOptions::TypePath {
+            pdb_path,
+            from_type_name,
+            to_type_name,
+            output_file_path,
+            follow_pointers,
+        } => app.type_graph_command(
+            pdb_path,
+            TypeGraphQuery::Path {
+                from_type_name,
+                to_type_name,
+                follow_pointers,
+            },
+            output_file_path,
+        ),
+        ResymcOptions::TypeCycles {
+            pdb_path,
+            output_file_path,
+            type_name,
+        } => app.type_graph_command(
+            pdb_path,
+            TypeGraphQuery::Cycles { type_name },
+            output_file_path,
+        ),
+        ResymcOptions::Graph {
+            pdb_path,
+            type_name,
+            output_file_path,
+            format,
+            max_depth,
+            ignore_std_types,
+        } => app.graph_command(
+            pdb_path,
+            type_name,
+            format,
+            max_depth,
+            ignore_std_types,
+            output_file_path,
+        ),
+        ResymcOptions::Hierarchy {
+            pdb_path,
+            type_name,
+            output_file_path,
+        } => app.hierarchy_command(pdb_path, type_name, output_file_path),
+        ResymcOptions::FindField {
+            pdb_path,
+            field_name_filter,
+            output_file_path,
+            field_type,
+            offset,
+            case_insensitive,
+            use_regex,
+            ignore_std_types,
+        } => app.find_field_command(
+            pdb_path,
+            FieldSearchQuery {
+                field_name_filter,
+                field_type_filter: field_type.unwrap_or_default(),
+                offset,
+            },
+            FilterOptions::new()
+                .case_insensitive(case_insensitive)
+                .use_regex(use_regex)
+                .ignore_std_types(ignore_std_types),
+            output_file_path,
+        ),
+        ResymcOptions::MatchLayout {
+            pdb_path,
+            output_file_path,
+            size,
+            fields,
+            max_results,
+        } => app.match_layout_command(
+            pdb_path,
+            LayoutQuery { size, fields },
+            max_results,
+            output_file_path,
+        ),
+        ResymcOptions::FindPdb {
+            pe_path,
+            symbol_path,
+        } => app.find_pdb_command(pe_path, symbol_path),
+        ResymcOptions::ClearCache { cache_dir } => app.clear_cache_command(cache_dir),
     }
 }
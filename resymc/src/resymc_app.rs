@@ -1,14 +1,29 @@
-use std::{fs::File, io::Write, path::PathBuf, sync::Arc};
+use std::{cmp::Reverse, fs::File, io::Write, path::PathBuf, sync::Arc};
 
 use anyhow::{anyhow, Result};
 use resym_core::{
+    annotations::Annotations,
     backend::{Backend, BackendCommand, PDBSlot},
+    class_hierarchy::ClassHierarchyNode,
+    field_search::{FieldInfo, FieldSearchQuery},
     frontend::FrontendCommand,
+    index_cache::IndexCache,
+    inline_sites::InlineSite,
+    layout_search::{LayoutMatch, LayoutQuery},
+    options::{FilterOptions, ReconstructionOptions, TypeGraphExportOptions},
     pdb_types::PrimitiveReconstructionFlavor,
+    pe_file::PeFile,
+    size_report::{SizeReport, SizeReportGrouping},
+    symbol_store::SymbolPath,
     syntax_highlighting::CodeTheme,
+    type_graph::{TypeGraphFormat, TypeGraphNode, TypeGraphQuery},
 };
 
-use crate::{frontend::CLIFrontendController, syntax_highlighting::highlight_code};
+use crate::{
+    frontend::CLIFrontendController,
+    resymc_options::{SizeReportFormat, SizeReportOrdering},
+    syntax_highlighting::highlight_code,
+};
 
 /// Slot for the single PDB or for the PDB we're diffing from
 const PDB_MAIN_SLOT: PDBSlot = 0;
@@ -61,9 +76,10 @@ impl ResymcApp {
         self.backend.send_command(BackendCommand::ListTypes(
             PDB_MAIN_SLOT,
             type_name_filter,
-            case_insensitive,
-            use_regex,
-            ignore_std_types,
+            FilterOptions::new()
+                .case_insensitive(case_insensitive)
+                .use_regex(use_regex)
+                .ignore_std_types(ignore_std_types),
         ))?;
         // Wait for the backend to finish filtering types
         if let FrontendCommand::ListTypesResult(type_list) =
@@ -98,8 +114,14 @@ impl ResymcApp {
         integers_as_hexadecimal: bool,
         ignore_std_types: bool,
         highlight_syntax: bool,
+        notes_file_path: Option<PathBuf>,
         output_file_path: Option<PathBuf>,
     ) -> Result<()> {
+        // Load notes first, to avoid reconstructing types needlessly if the file is invalid
+        let annotations = notes_file_path
+            .map(|notes_file_path| Annotations::load_from_file(&notes_file_path))
+            .transpose()?;
+
         // Request the backend to load the PDB
         self.backend
             .send_command(BackendCommand::LoadPDBFromPath(PDB_MAIN_SLOT, pdb_path))?;
@@ -113,34 +135,34 @@ impl ResymcApp {
         }
 
         // Queue a request for the backend to reconstruct the given type
+        let reconstruction_options = ReconstructionOptions::new()
+            .primitives_flavor(primitive_types_flavor)
+            .print_header(print_header)
+            .print_access_specifiers(print_access_specifiers)
+            .integers_as_hexadecimal(integers_as_hexadecimal)
+            .ignore_std_types(ignore_std_types);
         if let Some(type_name) = type_name {
             self.backend
                 .send_command(BackendCommand::ReconstructTypeByName(
                     PDB_MAIN_SLOT,
                     type_name,
-                    primitive_types_flavor,
-                    print_header,
-                    print_dependencies,
-                    print_access_specifiers,
-                    integers_as_hexadecimal,
-                    ignore_std_types,
+                    reconstruction_options.reconstruct_dependencies(print_dependencies),
                 ))?;
         } else {
             self.backend
                 .send_command(BackendCommand::ReconstructAllTypes(
                     PDB_MAIN_SLOT,
-                    primitive_types_flavor,
-                    print_header,
-                    print_access_specifiers,
-                    integers_as_hexadecimal,
-                    ignore_std_types,
+                    reconstruction_options,
                 ))?;
         }
         // Wait for the backend to finish filtering types
         if let FrontendCommand::ReconstructTypeResult(reconstructed_type_result) =
             self.frontend_controller.rx_ui.recv()?
         {
-            let (reconstructed_type, _) = reconstructed_type_result?;
+            let (mut reconstructed_type, _) = reconstructed_type_result?;
+            if let Some(annotations) = annotations {
+                reconstructed_type = annotations.annotate(&reconstructed_type);
+            }
             // Dump output
             if let Some(output_file_path) = output_file_path {
                 let mut output_file = File::create(output_file_path)?;
@@ -218,12 +240,13 @@ impl ResymcApp {
             PDB_MAIN_SLOT,
             PDB_DIFF_TO_SLOT,
             type_name,
-            primitive_types_flavor,
-            print_header,
-            print_dependencies,
-            print_access_specifiers,
-            integers_as_hexadecimal,
-            ignore_std_types,
+            ReconstructionOptions::new()
+                .primitives_flavor(primitive_types_flavor)
+                .print_header(print_header)
+                .reconstruct_dependencies(print_dependencies)
+                .print_access_specifiers(print_access_specifiers)
+                .integers_as_hexadecimal(integers_as_hexadecimal)
+                .ignore_std_types(ignore_std_types),
         ))?;
         // Wait for the backend to finish
         if let FrontendCommand::DiffResult(reconstructed_type_diff_result) =
@@ -285,8 +308,9 @@ impl ResymcApp {
         self.backend.send_command(BackendCommand::ListModules(
             PDB_MAIN_SLOT,
             module_path_filter,
-            case_insensitive,
-            use_regex,
+            FilterOptions::new()
+                .case_insensitive(case_insensitive)
+                .use_regex(use_regex),
         ))?;
         // Wait for the backend to finish listing modules
         if let FrontendCommand::ListModulesResult(module_list_result) =
@@ -339,9 +363,10 @@ impl ResymcApp {
             .send_command(BackendCommand::ReconstructModuleByIndex(
                 PDB_MAIN_SLOT,
                 module_id,
-                primitive_types_flavor,
-                print_header,
-                print_access_specifiers,
+                ReconstructionOptions::new()
+                    .primitives_flavor(primitive_types_flavor)
+                    .print_header(print_header)
+                    .print_access_specifiers(print_access_specifiers),
             ))?;
         // Wait for the backend to finish filtering types
         if let FrontendCommand::ReconstructModuleResult(reconstructed_module) =
@@ -421,9 +446,10 @@ impl ResymcApp {
             PDB_MAIN_SLOT,
             PDB_DIFF_TO_SLOT,
             module_path,
-            primitive_types_flavor,
-            print_header,
-            print_access_specifiers,
+            ReconstructionOptions::new()
+                .primitives_flavor(primitive_types_flavor)
+                .print_header(print_header)
+                .print_access_specifiers(print_access_specifiers),
         ))?;
         // Wait for the backend to finish
         if let FrontendCommand::DiffResult(reconstructed_module_diff_result) =
@@ -488,9 +514,10 @@ impl ResymcApp {
         self.backend.send_command(BackendCommand::ListSymbols(
             PDB_MAIN_SLOT,
             symbol_name_filter,
-            case_insensitive,
-            use_regex,
-            ignore_std_types,
+            FilterOptions::new()
+                .case_insensitive(case_insensitive)
+                .use_regex(use_regex)
+                .ignore_std_types(ignore_std_types),
         ))?;
         // Wait for the backend to finish listing modules
         if let FrontendCommand::ListSymbolsResult(symbol_list) =
@@ -520,6 +547,7 @@ impl ResymcApp {
     pub fn dump_symbol_command(
         &self,
         pdb_path: PathBuf,
+        pe_path: Option<PathBuf>,
         symbol_name: Option<String>,
         primitive_types_flavor: PrimitiveReconstructionFlavor,
         print_header: bool,
@@ -541,23 +569,39 @@ impl ResymcApp {
             ));
         }
 
-        // Queue a request for the backend to reconstruct the given module
+        if let Some(pe_path) = pe_path {
+            // Request the backend to load the matching PE image
+            self.backend
+                .send_command(BackendCommand::LoadPEFromPath(PDB_MAIN_SLOT, pe_path))?;
+            // Wait for the backend to finish loading the PE image
+            if let FrontendCommand::LoadPEResult(result) = self.frontend_controller.rx_ui.recv()? {
+                if let Err(err) = result {
+                    return Err(anyhow!("Failed to load PE image: {}", err));
+                }
+            } else {
+                return Err(anyhow!(
+                    "LoadPEFromPath expected. Invalid response received from the backend?"
+                ));
+            }
+        }
+
+        // Queue a request for the backend to reconstruct the given symbol
+        let reconstruction_options = ReconstructionOptions::new()
+            .primitives_flavor(primitive_types_flavor)
+            .print_header(print_header)
+            .print_access_specifiers(print_access_specifiers);
         if let Some(symbol_name) = symbol_name {
             self.backend
                 .send_command(BackendCommand::ReconstructSymbolByName(
                     PDB_MAIN_SLOT,
                     symbol_name,
-                    primitive_types_flavor,
-                    print_header,
-                    print_access_specifiers,
+                    reconstruction_options,
                 ))?;
         } else {
             self.backend
                 .send_command(BackendCommand::ReconstructAllSymbols(
                     PDB_MAIN_SLOT,
-                    primitive_types_flavor,
-                    print_header,
-                    print_access_specifiers,
+                    reconstruction_options,
                 ))?;
         }
         // Wait for the backend to finish filtering types
@@ -644,9 +688,10 @@ impl ResymcApp {
             PDB_MAIN_SLOT,
             PDB_DIFF_TO_SLOT,
             symbol_name,
-            primitive_types_flavor,
-            print_header,
-            print_access_specifiers,
+            ReconstructionOptions::new()
+                .primitives_flavor(primitive_types_flavor)
+                .print_header(print_header)
+                .print_access_specifiers(print_access_specifiers),
         ))?;
         // Wait for the backend to finish
         if let FrontendCommand::DiffResult(reconstructed_symbol_diff_result) =
@@ -685,181 +730,1237 @@ impl ResymcApp {
             ))
         }
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use std::fs;
 
-    use super::*;
+    pub fn size_report_command(
+        &self,
+        pdb_path: PathBuf,
+        grouping: SizeReportGrouping,
+        ordering: SizeReportOrdering,
+        output_format: SizeReportFormat,
+        output_file_path: Option<PathBuf>,
+    ) -> Result<()> {
+        // Request the backend to load the PDB
+        self.backend
+            .send_command(BackendCommand::LoadPDBFromPath(PDB_MAIN_SLOT, pdb_path))?;
+        // Wait for the backend to finish loading the PDB
+        if let FrontendCommand::LoadPDBResult(result) = self.frontend_controller.rx_ui.recv()? {
+            if let Err(err) = result {
+                return Err(anyhow!("Failed to load PDB: {}", err));
+            }
+        } else {
+            return Err(anyhow!(
+                "LoadPDBResult expected. Invalid response received from the backend?"
+            ));
+        }
 
-    use tempdir::TempDir;
+        // Queue a request for the backend to compute the size report
+        self.backend
+            .send_command(BackendCommand::ComputeSizeReport(PDB_MAIN_SLOT, grouping))?;
+        // Wait for the backend to finish
+        if let FrontendCommand::SizeReportResult(size_report_result) =
+            self.frontend_controller.rx_ui.recv()?
+        {
+            let mut size_report = size_report_result?;
+            sort_size_report(&mut size_report, ordering);
+            let output = match output_format {
+                SizeReportFormat::Table => format_size_report_as_table(&size_report),
+                SizeReportFormat::Csv => format_size_report_as_csv(&size_report),
+                SizeReportFormat::Json => format_size_report_as_json(&size_report),
+            };
 
-    const TEST_PDB_FILE_PATH: &str = "../resym_core/tests/data/test.pdb";
-    const TEST_PDB_FROM_FILE_PATH: &str = "../resym_core/tests/data/test_diff_from.pdb";
-    const TEST_PDB_TO_FILE_PATH: &str = "../resym_core/tests/data/test_diff_to.pdb";
+            // Dump output
+            if let Some(output_file_path) = output_file_path {
+                let mut output_file = File::create(output_file_path)?;
+                output_file.write_all(output.as_bytes())?;
+            } else {
+                print!("{output}");
+            }
 
-    // List types
-    #[test]
-    fn list_types_command_invalid_pdb_path() {
-        let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path = PathBuf::new();
-        // The command should fail
-        assert!(app
-            .list_types_command(
-                pdb_path,
-                "resym_test::StructTest".to_string(),
-                false,
-                false,
-                false,
-                None,
-            )
-            .is_err());
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "SizeReportResult expected. Invalid response received from the backend?"
+            ))
+        }
     }
 
-    #[test]
-    fn list_types_command_stdio_successful() {
-        let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
-        // The command should succeed
-        assert!(app
-            .list_types_command(
-                pdb_path,
-                "resym_test::StructTest".to_string(),
-                true,
-                true,
-                true,
-                None,
-            )
-            .is_ok());
-    }
+    pub fn inlines_command(
+        &self,
+        pdb_path: PathBuf,
+        function_name: String,
+        list_inlinees: bool,
+        output_file_path: Option<PathBuf>,
+    ) -> Result<()> {
+        // Request the backend to load the PDB
+        self.backend
+            .send_command(BackendCommand::LoadPDBFromPath(PDB_MAIN_SLOT, pdb_path))?;
+        // Wait for the backend to finish loading the PDB
+        if let FrontendCommand::LoadPDBResult(result) = self.frontend_controller.rx_ui.recv()? {
+            if let Err(err) = result {
+                return Err(anyhow!("Failed to load PDB: {}", err));
+            }
+        } else {
+            return Err(anyhow!(
+                "LoadPDBResult expected. Invalid response received from the backend?"
+            ));
+        }
 
-    #[test]
-    fn list_types_command_file_successful() {
-        let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
-        let tmp_dir =
-            TempDir::new("list_types_command_file_successful").expect("TempDir creation failed");
-        let output_path = tmp_dir.path().join("output.txt");
-        // The command should succeed
-        assert!(app
-            .list_types_command(
-                pdb_path,
-                "resym_test::ClassWithNestedDeclarationsTest".to_string(),
-                false,
-                false,
-                false,
-                Some(output_path.clone()),
-            )
-            .is_ok());
+        // Queue a request for the backend to list inline sites
+        self.backend.send_command(BackendCommand::ListInlineSites(
+            PDB_MAIN_SLOT,
+            function_name.clone(),
+        ))?;
+        // Wait for the backend to finish
+        if let FrontendCommand::ListInlineSitesResult(inline_site_list_result) =
+            self.frontend_controller.rx_ui.recv()?
+        {
+            let inline_site_list = inline_site_list_result?;
+            // Note: the backend returns both call sites and inlinees
+            let output = inline_site_list
+                .iter()
+                .filter_map(|inline_site| {
+                    if list_inlinees && inline_site.caller_name == function_name {
+                        Some(format_inline_site(inline_site, &inline_site.inlinee_name))
+                    } else if !list_inlinees && inline_site.inlinee_name == function_name {
+                        Some(format_inline_site(inline_site, &inline_site.caller_name))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<String>();
 
-        // Check output file's content
-        let output = fs::read_to_string(output_path).expect("Failed to read output file");
-        assert_eq!(
-            output,
-            concat!(
-                "resym_test::ClassWithNestedDeclarationsTest::NestEnum\n",
-                "resym_test::ClassWithNestedDeclarationsTest\n",
-                "resym_test::ClassWithNestedDeclarationsTest::NestedUnion\n",
-                "resym_test::ClassWithNestedDeclarationsTest::NestedClass\n",
-                "resym_test::ClassWithNestedDeclarationsTest::NestedStruct\n"
-            )
-        );
-    }
+            // Dump output
+            if let Some(output_file_path) = output_file_path {
+                let mut output_file = File::create(output_file_path)?;
+                output_file.write_all(output.as_bytes())?;
+            } else {
+                print!("{output}");
+            }
 
-    // Dump types
-    #[test]
-    fn dump_types_command_invalid_pdb_path() {
-        let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path = PathBuf::new();
-        // The command should fail
-        assert!(app
-            .dump_types_command(
-                pdb_path,
-                None,
-                PrimitiveReconstructionFlavor::Microsoft,
-                false,
-                false,
-                false,
-                false,
-                false,
-                false,
-                None
-            )
-            .is_err());
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "ListInlineSitesResult expected. Invalid response received from the backend?"
+            ))
+        }
     }
 
-    #[test]
-    fn dump_types_command_stdio_successful() {
-        let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
-
-        // The command should succeed
-        assert!(app
-            .dump_types_command(
-                pdb_path,
-                None,
-                PrimitiveReconstructionFlavor::Microsoft,
-                true,
-                true,
-                true,
-                true,
-                true,
-                true,
-                None
-            )
-            .is_ok());
-    }
+    pub fn type_graph_command(
+        &self,
+        pdb_path: PathBuf,
+        query: TypeGraphQuery,
+        output_file_path: Option<PathBuf>,
+    ) -> Result<()> {
+        // Request the backend to load the PDB
+        self.backend
+            .send_command(BackendCommand::LoadPDBFromPath(PDB_MAIN_SLOT, pdb_path))?;
+        // Wait for the backend to finish loading the PDB
+        if let FrontendCommand::LoadPDBResult(result) = self.frontend_controller.rx_ui.recv()? {
+            if let Err(err) = result {
+                return Err(anyhow!("Failed to load PDB: {}", err));
+            }
+        } else {
+            return Err(anyhow!(
+                "LoadPDBResult expected. Invalid response received from the backend?"
+            ));
+        }
 
-    #[test]
-    fn dump_types_command_file_successful() {
-        let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
-        let tmp_dir =
-            TempDir::new("dump_types_command_file_successful").expect("TempDir creation failed");
-        let output_path = tmp_dir.path().join("output.txt");
+        // Queue a request for the backend to query the type graph
+        self.backend
+            .send_command(BackendCommand::QueryTypeGraph(PDB_MAIN_SLOT, query.clone()))?;
+        // Wait for the backend to finish
+        if let FrontendCommand::TypeGraphQueryResult(query_result) =
+            self.frontend_controller.rx_ui.recv()?
+        {
+            let query_result = query_result?;
+            let output = match query {
+                TypeGraphQuery::Dependencies { .. } | TypeGraphQuery::Dependents { .. } => {
+                    query_result
+                        .iter()
+                        .flatten()
+                        .map(|node| format!("{:>3} {}\n", node.depth, format_type_graph_node(node)))
+                        .collect::<String>()
+                }
+                TypeGraphQuery::Path { .. } | TypeGraphQuery::Cycles { .. } => query_result
+                    .iter()
+                    .filter(|path| !path.is_empty())
+                    .map(|path| format!("{}\n", format_type_graph_path(path)))
+                    .collect::<String>(),
+            };
 
-        // The command should succeed
-        assert!(app
-            .dump_types_command(
-                pdb_path,
-                Some("resym_test::ClassWithNestedDeclarationsTest".to_string()),
-                PrimitiveReconstructionFlavor::Microsoft,
-                false,
-                false,
-                false,
-                false,
-                false,
-                false,
-                Some(output_path.clone()),
-            )
-            .is_ok());
+            // Dump output
+            if let Some(output_file_path) = output_file_path {
+                let mut output_file = File::create(output_file_path)?;
+                output_file.write_all(output.as_bytes())?;
+            } else {
+                print!("{output}");
+            }
 
-        // Check output file's content
-        let output = fs::read_to_string(output_path).expect("Failed to read output file");
-        assert_eq!(
-            output,
-            concat!("\nclass resym_test::ClassWithNestedDeclarationsTest { /* Size=0x1 */\n};\n")
-        );
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "TypeGraphQueryResult expected. Invalid response received from the backend?"
+            ))
+        }
     }
 
-    // Diff type
-    #[test]
-    fn diff_type_command_invalid_pdb_path() {
-        let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path_from = PathBuf::new();
-        let pdb_path_to = PathBuf::new();
+    pub fn graph_command(
+        &self,
+        pdb_path: PathBuf,
+        type_name: String,
+        format: TypeGraphFormat,
+        max_depth: Option<usize>,
+        ignore_std_types: bool,
+        output_file_path: Option<PathBuf>,
+    ) -> Result<()> {
+        // Request the backend to load the PDB
+        self.backend
+            .send_command(BackendCommand::LoadPDBFromPath(PDB_MAIN_SLOT, pdb_path))?;
+        // Wait for the backend to finish loading the PDB
+        if let FrontendCommand::LoadPDBResult(result) = self.frontend_controller.rx_ui.recv()? {
+            if let Err(err) = result {
+                return Err(anyhow!("Failed to load PDB: {}", err));
+            }
+        } else {
+            return Err(anyhow!(
+                "LoadPDBResult expected. Invalid response received from the backend?"
+            ));
+        }
+
+        // Queue a request for the backend to export the type graph
+        self.backend.send_command(BackendCommand::ExportTypeGraph(
+            PDB_MAIN_SLOT,
+            type_name,
+            TypeGraphExportOptions::new()
+                .format(format)
+                .max_depth(max_depth)
+                .ignore_std_types(ignore_std_types),
+        ))?;
+        // Wait for the backend to finish
+        if let FrontendCommand::TypeGraphExportResult(export_result) =
+            self.frontend_controller.rx_ui.recv()?
+        {
+            let output = export_result?;
+
+            // Dump output
+            if let Some(output_file_path) = output_file_path {
+                let mut output_file = File::create(output_file_path)?;
+                output_file.write_all(output.as_bytes())?;
+            } else {
+                print!("{output}");
+            }
+
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "TypeGraphExportResult expected. Invalid response received from the backend?"
+            ))
+        }
+    }
+
+    pub fn hierarchy_command(
+        &self,
+        pdb_path: PathBuf,
+        type_name: String,
+        output_file_path: Option<PathBuf>,
+    ) -> Result<()> {
+        // Request the backend to load the PDB
+        self.backend
+            .send_command(BackendCommand::LoadPDBFromPath(PDB_MAIN_SLOT, pdb_path))?;
+        // Wait for the backend to finish loading the PDB
+        if let FrontendCommand::LoadPDBResult(result) = self.frontend_controller.rx_ui.recv()? {
+            if let Err(err) = result {
+                return Err(anyhow!("Failed to load PDB: {}", err));
+            }
+        } else {
+            return Err(anyhow!(
+                "LoadPDBResult expected. Invalid response received from the backend?"
+            ));
+        }
+
+        // Queue a request for the backend to retrieve the class hierarchy
+        self.backend
+            .send_command(BackendCommand::ListClassHierarchy(PDB_MAIN_SLOT, type_name))?;
+        // Wait for the backend to finish
+        if let FrontendCommand::ClassHierarchyResult(class_hierarchy_result) =
+            self.frontend_controller.rx_ui.recv()?
+        {
+            let class_hierarchy = class_hierarchy_result?;
+            let mut output = format!("{}\n", class_hierarchy.type_name);
+            output += "Base classes:\n";
+            format_class_hierarchy_nodes(&class_hierarchy.base_classes, 1, &mut output);
+            output += "Derived classes:\n";
+            format_class_hierarchy_nodes(&class_hierarchy.derived_classes, 1, &mut output);
+
+            // Dump output
+            if let Some(output_file_path) = output_file_path {
+                let mut output_file = File::create(output_file_path)?;
+                output_file.write_all(output.as_bytes())?;
+            } else {
+                print!("{output}");
+            }
+
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "ClassHierarchyResult expected. Invalid response received from the backend?"
+            ))
+        }
+    }
+
+    pub fn find_field_command(
+        &self,
+        pdb_path: PathBuf,
+        query: FieldSearchQuery,
+        filter_options: FilterOptions,
+        output_file_path: Option<PathBuf>,
+    ) -> Result<()> {
+        // Request the backend to load the PDB
+        self.backend
+            .send_command(BackendCommand::LoadPDBFromPath(PDB_MAIN_SLOT, pdb_path))?;
+        // Wait for the backend to finish loading the PDB
+        if let FrontendCommand::LoadPDBResult(result) = self.frontend_controller.rx_ui.recv()? {
+            if let Err(err) = result {
+                return Err(anyhow!("Failed to load PDB: {}", err));
+            }
+        } else {
+            return Err(anyhow!(
+                "LoadPDBResult expected. Invalid response received from the backend?"
+            ));
+        }
+
+        // Queue a request for the backend to search fields
+        self.backend.send_command(BackendCommand::FindFields(
+            PDB_MAIN_SLOT,
+            query,
+            filter_options,
+        ))?;
+        // Wait for the backend to finish
+        if let FrontendCommand::FindFieldsResult(field_list_result) =
+            self.frontend_controller.rx_ui.recv()?
+        {
+            let output = field_list_result?
+                .iter()
+                .map(|field| format!("{}\n", format_field_info(field)))
+                .collect::<String>();
+
+            // Dump output
+            if let Some(output_file_path) = output_file_path {
+                let mut output_file = File::create(output_file_path)?;
+                output_file.write_all(output.as_bytes())?;
+            } else {
+                print!("{output}");
+            }
+
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "FindFieldsResult expected. Invalid response received from the backend?"
+            ))
+        }
+    }
+
+    pub fn match_layout_command(
+        &self,
+        pdb_path: PathBuf,
+        query: LayoutQuery,
+        max_results: usize,
+        output_file_path: Option<PathBuf>,
+    ) -> Result<()> {
+        // Request the backend to load the PDB
+        self.backend
+            .send_command(BackendCommand::LoadPDBFromPath(PDB_MAIN_SLOT, pdb_path))?;
+        // Wait for the backend to finish loading the PDB
+        if let FrontendCommand::LoadPDBResult(result) = self.frontend_controller.rx_ui.recv()? {
+            if let Err(err) = result {
+                return Err(anyhow!("Failed to load PDB: {}", err));
+            }
+        } else {
+            return Err(anyhow!(
+                "LoadPDBResult expected. Invalid response received from the backend?"
+            ));
+        }
+
+        // Queue a request for the backend to match types against the layout
+        self.backend.send_command(BackendCommand::MatchLayout(
+            PDB_MAIN_SLOT,
+            query,
+            Some(max_results),
+        ))?;
+        // Wait for the backend to finish
+        if let FrontendCommand::MatchLayoutResult(layout_match_list_result) =
+            self.frontend_controller.rx_ui.recv()?
+        {
+            let output = layout_match_list_result?
+                .iter()
+                .map(|layout_match| format!("{}\n", format_layout_match(layout_match)))
+                .collect::<String>();
+
+            // Dump output
+            if let Some(output_file_path) = output_file_path {
+                let mut output_file = File::create(output_file_path)?;
+                output_file.write_all(output.as_bytes())?;
+            } else {
+                print!("{output}");
+            }
+
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "MatchLayoutResult expected. Invalid response received from the backend?"
+            ))
+        }
+    }
+
+    pub fn find_pdb_command(&self, pe_path: PathBuf, symbol_path: Option<String>) -> Result<()> {
+        let symbol_path = match symbol_path {
+            Some(symbol_path) => symbol_path.parse()?,
+            None => SymbolPath::from_env(),
+        };
+
+        let pe_file = PeFile::load_from_file(&pe_path)?;
+        let pdb_path = symbol_path.find_pdb_for_pe(&pe_file)?;
+        println!("{}", pdb_path.display());
+
+        Ok(())
+    }
+
+    pub fn clear_cache_command(&self, cache_dir: Option<PathBuf>) -> Result<()> {
+        let index_cache = match cache_dir {
+            Some(cache_dir) => IndexCache::new(cache_dir),
+            None => IndexCache::from_env_or_default(),
+        };
+
+        let entry_count = index_cache.clear()?;
+        println!(
+            "{} entries removed from '{}'",
+            entry_count,
+            index_cache.cache_dir().display()
+        );
+
+        Ok(())
+    }
+}
+
+fn format_inline_site(inline_site: &InlineSite, function_name: &str) -> String {
+    let mut output = function_name.to_string();
+    for (range_index, range) in inline_site.ranges.iter().enumerate() {
+        let separator = if range_index == 0 { " " } else { ", " };
+        output += &format!(
+            "{separator}RVA=0x{:x}-0x{:x}",
+            range.rva,
+            range.rva + range.length
+        );
+    }
+    if let Some(source_file) = &inline_site.source_file {
+        output += &format!(" // {source_file}");
+        if let Some(source_line) = inline_site.source_line {
+            output += &format!(":{source_line}");
+        }
+    }
+    output.push('\n');
+
+    output
+}
+
+/// Nodes are indented according to their depth, virtual inheritance is
+/// prefixed with `virtual`
+fn format_class_hierarchy_nodes(nodes: &[ClassHierarchyNode], depth: usize, output: &mut String) {
+    if depth == 1 && nodes.is_empty() {
+        output.push_str("  (none)\n");
+    }
+    for node in nodes {
+        let virtual_prefix = if node.is_virtual { "virtual " } else { "" };
+        output.push_str(&format!(
+            "{:indent$}{virtual_prefix}{}\n",
+            "",
+            node.type_name,
+            indent = 2 * depth
+        ));
+        format_class_hierarchy_nodes(&node.children, depth + 1, output);
+    }
+}
+
+/// Fields are formatted as `Type::field (+0x10): FieldType`
+fn format_field_info(field: &FieldInfo) -> String {
+    format!(
+        "{}::{} (+{:#x}): {}",
+        field.type_name, field.field_name, field.offset, field.field_type_name
+    )
+}
+
+/// Matches are formatted as `[2/3] Type (size: 0x18)`
+fn format_layout_match(layout_match: &LayoutMatch) -> String {
+    format!(
+        "[{}/{}] {} (size: {:#x})",
+        layout_match.matched_constraint_count,
+        layout_match.constraint_count,
+        layout_match.type_name,
+        layout_match.size
+    )
+}
+
+/// Type names reached through pointers are followed by a `*`
+fn format_type_graph_node(node: &TypeGraphNode) -> String {
+    if node.is_pointer {
+        format!("{}*", node.type_name)
+    } else {
+        node.type_name.clone()
+    }
+}
+
+fn format_type_graph_path(path: &[TypeGraphNode]) -> String {
+    path.iter()
+        .map(format_type_graph_node)
+        .collect::<Vec<_>>()
+        .join(" -> ")
+}
+
+fn sort_size_report(size_report: &mut SizeReport, ordering: SizeReportOrdering) {
+    match ordering {
+        SizeReportOrdering::Name => size_report.sort_by(|lhs, rhs| lhs.name.cmp(&rhs.name)),
+        // Biggest entries first
+        SizeReportOrdering::Code => size_report.sort_by_key(|e| Reverse(e.code_size)),
+        SizeReportOrdering::Data => size_report.sort_by_key(|e| Reverse(e.data_size)),
+        SizeReportOrdering::Total => size_report.sort_by_key(|e| Reverse(e.total_size())),
+        SizeReportOrdering::Count => size_report.sort_by_key(|e| Reverse(e.symbol_count)),
+    }
+}
+
+fn format_size_report_as_table(size_report: &SizeReport) -> String {
+    let mut output = format!(
+        "{:>12} | {:>12} | {:>12} | {:>8} | {:>12} | Name\n",
+        "Code", "Data", "Total", "Symbols", "Sections"
+    );
+    for entry in size_report {
+        output += &format!(
+            "{:>12} | {:>12} | {:>12} | {:>8} | {:>12} | {}\n",
+            entry.code_size,
+            entry.data_size,
+            entry.total_size(),
+            entry.symbol_count,
+            entry
+                .section_contribution_size
+                .map(|size| size.to_string())
+                .unwrap_or_else(|| "-".to_string()),
+            entry.name
+        );
+    }
+
+    output
+}
+
+fn format_size_report_as_csv(size_report: &SizeReport) -> String {
+    let mut output = "name,code_size,data_size,total_size,symbol_count,section_size\n".to_string();
+    for entry in size_report {
+        output += &format!(
+            "\"{}\",{},{},{},{},{}\n",
+            entry.name.replace('"', "\"\""),
+            entry.code_size,
+            entry.data_size,
+            entry.total_size(),
+            entry.symbol_count,
+            entry
+                .section_contribution_size
+                .map(|size| size.to_string())
+                .unwrap_or_default(),
+        );
+    }
+
+    output
+}
+
+fn format_size_report_as_json(size_report: &SizeReport) -> String {
+    let entries: Vec<String> = size_report
+        .iter()
+        .map(|entry| {
+            format!(
+                concat!(
+                    "  {{\"name\": \"{}\", \"code_size\": {}, \"data_size\": {}, ",
+                    "\"total_size\": {}, \"symbol_count\": {}, \"section_size\": {}}}"
+                ),
+                escape_json_string(&entry.name),
+                entry.code_size,
+                entry.data_size,
+                entry.total_size(),
+                entry.symbol_count,
+                entry
+                    .section_contribution_size
+                    .map(|size| size.to_string())
+                    .unwrap_or_else(|| "null".to_string()),
+            )
+        })
+        .collect();
+
+    format!("[\n{}\n]\n", entries.join(",\n"))
+}
+
+fn escape_json_string(s: &str) -> String {
+    s.chars()
+        .fold(String::with_capacity(s.len()), |mut acc, c| {
+            match c {
+                '"' => acc.push_str("\\\""),
+                '\\' => acc.push_str("\\\\"),
+                '\n' => acc.push_str("\\n"),
+                '\r' => acc.push_str("\\r"),
+                '\t' => acc.push_str("\\t"),
+                c if (c as u32) < 0x20 => acc.push_str(&format!("\\u{:04x}", c as u32)),
+                c => acc.push(c),
+            }
+            acc
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    use tempdir::TempDir;
+
+    const TEST_PDB_FILE_PATH: &str = "../resym_core/tests/data/test.pdb";
+    const TEST_PDB_FROM_FILE_PATH: &str = "../resym_core/tests/data/test_diff_from.pdb";
+    const TEST_PDB_TO_FILE_PATH: &str = "../resym_core/tests/data/test_diff_to.pdb";
+    const TEST_INLINE_SITES_PDB_FILE_PATH: &str = "../resym_core/tests/data/test_inline_sites.pdb";
+
+    // List types
+    #[test]
+    fn list_types_command_invalid_pdb_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::new();
+        // The command should fail
+        assert!(app
+            .list_types_command(
+                pdb_path,
+                "resym_test::StructTest".to_string(),
+                false,
+                false,
+                false,
+                None,
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn list_types_command_stdio_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        // The command should succeed
+        assert!(app
+            .list_types_command(
+                pdb_path,
+                "resym_test::StructTest".to_string(),
+                true,
+                true,
+                true,
+                None,
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn list_types_command_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let tmp_dir =
+            TempDir::new("list_types_command_file_successful").expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.txt");
+        // The command should succeed
+        assert!(app
+            .list_types_command(
+                pdb_path,
+                "resym_test::ClassWithNestedDeclarationsTest".to_string(),
+                false,
+                false,
+                false,
+                Some(output_path.clone()),
+            )
+            .is_ok());
+
+        // Check output file's content
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert_eq!(
+            output,
+            concat!(
+                "resym_test::ClassWithNestedDeclarationsTest::NestEnum\n",
+                "resym_test::ClassWithNestedDeclarationsTest\n",
+                "resym_test::ClassWithNestedDeclarationsTest::NestedUnion\n",
+                "resym_test::ClassWithNestedDeclarationsTest::NestedClass\n",
+                "resym_test::ClassWithNestedDeclarationsTest::NestedStruct\n"
+            )
+        );
+    }
+
+    // Dump types
+    #[test]
+    fn dump_types_command_invalid_pdb_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::new();
+        // The command should fail
+        assert!(app
+            .dump_types_command(
+                pdb_path,
+                None,
+                PrimitiveReconstructionFlavor::Microsoft,
+                false,
+                false,
+                false,
+                false,
+                false,
+                false,
+                None,
+                None
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn dump_types_command_stdio_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+
+        // The command should succeed
+        assert!(app
+            .dump_types_command(
+                pdb_path,
+                None,
+                PrimitiveReconstructionFlavor::Microsoft,
+                true,
+                true,
+                true,
+                true,
+                true,
+                true,
+                None,
+                None
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn dump_types_command_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let tmp_dir =
+            TempDir::new("dump_types_command_file_successful").expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.txt");
+
+        // The command should succeed
+        assert!(app
+            .dump_types_command(
+                pdb_path,
+                Some("resym_test::ClassWithNestedDeclarationsTest".to_string()),
+                PrimitiveReconstructionFlavor::Microsoft,
+                false,
+                false,
+                false,
+                false,
+                false,
+                false,
+                None,
+                Some(output_path.clone()),
+            )
+            .is_ok());
+
+        // Check output file's content
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert_eq!(
+            output,
+            concat!("\nclass resym_test::ClassWithNestedDeclarationsTest { /* Size=0x1 */\n};\n")
+        );
+    }
+
+    #[test]
+    fn dump_types_command_notes_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let tmp_dir =
+            TempDir::new("dump_types_command_notes_successful").expect("TempDir creation failed");
+        let notes_path = tmp_dir.path().join("test.notes");
+        let output_path = tmp_dir.path().join("output.txt");
+        fs::write(
+            &notes_path,
+            "note\tresym_test::ClassWithNestedDeclarationsTest\t\tEmpty class",
+        )
+        .expect("Failed to write notes file");
+
+        // The command should succeed
+        assert!(app
+            .dump_types_command(
+                pdb_path,
+                Some("resym_test::ClassWithNestedDeclarationsTest".to_string()),
+                PrimitiveReconstructionFlavor::Microsoft,
+                false,
+                false,
+                false,
+                false,
+                false,
+                false,
+                Some(notes_path),
+                Some(output_path.clone()),
+            )
+            .is_ok());
+
+        // Check output file's content
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert_eq!(
+            output,
+            concat!(
+                "\n// Empty class\n",
+                "class resym_test::ClassWithNestedDeclarationsTest { /* Size=0x1 */\n};\n"
+            )
+        );
+    }
+
+    // Diff type
+    #[test]
+    fn diff_type_command_invalid_pdb_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path_from = PathBuf::new();
+        let pdb_path_to = PathBuf::new();
+
+        // The command should fail
+        assert!(app
+            .diff_type_command(
+                pdb_path_from,
+                pdb_path_to,
+                "".to_string(),
+                PrimitiveReconstructionFlavor::Microsoft,
+                false,
+                false,
+                false,
+                false,
+                false,
+                false,
+                None
+            )
+            .is_err());
+    }
+    #[test]
+    fn diff_type_command_stdio_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path_from = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
+        let pdb_path_to = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
+
+        // The command should succeed
+        assert!(app
+            .diff_type_command(
+                pdb_path_from,
+                pdb_path_to,
+                "UserStructAddAndReplace".to_string(),
+                PrimitiveReconstructionFlavor::Microsoft,
+                true,
+                true,
+                true,
+                false,
+                true,
+                true,
+                None
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn diff_type_command_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path_from = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
+        let pdb_path_to = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
+
+        let tmp_dir =
+            TempDir::new("diff_type_command_file_successful").expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.txt");
+
+        // The command should succeed
+        assert!(app
+            .diff_type_command(
+                pdb_path_from,
+                pdb_path_to,
+                "UserStructAddAndReplace".to_string(),
+                PrimitiveReconstructionFlavor::Portable,
+                false,
+                false,
+                false,
+                false,
+                false,
+                false,
+                Some(output_path.clone()),
+            )
+            .is_ok());
+
+        // Check output file's content
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert_eq!(
+            output,
+            concat!(
+                " \n-struct UserStructAddAndReplace { /* Size=0x10 */\n",
+                "-  /* 0x0000 */ int32_t field1;\n-  /* 0x0004 */ char field2;\n",
+                "-  /* 0x0008 */ void* field3;\n+struct UserStructAddAndReplace { /* Size=0x28 */\n",
+                "+  /* 0x0000 */ int32_t before1;\n+  /* 0x0004 */ int32_t field1;\n",
+                "+  /* 0x0008 */ int32_t between12;\n+  /* 0x000c */ char field2;\n",
+                "+  /* 0x0010 */ int32_t between23;\n+  /* 0x0018 */ void* field3;\n",
+                "+  /* 0x0020 */ int32_t after3;\n };\n",
+            )
+        );
+    }
+
+    // List modules
+    #[test]
+    fn list_modules_command_invalid_pdb_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::new();
+        // The command should fail
+        assert!(app
+            .list_modules_command(pdb_path, "*".to_string(), false, false, None)
+            .is_err());
+    }
+
+    #[test]
+    fn list_modules_command_stdio_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        // The command should succeed
+        assert!(app
+            .list_modules_command(pdb_path, "*".to_string(), true, true, None)
+            .is_ok());
+    }
+
+    #[test]
+    fn list_modules_command_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let tmp_dir =
+            TempDir::new("list_modules_command_file_successful").expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.txt");
+        // The command should succeed
+        assert!(app
+            .list_modules_command(
+                pdb_path,
+                "*".to_string(),
+                false,
+                false,
+                Some(output_path.clone()),
+            )
+            .is_ok());
+
+        // Check output file's content
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert_eq!(
+            output,
+            concat!(
+                "Mod 0048 | '* Linker Generated Manifest RES *'\n",
+                "Mod 0053 | '* Linker *'\n"
+            )
+        );
+    }
+
+    // Dump module
+    #[test]
+    fn dump_module_command_invalid_pdb_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::new();
+        // The command should fail
+        assert!(app
+            .dump_module_command(
+                pdb_path,
+                9, // exe_main.obj
+                PrimitiveReconstructionFlavor::Microsoft,
+                false,
+                false,
+                false,
+                None
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn dump_module_command_stdio_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        // The command should succeed
+        assert!(app
+            .dump_module_command(
+                pdb_path,
+                9, // exe_main.obj
+                PrimitiveReconstructionFlavor::Microsoft,
+                true,
+                true,
+                true,
+                None
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn dump_module_command_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let tmp_dir =
+            TempDir::new("dump_module_command_file_successful").expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.txt");
+        // The command should succeed
+        assert!(app
+            .dump_module_command(
+                pdb_path,
+                27, // default_local_stdio_options.obj
+                PrimitiveReconstructionFlavor::Portable,
+                false,
+                false,
+                false,
+                Some(output_path.clone()),
+            )
+            .is_ok());
+
+        // Check output file's content
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert_eq!(
+            output,
+            concat!(
+                "using namespace std;\n",
+                "using PUWSTR_C = const wchar_t*;\n",
+                "using TP_CALLBACK_ENVIRON_V3 = _TP_CALLBACK_ENVIRON_V3;\n",
+                "uint64_t* (__local_stdio_scanf_options)(); // RVA=0x14670 CodeSize=0x8\n",
+                "static uint64_t _OptionsStorage; // RVA=0x1eb28 \n",
+                "void (__scrt_initialize_default_local_stdio_options)(); // RVA=0x14680 CodeSize=0x45\n",
+            )
+        );
+    }
+
+    // Diff module
+    #[test]
+    fn diff_module_command_invalid_pdb_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path_from = PathBuf::new();
+        let pdb_path_to = PathBuf::new();
+
+        // The command should fail
+        assert!(app
+            .diff_module_command(
+                pdb_path_from,
+                pdb_path_to,
+                "d:\\a01\\_work\\43\\s\\Intermediate\\vctools\\msvcrt.nativeproj_607447030\\objd\\amd64\\exe_main.obj".to_string(),
+                PrimitiveReconstructionFlavor::Microsoft,
+                false,
+                false,
+                false,
+                None
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn diff_module_command_stdio_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path_from = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
+        let pdb_path_to = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
+
+        // The command should succeed
+        assert!(app
+            .diff_module_command(
+                pdb_path_from,
+                pdb_path_to,
+                "d:\\a01\\_work\\43\\s\\Intermediate\\vctools\\msvcrt.nativeproj_607447030\\objd\\amd64\\exe_main.obj".to_string(),
+                PrimitiveReconstructionFlavor::Microsoft,
+                true,
+                true,true,
+                None
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn diff_module_command_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path_from = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
+        let pdb_path_to = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
+
+        let tmp_dir =
+            TempDir::new("diff_module_command_file_successful").expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.txt");
+
+        // The command should succeed
+        assert!(app
+            .diff_module_command(
+                pdb_path_from,
+                pdb_path_to,
+                "d:\\a01\\_work\\43\\s\\Intermediate\\vctools\\msvcrt.nativeproj_607447030\\objd\\amd64\\default_local_stdio_options.obj".to_string(),
+                PrimitiveReconstructionFlavor::Portable,
+                false,
+                false,
+                false,
+                Some(output_path.clone()),
+            )
+            .is_ok());
+
+        // Check output file's content
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert_eq!(
+            output,
+            concat!(
+                " using namespace std;\n",
+                " using PUWSTR_C = const wchar_t*;\n",
+                " using TP_CALLBACK_ENVIRON_V3 = _TP_CALLBACK_ENVIRON_V3;\n",
+                " uint64_t* (__local_stdio_scanf_options)(); // RVA=0x13c30 CodeSize=0x8\n",
+                " static uint64_t _OptionsStorage; // RVA=0x1c898 \n",
+                " void (__scrt_initialize_default_local_stdio_options)(); // RVA=0x13c40 CodeSize=0x45\n",
+            )
+        );
+    }
 
+    // List symbols
+    #[test]
+    fn list_symbols_command_invalid_pdb_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::new();
         // The command should fail
         assert!(app
-            .diff_type_command(
-                pdb_path_from,
-                pdb_path_to,
-                "".to_string(),
+            .list_symbols_command(pdb_path, "*".to_string(), false, false, false, None)
+            .is_err());
+    }
+
+    #[test]
+    fn list_symbols_command_stdio_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        // The command should succeed
+        assert!(app
+            .list_symbols_command(pdb_path, "*".to_string(), true, true, true, None)
+            .is_ok());
+    }
+
+    #[test]
+    fn list_symbols_command_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let tmp_dir =
+            TempDir::new("list_modules_command_file_successful").expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.txt");
+        // The command should succeed
+        assert!(app
+            .list_symbols_command(
+                pdb_path,
+                "resym_test::UnionTest".to_string(),
+                false,
+                false,
+                false,
+                Some(output_path.clone()),
+            )
+            .is_ok());
+
+        // Check output file's content
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert_eq!(
+            output,
+            concat!(
+                "resym_test::UnionTest::UnionTest\n",
+                "resym_test::UnionTest::~UnionTest\n"
+            )
+        );
+    }
+
+    // Dump symbol
+    #[test]
+    fn dump_symbol_command_invalid_pdb_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::new();
+        // The command should fail
+        assert!(app
+            .dump_symbol_command(
+                pdb_path,
+                None,
+                Some("??1UnionTest@resym_test@@QEAA@XZ".to_string()),
+                PrimitiveReconstructionFlavor::Microsoft,
+                false,
+                false,
+                false,
+                None
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn dump_symbol_command_stdio_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        // The command should succeed
+        assert!(app
+            .dump_symbol_command(
+                pdb_path,
+                None,
+                Some("??1UnionTest@resym_test@@QEAA@XZ".to_string()),
+                PrimitiveReconstructionFlavor::Microsoft,
+                true,
+                true,
+                true,
+                None
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn dump_symbol_command_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let tmp_dir =
+            TempDir::new("dump_module_command_file_successful").expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.txt");
+        // The command should succeed
+        assert!(app
+            .dump_symbol_command(
+                pdb_path,
+                None,
+                Some("??1UnionTest@resym_test@@QEAA@XZ".to_string()),
+                PrimitiveReconstructionFlavor::Portable,
+                false,
+                false,
+                false,
+                Some(output_path.clone()),
+            )
+            .is_ok());
+
+        // Check output file's content
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert_eq!(
+            output,
+            "__cdecl resym_test::UnionTest::~UnionTest(void); // RVA=0x11bc0 ",
+        );
+    }
+
+    #[test]
+    fn dump_symbol_command_invalid_pe_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let pe_path = PathBuf::new();
+
+        // The command should fail
+        assert!(app
+            .dump_symbol_command(
+                pdb_path,
+                Some(pe_path),
+                Some("??1UnionTest@resym_test@@QEAA@XZ".to_string()),
                 PrimitiveReconstructionFlavor::Microsoft,
                 false,
                 false,
                 false,
+                None
+            )
+            .is_err());
+    }
+
+    // Diff symbol
+    #[test]
+    fn diff_symbol_command_invalid_pdb_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path_from = PathBuf::new();
+        let pdb_path_to = PathBuf::new();
+
+        // The command should fail
+        assert!(app
+            .diff_symbol_command(
+                pdb_path_from,
+                pdb_path_to,
+                "?_RTC_GetSrcLine@@YAHPEAEPEA_WKPEAH1K@Z".to_string(),
+                PrimitiveReconstructionFlavor::Microsoft,
                 false,
                 false,
                 false,
@@ -867,53 +1968,48 @@ mod tests {
             )
             .is_err());
     }
+
     #[test]
-    fn diff_type_command_stdio_successful() {
+    fn diff_symbol_command_stdio_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path_from = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
         let pdb_path_to = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
 
         // The command should succeed
         assert!(app
-            .diff_type_command(
+            .diff_symbol_command(
                 pdb_path_from,
                 pdb_path_to,
-                "UserStructAddAndReplace".to_string(),
+                "?_RTC_GetSrcLine@@YAHPEAEPEA_WKPEAH1K@Z".to_string(),
                 PrimitiveReconstructionFlavor::Microsoft,
                 true,
                 true,
                 true,
-                false,
-                true,
-                true,
                 None
             )
             .is_ok());
     }
 
     #[test]
-    fn diff_type_command_file_successful() {
+    fn diff_symbol_command_file_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path_from = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
         let pdb_path_to = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
 
         let tmp_dir =
-            TempDir::new("diff_type_command_file_successful").expect("TempDir creation failed");
+            TempDir::new("diff_module_command_file_successful").expect("TempDir creation failed");
         let output_path = tmp_dir.path().join("output.txt");
 
         // The command should succeed
         assert!(app
-            .diff_type_command(
+            .diff_symbol_command(
                 pdb_path_from,
                 pdb_path_to,
-                "UserStructAddAndReplace".to_string(),
+                "?_RTC_GetSrcLine@@YAHPEAEPEA_WKPEAH1K@Z".to_string(),
                 PrimitiveReconstructionFlavor::Portable,
                 false,
                 false,
                 false,
-                false,
-                false,
-                false,
                 Some(output_path.clone()),
             )
             .is_ok());
@@ -922,121 +2018,205 @@ mod tests {
         let output = fs::read_to_string(output_path).expect("Failed to read output file");
         assert_eq!(
             output,
-            concat!(
-                " \n-struct UserStructAddAndReplace { /* Size=0x10 */\n",
-                "-  /* 0x0000 */ int32_t field1;\n-  /* 0x0004 */ char field2;\n",
-                "-  /* 0x0008 */ void* field3;\n+struct UserStructAddAndReplace { /* Size=0x28 */\n",
-                "+  /* 0x0000 */ int32_t before1;\n+  /* 0x0004 */ int32_t field1;\n",
-                "+  /* 0x0008 */ int32_t between12;\n+  /* 0x000c */ char field2;\n",
-                "+  /* 0x0010 */ int32_t between23;\n+  /* 0x0018 */ void* field3;\n",
-                "+  /* 0x0020 */ int32_t after3;\n };\n",
+            " int __cdecl _RTC_GetSrcLine(unsigned char *, wchar_t *, unsigned long, int *, wchar_t *, unsigned long); // RVA=0x14c90 \n",
+        );
+    }
+
+    // Size report
+    #[test]
+    fn size_report_command_invalid_pdb_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::new();
+        // The command should fail
+        assert!(app
+            .size_report_command(
+                pdb_path,
+                SizeReportGrouping::Module,
+                SizeReportOrdering::Total,
+                SizeReportFormat::Table,
+                None
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn size_report_command_stdio_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        // The command should succeed
+        assert!(app
+            .size_report_command(
+                pdb_path,
+                SizeReportGrouping::Namespace,
+                SizeReportOrdering::Name,
+                SizeReportFormat::Json,
+                None
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn size_report_command_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let tmp_dir =
+            TempDir::new("size_report_command_file_successful").expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.csv");
+        // The command should succeed
+        assert!(app
+            .size_report_command(
+                pdb_path,
+                SizeReportGrouping::Module,
+                SizeReportOrdering::Total,
+                SizeReportFormat::Csv,
+                Some(output_path.clone()),
             )
+            .is_ok());
+
+        // Check output file's content
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        let mut lines = output.lines();
+        assert_eq!(
+            lines.next(),
+            Some("name,code_size,data_size,total_size,symbol_count,section_size")
         );
+        assert!(lines.next().is_some());
     }
 
-    // List modules
+    // Inlines
     #[test]
-    fn list_modules_command_invalid_pdb_path() {
+    fn inlines_command_invalid_pdb_path() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path = PathBuf::new();
         // The command should fail
         assert!(app
-            .list_modules_command(pdb_path, "*".to_string(), false, false, None)
+            .inlines_command(pdb_path, "main".to_string(), false, None)
             .is_err());
     }
 
     #[test]
-    fn list_modules_command_stdio_successful() {
+    fn inlines_command_stdio_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        // The command should succeed
+        assert!(app
+            .inlines_command(pdb_path, "main".to_string(), true, None)
+            .is_ok());
+    }
+
+    #[test]
+    fn inlines_command_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let tmp_dir =
+            TempDir::new("inlines_command_file_successful").expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.txt");
+        // The command should succeed
+        assert!(app
+            .inlines_command(
+                pdb_path,
+                "resym_test::UnionTest::UnionTest".to_string(),
+                false,
+                Some(output_path.clone()),
+            )
+            .is_ok());
+
+        // Check output file's content
+        // Note: the test PDB comes from a debug build, nothing gets inlined
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert_eq!(output, "");
+    }
+
+    #[test]
+    fn inlines_command_call_sites_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let pdb_path =
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_INLINE_SITES_PDB_FILE_PATH);
+        let tmp_dir =
+            TempDir::new("inlines_command_call_sites_successful").expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.txt");
         // The command should succeed
         assert!(app
-            .list_modules_command(pdb_path, "*".to_string(), true, true, None)
+            .inlines_command(
+                pdb_path,
+                "resym_test::Square".to_string(),
+                false,
+                Some(output_path.clone()),
+            )
             .is_ok());
+
+        // Check output file's content
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert_eq!(output, "main\nCompute\n");
     }
 
     #[test]
-    fn list_modules_command_file_successful() {
+    fn inlines_command_inlinees_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let pdb_path =
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_INLINE_SITES_PDB_FILE_PATH);
         let tmp_dir =
-            TempDir::new("list_modules_command_file_successful").expect("TempDir creation failed");
+            TempDir::new("inlines_command_inlinees_successful").expect("TempDir creation failed");
         let output_path = tmp_dir.path().join("output.txt");
         // The command should succeed
         assert!(app
-            .list_modules_command(
+            .inlines_command(
                 pdb_path,
-                "*".to_string(),
-                false,
-                false,
-                Some(output_path.clone()),
+                "main".to_string(),
+                true,
+                Some(output_path.clone())
             )
             .is_ok());
 
         // Check output file's content
         let output = fs::read_to_string(output_path).expect("Failed to read output file");
-        assert_eq!(
-            output,
-            concat!(
-                "Mod 0048 | '* Linker Generated Manifest RES *'\n",
-                "Mod 0053 | '* Linker *'\n"
-            )
-        );
+        assert_eq!(output, "resym_test::Square\nClamp\n");
     }
 
-    // Dump module
+    // Type graph
     #[test]
-    fn dump_module_command_invalid_pdb_path() {
+    fn type_graph_command_invalid_pdb_path() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path = PathBuf::new();
         // The command should fail
         assert!(app
-            .dump_module_command(
-                pdb_path,
-                9, // exe_main.obj
-                PrimitiveReconstructionFlavor::Microsoft,
-                false,
-                false,
-                false,
-                None
-            )
+            .type_graph_command(pdb_path, TypeGraphQuery::Cycles { type_name: None }, None)
             .is_err());
     }
 
     #[test]
-    fn dump_module_command_stdio_successful() {
+    fn type_graph_command_invalid_type_name() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
-        // The command should succeed
+        // The command should fail
         assert!(app
-            .dump_module_command(
+            .type_graph_command(
                 pdb_path,
-                9, // exe_main.obj
-                PrimitiveReconstructionFlavor::Microsoft,
-                true,
-                true,
-                true,
+                TypeGraphQuery::Dependencies {
+                    type_name: "resym_test::DoesNotExist".to_string(),
+                    max_depth: None,
+                },
                 None
             )
-            .is_ok());
+            .is_err());
     }
 
     #[test]
-    fn dump_module_command_file_successful() {
+    fn type_graph_command_file_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
         let tmp_dir =
-            TempDir::new("dump_module_command_file_successful").expect("TempDir creation failed");
+            TempDir::new("type_graph_command_file_successful").expect("TempDir creation failed");
         let output_path = tmp_dir.path().join("output.txt");
         // The command should succeed
         assert!(app
-            .dump_module_command(
+            .type_graph_command(
                 pdb_path,
-                27, // default_local_stdio_options.obj
-                PrimitiveReconstructionFlavor::Portable,
-                false,
-                false,
-                false,
+                TypeGraphQuery::Path {
+                    from_type_name: "resym_test::SpecializedInterfaceImplClass".to_string(),
+                    to_type_name: "resym_test::PureVirtualClass".to_string(),
+                    follow_pointers: false,
+                },
                 Some(output_path.clone()),
             )
             .is_ok());
@@ -1046,32 +2226,24 @@ mod tests {
         assert_eq!(
             output,
             concat!(
-                "using namespace std;\n",
-                "using PUWSTR_C = const wchar_t*;\n",
-                "using TP_CALLBACK_ENVIRON_V3 = _TP_CALLBACK_ENVIRON_V3;\n",
-                "uint64_t* (__local_stdio_scanf_options)(); // RVA=0x14670 CodeSize=0x8\n",
-                "static uint64_t _OptionsStorage; // RVA=0x1eb28 \n",
-                "void (__scrt_initialize_default_local_stdio_options)(); // RVA=0x14680 CodeSize=0x45\n",
+                "resym_test::SpecializedInterfaceImplClass -> ",
+                "resym_test::PureVirtualClassSpecialized -> resym_test::PureVirtualClass\n"
             )
         );
     }
 
-    // Diff module
+    // Graph
     #[test]
-    fn diff_module_command_invalid_pdb_path() {
+    fn graph_command_invalid_pdb_path() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path_from = PathBuf::new();
-        let pdb_path_to = PathBuf::new();
-
+        let pdb_path = PathBuf::new();
         // The command should fail
         assert!(app
-            .diff_module_command(
-                pdb_path_from,
-                pdb_path_to,
-                "d:\\a01\\_work\\43\\s\\Intermediate\\vctools\\msvcrt.nativeproj_607447030\\objd\\amd64\\exe_main.obj".to_string(),
-                PrimitiveReconstructionFlavor::Microsoft,
-                false,
-                false,
+            .graph_command(
+                pdb_path,
+                "resym_test::StructTest".to_string(),
+                TypeGraphFormat::Dot,
+                None,
                 false,
                 None
             )
@@ -1079,45 +2251,57 @@ mod tests {
     }
 
     #[test]
-    fn diff_module_command_stdio_successful() {
+    fn graph_command_file_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path_from = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
-        let pdb_path_to = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
-
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        let tmp_dir =
+            TempDir::new("graph_command_file_successful").expect("TempDir creation failed");
+        let output_path = tmp_dir.path().join("output.mmd");
         // The command should succeed
         assert!(app
-            .diff_module_command(
-                pdb_path_from,
-                pdb_path_to,
-                "d:\\a01\\_work\\43\\s\\Intermediate\\vctools\\msvcrt.nativeproj_607447030\\objd\\amd64\\exe_main.obj".to_string(),
-                PrimitiveReconstructionFlavor::Microsoft,
+            .graph_command(
+                pdb_path,
+                "resym_test::SpecializedInterfaceImplClass".to_string(),
+                TypeGraphFormat::Mermaid,
+                Some(1),
                 true,
-                true,true,
-                None
+                Some(output_path.clone()),
             )
             .is_ok());
+
+        // Check output file's content
+        let output = fs::read_to_string(output_path).expect("Failed to read output file");
+        assert!(output.starts_with(concat!("flowchart LR\n", "    t",)));
+        assert!(output.contains("[\"resym_test::PureVirtualClassSpecialized\"]\n"));
+        // The base class is linked with a bold arrow, and its own base class
+        // is left out
+        assert!(output.contains(" ==> "));
+        assert!(!output.contains("[\"resym_test::PureVirtualClass\"]"));
     }
 
+    // Hierarchy
     #[test]
-    fn diff_module_command_file_successful() {
+    fn hierarchy_command_invalid_pdb_path() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path_from = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
-        let pdb_path_to = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
+        let pdb_path = PathBuf::new();
+        // The command should fail
+        assert!(app
+            .hierarchy_command(pdb_path, "resym_test::PureVirtualClass".to_string(), None)
+            .is_err());
+    }
 
+    #[test]
+    fn hierarchy_command_file_successful() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
         let tmp_dir =
-            TempDir::new("diff_module_command_file_successful").expect("TempDir creation failed");
+            TempDir::new("hierarchy_command_file_successful").expect("TempDir creation failed");
         let output_path = tmp_dir.path().join("output.txt");
-
         // The command should succeed
         assert!(app
-            .diff_module_command(
-                pdb_path_from,
-                pdb_path_to,
-                "d:\\a01\\_work\\43\\s\\Intermediate\\vctools\\msvcrt.nativeproj_607447030\\objd\\amd64\\default_local_stdio_options.obj".to_string(),
-                PrimitiveReconstructionFlavor::Portable,
-                false,
-                false,
-                false,
+            .hierarchy_command(
+                pdb_path,
+                "resym_test::PureVirtualClassSpecialized".to_string(),
                 Some(output_path.clone()),
             )
             .is_ok());
@@ -1127,204 +2311,159 @@ mod tests {
         assert_eq!(
             output,
             concat!(
-                " using namespace std;\n",
-                " using PUWSTR_C = const wchar_t*;\n",
-                " using TP_CALLBACK_ENVIRON_V3 = _TP_CALLBACK_ENVIRON_V3;\n",
-                " uint64_t* (__local_stdio_scanf_options)(); // RVA=0x13c30 CodeSize=0x8\n",
-                " static uint64_t _OptionsStorage; // RVA=0x1c898 \n",
-                " void (__scrt_initialize_default_local_stdio_options)(); // RVA=0x13c40 CodeSize=0x45\n",
+                "resym_test::PureVirtualClassSpecialized\n",
+                "Base classes:\n",
+                "  resym_test::PureVirtualClass\n",
+                "Derived classes:\n",
+                "  resym_test::SpecializedInterfaceImplClass\n",
             )
         );
     }
 
-    // List symbols
+    // Find field
     #[test]
-    fn list_symbols_command_invalid_pdb_path() {
+    fn find_field_command_invalid_pdb_path() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path = PathBuf::new();
         // The command should fail
         assert!(app
-            .list_symbols_command(pdb_path, "*".to_string(), false, false, false, None)
+            .find_field_command(
+                pdb_path,
+                FieldSearchQuery::default(),
+                FilterOptions::new(),
+                None
+            )
             .is_err());
     }
 
     #[test]
-    fn list_symbols_command_stdio_successful() {
-        let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
-        // The command should succeed
-        assert!(app
-            .list_symbols_command(pdb_path, "*".to_string(), true, true, true, None)
-            .is_ok());
-    }
-
-    #[test]
-    fn list_symbols_command_file_successful() {
+    fn find_field_command_file_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
         let tmp_dir =
-            TempDir::new("list_modules_command_file_successful").expect("TempDir creation failed");
+            TempDir::new("find_field_command_file_successful").expect("TempDir creation failed");
         let output_path = tmp_dir.path().join("output.txt");
         // The command should succeed
         assert!(app
-            .list_symbols_command(
+            .find_field_command(
                 pdb_path,
-                "resym_test::UnionTest".to_string(),
-                false,
-                false,
-                false,
+                FieldSearchQuery {
+                    field_name_filter: "^u4$".to_string(),
+                    field_type_filter: "uint64_t".to_string(),
+                    offset: Some(0x10),
+                },
+                FilterOptions::new().use_regex(true),
                 Some(output_path.clone()),
             )
             .is_ok());
 
         // Check output file's content
         let output = fs::read_to_string(output_path).expect("Failed to read output file");
-        assert_eq!(
-            output,
-            concat!(
-                "resym_test::UnionTest::UnionTest\n",
-                "resym_test::UnionTest::~UnionTest\n"
-            )
-        );
+        assert_eq!(output, "resym_test::StructTest::u4 (+0x10): uint64_t\n");
     }
 
-    // Dump symbol
+    // Match layout
     #[test]
-    fn dump_symbol_command_invalid_pdb_path() {
+    fn match_layout_command_invalid_pdb_path() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path = PathBuf::new();
         // The command should fail
         assert!(app
-            .dump_symbol_command(
+            .match_layout_command(
                 pdb_path,
-                Some("??1UnionTest@resym_test@@QEAA@XZ".to_string()),
-                PrimitiveReconstructionFlavor::Microsoft,
-                false,
-                false,
-                false,
+                LayoutQuery {
+                    size: Some(0x18),
+                    fields: vec![],
+                },
+                20,
                 None
             )
             .is_err());
     }
 
     #[test]
-    fn dump_symbol_command_stdio_successful() {
+    fn match_layout_command_empty_query() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
-        // The command should succeed
+        // The command should fail
         assert!(app
-            .dump_symbol_command(
-                pdb_path,
-                Some("??1UnionTest@resym_test@@QEAA@XZ".to_string()),
-                PrimitiveReconstructionFlavor::Microsoft,
-                true,
-                true,
-                true,
-                None
-            )
-            .is_ok());
+            .match_layout_command(pdb_path, LayoutQuery::default(), 20, None)
+            .is_err());
     }
 
     #[test]
-    fn dump_symbol_command_file_successful() {
+    fn match_layout_command_file_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
         let pdb_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
         let tmp_dir =
-            TempDir::new("dump_module_command_file_successful").expect("TempDir creation failed");
+            TempDir::new("match_layout_command_file_successful").expect("TempDir creation failed");
         let output_path = tmp_dir.path().join("output.txt");
         // The command should succeed
         assert!(app
-            .dump_symbol_command(
+            .match_layout_command(
                 pdb_path,
-                Some("??1UnionTest@resym_test@@QEAA@XZ".to_string()),
-                PrimitiveReconstructionFlavor::Portable,
-                false,
-                false,
-                false,
+                LayoutQuery {
+                    size: Some(0x18),
+                    fields: vec![
+                        "0x8:unsigned char".parse().expect("valid constraint"),
+                        "0xa:uint16_t".parse().expect("valid constraint"),
+                        "0x10:uint64_t".parse().expect("valid constraint"),
+                    ],
+                },
+                1,
                 Some(output_path.clone()),
             )
             .is_ok());
 
         // Check output file's content
         let output = fs::read_to_string(output_path).expect("Failed to read output file");
-        assert_eq!(
-            output,
-            "__cdecl resym_test::UnionTest::~UnionTest(void); // RVA=0x11bc0 ",
-        );
+        assert_eq!(output, "[4/4] resym_test::StructTest (size: 0x18)\n");
     }
 
-    // Diff symbol
+    // Find PDB
     #[test]
-    fn diff_symbol_command_invalid_pdb_path() {
+    fn find_pdb_command_invalid_pe_path() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path_from = PathBuf::new();
-        let pdb_path_to = PathBuf::new();
-
+        let pe_path = PathBuf::new();
         // The command should fail
-        assert!(app
-            .diff_symbol_command(
-                pdb_path_from,
-                pdb_path_to,
-                "?_RTC_GetSrcLine@@YAHPEAEPEA_WKPEAH1K@Z".to_string(),
-                PrimitiveReconstructionFlavor::Microsoft,
-                false,
-                false,
-                false,
-                None
-            )
-            .is_err());
+        assert!(app.find_pdb_command(pe_path, Some(String::new())).is_err());
     }
 
     #[test]
-    fn diff_symbol_command_stdio_successful() {
+    fn find_pdb_command_invalid_pe_file() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path_from = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
-        let pdb_path_to = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
+        let pe_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        // The command should fail, PDB files aren't PE images
+        assert!(app.find_pdb_command(pe_path, Some(String::new())).is_err());
+    }
 
-        // The command should succeed
+    #[test]
+    fn find_pdb_command_invalid_symbol_path() {
+        let app = ResymcApp::new().expect("ResymcApp creation failed");
+        let pe_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FILE_PATH);
+        // The command should fail
         assert!(app
-            .diff_symbol_command(
-                pdb_path_from,
-                pdb_path_to,
-                "?_RTC_GetSrcLine@@YAHPEAEPEA_WKPEAH1K@Z".to_string(),
-                PrimitiveReconstructionFlavor::Microsoft,
-                true,
-                true,
-                true,
-                None
-            )
-            .is_ok());
+            .find_pdb_command(pe_path, Some("srv*".to_string()))
+            .is_err());
     }
 
+    // Clear cache
     #[test]
-    fn diff_symbol_command_file_successful() {
+    fn clear_cache_command_successful() {
         let app = ResymcApp::new().expect("ResymcApp creation failed");
-        let pdb_path_from = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_FROM_FILE_PATH);
-        let pdb_path_to = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TEST_PDB_TO_FILE_PATH);
-
         let tmp_dir =
-            TempDir::new("diff_module_command_file_successful").expect("TempDir creation failed");
-        let output_path = tmp_dir.path().join("output.txt");
+            TempDir::new("clear_cache_command_successful").expect("TempDir creation failed");
+        let cache_entry_path = tmp_dir.path().join("0123456789ABCDEF0123456789ABCDEF1.idx");
+        let other_file_path = tmp_dir.path().join("other.txt");
+        fs::write(&cache_entry_path, b"").expect("Failed to write cache entry");
+        fs::write(&other_file_path, b"").expect("Failed to write file");
 
         // The command should succeed
         assert!(app
-            .diff_symbol_command(
-                pdb_path_from,
-                pdb_path_to,
-                "?_RTC_GetSrcLine@@YAHPEAEPEA_WKPEAH1K@Z".to_string(),
-                PrimitiveReconstructionFlavor::Portable,
-                false,
-                false,
-                false,
-                Some(output_path.clone()),
-            )
+            .clear_cache_command(Some(tmp_dir.path().to_path_buf()))
             .is_ok());
-
-        // Check output file's content
-        let output = fs::read_to_string(output_path).expect("Failed to read output file");
-        assert_eq!(
-            output,
-            " int __cdecl _RTC_GetSrcLine(unsigned char *, wchar_t *, unsigned long, int *, wchar_t *, unsigned long); // RVA=0x14c90 \n",
-        );
+        // Only cache entries should have been removed
+        assert!(!cache_entry_path.exists());
+        assert!(other_file_path.exists());
     }
 }
@@ -1,6 +1,11 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, str::FromStr};
 
-use resym_core::pdb_types::PrimitiveReconstructionFlavor;
+use anyhow::anyhow;
+use resym_core::{
+    field_search::parse_field_offset, layout_search::FieldConstraint,
+    pdb_types::PrimitiveReconstructionFlavor, size_report::SizeReportGrouping,
+    type_graph::TypeGraphFormat,
+};
 use structopt::StructOpt;
 
 const PKG_NAME: &str = env!("CARGO_PKG_NAME");
@@ -58,6 +63,9 @@ pub enum ResymcOptions {
         /// Highlight C++ output
         #[structopt(short = "H", long)]
         highlight_syntax: bool,
+        /// Path to a notes file, whose notes are printed as comments
+        #[structopt(short = "n", long = "notes")]
+        notes_file_path: Option<PathBuf>,
     },
     /// Dump all types from a given PDB file
     DumpAll {
@@ -83,6 +91,9 @@ pub enum ResymcOptions {
         /// Highlight C++ output
         #[structopt(short = "H", long)]
         highlight_syntax: bool,
+        /// Path to a notes file, whose notes are printed as comments
+        #[structopt(short = "n", long = "notes")]
+        notes_file_path: Option<PathBuf>,
     },
     /// Compute diff for a type between two given PDB files
     Diff {
@@ -213,6 +224,9 @@ pub enum ResymcOptions {
         /// Highlight C++ output
         #[structopt(short = "H", long)]
         highlight_syntax: bool,
+        /// Path to the PE image matching the PDB file
+        #[structopt(short = "p", long = "pe")]
+        pe_path: Option<PathBuf>,
     },
     /// Dump all symbols from a given PDB file
     DumpAllSymbols {
@@ -232,6 +246,9 @@ pub enum ResymcOptions {
         /// Highlight C++ output
         #[structopt(short = "H", long)]
         highlight_syntax: bool,
+        /// Path to the PE image matching the PDB file
+        #[structopt(short = "p", long = "pe")]
+        pe_path: Option<PathBuf>,
     },
     /// Compute diff for a symbol between two given PDB files
     DiffSymbol {
@@ -256,4 +273,219 @@ pub enum ResymcOptions {
         #[structopt(short = "H", long)]
         highlight_syntax: bool,
     },
+    /// Report code and data sizes from a given PDB file
+    SizeReport {
+        /// Path to the PDB file
+        pdb_path: PathBuf,
+        /// Path of the output file
+        output_file_path: Option<PathBuf>,
+        /// Aggregate sizes by `module`, `source-dir`, `namespace` or `template`
+        #[structopt(short = "g", long, default_value = "module")]
+        group_by: SizeReportGrouping,
+        /// Sort entries by `name`, `code`, `data`, `total` or `count`
+        #[structopt(short = "S", long, default_value = "total")]
+        sort_by: SizeReportOrdering,
+        /// Output format: `table`, `csv` or `json`
+        #[structopt(short = "F", long = "format", default_value = "table")]
+        output_format: SizeReportFormat,
+    },
+    /// List the locations at which a function has been inlined
+    Inlines {
+        /// Path to the PDB file
+        pdb_path: PathBuf,
+        /// Name of the function
+        function_name: String,
+        /// Path of the output file
+        output_file_path: Option<PathBuf>,
+        /// List the functions inlined into the function instead
+        #[structopt(short = "I", long)]
+        inlinees: bool,
+    },
+    /// List the types needed by a given type, transitively
+    Dependencies {
+        /// Path to the PDB file
+        pdb_path: PathBuf,
+        /// Name of the type
+        type_name: String,
+        /// Path of the output file
+        output_file_path: Option<PathBuf>,
+        /// Maximum number of levels to go through
+        #[structopt(short = "d", long)]
+        max_depth: Option<usize>,
+    },
+    /// List the types that need a given type, transitively
+    Dependents {
+        /// Path to the PDB file
+        pdb_path: PathBuf,
+        /// Name of the type
+        type_name: String,
+        /// Path of the output file
+        output_file_path: Option<PathBuf>,
+        /// Maximum number of levels to go through
+        #[structopt(short = "d", long)]
+        max_depth: Option<usize>,
+    },
+    /// Find the shortest chain of types through which a type contains another
+    TypePath {
+        /// Path to the PDB file
+        pdb_path: PathBuf,
+        /// Name of the containing type
+        from_type_name: String,
+        /// Name of the contained type
+        to_type_name: String,
+        /// Path of the output file
+        output_file_path: Option<PathBuf>,
+        /// Also follow pointers and references
+        #[structopt(short = "p", long)]
+        follow_pointers: bool,
+    },
+    /// List the cycles in the type dependency graph
+    TypeCycles {
+        /// Path to the PDB file
+        pdb_path: PathBuf,
+        /// Path of the output file
+        output_file_path: Option<PathBuf>,
+        /// Only list the cycle going through the given type
+        #[structopt(short = "t", long)]
+        type_name: Option<String>,
+    },
+    /// Export the graph of the types needed by a given type (DOT or Mermaid)
+    Graph {
+        /// Path to the PDB file
+        pdb_path: PathBuf,
+        /// Name of the type
+        type_name: String,
+        /// Path of the output file
+        output_file_path: Option<PathBuf>,
+        /// Output format: `dot` or `mermaid`
+        #[structopt(short = "f", long, default_value = "dot")]
+        format: TypeGraphFormat,
+        /// Maximum number of levels to go through
+        #[structopt(short = "d", long)]
+        max_depth: Option<usize>,
+        /// Filter out types in the `std` namespace
+        #[structopt(short = "s", long)]
+        ignore_std_types: bool,
+    },
+    /// List the base classes of a class and the classes that derive from it
+    Hierarchy {
+        /// Path to the PDB file
+        pdb_path: PathBuf,
+        /// Name of the class
+        type_name: String,
+        /// Path of the output file
+        output_file_path: Option<PathBuf>,
+    },
+    /// Search the fields of all types from a given PDB file, by name, type
+    /// and/or offset
+    FindField {
+        /// Path to the PDB file
+        pdb_path: PathBuf,
+        /// Search filter applied to field names (empty to match any name)
+        field_name_filter: String,
+        /// Path of the output file
+        output_file_path: Option<PathBuf>,
+        /// Search filter applied to the fields' type names
+        #[structopt(short = "t", long)]
+        field_type: Option<String>,
+        /// Offset of the fields in their type (decimal or `0x`-prefixed
+        /// hexadecimal)
+        #[structopt(short = "o", long, parse(try_from_str = parse_field_offset))]
+        offset: Option<u64>,
+        /// Do not match case
+        #[structopt(short = "i", long)]
+        case_insensitive: bool,
+        /// Use regular expressions
+        #[structopt(short = "r", long)]
+        use_regex: bool,
+        /// Filter out types in the `std` namespace
+        #[structopt(short = "s", long)]
+        ignore_std_types: bool,
+    },
+    /// Search the types of a given PDB file by layout, ranked by the number of
+    /// constraints they satisfy
+    MatchLayout {
+        /// Path to the PDB file
+        pdb_path: PathBuf,
+        /// Path of the output file
+        output_file_path: Option<PathBuf>,
+        /// Size of the type (decimal or `0x`-prefixed hexadecimal)
+        #[structopt(short = "z", long, parse(try_from_str = parse_field_offset))]
+        size: Option<u64>,
+        /// Field expected in the type, as `<offset>[:<type>]`, where the type
+        /// is `ptr` (any pointer) or a type name (e.g., `0x10:uint32_t`).
+        /// Can be repeated.
+        #[structopt(short = "f", long = "field")]
+        fields: Vec<FieldConstraint>,
+        /// Maximum number of types to list
+        #[structopt(short = "n", long, default_value = "20")]
+        max_results: usize,
+    },
+    /// Locate (and download if needed) the PDB file matching a PE image
+    FindPdb {
+        /// Path to the PE image
+        pe_path: PathBuf,
+        /// `_NT_SYMBOL_PATH`-style symbol search path (defaults to the value
+        /// of `_NT_SYMBOL_PATH`)
+        #[structopt(short = "s", long)]
+        symbol_path: Option<String>,
+    },
+    /// Remove all entries from the on-disk PDB index cache
+    ClearCache {
+        /// Path of the cache directory (defaults to the value of
+        /// `RESYM_INDEX_CACHE_DIR` or to the platform's cache directory)
+        #[structopt(short = "d", long)]
+        cache_dir: Option<PathBuf>,
+    },
+}
+
+/// Key used to sort entries of size reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SizeReportOrdering {
+    /// Alphabetical order
+    Name,
+    /// Bigger code sizes first
+    Code,
+    /// Bigger data sizes first
+    Data,
+    /// Bigger total sizes first
+    Total,
+    /// Bigger symbol counts first
+    Count,
+}
+
+impl FromStr for SizeReportOrdering {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "name" => Ok(SizeReportOrdering::Name),
+            "code" => Ok(SizeReportOrdering::Code),
+            "data" => Ok(SizeReportOrdering::Data),
+            "total" | "size" => Ok(SizeReportOrdering::Total),
+            "count" => Ok(SizeReportOrdering::Count),
+            _ => Err(anyhow!("invalid sort key: {s}")),
+        }
+    }
+}
+
+/// Output format of size reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SizeReportFormat {
+    Table,
+    Csv,
+    Json,
+}
+
+impl FromStr for SizeReportFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "table" => Ok(SizeReportFormat::Table),
+            "csv" => Ok(SizeReportFormat::Csv),
+            "json" => Ok(SizeReportFormat::Json),
+            _ => Err(anyhow!("invalid output format: {s}")),
+        }
+    }
 }